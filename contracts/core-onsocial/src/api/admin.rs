@@ -1,10 +1,17 @@
 use crate::{
-    SocialError,
+    Action, Options, Request, SocialError,
     config::{ContractInfo, GovernanceConfig},
     constants,
-    events::{EventBatch, EventBuilder},
-    state::{ContractStatus, models::SocialPlatform},
+    events::{
+        EventBatch, EventBuilder, EventConfig, EventEncoding, borsh as event_borsh,
+        config as event_config,
+    },
+    state::{
+        ContractStatus,
+        models::{SocialPlatform, SocialPlatformV1},
+    },
 };
+use near_sdk::borsh::BorshDeserialize;
 use near_sdk::json_types::Base58CryptoHash;
 use near_sdk::{AccountId, Gas, NearToken, Promise, env, near, serde_json::Value};
 
@@ -12,6 +19,15 @@ use crate::api::guards::ContractGuards;
 
 use crate::{Contract, ContractExt};
 
+impl Contract {
+    fn manager_event_path() -> String {
+        format!(
+            "{}/contract/manager",
+            SocialPlatform::platform_pool_account().as_str()
+        )
+    }
+}
+
 const GAS_MIGRATE: Gas = Gas::from_tgas(200);
 
 #[near]
@@ -35,6 +51,75 @@ impl Contract {
         crate::status::resume_live(&mut self.platform)
     }
 
+    /// Manager-only `set` that is allowed while the contract is read-only,
+    /// so data can be repaired without resuming live. The manager may write
+    /// `target_account`'s data paths without a permission grant; reserved
+    /// paths, storage and permission operations keep their normal checks. A
+    /// `maintenance_set` event records the target and the keys it touched.
+    #[payable]
+    #[handle_result]
+    pub fn set_maintenance(
+        &mut self,
+        target_account: AccountId,
+        data: Value,
+        options: Option<Options>,
+    ) -> Result<Value, SocialError> {
+        self.platform.require_manager()?;
+        if self.platform.status == ContractStatus::Genesis {
+            return Err(SocialError::ContractReadOnly);
+        }
+
+        let keys: Vec<String> = data
+            .as_object()
+            .map(|obj| obj.keys().cloned().collect())
+            .unwrap_or_default();
+        self.platform.maintenance_write = true;
+        let result = self.platform.execute(Request {
+            target_account: Some(target_account.clone()),
+            action: Action::Set { data },
+            options,
+        });
+        self.platform.maintenance_write = false;
+        let result = result?;
+
+        let path = format!(
+            "{}/contract/maintenance",
+            SocialPlatform::platform_pool_account().as_str()
+        );
+        let mut batch = EventBatch::new();
+        EventBuilder::new(
+            constants::EVENT_TYPE_CONTRACT_UPDATE,
+            "maintenance_set",
+            SocialPlatform::current_caller(),
+        )
+        .with_path(&path)
+        .with_target(&target_account)
+        .with_field("status", format!("{:?}", self.platform.status))
+        .with_field("keys", keys)
+        .emit(&mut batch);
+        batch.emit()?;
+
+        Ok(result)
+    }
+
+    /// Manager-only backfill of the member index behind `get_group_members`
+    /// for groups whose members joined before it was kept. Returns how many
+    /// of `member_ids` were newly indexed.
+    #[payable]
+    #[handle_result]
+    pub fn index_group_members(
+        &mut self,
+        group_id: String,
+        member_ids: Vec<AccountId>,
+    ) -> Result<u32, SocialError> {
+        ContractGuards::require_manager_one_yocto(&self.platform)?;
+        crate::domain::groups::core::GroupStorage::index_members(
+            &mut self.platform,
+            &group_id,
+            &member_ids,
+        )
+    }
+
     #[payable]
     #[handle_result]
     pub fn activate_contract(&mut self) -> Result<bool, SocialError> {
@@ -57,6 +142,7 @@ impl Contract {
     pub fn get_contract_info(&self) -> ContractInfo {
         ContractInfo {
             manager: self.platform.manager.clone(),
+            pending_manager: self.platform.pending_manager.clone(),
             version: self.get_version(),
             status: self.get_contract_status(),
             config: self.get_config(),
@@ -114,18 +200,15 @@ impl Contract {
 
         let old_manager = self.platform.manager.clone();
         self.platform.manager = new_manager.clone();
+        self.platform.pending_manager = None;
 
         let mut batch = EventBatch::new();
-        let path = format!(
-            "{}/contract/manager",
-            SocialPlatform::platform_pool_account().as_str()
-        );
         EventBuilder::new(
             constants::EVENT_TYPE_CONTRACT_UPDATE,
             "update_manager",
             caller,
         )
-        .with_path(&path)
+        .with_path(&Self::manager_event_path())
         .with_field("old_manager", old_manager.as_str())
         .with_field("new_manager", new_manager.as_str())
         .emit(&mut batch);
@@ -134,6 +217,164 @@ impl Contract {
         Ok(())
     }
 
+    /// First step of a two-step manager transfer; `new_manager` must call `accept_manager`.
+    #[payable]
+    #[handle_result]
+    pub fn propose_manager(&mut self, new_manager: AccountId) -> Result<(), SocialError> {
+        ContractGuards::require_live_state(&self.platform)?;
+        ContractGuards::require_manager_one_yocto(&self.platform)?;
+        let caller = SocialPlatform::current_caller();
+
+        if new_manager == self.platform.manager {
+            return Err(crate::invalid_input!(
+                "Proposed manager must differ from current manager"
+            ));
+        }
+        self.platform.pending_manager = Some(new_manager.clone());
+
+        let mut batch = EventBatch::new();
+        EventBuilder::new(
+            constants::EVENT_TYPE_CONTRACT_UPDATE,
+            "propose_manager",
+            caller,
+        )
+        .with_path(&Self::manager_event_path())
+        .with_field("old_manager", self.platform.manager.as_str())
+        .with_field("pending_manager", new_manager.as_str())
+        .emit(&mut batch);
+        batch.emit()?;
+
+        Ok(())
+    }
+
+    #[payable]
+    #[handle_result]
+    pub fn accept_manager(&mut self) -> Result<(), SocialError> {
+        ContractGuards::require_live_state(&self.platform)?;
+        if env::attached_deposit().as_yoctonear() != 1 {
+            return Err(crate::invalid_input!(
+                "Requires attached deposit of exactly 1 yoctoNEAR"
+            ));
+        }
+        let caller = SocialPlatform::current_caller();
+        if self.platform.pending_manager.as_ref() != Some(&caller) {
+            return Err(crate::unauthorized!("accept_manager", caller.to_string()));
+        }
+
+        let old_manager = std::mem::replace(&mut self.platform.manager, caller.clone());
+        self.platform.pending_manager = None;
+
+        let mut batch = EventBatch::new();
+        EventBuilder::new(
+            constants::EVENT_TYPE_CONTRACT_UPDATE,
+            "accept_manager",
+            caller.clone(),
+        )
+        .with_path(&Self::manager_event_path())
+        .with_field("old_manager", old_manager.as_str())
+        .with_field("new_manager", caller.as_str())
+        .emit(&mut batch);
+        batch.emit()?;
+
+        Ok(())
+    }
+
+    #[payable]
+    #[handle_result]
+    pub fn cancel_manager_transfer(&mut self) -> Result<(), SocialError> {
+        ContractGuards::require_live_state(&self.platform)?;
+        ContractGuards::require_manager_one_yocto(&self.platform)?;
+        let caller = SocialPlatform::current_caller();
+
+        let pending = self
+            .platform
+            .pending_manager
+            .take()
+            .ok_or_else(|| crate::invalid_input!("No pending manager transfer"))?;
+
+        let mut batch = EventBatch::new();
+        EventBuilder::new(
+            constants::EVENT_TYPE_CONTRACT_UPDATE,
+            "cancel_manager_transfer",
+            caller,
+        )
+        .with_path(&Self::manager_event_path())
+        .with_field("pending_manager", pending.as_str())
+        .emit(&mut batch);
+        batch.emit()?;
+
+        Ok(())
+    }
+
+    /// Selects JSON and/or borsh event logs. The change event is emitted
+    /// under the new encoding so indexers see the switch in the format they
+    /// must follow.
+    #[payable]
+    #[handle_result]
+    pub fn set_event_encoding(&mut self, encoding: EventEncoding) -> Result<(), SocialError> {
+        ContractGuards::require_manager_one_yocto(&self.platform)?;
+        let previous = event_borsh::read_event_encoding();
+        event_borsh::write_event_encoding(encoding);
+
+        let caller = SocialPlatform::current_caller();
+        let mut batch = EventBatch::new();
+        EventBuilder::new(
+            constants::EVENT_TYPE_CONTRACT_UPDATE,
+            "event_encoding_set",
+            caller,
+        )
+        .with_field(
+            "old_encoding",
+            near_sdk::serde_json::to_value(previous).unwrap_or_default(),
+        )
+        .with_field(
+            "new_encoding",
+            near_sdk::serde_json::to_value(encoding).unwrap_or_default(),
+        )
+        .emit(&mut batch);
+        batch.emit()?;
+
+        Ok(())
+    }
+
+    pub fn get_event_encoding(&self) -> EventEncoding {
+        event_borsh::read_event_encoding()
+    }
+
+    /// Sets deployment-level event options such as consolidated
+    /// `DATA_UPDATE` events for batched sets.
+    #[payable]
+    #[handle_result]
+    pub fn set_event_config(&mut self, config: EventConfig) -> Result<(), SocialError> {
+        ContractGuards::require_manager_one_yocto(&self.platform)?;
+        let previous = event_config::read_event_config();
+        event_config::write_event_config(config);
+
+        let caller = SocialPlatform::current_caller();
+        let mut batch = EventBatch::new();
+        EventBuilder::new(
+            constants::EVENT_TYPE_CONTRACT_UPDATE,
+            "event_config_set",
+            caller,
+        )
+        .with_field(
+            "old_config",
+            near_sdk::serde_json::to_value(previous).unwrap_or_default(),
+        )
+        .with_field(
+            "new_config",
+            near_sdk::serde_json::to_value(config).unwrap_or_default(),
+        )
+        .emit(&mut batch);
+        batch.emit()?;
+
+        Ok(())
+    }
+
+    pub fn get_event_config(&self) -> EventConfig {
+        event_config::read_event_config()
+    }
+
     #[handle_result]
     pub fn update_contract(&self) -> Result<Promise, SocialError> {
         self.platform.require_manager()?;
@@ -169,7 +410,14 @@ impl Contract {
     #[private]
     #[init(ignore_state)]
     pub fn migrate() -> Self {
-        let mut contract: Self = env::state_read().expect("State read failed");
+        let raw = env::storage_read(b"STATE").expect("State read failed");
+        let mut contract: Self = Self::try_from_slice(&raw)
+            .or_else(|_| {
+                SocialPlatformV1::try_from_slice(&raw).map(|platform| Self {
+                    platform: platform.into(),
+                })
+            })
+            .expect("State read failed");
         let old_version = contract.platform.version.clone();
         contract.platform.version = env!("CARGO_PKG_VERSION").to_string();
 
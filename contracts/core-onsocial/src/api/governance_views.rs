@@ -24,6 +24,20 @@ impl Contract {
         self.platform.storage_get(&vote_path)
     }
 
+    pub fn get_vote_delegation(&self, group_id: String, account_id: AccountId) -> Option<Value> {
+        let delegation_path = format!("groups/{}/delegations/{}", group_id, account_id);
+        self.platform.storage_get(&delegation_path)
+    }
+
+    /// Direct delegators of `member`; indirect chains are not expanded.
+    pub fn get_vote_delegations(&self, group_id: String, member: AccountId) -> Vec<AccountId> {
+        crate::domain::groups::governance::GroupGovernance::get_active_delegators(
+            &self.platform,
+            &group_id,
+            &member,
+        )
+    }
+
     /// Resolve sequence number (1-based) to full proposal via the on-chain index.
     pub fn get_proposal_by_sequence(
         &self,
@@ -17,6 +17,24 @@ impl Contract {
         )
     }
 
+    /// Paged member records; `role_filter` is one of owner, admin, moderator
+    /// or member. `limit` is capped at 50.
+    pub fn get_group_members(
+        &self,
+        group_id: String,
+        from_index: u64,
+        limit: u64,
+        role_filter: Option<String>,
+    ) -> Vec<Value> {
+        crate::domain::groups::core::GroupStorage::list_members(
+            &self.platform,
+            &group_id,
+            from_index as usize,
+            limit.min(50) as usize,
+            role_filter.as_deref(),
+        )
+    }
+
     pub fn is_group_member(&self, group_id: String, member_id: AccountId) -> bool {
         crate::domain::groups::core::GroupStorage::is_member(&self.platform, &group_id, &member_id)
     }
@@ -41,6 +59,34 @@ impl Contract {
         )
     }
 
+    /// Group content created between `start_ts` (inclusive) and `end_ts`
+    /// (exclusive), in block-time milliseconds, oldest first. `limit` is
+    /// capped at 50; continue from the last `created_at`.
+    ///
+    /// For private groups the list is only returned when `viewer_id` names a
+    /// member. `viewer_id` is supplied by the caller and not verified, and
+    /// contract state is readable over RPC, so this is a listing filter for
+    /// clients, not access control: private group content is not protected
+    /// from view reads.
+    #[handle_result]
+    pub fn get_group_content(
+        &self,
+        group_id: String,
+        start_ts: Option<u64>,
+        end_ts: Option<u64>,
+        limit: u64,
+        viewer_id: Option<AccountId>,
+    ) -> Result<Vec<Value>, crate::SocialError> {
+        crate::domain::groups::GroupContentManager::list_content(
+            &self.platform,
+            &group_id,
+            start_ts,
+            end_ts,
+            limit.min(50) as usize,
+            viewer_id.as_ref(),
+        )
+    }
+
     pub fn get_group_stats(&self, group_id: String) -> Option<Value> {
         crate::domain::groups::core::GroupStorage::get_group_stats(&self.platform, &group_id)
     }
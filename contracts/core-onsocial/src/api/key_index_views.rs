@@ -1,4 +1,5 @@
-use near_sdk::near;
+use near_sdk::serde_json::Value;
+use near_sdk::{AccountId, near};
 
 use crate::state::key_index::KeyEntry;
 use crate::{Contract, ContractExt};
@@ -22,7 +23,32 @@ impl Contract {
         )
     }
 
+    /// Page through everything stored under `account_id/` for backups and
+    /// migrations. Returns `(entries, next_key)`; pass `next_key` back as
+    /// `from_key` until it is null. Limit capped at 50.
+    pub fn export_account(
+        &self,
+        account_id: AccountId,
+        from_key: Option<String>,
+        limit: u32,
+    ) -> (Vec<(String, Value)>, Option<String>) {
+        self.platform
+            .export_account(&account_id, from_key.as_deref(), limit)
+    }
+
     pub fn count_keys(&self, prefix: String) -> u32 {
         self.platform.count_keys(&prefix)
     }
+
+    /// Paths written with `options.index_references` whose value named
+    /// `path` as `parent`, oldest first. Limit capped at 50.
+    pub fn get_referencers(
+        &self,
+        path: String,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Vec<String> {
+        self.platform
+            .get_referencers(&path, offset.unwrap_or(0), limit.unwrap_or(20).min(50))
+    }
 }
@@ -1,5 +1,5 @@
-use crate::{EntryView, PlatformPoolInfo, state::models::SocialPlatform};
-use near_sdk::{AccountId, near, serde_json::Value};
+use crate::{EntryView, PlatformPoolInfo, SocialError, state::models::SocialPlatform};
+use near_sdk::{AccountId, json_types::U128, near, serde_json::Value};
 
 use crate::{Contract, ContractExt};
 
@@ -9,6 +9,15 @@ impl Contract {
         self.platform.get(keys, account_id)
     }
 
+    pub fn get_many(
+        &self,
+        requests: Vec<(AccountId, Vec<String>)>,
+        include_metadata: Option<bool>,
+    ) -> std::collections::HashMap<String, Value> {
+        self.platform
+            .get_many(requests, include_metadata.unwrap_or(false))
+    }
+
     pub fn get_one(&self, key: String, account_id: Option<AccountId>) -> EntryView {
         self.platform.get_one(key, account_id)
     }
@@ -74,6 +83,96 @@ impl Contract {
         }))
     }
 
+    /// Sponsorship terms and pool balance for an app registered via
+    /// `storage/app_pool_register`. Balance fields are zero until funded.
+    pub fn get_storage_pool(&self, app_id: AccountId) -> Option<Value> {
+        let app_pool = self.platform.app_storage_pools.get(&app_id)?;
+        let pool = self
+            .platform
+            .shared_storage_pools
+            .get(&app_id)
+            .cloned()
+            .unwrap_or_default();
+
+        let total_capacity_u128 =
+            pool.storage_balance / near_sdk::env::storage_byte_cost().as_yoctonear();
+        let total_capacity = u64::try_from(total_capacity_u128).unwrap_or(u64::MAX);
+
+        Some(serde_json::json!({
+            "app_id": app_id.to_string(),
+            "max_bytes_per_user": app_pool.max_bytes_per_user,
+            "storage_balance": pool.storage_balance.to_string(),
+            "used_bytes": pool.used_bytes,
+            "shared_bytes": pool.shared_bytes,
+            "available_bytes": pool.available_bytes(),
+            "total_capacity_bytes": total_capacity
+        }))
+    }
+
+    /// Upper bound on the deposit a write of `value` at `path` would charge.
+    /// Group paths resolve to the author's copy, so `account_id` is required for them.
+    #[handle_result]
+    pub fn estimate_write_cost(
+        &self,
+        path: String,
+        value: Value,
+        account_id: Option<AccountId>,
+    ) -> Result<U128, SocialError> {
+        let full_path = estimate_full_path(&path, account_id.as_ref())?;
+        let bytes = self.platform.estimate_write_bytes(&full_path, &value)?;
+        Ok(U128(crate::storage::calculate_storage_balance_needed(
+            bytes,
+        )))
+    }
+
+    /// Storage effect of `set(data)` by `account_id`, without writing.
+    /// `required_deposit` is what the account's balance can't cover once the
+    /// batch lands; pool sponsorship is ignored, so it is an upper bound.
+    #[handle_result]
+    pub fn estimate_set_cost(
+        &self,
+        account_id: AccountId,
+        data: Value,
+    ) -> Result<Value, SocialError> {
+        let data_obj = crate::protocol::operation::require_non_empty_object(&data)?;
+
+        let mut added_bytes: u64 = 0;
+        let mut freed_bytes: u64 = 0;
+        for (key, value) in data_obj {
+            let crate::protocol::operation::ApiOperationKey::DataPath(path) =
+                crate::protocol::operation::classify_api_operation_key(key)?
+            else {
+                return Err(crate::invalid_input!("Only data paths can be estimated"));
+            };
+            let full_path = estimate_full_path(path, Some(&account_id))?;
+            let (added, freed) = self.platform.estimate_write_delta(&full_path, value)?;
+            added_bytes = added_bytes.saturating_add(added);
+            freed_bytes = freed_bytes.saturating_add(freed);
+        }
+
+        let storage = self
+            .platform
+            .user_storage
+            .get(&account_id)
+            .cloned()
+            .unwrap_or_default();
+        let used_after = storage
+            .used_bytes
+            .saturating_add(added_bytes)
+            .saturating_sub(freed_bytes);
+        let needed_after = crate::storage::calculate_storage_balance_needed(
+            crate::storage::calculate_effective_bytes(used_after, storage.covered_bytes()),
+        );
+        let required_deposit = needed_after.saturating_sub(storage.available_balance());
+
+        Ok(serde_json::json!({
+            "added_bytes": added_bytes,
+            "freed_bytes": freed_bytes,
+            "net_bytes": added_bytes as i64 - freed_bytes as i64,
+            "required_deposit": U128(required_deposit),
+        }))
+    }
+
     pub fn get_platform_allowance(&self, account_id: AccountId) -> Value {
         let storage = self.platform.user_storage.get(&account_id);
         let config = &self.platform.config;
@@ -95,4 +194,34 @@ impl Contract {
             }
         })
     }
+
+    /// Per-account storage caps alongside the account's current usage.
+    pub fn get_account_storage_cap(&self, account_id: AccountId) -> Value {
+        let storage = self.platform.user_storage.get(&account_id);
+        let config = &self.platform.config;
+
+        let (used, sponsored, self_paid) = storage
+            .map(|s| (s.used_bytes, s.covered_bytes(), s.self_paid_bytes()))
+            .unwrap_or((0, 0, 0));
+
+        serde_json::json!({
+            "used_bytes": used,
+            "sponsored_bytes": sponsored,
+            "self_paid_bytes": self_paid,
+            "max_sponsored_bytes": config.max_sponsored_bytes_per_account,
+            "max_self_paid_bytes": config.max_self_paid_bytes_per_account
+        })
+    }
+}
+
+/// Group paths resolve to the author's copy, so they need `account_id`.
+fn estimate_full_path(path: &str, account_id: Option<&AccountId>) -> Result<String, SocialError> {
+    let full_path = crate::validation::resolve_view_key(path, account_id)
+        .ok_or_else(|| crate::invalid_input!("Invalid path format"))?;
+    if full_path.starts_with("groups/") {
+        let author =
+            account_id.ok_or_else(|| crate::invalid_input!("Group paths need account_id"))?;
+        return Ok(format!("{}/{}", author, full_path));
+    }
+    Ok(full_path)
 }
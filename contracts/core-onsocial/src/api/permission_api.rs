@@ -1,4 +1,4 @@
-use near_sdk::{AccountId, PublicKey, near};
+use near_sdk::{AccountId, PublicKey, env, near};
 
 use crate::{Contract, ContractExt};
 
@@ -21,6 +21,38 @@ impl Contract {
         )
     }
 
+    /// One result per path, in order. Account paths are always granted when
+    /// `owner == grantee`; group paths are evaluated against the group.
+    pub fn has_any_permission(
+        &self,
+        owner: AccountId,
+        grantee: AccountId,
+        paths: Vec<String>,
+        permission_flags: u8,
+    ) -> Vec<bool> {
+        if paths.len() > crate::constants::MAX_PERMISSION_CHECK_PATHS {
+            env::panic_str(&format!(
+                "Too many paths: {} (max {})",
+                paths.len(),
+                crate::constants::MAX_PERMISSION_CHECK_PATHS
+            ));
+        }
+        let self_owned = owner == grantee;
+        paths
+            .iter()
+            .map(|path| {
+                (self_owned && crate::storage::utils::extract_group_id_from_path(path).is_none())
+                    || crate::domain::groups::permissions::kv::has_permissions(
+                        &self.platform,
+                        owner.as_str(),
+                        grantee.as_str(),
+                        path,
+                        permission_flags,
+                    )
+            })
+            .collect()
+    }
+
     pub fn get_permissions(&self, owner: AccountId, grantee: AccountId, path: String) -> u8 {
         crate::domain::groups::permissions::kv::get_user_permissions(
             &self.platform,
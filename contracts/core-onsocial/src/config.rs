@@ -5,7 +5,7 @@ use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::AccountId;
 
 use crate::constants::{
-    MIN_PLATFORM_ALLOWANCE_MAX_BYTES, MIN_PLATFORM_DAILY_REFILL_BYTES,
+    DEFAULT_RESERVED_PREFIXES, MIN_PLATFORM_ALLOWANCE_MAX_BYTES, MIN_PLATFORM_DAILY_REFILL_BYTES,
     MIN_PLATFORM_ONBOARDING_BYTES,
 };
 use crate::state::ContractStatus;
@@ -17,6 +17,7 @@ use crate::state::ContractStatus;
 #[serde(crate = "near_sdk::serde")]
 pub struct ContractInfo {
     pub manager: AccountId,
+    pub pending_manager: Option<AccountId>,
     pub version: String,
     pub status: ContractStatus,
     pub config: GovernanceConfig,
@@ -33,6 +34,12 @@ pub struct ConfigUpdate {
     pub platform_onboarding_bytes: Option<u64>,
     pub platform_daily_refill_bytes: Option<u64>,
     pub platform_allowance_max_bytes: Option<u64>,
+    /// `Some(0)` removes the cap.
+    pub max_sponsored_bytes_per_account: Option<u64>,
+    /// `Some(0)` removes the cap.
+    pub max_self_paid_bytes_per_account: Option<u64>,
+    /// Replaces the reserved list; every current entry must be kept.
+    pub reserved_prefixes: Option<Vec<String>>,
 }
 
 #[derive(
@@ -60,6 +67,17 @@ pub struct GovernanceConfig {
     pub platform_daily_refill_bytes: u64,
     #[serde(default = "default_platform_allowance_max_bytes")]
     pub platform_allowance_max_bytes: u64,
+
+    /// Per-account cap on bytes covered by platform, group, or shared pools.
+    #[serde(default)]
+    pub max_sponsored_bytes_per_account: Option<u64>,
+    /// Per-account cap on bytes paid from the account's own balance.
+    #[serde(default)]
+    pub max_self_paid_bytes_per_account: Option<u64>,
+
+    /// Path prefixes only the manager may write through `set`.
+    #[serde(default = "default_reserved_prefixes")]
+    pub reserved_prefixes: Vec<String>,
 }
 
 fn default_platform_onboarding_bytes() -> u64 {
@@ -71,6 +89,12 @@ fn default_platform_daily_refill_bytes() -> u64 {
 fn default_platform_allowance_max_bytes() -> u64 {
     MIN_PLATFORM_ALLOWANCE_MAX_BYTES
 }
+fn default_reserved_prefixes() -> Vec<String> {
+    DEFAULT_RESERVED_PREFIXES
+        .iter()
+        .map(|p| p.to_string())
+        .collect()
+}
 
 impl Default for GovernanceConfig {
     fn default() -> Self {
@@ -82,6 +106,38 @@ impl Default for GovernanceConfig {
             platform_onboarding_bytes: MIN_PLATFORM_ONBOARDING_BYTES,
             platform_daily_refill_bytes: MIN_PLATFORM_DAILY_REFILL_BYTES,
             platform_allowance_max_bytes: MIN_PLATFORM_ALLOWANCE_MAX_BYTES,
+            max_sponsored_bytes_per_account: None,
+            max_self_paid_bytes_per_account: None,
+            reserved_prefixes: default_reserved_prefixes(),
+        }
+    }
+}
+
+/// `GovernanceConfig` as persisted by the first deployment; read only by `migrate`.
+#[derive(BorshDeserialize)]
+pub(crate) struct GovernanceConfigV1 {
+    max_key_length: u16,
+    max_path_depth: u16,
+    max_batch_size: u16,
+    max_value_bytes: u32,
+    platform_onboarding_bytes: u64,
+    platform_daily_refill_bytes: u64,
+    platform_allowance_max_bytes: u64,
+}
+
+impl From<GovernanceConfigV1> for GovernanceConfig {
+    fn from(v1: GovernanceConfigV1) -> Self {
+        Self {
+            max_key_length: v1.max_key_length,
+            max_path_depth: v1.max_path_depth,
+            max_batch_size: v1.max_batch_size,
+            max_value_bytes: v1.max_value_bytes,
+            platform_onboarding_bytes: v1.platform_onboarding_bytes,
+            platform_daily_refill_bytes: v1.platform_daily_refill_bytes,
+            platform_allowance_max_bytes: v1.platform_allowance_max_bytes,
+            max_sponsored_bytes_per_account: None,
+            max_self_paid_bytes_per_account: None,
+            reserved_prefixes: default_reserved_prefixes(),
         }
     }
 }
@@ -121,6 +177,17 @@ impl GovernanceConfig {
                 return Err("platform_allowance_max_bytes cannot be below minimum");
             }
         }
+        if let Some(prefixes) = &patch.reserved_prefixes {
+            if !self.reserved_prefixes.iter().all(|p| prefixes.contains(p)) {
+                return Err("Reserved prefixes can only be extended");
+            }
+            if !prefixes
+                .iter()
+                .all(|p| crate::validation::is_safe_path(p.trim_end_matches('/')))
+            {
+                return Err("Invalid reserved prefix");
+            }
+        }
 
         Ok(())
     }
@@ -147,5 +214,14 @@ impl GovernanceConfig {
         if let Some(v) = patch.platform_allowance_max_bytes {
             self.platform_allowance_max_bytes = v;
         }
+        if let Some(v) = patch.max_sponsored_bytes_per_account {
+            self.max_sponsored_bytes_per_account = (v > 0).then_some(v);
+        }
+        if let Some(v) = patch.max_self_paid_bytes_per_account {
+            self.max_self_paid_bytes_per_account = (v > 0).then_some(v);
+        }
+        if let Some(v) = &patch.reserved_prefixes {
+            self.reserved_prefixes = v.clone();
+        }
     }
 }
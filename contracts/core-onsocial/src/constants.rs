@@ -17,6 +17,43 @@ pub const MIN_PLATFORM_ONBOARDING_BYTES: u64 = 6_000;
 pub const MIN_PLATFORM_DAILY_REFILL_BYTES: u64 = 3_000;
 /// Minimum platform allowance max bytes (6 KB).
 pub const MIN_PLATFORM_ALLOWANCE_MAX_BYTES: u64 = 6_000;
+/// Protocol overhead charged per new storage record (`storage_num_extra_bytes_record`).
+pub const STORAGE_RECORD_OVERHEAD_BYTES: u64 = 40;
+
+// --- Views ---
+
+/// Total keys across all accounts in one `get_many` call.
+pub const MAX_GET_MANY_KEYS: usize = 100;
+
+/// Paths checked in one `has_any_permission` call.
+pub const MAX_PERMISSION_CHECK_PATHS: usize = 50;
+
+/// Referencers kept per parent path; later referencers are not indexed.
+pub const MAX_REFERENCERS_PER_PATH: usize = 100;
+
+/// App pools that may sponsor one account at a time.
+pub const MAX_APP_POOLS_PER_ACCOUNT: usize = 8;
+
+/// Accounts checked in one `index_group_members` call.
+pub const MAX_MEMBER_INDEX_BATCH: usize = 100;
+
+/// Group internals that `set` may not write. `*` matches one path segment.
+pub const DEFAULT_RESERVED_PREFIXES: &[&str] = &[
+    "groups/*/config",
+    "groups/*/members",
+    "groups/*/member_nonces",
+    "groups/*/blacklist",
+    "groups/*/join_requests",
+    "groups/*/permissions",
+    "groups/*/stats",
+    "groups/*/proposals",
+    "groups/*/proposal_counter",
+    "groups/*/proposal_index",
+    "groups/*/votes",
+    "groups/*/delegations",
+    "groups/*/delegators",
+    "groups/*/executions",
+];
 
 // --- Key Formats ---
 
@@ -25,6 +62,11 @@ pub const SHARED_STORAGE_PATH_SUFFIX: &str = "/shared_storage";
 /// Group pool key prefix: `group-{group_id}.pool`.
 pub const GROUP_POOL_PREFIX: &str = "group-";
 pub const GROUP_POOL_SUFFIX: &str = ".pool";
+/// Group pool usage, in bps of capacity, that triggers a `storage_warning`.
+pub const DEFAULT_GROUP_STORAGE_WARNING_BPS: u16 = 9_000; // 90%
+/// Raw storage key prefix for the per-group "warning sent" flag; kept outside
+/// the borsh state like `EVENT_ENCODING_STORAGE_KEY`.
+pub const GROUP_STORAGE_WARNED_KEY_PREFIX: &str = "gsw:";
 
 // --- Partitioning ---
 
@@ -36,6 +78,11 @@ pub const NUM_PARTITIONS: u16 = 4096;
 pub const EVENT_STANDARD: &str = "onsocial";
 pub const EVENT_VERSION: &str = "1.0.0";
 pub const EVENT_JSON_PREFIX: &str = "EVENT_JSON:";
+pub const EVENT_BORSH_PREFIX: &str = "EVENT_BORSH:";
+/// Raw storage key for the deployment's `EventEncoding`.
+pub const EVENT_ENCODING_STORAGE_KEY: &[u8] = b"e";
+/// Raw storage key for the deployment's `EventConfig`.
+pub const EVENT_CONFIG_STORAGE_KEY: &[u8] = b"c";
 
 pub const EVENT_TYPE_DATA_UPDATE: &str = "DATA_UPDATE";
 pub const EVENT_TYPE_STORAGE_UPDATE: &str = "STORAGE_UPDATE";
@@ -57,6 +104,14 @@ pub const DEFAULT_VOTING_MAJORITY_THRESHOLD_BPS: u16 = 5_001; // 50.01%
 pub const MIN_VOTING_PARTICIPATION_QUORUM_BPS: u16 = 100; // 1%
 pub const MIN_VOTING_MAJORITY_THRESHOLD_BPS: u16 = 5_001; // >50%
 
+// --- Governance: Delegation ---
+
+/// Hops from a delegator to the voter whose ballot it follows; 0 disables delegation.
+pub const DEFAULT_MAX_DELEGATION_DEPTH: u8 = 2;
+pub const MAX_DELEGATION_DEPTH: u8 = 3;
+/// Bounds the per-vote fan-out when delegated ballots are cast.
+pub const MAX_DELEGATORS_PER_DELEGATE: usize = 20;
+
 // --- Governance: Proposals ---
 
 /// Minimum deposit to create a proposal (0.1 NEAR).
@@ -24,6 +24,7 @@ pub fn validate_cross_account_permissions_simple(
             ApiOperationKey::DataPath(path) => {
                 let path_obj = Path::new(target_account, path, platform)?;
                 let full_path = path_obj.full_path();
+                crate::validation::require_unreserved_path(full_path, actor_id, platform)?;
 
                 let is_group_path =
                     crate::storage::utils::extract_group_id_from_path(full_path).is_some();
@@ -50,7 +51,7 @@ pub fn validate_cross_account_permissions_simple(
                     )
                 };
 
-                if !can_write {
+                if !can_write && !platform.maintenance_write {
                     return Err(permission_denied!("write", full_path));
                 }
             }
@@ -59,6 +60,9 @@ pub fn validate_cross_account_permissions_simple(
             | ApiOperationKey::StorageDeposit
             | ApiOperationKey::StorageWithdraw
             | ApiOperationKey::StorageSharedPoolDeposit
+            | ApiOperationKey::StorageSharedPoolWithdraw
+            | ApiOperationKey::StorageAppPoolRegister
+            | ApiOperationKey::StorageAppPoolSponsor
             | ApiOperationKey::StoragePlatformPoolDeposit
             | ApiOperationKey::StorageGroupPoolDeposit
             | ApiOperationKey::StorageGroupSponsorQuotaSet
@@ -8,6 +8,8 @@ pub(crate) struct GroupConfig {
     pub owner: AccountId,
     pub member_driven: bool,
     pub is_private: Option<bool>,
+    /// Pool usage (bps of capacity) at which content writes warn the group.
+    pub storage_warning_bps: u16,
 }
 
 impl GroupConfig {
@@ -27,10 +29,17 @@ impl GroupConfig {
 
         let is_private = value.get("is_private").and_then(|v| v.as_bool());
 
+        let storage_warning_bps = value
+            .get("storage_warning_bps")
+            .and_then(|v| v.as_u64())
+            .and_then(|v| u16::try_from(v).ok())
+            .unwrap_or(crate::constants::DEFAULT_GROUP_STORAGE_WARNING_BPS);
+
         Ok(Self {
             owner,
             member_driven,
             is_private,
+            storage_warning_bps,
         })
     }
 }
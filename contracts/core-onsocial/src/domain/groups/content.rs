@@ -1,10 +1,10 @@
 use crate::SocialError;
 use crate::domain::groups::config::GroupConfig;
 use crate::events::{EventBatch, EventBuilder};
-use crate::state::models::{DataEntry, DataValue, SocialPlatform};
+use crate::state::models::{DataEntry, DataValue, SharedStoragePool, SocialPlatform};
 use crate::validation::validate_json_value_simple;
 use near_sdk::{
-    AccountId,
+    AccountId, env,
     serde_json::{self, Value},
 };
 
@@ -17,7 +17,7 @@ impl GroupContentManager {
         group_path: &str,
         content: &Value,
         author: &AccountId,
-        attached_balance: Option<&mut u128>,
+        mut attached_balance: Option<&mut u128>,
         event_batch: &mut EventBatch,
     ) -> Result<String, SocialError> {
         let info = crate::domain::groups::permissions::kv::classify_group_path(group_path)
@@ -34,7 +34,7 @@ impl GroupContentManager {
         let config = platform
             .storage_get(&config_path)
             .ok_or_else(|| crate::invalid_input!("Group does not exist"))?;
-        GroupConfig::try_from_value(&config)
+        let cfg = GroupConfig::try_from_value(&config)
             .map_err(|_| crate::invalid_input!("Group has no valid owner"))?;
 
         let can_write = crate::domain::groups::permissions::kv::can_write(
@@ -51,11 +51,23 @@ impl GroupContentManager {
 
         if content.is_null() {
             if let Some(entry) = platform.get_entry(&user_storage_path) {
+                platform.sync_reference_index(
+                    &user_storage_path,
+                    Some(&entry),
+                    content,
+                    attached_balance.as_deref_mut(),
+                )?;
                 let deleted =
                     crate::storage::soft_delete_entry(platform, &user_storage_path, entry)?;
 
                 if deleted {
                     platform.key_index_remove(&user_storage_path);
+                    Self::unindex_content(
+                        platform,
+                        group_id,
+                        &user_storage_path,
+                        attached_balance,
+                    )?;
                     EventBuilder::new(
                         crate::constants::EVENT_TYPE_GROUP_UPDATE,
                         "delete",
@@ -64,6 +76,7 @@ impl GroupContentManager {
                     .with_path(&user_storage_path)
                     .with_value(Value::Null)
                     .emit(event_batch);
+                    Self::check_storage_warning(platform, group_id, &cfg, event_batch);
                 }
             }
             return Ok(user_storage_path);
@@ -76,18 +89,31 @@ impl GroupContentManager {
         let serialized_content = serde_json::to_vec(content)
             .map_err(|e| crate::invalid_input!(format!("Failed to serialize content: {}", e)))?;
 
-        if serialized_content.len() > platform.config.max_value_bytes as usize {
-            return Err(crate::invalid_input!("Value payload too large"));
-        }
+        crate::validation::require_value_within_limit(
+            serialized_content.len(),
+            platform.config.max_value_bytes as usize,
+        )?;
 
         let data_entry = DataEntry {
             value: DataValue::Value(serialized_content),
             block_height: near_sdk::env::block_height(),
         };
 
-        let sponsor_outcome = platform
-            .insert_entry_with_fallback(&user_storage_path, data_entry, attached_balance)?
-            .1;
+        let (previous_entry, sponsor_outcome) = platform.insert_entry_with_fallback(
+            &user_storage_path,
+            data_entry,
+            attached_balance.as_deref_mut(),
+        )?;
+        platform.sync_reference_index(
+            &user_storage_path,
+            previous_entry.as_ref(),
+            content,
+            attached_balance.as_deref_mut(),
+        )?;
+        let was_live = previous_entry.is_some_and(|e| matches!(e.value, DataValue::Value(_)));
+        if !was_live {
+            Self::index_content(platform, group_id, &user_storage_path, attached_balance)?;
+        }
 
         platform.key_index_insert(&user_storage_path, near_sdk::env::block_height());
 
@@ -114,6 +140,7 @@ impl GroupContentManager {
 
             builder.emit(event_batch);
         }
+        Self::check_storage_warning(platform, group_id, &cfg, event_batch);
 
         let operation = if is_update { "update" } else { "create" };
         EventBuilder::new(
@@ -127,4 +154,141 @@ impl GroupContentManager {
 
         Ok(user_storage_path)
     }
+
+    /// Records `path` in the group's creation-time index, billed to its author.
+    fn index_content(
+        platform: &mut SocialPlatform,
+        group_id: &str,
+        path: &str,
+        attached_balance: Option<&mut u128>,
+    ) -> Result<(), SocialError> {
+        let created_ms = env::block_timestamp_ms();
+        platform.charge_index_update(path, attached_balance, |platform| {
+            platform
+                .group_content_index
+                .insert(content_index_key(group_id, created_ms, path), created_ms);
+            platform
+                .group_content_created
+                .insert(path.to_string(), created_ms);
+        })
+    }
+
+    fn unindex_content(
+        platform: &mut SocialPlatform,
+        group_id: &str,
+        path: &str,
+        attached_balance: Option<&mut u128>,
+    ) -> Result<(), SocialError> {
+        let Some(created_ms) = platform.group_content_created.get(path).copied() else {
+            return Ok(());
+        };
+        platform.charge_index_update(path, attached_balance, |platform| {
+            platform
+                .group_content_index
+                .remove(&content_index_key(group_id, created_ms, path));
+            platform.group_content_created.remove(path);
+        })
+    }
+
+    /// Live group content created in `[start_ms, end_ms)`, oldest first.
+    /// Private groups are only listed when the unverified `viewer_id` is a member.
+    pub fn list_content(
+        platform: &SocialPlatform,
+        group_id: &str,
+        start_ms: Option<u64>,
+        end_ms: Option<u64>,
+        limit: usize,
+        viewer_id: Option<&AccountId>,
+    ) -> Result<Vec<Value>, SocialError> {
+        let config = crate::domain::groups::GroupStorage::get_group_config(platform, group_id)
+            .ok_or_else(|| crate::invalid_input!("Group does not exist"))?;
+        let is_private = GroupConfig::try_from_value(&config)
+            .map(|cfg| cfg.is_private.unwrap_or(false))
+            .unwrap_or(false);
+        if is_private
+            && !viewer_id.is_some_and(|viewer| {
+                crate::domain::groups::GroupStorage::is_member(platform, group_id, viewer)
+            })
+        {
+            return Err(crate::permission_denied!(
+                "read",
+                format!("groups/{}", group_id)
+            ));
+        }
+
+        let start = format!("{}|{:020}|", group_id, start_ms.unwrap_or(0));
+        let end = format!("{}|{:020}|", group_id, end_ms.unwrap_or(u64::MAX));
+        if start >= end {
+            return Ok(vec![]);
+        }
+
+        Ok(platform
+            .group_content_index
+            .range(start..end)
+            .take(limit)
+            .filter_map(|(key, created_ms)| {
+                let path = key.splitn(3, '|').nth(2)?;
+                let value = platform.storage_get(path)?;
+                let author = path.split('/').next()?;
+                Some(serde_json::json!({
+                    "path": path,
+                    "author": author,
+                    "created_at": created_ms,
+                    "value": value,
+                }))
+            })
+            .collect())
+    }
+
+    /// Emits one `storage_warning` when the group pool crosses the config's
+    /// threshold; the flag clears once usage drops back below it.
+    fn check_storage_warning(
+        platform: &SocialPlatform,
+        group_id: &str,
+        cfg: &GroupConfig,
+        event_batch: &mut EventBatch,
+    ) {
+        let Some(pool) = SharedStoragePool::group_pool_key(group_id)
+            .ok()
+            .and_then(|key| platform.shared_storage_pools.get(&key))
+        else {
+            return;
+        };
+        let quota_bytes = pool.used_bytes.saturating_add(pool.available_bytes());
+        if quota_bytes == 0 {
+            return;
+        }
+
+        let crossed = u128::from(pool.used_bytes) * 10_000
+            >= u128::from(quota_bytes) * u128::from(cfg.storage_warning_bps);
+        let flag_key = format!(
+            "{}{}",
+            crate::constants::GROUP_STORAGE_WARNED_KEY_PREFIX,
+            group_id
+        );
+        let warned = env::storage_has_key(flag_key.as_bytes());
+
+        if crossed && !warned {
+            env::storage_write(flag_key.as_bytes(), &[1]);
+            EventBuilder::new(
+                crate::constants::EVENT_TYPE_GROUP_UPDATE,
+                "storage_warning",
+                cfg.owner.clone(),
+            )
+            .with_field("group_id", group_id)
+            .with_field("used_bytes", pool.used_bytes.to_string())
+            .with_field("quota_bytes", quota_bytes.to_string())
+            .with_field("remaining_bytes", pool.available_bytes().to_string())
+            .with_field("threshold_bps", cfg.storage_warning_bps)
+            .emit(event_batch);
+        } else if !crossed && warned {
+            env::storage_remove(flag_key.as_bytes());
+        }
+    }
+}
+
+/// `{group_id}|{created_ms}|{path}`; the zero-padded time keeps a group's
+/// entries in creation order.
+fn content_index_key(group_id: &str, created_ms: u64, path: &str) -> String {
+    format!("{}|{:020}|{}", group_id, created_ms, path)
 }
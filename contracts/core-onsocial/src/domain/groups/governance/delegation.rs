@@ -0,0 +1,338 @@
+use near_sdk::{
+    AccountId, env,
+    serde_json::{self, Value, json},
+};
+
+use crate::constants::{MAX_DELEGATION_DEPTH, MAX_DELEGATORS_PER_DELEGATE};
+use crate::domain::groups::GroupStorage;
+use crate::state::models::SocialPlatform;
+use crate::{SocialError, invalid_input, permission_denied};
+
+use super::events;
+use super::proposals::GroupGovernance;
+
+fn delegation_path(group_id: &str, delegator: &AccountId) -> String {
+    format!("groups/{}/delegations/{}", group_id, delegator)
+}
+
+fn delegators_path(group_id: &str, delegate: &AccountId) -> String {
+    format!("groups/{}/delegators/{}", group_id, delegate)
+}
+
+fn held_vote_path(group_id: &str, proposal_id: &str, delegator: &AccountId) -> String {
+    format!(
+        "groups/{}/held_votes/{}/{}",
+        group_id, proposal_id, delegator
+    )
+}
+
+impl GroupGovernance {
+    pub fn delegate_vote(
+        platform: &mut SocialPlatform,
+        group_id: &str,
+        delegator: &AccountId,
+        delegate: &AccountId,
+    ) -> Result<(), SocialError> {
+        if GroupStorage::get_group_config(platform, group_id).is_none() {
+            return Err(invalid_input!("Group not found"));
+        }
+        if delegator == delegate {
+            return Err(invalid_input!("Cannot delegate vote to yourself"));
+        }
+        if !Self::is_active_voter(platform, group_id, delegator) {
+            return Err(permission_denied!(
+                "delegate_vote",
+                "Only active members can delegate their vote"
+            ));
+        }
+        if !Self::is_active_voter(platform, group_id, delegate) {
+            return Err(invalid_input!("Delegate must be an active group member"));
+        }
+
+        let max_depth = Self::get_voting_config(platform, group_id).max_delegation_depth;
+        if max_depth == 0 {
+            return Err(invalid_input!("Vote delegation is disabled for this group"));
+        }
+
+        // Cycle invariant: the delegate's outgoing chain never reaches the delegator.
+        let mut downstream: u8 = 0;
+        let mut cursor = delegate.clone();
+        while let Some(next) = Self::get_delegate(platform, group_id, &cursor) {
+            if &next == delegator {
+                return Err(invalid_input!("Delegation would create a cycle"));
+            }
+            downstream = downstream.saturating_add(1);
+            if downstream > MAX_DELEGATION_DEPTH {
+                break;
+            }
+            cursor = next;
+        }
+
+        let upstream = Self::upstream_depth(platform, group_id, delegator, max_depth);
+        let chain = upstream.saturating_add(1).saturating_add(downstream);
+        if chain > max_depth {
+            return Err(invalid_input!(format!(
+                "Delegation chain of {} exceeds maximum depth of {}",
+                chain, max_depth
+            )));
+        }
+
+        let mut delegators = Self::get_delegators(platform, group_id, delegate);
+        if delegators.len() >= MAX_DELEGATORS_PER_DELEGATE {
+            return Err(invalid_input!(format!(
+                "Delegate already has the maximum of {} delegators",
+                MAX_DELEGATORS_PER_DELEGATE
+            )));
+        }
+
+        let previous = Self::get_delegate(platform, group_id, delegator);
+        if let Some(ref previous) = previous {
+            Self::remove_delegator(platform, group_id, previous, delegator)?;
+        }
+
+        let delegation_path = delegation_path(group_id, delegator);
+        let delegation_value = json!({
+            "delegate": delegate,
+            "delegated_at": env::block_timestamp().to_string()
+        });
+        platform.storage_set(&delegation_path, &delegation_value)?;
+
+        delegators.push(delegator.clone());
+        platform.storage_set(&delegators_path(group_id, delegate), &json!(delegators))?;
+
+        events::VoteDelegated {
+            delegator,
+            group_id,
+            delegate,
+            previous_delegate: previous.as_ref(),
+            delegation_path: &delegation_path,
+            delegation_value,
+        }
+        .emit()
+    }
+
+    pub fn undelegate_vote(
+        platform: &mut SocialPlatform,
+        group_id: &str,
+        delegator: &AccountId,
+    ) -> Result<(), SocialError> {
+        let delegation_path = delegation_path(group_id, delegator);
+        let delegate = Self::get_delegate(platform, group_id, delegator)
+            .ok_or_else(|| invalid_input!("No active vote delegation"))?;
+
+        if let Some(entry) = platform.get_entry(&delegation_path) {
+            crate::storage::soft_delete_entry(platform, &delegation_path, entry)?;
+        }
+        Self::remove_delegator(platform, group_id, &delegate, delegator)?;
+
+        events::VoteUndelegated {
+            delegator,
+            group_id,
+            delegate: &delegate,
+            delegation_path: &delegation_path,
+        }
+        .emit()
+    }
+
+    /// Counts the delegated weight `voter`'s ballot carries: every eligible
+    /// account whose chain reaches `voter` through members without a ballot.
+    /// Delegators who already voted keep their own ballot, and their own
+    /// delegators were counted when they voted.
+    ///
+    /// Each counted delegator is snapshotted against `voter`'s ballot, so later
+    /// delegation changes cannot count it a second time. Delegators already held
+    /// by another ballot stay with it, except those held by `reclaim`, the ballot
+    /// `voter` is overriding. Returns the weight and how much of it was reclaimed.
+    pub(super) fn claim_delegated_weight(
+        platform: &mut SocialPlatform,
+        group_id: &str,
+        proposal_id: &str,
+        voter: &AccountId,
+        max_depth: u8,
+        proposal_created_at: u64,
+        reclaim: Option<&AccountId>,
+    ) -> Result<(u64, u64), SocialError> {
+        let mut counted = Vec::new();
+        let mut reclaimed = 0;
+        let mut frontier = vec![voter.clone()];
+
+        for _ in 1..=max_depth.min(MAX_DELEGATION_DEPTH) {
+            let mut next_frontier = Vec::new();
+            for via in frontier {
+                for delegator in Self::get_delegators(platform, group_id, &via) {
+                    // Stale index entries are ignored; the delegation record is authoritative.
+                    if Self::get_delegate(platform, group_id, &delegator).as_ref() != Some(&via) {
+                        continue;
+                    }
+                    let vote_path =
+                        format!("groups/{}/votes/{}/{}", group_id, proposal_id, delegator);
+                    if platform.storage_get(&vote_path).is_some() {
+                        continue;
+                    }
+                    let holder = Self::holding_ballot(platform, group_id, proposal_id, &delegator);
+                    if holder.is_some() && holder.as_ref() != reclaim {
+                        continue;
+                    }
+                    if Self::is_eligible_voter(platform, group_id, &delegator, proposal_created_at)
+                    {
+                        reclaimed += u64::from(holder.is_some());
+                        counted.push(delegator.clone());
+                    }
+                    next_frontier.push(delegator);
+                }
+            }
+            if next_frontier.is_empty() {
+                break;
+            }
+            frontier = next_frontier;
+        }
+
+        for delegator in &counted {
+            platform.storage_set(
+                &held_vote_path(group_id, proposal_id, delegator),
+                &json!({ "ballot": voter }),
+            )?;
+        }
+        Ok((counted.len() as u64, reclaimed))
+    }
+
+    /// The ballot that counted `voter` as delegated weight, as snapshotted when
+    /// it was cast.
+    pub(super) fn covering_ballot(
+        platform: &SocialPlatform,
+        group_id: &str,
+        proposal_id: &str,
+        voter: &AccountId,
+    ) -> Option<(AccountId, String, Value)> {
+        let delegate = Self::holding_ballot(platform, group_id, proposal_id, voter)?;
+        let vote_path = format!("groups/{}/votes/{}/{}", group_id, proposal_id, delegate);
+        let ballot = platform.storage_get(&vote_path)?;
+        Some((delegate, vote_path, ballot))
+    }
+
+    /// Accounts whose delegation currently points directly at `delegate`.
+    pub fn get_active_delegators(
+        platform: &SocialPlatform,
+        group_id: &str,
+        delegate: &AccountId,
+    ) -> Vec<AccountId> {
+        Self::get_delegators(platform, group_id, delegate)
+            .into_iter()
+            .filter(|d| Self::get_delegate(platform, group_id, d).as_ref() == Some(delegate))
+            .collect()
+    }
+
+    fn is_active_voter(platform: &SocialPlatform, group_id: &str, account_id: &AccountId) -> bool {
+        (GroupStorage::is_member(platform, group_id, account_id)
+            || GroupStorage::is_owner(platform, group_id, account_id))
+            && !GroupStorage::is_blacklisted(platform, group_id, account_id)
+    }
+
+    /// Mirrors the direct-vote checks: members who joined after the proposal
+    /// was created are not counted.
+    fn is_eligible_voter(
+        platform: &SocialPlatform,
+        group_id: &str,
+        account_id: &AccountId,
+        proposal_created_at: u64,
+    ) -> bool {
+        if !Self::is_active_voter(platform, group_id, account_id) {
+            return false;
+        }
+        if GroupStorage::is_owner(platform, group_id, account_id) {
+            return true;
+        }
+        let joined_at = platform
+            .storage_get(&format!("groups/{}/members/{}", group_id, account_id))
+            .and_then(|m| {
+                m.get("joined_at")
+                    .and_then(|v| v.as_str())
+                    .and_then(|s| s.parse::<u64>().ok())
+            })
+            .unwrap_or(0);
+        joined_at <= proposal_created_at
+    }
+
+    fn holding_ballot(
+        platform: &SocialPlatform,
+        group_id: &str,
+        proposal_id: &str,
+        account_id: &AccountId,
+    ) -> Option<AccountId> {
+        platform
+            .storage_get(&held_vote_path(group_id, proposal_id, account_id))?
+            .get("ballot")?
+            .as_str()?
+            .parse()
+            .ok()
+    }
+
+    fn get_delegate(
+        platform: &SocialPlatform,
+        group_id: &str,
+        delegator: &AccountId,
+    ) -> Option<AccountId> {
+        Self::get_delegation(platform, group_id, delegator).map(|(delegate, _)| delegate)
+    }
+
+    /// `delegator`'s delegate and the block timestamp the link was made at.
+    fn get_delegation(
+        platform: &SocialPlatform,
+        group_id: &str,
+        delegator: &AccountId,
+    ) -> Option<(AccountId, u64)> {
+        let record = platform.storage_get(&delegation_path(group_id, delegator))?;
+        let delegate = record.get("delegate")?.as_str()?.parse().ok()?;
+        let delegated_at = record
+            .get("delegated_at")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0);
+        Some((delegate, delegated_at))
+    }
+
+    fn get_delegators(
+        platform: &SocialPlatform,
+        group_id: &str,
+        delegate: &AccountId,
+    ) -> Vec<AccountId> {
+        platform
+            .storage_get(&delegators_path(group_id, delegate))
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default()
+    }
+
+    fn remove_delegator(
+        platform: &mut SocialPlatform,
+        group_id: &str,
+        delegate: &AccountId,
+        delegator: &AccountId,
+    ) -> Result<(), SocialError> {
+        let mut delegators = Self::get_delegators(platform, group_id, delegate);
+        delegators.retain(|d| d != delegator);
+        platform.storage_set(&delegators_path(group_id, delegate), &json!(delegators))
+    }
+
+    /// Longest chain of delegators ending at `account_id`, bounded by `limit`.
+    fn upstream_depth(
+        platform: &SocialPlatform,
+        group_id: &str,
+        account_id: &AccountId,
+        limit: u8,
+    ) -> u8 {
+        let mut depth = 0;
+        let mut frontier = vec![account_id.clone()];
+        while depth < limit {
+            let next: Vec<AccountId> = frontier
+                .iter()
+                .flat_map(|a| Self::get_delegators(platform, group_id, a))
+                .collect();
+            if next.is_empty() {
+                break;
+            }
+            depth += 1;
+            frontier = next;
+        }
+        depth
+    }
+}
@@ -67,11 +67,21 @@ impl ProposalCreated<'_> {
     }
 }
 
+/// A delegate's ballot after a direct vote took back the share it counted.
+pub(super) struct OverriddenBallot {
+    pub delegate: AccountId,
+    pub vote_path: String,
+    pub vote_value: serde_json::Value,
+}
+
 pub(super) struct VoteCast<'a> {
     pub voter: &'a AccountId,
     pub group_id: &'a str,
     pub proposal_id: &'a str,
     pub approve: bool,
+    /// Votes of delegators without a ballot, counted on top of this one.
+    pub delegated_weight: u64,
+    pub overrides: Option<&'a OverriddenBallot>,
     pub tally: &'a VoteTally,
     pub should_execute: bool,
     pub should_reject: bool,
@@ -101,28 +111,92 @@ impl VoteCast<'_> {
         };
 
         let mut event_batch = EventBatch::new();
-        EventBuilder::new(EVENT_TYPE_GROUP_UPDATE, "vote_cast", self.voter.clone())
-            .with_field("group_id", self.group_id)
-            .with_field("proposal_id", self.proposal_id)
-            .with_field("voter", self.voter.as_str())
-            .with_field("approve", self.approve)
-            .with_field("total_votes", self.tally.total_votes)
-            .with_field("yes_votes", self.tally.yes_votes)
-            .with_field(
-                "no_votes",
-                self.tally.total_votes.saturating_sub(self.tally.yes_votes),
-            )
-            .with_field("locked_member_count", self.tally.locked_member_count)
-            .with_field("participation_bps", participation_bps)
-            .with_field("approval_bps", approval_bps)
-            .with_field("should_execute", self.should_execute)
-            .with_field("should_reject", self.should_reject)
-            .with_path(self.vote_path)
-            .with_value(self.vote_value.clone())
-            .with_field("tally_path", self.tally_path)
-            .with_write(self.tally_path, self.tally_value.clone())
-            .with_field("voted_at", env::block_timestamp().to_string())
-            .emit(&mut event_batch);
+        let mut builder =
+            EventBuilder::new(EVENT_TYPE_GROUP_UPDATE, "vote_cast", self.voter.clone())
+                .with_field("group_id", self.group_id)
+                .with_field("proposal_id", self.proposal_id)
+                .with_field("voter", self.voter.as_str())
+                .with_field("approve", self.approve)
+                .with_field("delegated_weight", self.delegated_weight)
+                .with_field("total_votes", self.tally.total_votes)
+                .with_field("yes_votes", self.tally.yes_votes)
+                .with_field(
+                    "no_votes",
+                    self.tally.total_votes.saturating_sub(self.tally.yes_votes),
+                )
+                .with_field("locked_member_count", self.tally.locked_member_count)
+                .with_field("participation_bps", participation_bps)
+                .with_field("approval_bps", approval_bps)
+                .with_field("should_execute", self.should_execute)
+                .with_field("should_reject", self.should_reject)
+                .with_path(self.vote_path)
+                .with_value(self.vote_value.clone())
+                .with_field("tally_path", self.tally_path)
+                .with_write(self.tally_path, self.tally_value.clone())
+                .with_field("voted_at", env::block_timestamp().to_string())
+                .with_field("overrides", self.overrides.map(|o| o.delegate.to_string()));
+        if let Some(overridden) = self.overrides {
+            builder = builder.with_write(&overridden.vote_path, overridden.vote_value.clone());
+        }
+        builder.emit(&mut event_batch);
+
+        event_batch.emit()
+    }
+}
+
+pub(super) struct VoteDelegated<'a> {
+    pub delegator: &'a AccountId,
+    pub group_id: &'a str,
+    pub delegate: &'a AccountId,
+    pub previous_delegate: Option<&'a AccountId>,
+    pub delegation_path: &'a str,
+    pub delegation_value: serde_json::Value,
+}
+
+impl VoteDelegated<'_> {
+    pub fn emit(&self) -> Result<(), SocialError> {
+        let mut event_batch = EventBatch::new();
+        EventBuilder::new(
+            EVENT_TYPE_GROUP_UPDATE,
+            "vote_delegated",
+            self.delegator.clone(),
+        )
+        .with_field("group_id", self.group_id)
+        .with_field("delegator", self.delegator.as_str())
+        .with_field("delegate", self.delegate.as_str())
+        .with_field(
+            "previous_delegate",
+            self.previous_delegate.map(|d| d.to_string()),
+        )
+        .with_path(self.delegation_path)
+        .with_value(self.delegation_value.clone())
+        .emit(&mut event_batch);
+
+        event_batch.emit()
+    }
+}
+
+pub(super) struct VoteUndelegated<'a> {
+    pub delegator: &'a AccountId,
+    pub group_id: &'a str,
+    pub delegate: &'a AccountId,
+    pub delegation_path: &'a str,
+}
+
+impl VoteUndelegated<'_> {
+    pub fn emit(&self) -> Result<(), SocialError> {
+        let mut event_batch = EventBatch::new();
+        EventBuilder::new(
+            EVENT_TYPE_GROUP_UPDATE,
+            "vote_undelegated",
+            self.delegator.clone(),
+        )
+        .with_field("group_id", self.group_id)
+        .with_field("delegator", self.delegator.as_str())
+        .with_field("delegate", self.delegate.as_str())
+        .with_path(self.delegation_path)
+        .with_value(serde_json::Value::Null)
+        .emit(&mut event_batch);
 
         event_batch.emit()
     }
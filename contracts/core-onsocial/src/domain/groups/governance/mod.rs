@@ -1,3 +1,4 @@
+mod delegation;
 mod events;
 mod proposals;
 mod status;
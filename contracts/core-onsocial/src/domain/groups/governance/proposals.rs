@@ -65,16 +65,29 @@ impl GroupGovernance {
         let mut tally = VoteTally::new(member_count);
 
         let mut auto_vote_data: Option<(String, near_sdk::serde_json::Value)> = None;
+        let mut delegated_weight = 0;
 
         if should_auto_vote {
-            tally.record_vote(true, None);
+            (delegated_weight, _) = Self::claim_delegated_weight(
+                platform,
+                group_id,
+                &proposal_id,
+                proposer,
+                voting_config.max_delegation_depth,
+                tally.created_at.0,
+                None,
+            )?;
+            tally.record_weighted_vote(true, delegated_weight);
             let proposer_vote_path =
                 format!("groups/{}/votes/{}/{}", group_id, proposal_id, proposer);
-            let proposer_vote_data = json!({
+            let mut proposer_vote_data = json!({
                 "voter": proposer,
                 "approve": true,
                 "timestamp": env::block_timestamp().to_string()
             });
+            if delegated_weight > 0 {
+                proposer_vote_data["delegated_weight"] = json!(delegated_weight);
+            }
 
             platform.storage_set(&proposer_vote_path, &proposer_vote_data)?;
             auto_vote_data = Some((proposer_vote_path, proposer_vote_data));
@@ -142,6 +155,8 @@ impl GroupGovernance {
                 group_id,
                 proposal_id: &proposal_id,
                 approve: true,
+                delegated_weight,
+                overrides: None,
                 tally: &tally,
                 should_execute,
                 should_reject: false,
@@ -234,23 +249,83 @@ impl GroupGovernance {
         if status != ProposalStatus::Active {
             return Err(invalid_input!("Only active proposals can be expired"));
         }
+        Self::require_voting_period_elapsed(&proposal_data)?;
+
+        Self::update_proposal_status(platform, group_id, proposal_id, ProposalStatus::Expired)?;
+
+        Ok(())
+    }
+
+    /// Permissionless: settles an Active proposal once its voting period has
+    /// elapsed. Below quorum it becomes RejectedQuorum; otherwise the tally
+    /// decides between execution and rejection. Returns the final status.
+    pub fn finalize_expired_proposal(
+        platform: &mut SocialPlatform,
+        group_id: &str,
+        proposal_id: &str,
+    ) -> Result<&'static str, SocialError> {
+        let proposal_path = format!("groups/{}/proposals/{}", group_id, proposal_id);
+        let tally_path = format!("groups/{}/votes/{}", group_id, proposal_id);
+
+        let proposal_data = platform
+            .storage_get(&proposal_path)
+            .ok_or_else(|| invalid_input!("Proposal not found"))?;
+
+        let status =
+            ProposalStatus::from_json_status(proposal_data.get("status").and_then(|v| v.as_str()))?;
+
+        if status != ProposalStatus::Active {
+            return Err(invalid_input!("Only active proposals can be finalized"));
+        }
+        Self::require_voting_period_elapsed(&proposal_data)?;
+
+        let tally: VoteTally = platform
+            .storage_get(&tally_path)
+            .and_then(|v| near_sdk::serde_json::from_value(v).ok())
+            .ok_or_else(|| invalid_input!("Vote tally not found"))?;
+        let voting_config = Self::parse_proposal_voting_config(&proposal_data)?;
+
+        let final_status = if !tally.meets_quorum(voting_config.participation_quorum_bps) {
+            Self::update_proposal_status(
+                platform,
+                group_id,
+                proposal_id,
+                ProposalStatus::RejectedQuorum,
+            )?;
+            ProposalStatus::RejectedQuorum
+        } else if tally.meets_thresholds(
+            voting_config.participation_quorum_bps,
+            voting_config.majority_threshold_bps,
+        ) {
+            Self::execute_passed_proposal(platform, group_id, proposal_id, &proposal_data)?
+        } else {
+            Self::update_proposal_status(
+                platform,
+                group_id,
+                proposal_id,
+                ProposalStatus::Rejected,
+            )?;
+            ProposalStatus::Rejected
+        };
+
+        Ok(final_status.as_str())
+    }
 
+    fn require_voting_period_elapsed(
+        proposal_data: &near_sdk::serde_json::Value,
+    ) -> Result<(), SocialError> {
         let created_at: u64 = proposal_data
             .get("created_at")
             .and_then(|v| v.as_str())
             .and_then(|s| s.parse::<u64>().ok())
             .ok_or_else(|| invalid_input!("Proposal missing created_at"))?;
 
-        let voting_config = Self::parse_proposal_voting_config(&proposal_data)?;
-        let voting_period = voting_config.voting_period.0;
-        let expires_at = created_at.saturating_add(voting_period);
+        let voting_config = Self::parse_proposal_voting_config(proposal_data)?;
+        let expires_at = created_at.saturating_add(voting_config.voting_period.0);
 
         if env::block_timestamp() <= expires_at {
             return Err(invalid_input!("Voting period has not elapsed"));
         }
-
-        Self::update_proposal_status(platform, group_id, proposal_id, ProposalStatus::Expired)?;
-
         Ok(())
     }
 
@@ -11,6 +11,8 @@ pub(super) enum ProposalStatus {
     Cancelled,
     /// Voting period elapsed without reaching quorum or inevitable defeat. Bond is released.
     Expired,
+    /// Voting period elapsed with participation below quorum. Bond is released.
+    RejectedQuorum,
 }
 
 impl ProposalStatus {
@@ -22,6 +24,7 @@ impl ProposalStatus {
             Self::Rejected => "rejected",
             Self::Cancelled => "cancelled",
             Self::Expired => "expired",
+            Self::RejectedQuorum => "rejected_quorum",
         }
     }
 
@@ -33,6 +36,7 @@ impl ProposalStatus {
             "rejected" => Some(Self::Rejected),
             "cancelled" => Some(Self::Cancelled),
             "expired" => Some(Self::Expired),
+            "rejected_quorum" => Some(Self::RejectedQuorum),
             _ => None,
         }
     }
@@ -106,13 +106,56 @@ impl GroupGovernance {
             return Err(invalid_input!("Voting period has expired"));
         }
 
-        tally.record_vote(approve, previous_vote);
+        let max_depth = voting_config.max_delegation_depth;
+        let created_at = tally.created_at.0;
+
+        // A direct vote replaces the share of a delegate's ballot that counted
+        // this voter and whichever of their delegators it covered.
+        let covering = Self::covering_ballot(platform, group_id, proposal_id, voter);
+        let (delegated_weight, reclaimed) = Self::claim_delegated_weight(
+            platform,
+            group_id,
+            proposal_id,
+            voter,
+            max_depth,
+            created_at,
+            covering.as_ref().map(|(delegate, _, _)| delegate),
+        )?;
+        let overridden = covering.map(|(delegate, ballot_path, mut ballot)| {
+            let counted = ballot
+                .get("delegated_weight")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0);
+            let share = reclaimed.saturating_add(1).min(counted);
+            let ballot_approve = ballot
+                .get("approve")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            tally.retract_weighted_vote(ballot_approve, share);
+            ballot["delegated_weight"] = json!(counted - share);
+            events::OverriddenBallot {
+                delegate,
+                vote_path: ballot_path,
+                vote_value: ballot,
+            }
+        });
+        if let Some(overridden) = &overridden {
+            platform.storage_set(&overridden.vote_path, &overridden.vote_value)?;
+        }
+
+        tally.record_weighted_vote(approve, delegated_weight);
 
-        let vote_data = json!({
+        let mut vote_data = json!({
             "voter": voter,
             "approve": approve,
             "timestamp": env::block_timestamp().to_string()
         });
+        if delegated_weight > 0 {
+            vote_data["delegated_weight"] = json!(delegated_weight);
+        }
+        if let Some(overridden) = &overridden {
+            vote_data["overrides"] = json!(overridden.delegate);
+        }
 
         platform.storage_set(&vote_path, &vote_data)?;
         let tally_value = json!(tally);
@@ -128,45 +171,7 @@ impl GroupGovernance {
         );
 
         if should_execute {
-            if let Some(proposal_type_val) = proposal_data.get("data") {
-                let proposal_type =
-                    serde_json::from_value::<ProposalType>(proposal_type_val.clone())
-                        .map_err(|_| invalid_input!("Failed to parse proposal type"))?;
-
-                let proposer = proposal_data
-                    .get("proposer")
-                    .and_then(|v| v.as_str())
-                    .and_then(|s| s.parse::<near_sdk::AccountId>().ok())
-                    .ok_or_else(|| invalid_input!("Proposal missing proposer"))?;
-
-                let prev_payer = platform.execution_payer.clone();
-                platform.set_execution_payer(proposer.clone());
-                let exec_result = proposal_type.execute(platform, group_id, proposal_id, &proposer);
-                platform.execution_payer = prev_payer;
-
-                match exec_result {
-                    Ok(()) => {
-                        Self::update_proposal_status(
-                            platform,
-                            group_id,
-                            proposal_id,
-                            ProposalStatus::Executed,
-                        )?;
-                    }
-                    Err(e) => {
-                        if proposal_type.has_recoverable_execution_errors() {
-                            Self::update_proposal_status(
-                                platform,
-                                group_id,
-                                proposal_id,
-                                ProposalStatus::ExecutedSkipped,
-                            )?;
-                        } else {
-                            return Err(e);
-                        }
-                    }
-                }
-            }
+            Self::execute_passed_proposal(platform, group_id, proposal_id, &proposal_data)?;
         } else if should_reject {
             Self::update_proposal_status(
                 platform,
@@ -181,6 +186,8 @@ impl GroupGovernance {
             group_id,
             proposal_id,
             approve,
+            delegated_weight,
+            overrides: overridden.as_ref(),
             tally: &tally,
             should_execute,
             should_reject,
@@ -189,8 +196,43 @@ impl GroupGovernance {
             tally_path: &tally_path,
             tally_value,
         }
-        .emit()?;
+        .emit()
+    }
 
-        Ok(())
+    /// Applies a passed proposal's action and records Executed, or
+    /// ExecutedSkipped when the proposal type tolerates a failed apply.
+    /// Returns the recorded status (Active if the proposal carries no data).
+    pub(super) fn execute_passed_proposal(
+        platform: &mut SocialPlatform,
+        group_id: &str,
+        proposal_id: &str,
+        proposal_data: &serde_json::Value,
+    ) -> Result<ProposalStatus, SocialError> {
+        let Some(proposal_type_val) = proposal_data.get("data") else {
+            return Ok(ProposalStatus::Active);
+        };
+        let proposal_type = serde_json::from_value::<ProposalType>(proposal_type_val.clone())
+            .map_err(|_| invalid_input!("Failed to parse proposal type"))?;
+
+        let proposer = proposal_data
+            .get("proposer")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<near_sdk::AccountId>().ok())
+            .ok_or_else(|| invalid_input!("Proposal missing proposer"))?;
+
+        let prev_payer = platform.execution_payer.clone();
+        platform.set_execution_payer(proposer.clone());
+        let exec_result = proposal_type.execute(platform, group_id, proposal_id, &proposer);
+        platform.execution_payer = prev_payer;
+
+        let status = match exec_result {
+            Ok(()) => ProposalStatus::Executed,
+            Err(_) if proposal_type.has_recoverable_execution_errors() => {
+                ProposalStatus::ExecutedSkipped
+            }
+            Err(e) => return Err(e),
+        };
+        Self::update_proposal_status(platform, group_id, proposal_id, status)?;
+        Ok(status)
     }
 }
@@ -15,6 +15,9 @@ pub struct VotingConfig {
     pub majority_threshold_bps: u16,
     #[serde(default = "default_voting_period")]
     pub voting_period: U64,
+    /// 0 disables vote delegation for the group.
+    #[serde(default = "default_max_delegation_depth")]
+    pub max_delegation_depth: u8,
 }
 
 fn default_participation_quorum_bps() -> u16 {
@@ -29,12 +32,17 @@ fn default_voting_period() -> U64 {
     U64(DEFAULT_VOTING_PERIOD)
 }
 
+fn default_max_delegation_depth() -> u8 {
+    DEFAULT_MAX_DELEGATION_DEPTH
+}
+
 impl Default for VotingConfig {
     fn default() -> Self {
         Self {
             participation_quorum_bps: DEFAULT_VOTING_PARTICIPATION_QUORUM_BPS,
             majority_threshold_bps: DEFAULT_VOTING_MAJORITY_THRESHOLD_BPS,
             voting_period: U64(DEFAULT_VOTING_PERIOD),
+            max_delegation_depth: DEFAULT_MAX_DELEGATION_DEPTH,
         }
     }
 }
@@ -52,6 +60,7 @@ impl VotingConfig {
                 .voting_period
                 .0
                 .clamp(MIN_VOTING_PERIOD, MAX_VOTING_PERIOD)),
+            max_delegation_depth: self.max_delegation_depth.min(MAX_DELEGATION_DEPTH),
         }
     }
 }
@@ -96,6 +96,7 @@ impl crate::domain::groups::core::GroupStorage {
         ]));
 
         platform.storage_set(&member_path, &member_data)?;
+        platform.key_index_insert(&member_path, env::block_height());
 
         let group_owner: AccountId = cfg.owner;
 
@@ -226,6 +227,7 @@ impl crate::domain::groups::core::GroupStorage {
         }
 
         let _ = crate::storage::soft_delete_entry(platform, &member_path, member_entry)?;
+        platform.key_index_remove(&member_path);
 
         let mut event_batch = EventBatch::new();
 
@@ -1,6 +1,9 @@
-use near_sdk::AccountId;
+use near_sdk::serde_json::{Value, json};
+use near_sdk::{AccountId, env};
 
+use crate::constants::MAX_MEMBER_INDEX_BATCH;
 use crate::state::models::SocialPlatform;
+use crate::{SocialError, invalid_input};
 
 impl crate::domain::groups::core::GroupStorage {
     pub fn can_grant_permissions(
@@ -62,4 +65,100 @@ impl crate::domain::groups::core::GroupStorage {
             false
         }
     }
+
+    /// owner > admin (MANAGE on config) > moderator (MODERATE) > member.
+    pub fn member_role(
+        platform: &SocialPlatform,
+        group_id: &str,
+        member_id: &AccountId,
+    ) -> &'static str {
+        use crate::domain::groups::permissions::kv::{
+            has_group_admin_permission, has_group_moderate_permission,
+        };
+        if Self::is_owner(platform, group_id, member_id) {
+            "owner"
+        } else if has_group_admin_permission(platform, group_id, member_id) {
+            "admin"
+        } else if has_group_moderate_permission(platform, group_id, member_id) {
+            "moderator"
+        } else {
+            "member"
+        }
+    }
+
+    /// Members in account order, filtered by role before `from_index`/`limit`
+    /// are applied. Member records are only read for the returned page.
+    pub fn list_members(
+        platform: &SocialPlatform,
+        group_id: &str,
+        from_index: usize,
+        limit: usize,
+        role_filter: Option<&str>,
+    ) -> Vec<Value> {
+        let prefix = format!("groups/{}/members/", group_id);
+        let page: Vec<(AccountId, Option<&'static str>)> = platform
+            .iter_keys_with_prefix(&prefix)
+            .filter_map(|path| path[prefix.len()..].parse::<AccountId>().ok())
+            .filter_map(|member_id| match role_filter {
+                Some(filter) => {
+                    let role = Self::member_role(platform, group_id, &member_id);
+                    (role == filter).then_some((member_id, Some(role)))
+                }
+                None => Some((member_id, None)),
+            })
+            .skip(from_index)
+            .take(limit)
+            .collect();
+
+        page.into_iter()
+            .filter_map(|(member_id, role)| {
+                let data =
+                    platform.storage_get(&Self::group_member_path(group_id, member_id.as_str()))?;
+                let role =
+                    role.unwrap_or_else(|| Self::member_role(platform, group_id, &member_id));
+                let nonce = platform
+                    .storage_get(&Self::group_member_nonce_path(group_id, member_id.as_str()))
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(0);
+                Some(json!({
+                    "account_id": member_id,
+                    "role": role,
+                    "level": data.get("level").cloned().unwrap_or(Value::Null),
+                    "can_manage": matches!(role, "owner" | "admin"),
+                    "can_moderate": role != "member",
+                    "nonce": nonce,
+                    "data": data,
+                }))
+            })
+            .collect()
+    }
+
+    /// Adds existing members of `group_id` to the key index that
+    /// `list_members` reads; members who joined before the index was kept
+    /// are otherwise not listed. Accounts that are not members are skipped.
+    /// Returns how many accounts were newly indexed.
+    pub fn index_members(
+        platform: &mut SocialPlatform,
+        group_id: &str,
+        member_ids: &[AccountId],
+    ) -> Result<u32, SocialError> {
+        if member_ids.len() > MAX_MEMBER_INDEX_BATCH {
+            return Err(invalid_input!(format!(
+                "At most {} members per call",
+                MAX_MEMBER_INDEX_BATCH
+            )));
+        }
+        let mut indexed = 0;
+        for member_id in member_ids {
+            let member_path = Self::group_member_path(group_id, member_id.as_str());
+            if !Self::is_member(platform, group_id, member_id)
+                || platform.key_index.contains_key(&member_path)
+            {
+                continue;
+            }
+            platform.key_index_insert(&member_path, env::block_height());
+            indexed += 1;
+        }
+        Ok(indexed)
+    }
 }
@@ -4,8 +4,8 @@ use near_sdk::{
 };
 
 use crate::constants::{
-    DEFAULT_VOTING_MAJORITY_THRESHOLD_BPS, DEFAULT_VOTING_PARTICIPATION_QUORUM_BPS,
-    DEFAULT_VOTING_PERIOD,
+    DEFAULT_MAX_DELEGATION_DEPTH, DEFAULT_VOTING_MAJORITY_THRESHOLD_BPS,
+    DEFAULT_VOTING_PARTICIPATION_QUORUM_BPS, DEFAULT_VOTING_PERIOD,
 };
 use crate::domain::groups::config::GroupConfig;
 use crate::events::{EventBatch, EventBuilder};
@@ -58,6 +58,14 @@ impl crate::domain::groups::core::GroupStorage {
             return Err(invalid_input!("Group already exists"));
         }
 
+        if let Some(bps) = config.get("storage_warning_bps")
+            && !bps.as_u64().is_some_and(|bps| (1..=10_000).contains(&bps))
+        {
+            return Err(invalid_input!(
+                "storage_warning_bps must be between 1 and 10000"
+            ));
+        }
+
         if let Some(obj) = config.as_object_mut() {
             obj.insert("owner".to_string(), Value::String(owner.to_string()));
             obj.insert(
@@ -79,7 +87,8 @@ impl crate::domain::groups::core::GroupStorage {
                 let default_voting_config = json!({
                     "participation_quorum_bps": DEFAULT_VOTING_PARTICIPATION_QUORUM_BPS,
                     "majority_threshold_bps": DEFAULT_VOTING_MAJORITY_THRESHOLD_BPS,
-                    "voting_period": DEFAULT_VOTING_PERIOD.to_string()
+                    "voting_period": DEFAULT_VOTING_PERIOD.to_string(),
+                    "max_delegation_depth": DEFAULT_MAX_DELEGATION_DEPTH
                 });
                 obj.insert("voting_config".to_string(), default_voting_config);
             }
@@ -105,6 +114,7 @@ impl crate::domain::groups::core::GroupStorage {
             ("is_creator".to_string(), Value::Bool(true)),
         ]));
         platform.storage_set(&member_path, &member_data)?;
+        platform.key_index_insert(&member_path, env::block_height());
 
         let stats_path = Self::group_stats_path(group_id);
         let initial_stats = json!({
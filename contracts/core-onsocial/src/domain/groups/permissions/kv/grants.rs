@@ -48,6 +48,10 @@ pub fn grant_permissions(
 
     let value = format!("{}:{}", grant.level, grant.expires_at.unwrap_or(0));
     platform.storage_write_string(&key, &value, attached_balance)?;
+    // Account grants are indexed so expired ones can be swept per owner.
+    if extract_group_id_from_path(grant.path).is_none() {
+        platform.key_index_insert(&key, near_sdk::env::block_height());
+    }
 
     let expires_at_string = grant.expires_at.unwrap_or(0).to_string();
 
@@ -99,6 +103,9 @@ pub fn revoke_permissions(
         if let Some(entry) = platform.get_entry(key) {
             deleted = crate::storage::soft_delete_entry(platform, key, entry)?;
         }
+        if extract_group_id_from_path(path).is_none() {
+            platform.key_index_remove(key);
+        }
     }
 
     let mut builder = EventBuilder::new(
@@ -6,6 +6,7 @@ use crate::domain::groups::config::GroupConfig;
 use crate::state::models::SocialPlatform;
 
 use super::super::types::ProposalType;
+use super::group_update::VotingConfigPatch;
 use super::helpers::{ExecutionContext, PathPermissionGrantData};
 
 impl ProposalType {
@@ -96,13 +97,17 @@ impl ProposalType {
                 participation_quorum_bps,
                 majority_threshold_bps,
                 voting_period,
+                max_delegation_depth,
             } => Self::execute_voting_config_change(
                 platform,
                 group_id,
                 proposal_id,
-                *participation_quorum_bps,
-                *majority_threshold_bps,
-                *voting_period,
+                VotingConfigPatch {
+                    participation_quorum_bps: *participation_quorum_bps,
+                    majority_threshold_bps: *majority_threshold_bps,
+                    voting_period: *voting_period,
+                    max_delegation_depth: *max_delegation_depth,
+                },
                 proposer,
             ),
             Self::JoinRequest {
@@ -14,6 +14,14 @@ use crate::{SocialError, invalid_input};
 use super::super::group_update_type::GroupUpdateType;
 use super::super::types::ProposalType;
 
+/// Fields of a `VotingConfigChange` proposal; `None` keeps the current value.
+pub(super) struct VotingConfigPatch {
+    pub participation_quorum_bps: Option<u16>,
+    pub majority_threshold_bps: Option<u16>,
+    pub voting_period: Option<u64>,
+    pub max_delegation_depth: Option<u8>,
+}
+
 impl ProposalType {
     pub(super) fn execute_group_update(
         platform: &mut SocialPlatform,
@@ -176,11 +184,15 @@ impl ProposalType {
         platform: &mut SocialPlatform,
         group_id: &str,
         proposal_id: &str,
-        participation_quorum_bps: Option<u16>,
-        majority_threshold_bps: Option<u16>,
-        voting_period: Option<u64>,
+        patch: VotingConfigPatch,
         proposer: &AccountId,
     ) -> Result<(), SocialError> {
+        let VotingConfigPatch {
+            participation_quorum_bps,
+            majority_threshold_bps,
+            voting_period,
+            max_delegation_depth,
+        } = patch;
         let config_key = GroupStorage::group_config_path(group_id);
 
         let mut config = platform
@@ -201,6 +213,9 @@ impl ProposalType {
         if let Some(period) = voting_period {
             voting_config.voting_period = near_sdk::json_types::U64(period);
         }
+        if let Some(depth) = max_delegation_depth {
+            voting_config.max_delegation_depth = depth;
+        }
 
         voting_config = voting_config.sanitized();
 
@@ -225,6 +240,7 @@ impl ProposalType {
         .with_field("participation_quorum_bps", participation_quorum_bps)
         .with_field("majority_threshold_bps", majority_threshold_bps)
         .with_field("voting_period", voting_period.map(|p| p.to_string()))
+        .with_field("max_delegation_depth", max_delegation_depth)
         .with_field(
             "effective_participation_quorum_bps",
             voting_config.participation_quorum_bps,
@@ -237,6 +253,10 @@ impl ProposalType {
             "effective_voting_period",
             voting_config.voting_period.0.to_string(),
         )
+        .with_field(
+            "effective_max_delegation_depth",
+            voting_config.max_delegation_depth,
+        )
         .with_path(&config_key)
         .with_value(config)
         .emit(&mut event_batch);
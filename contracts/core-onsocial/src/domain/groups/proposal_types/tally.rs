@@ -14,13 +14,21 @@ impl VoteTally {
         }
     }
 
-    pub fn record_vote(&mut self, approve: bool, previous_vote: Option<bool>) {
-        if previous_vote.is_none() {
-            if approve {
-                self.yes_votes += 1;
-            }
-            self.total_votes += 1;
+    /// Counts one ballot carrying `delegated_weight` extra delegated votes.
+    pub fn record_weighted_vote(&mut self, approve: bool, delegated_weight: u64) {
+        let weight = delegated_weight.saturating_add(1);
+        if approve {
+            self.yes_votes = self.yes_votes.saturating_add(weight);
         }
+        self.total_votes = self.total_votes.saturating_add(weight);
+    }
+
+    /// Takes `weight` votes back out of a ballot that had counted them.
+    pub fn retract_weighted_vote(&mut self, approve: bool, weight: u64) {
+        if approve {
+            self.yes_votes = self.yes_votes.saturating_sub(weight);
+        }
+        self.total_votes = self.total_votes.saturating_sub(weight);
     }
 
     pub fn meets_thresholds(
@@ -34,19 +42,25 @@ impl VoteTally {
 
         let total_votes = self.total_votes as u128;
         let yes_votes = self.yes_votes as u128;
-        let locked_member_count = self.locked_member_count as u128;
         let denom = BPS_DENOMINATOR as u128;
-        let quorum_bps = participation_quorum_bps.min(BPS_DENOMINATOR) as u128;
         let majority_bps = majority_threshold_bps.min(BPS_DENOMINATOR) as u128;
 
-        let meets_participation =
-            total_votes.saturating_mul(denom) >= quorum_bps.saturating_mul(locked_member_count);
+        let meets_participation = self.meets_quorum(participation_quorum_bps);
         let meets_majority =
             yes_votes.saturating_mul(denom) >= majority_bps.saturating_mul(total_votes);
 
         meets_participation && meets_majority
     }
 
+    pub fn meets_quorum(&self, participation_quorum_bps: u16) -> bool {
+        if self.total_votes == 0 || self.locked_member_count == 0 {
+            return false;
+        }
+        let quorum_bps = participation_quorum_bps.min(BPS_DENOMINATOR) as u128;
+        (self.total_votes as u128).saturating_mul(BPS_DENOMINATOR as u128)
+            >= quorum_bps.saturating_mul(self.locked_member_count as u128)
+    }
+
     pub fn is_expired(&self, voting_period: u64) -> bool {
         let expiration_time = self.created_at.0.saturating_add(voting_period);
         env::block_timestamp() >= expiration_time
@@ -36,6 +36,8 @@ pub enum ProposalType {
         participation_quorum_bps: Option<u16>,
         majority_threshold_bps: Option<u16>,
         voting_period: Option<u64>,
+        #[serde(default)]
+        max_delegation_depth: Option<u8>,
     },
     CustomProposal {
         title: String,
@@ -165,10 +165,12 @@ impl ProposalType {
                 participation_quorum_bps,
                 majority_threshold_bps,
                 voting_period,
+                max_delegation_depth,
             } => {
                 if participation_quorum_bps.is_none()
                     && majority_threshold_bps.is_none()
                     && voting_period.is_none()
+                    && max_delegation_depth.is_none()
                 {
                     return Err(invalid_input!(
                         "At least one voting config parameter must be specified"
@@ -202,6 +204,14 @@ impl ProposalType {
                         ));
                     }
                 }
+
+                if max_delegation_depth.is_some_and(|d| d > crate::constants::MAX_DELEGATION_DEPTH)
+                {
+                    return Err(invalid_input!(format!(
+                        "Max delegation depth must be at most {}",
+                        crate::constants::MAX_DELEGATION_DEPTH
+                    )));
+                }
             }
             Self::CustomProposal {
                 title, description, ..
@@ -58,10 +58,16 @@ impl SocialPlatform {
                     v.as_u64()
                         .or_else(|| v.as_str().and_then(|s| s.parse::<u64>().ok()))
                 });
+                let max_delegation_depth = parse_optional_u16_any("max_delegation_depth")?
+                    .map(|v| {
+                        u8::try_from(v).map_err(|_| invalid_input!("Invalid max_delegation_depth"))
+                    })
+                    .transpose()?;
                 crate::domain::groups::ProposalType::VotingConfigChange {
                     participation_quorum_bps,
                     majority_threshold_bps,
                     voting_period,
+                    max_delegation_depth,
                 }
             }
             "custom_proposal" => {
@@ -110,6 +116,27 @@ impl SocialPlatform {
         )
     }
 
+    pub fn delegate_vote(
+        &mut self,
+        group_id: String,
+        delegate: &AccountId,
+        caller: &AccountId,
+    ) -> Result<(), SocialError> {
+        crate::validation::validate_group_id(&group_id)?;
+        crate::domain::groups::governance::GroupGovernance::delegate_vote(
+            self, &group_id, caller, delegate,
+        )
+    }
+
+    pub fn undelegate_vote(
+        &mut self,
+        group_id: String,
+        caller: &AccountId,
+    ) -> Result<(), SocialError> {
+        crate::validation::validate_group_id(&group_id)?;
+        crate::domain::groups::governance::GroupGovernance::undelegate_vote(self, &group_id, caller)
+    }
+
     pub fn cancel_proposal(
         &mut self,
         group_id: String,
@@ -137,4 +164,17 @@ impl SocialPlatform {
             &proposal_id,
         )
     }
+
+    pub fn finalize_expired_proposal(
+        &mut self,
+        group_id: String,
+        proposal_id: String,
+    ) -> Result<&'static str, SocialError> {
+        crate::validation::validate_group_id(&group_id)?;
+        crate::domain::groups::governance::GroupGovernance::finalize_expired_proposal(
+            self,
+            &group_id,
+            &proposal_id,
+        )
+    }
 }
@@ -9,6 +9,14 @@ pub enum SocialError {
     InvalidInput(String),
     ContractReadOnly,
     PermissionDenied(String, String),
+    Expired(String),
+    AccountStorageCapExceeded(String),
+    PreconditionFailed(String),
+    /// Serialized value exceeds the configured `max_value_bytes`.
+    ValueTooLarge {
+        actual: u64,
+        max: u64,
+    },
 }
 
 impl std::fmt::Display for SocialError {
@@ -19,6 +27,14 @@ impl std::fmt::Display for SocialError {
             Self::InvalidInput(msg) => write!(f, "{}", msg),
             Self::ContractReadOnly => write!(f, "Contract is read-only"),
             Self::PermissionDenied(op, path) => write!(f, "Permission denied: {} on {}", op, path),
+            Self::Expired(msg) => write!(f, "Expired: {}", msg),
+            Self::AccountStorageCapExceeded(msg) => {
+                write!(f, "Account storage cap exceeded: {}", msg)
+            }
+            Self::PreconditionFailed(msg) => write!(f, "Precondition failed: {}", msg),
+            Self::ValueTooLarge { actual, max } => {
+                write!(f, "Value payload too large: {} bytes (max {})", actual, max)
+            }
         }
     }
 }
@@ -0,0 +1,203 @@
+//! Compact borsh encoding of NEP-297 events.
+//!
+//! Logged as `EVENT_BORSH:{base64(borsh(BorshEvent))}`. The outer enum tag is
+//! the schema version; new layouts are added as new variants so decoders can
+//! keep reading older logs.
+
+use super::types::{Event, EventData};
+use crate::constants::EVENT_ENCODING_STORAGE_KEY;
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::base64::{Engine, engine::general_purpose::STANDARD};
+use near_sdk::env;
+use near_sdk::serde_json::{Map, Number, Value};
+use near_sdk_macros::NearSchema;
+
+/// Which event logs the contract writes. Stored outside the borsh state so
+/// deployments can switch without a migration.
+#[derive(
+    NearSchema, serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq,
+)]
+#[abi(json)]
+pub enum EventEncoding {
+    /// `EVENT_JSON:` only.
+    #[default]
+    Json,
+    /// `EVENT_JSON:` followed by an equivalent `EVENT_BORSH:` log.
+    JsonAndBorsh,
+    /// `EVENT_BORSH:` only.
+    Borsh,
+}
+
+impl EventEncoding {
+    pub fn emits_json(self) -> bool {
+        matches!(self, Self::Json | Self::JsonAndBorsh)
+    }
+
+    pub fn emits_borsh(self) -> bool {
+        matches!(self, Self::JsonAndBorsh | Self::Borsh)
+    }
+
+    fn as_byte(self) -> u8 {
+        match self {
+            Self::Json => 0,
+            Self::JsonAndBorsh => 1,
+            Self::Borsh => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Self {
+        match byte {
+            1 => Self::JsonAndBorsh,
+            2 => Self::Borsh,
+            _ => Self::Json,
+        }
+    }
+}
+
+pub(crate) fn read_event_encoding() -> EventEncoding {
+    env::storage_read(EVENT_ENCODING_STORAGE_KEY)
+        .and_then(|bytes| bytes.first().copied())
+        .map(EventEncoding::from_byte)
+        .unwrap_or_default()
+}
+
+pub(crate) fn write_event_encoding(encoding: EventEncoding) {
+    match encoding {
+        EventEncoding::Json => env::storage_remove(EVENT_ENCODING_STORAGE_KEY),
+        other => env::storage_write(EVENT_ENCODING_STORAGE_KEY, &[other.as_byte()]),
+    };
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum BorshEvent {
+    V1(BorshEventV1),
+}
+
+/// Mirrors [`Event`].
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct BorshEventV1 {
+    pub standard: String,
+    pub version: String,
+    pub event: String,
+    pub data: Vec<BorshEventData>,
+}
+
+/// Mirrors [`EventData`]; `extra` keeps the JSON key order.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct BorshEventData {
+    pub operation: String,
+    pub author: String,
+    pub partition_id: Option<u16>,
+    pub extra: Vec<(String, BorshValue)>,
+}
+
+/// JSON value model. Integers keep their sign class so they round-trip
+/// without going through floats.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum BorshValue {
+    Null,
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Array(Vec<BorshValue>),
+    Object(Vec<(String, BorshValue)>),
+}
+
+impl From<&Value> for BorshValue {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(b) => Self::Bool(*b),
+            Value::Number(n) => {
+                if let Some(u) = n.as_u64() {
+                    Self::U64(u)
+                } else if let Some(i) = n.as_i64() {
+                    Self::I64(i)
+                } else {
+                    Self::F64(n.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(s) => Self::String(s.clone()),
+            Value::Array(items) => Self::Array(items.iter().map(Self::from).collect()),
+            Value::Object(map) => Self::Object(object_to_fields(map)),
+        }
+    }
+}
+
+impl From<BorshValue> for Value {
+    fn from(value: BorshValue) -> Self {
+        match value {
+            BorshValue::Null => Value::Null,
+            BorshValue::Bool(b) => Value::Bool(b),
+            BorshValue::U64(u) => Value::from(u),
+            BorshValue::I64(i) => Value::from(i),
+            BorshValue::F64(f) => Number::from_f64(f).map_or(Value::Null, Value::Number),
+            BorshValue::String(s) => Value::String(s),
+            BorshValue::Array(items) => Value::Array(items.into_iter().map(Value::from).collect()),
+            BorshValue::Object(fields) => Value::Object(fields_to_object(fields)),
+        }
+    }
+}
+
+fn object_to_fields(map: &Map<String, Value>) -> Vec<(String, BorshValue)> {
+    map.iter()
+        .map(|(k, v)| (k.clone(), BorshValue::from(v)))
+        .collect()
+}
+
+fn fields_to_object(fields: Vec<(String, BorshValue)>) -> Map<String, Value> {
+    fields
+        .into_iter()
+        .map(|(k, v)| (k, Value::from(v)))
+        .collect()
+}
+
+impl From<&Event> for BorshEvent {
+    fn from(event: &Event) -> Self {
+        Self::V1(BorshEventV1 {
+            standard: event.standard.clone(),
+            version: event.version.clone(),
+            event: event.event.clone(),
+            data: event
+                .data
+                .iter()
+                .map(|d| BorshEventData {
+                    operation: d.operation.clone(),
+                    author: d.author.clone(),
+                    partition_id: d.partition_id,
+                    extra: object_to_fields(&d.extra),
+                })
+                .collect(),
+        })
+    }
+}
+
+impl From<BorshEvent> for Event {
+    fn from(event: BorshEvent) -> Self {
+        let BorshEvent::V1(v1) = event;
+        Self {
+            standard: v1.standard,
+            version: v1.version,
+            event: v1.event,
+            data: v1
+                .data
+                .into_iter()
+                .map(|d| EventData {
+                    operation: d.operation,
+                    author: d.author,
+                    partition_id: d.partition_id,
+                    extra: fields_to_object(d.extra),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Base64 payload for an `EVENT_BORSH:` log.
+pub(crate) fn encode_event(event: &Event) -> Option<String> {
+    borsh::to_vec(&BorshEvent::from(event))
+        .ok()
+        .map(|bytes| STANDARD.encode(bytes))
+}
@@ -0,0 +1,42 @@
+//! Deployment-level event options, kept outside the borsh state (like
+//! `EventEncoding`) so they can change without a migration.
+
+use crate::constants::EVENT_CONFIG_STORAGE_KEY;
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::env;
+use near_sdk_macros::NearSchema;
+
+#[derive(
+    NearSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+)]
+#[abi(json)]
+pub struct EventConfig {
+    /// Fold every `DATA_UPDATE` of one `set` call into a single event whose
+    /// `writes` array lists each path. Off by default: one event per leaf.
+    #[serde(default)]
+    pub consolidate_data_updates: bool,
+}
+
+pub(crate) fn read_event_config() -> EventConfig {
+    env::storage_read(EVENT_CONFIG_STORAGE_KEY)
+        .and_then(|bytes| borsh::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+pub(crate) fn write_event_config(config: EventConfig) {
+    if config == EventConfig::default() {
+        env::storage_remove(EVENT_CONFIG_STORAGE_KEY);
+    } else if let Ok(bytes) = borsh::to_vec(&config) {
+        env::storage_write(EVENT_CONFIG_STORAGE_KEY, &bytes);
+    }
+}
@@ -1,6 +1,7 @@
+use super::borsh::{encode_event, read_event_encoding};
 use super::types::*;
 use crate::{
-    constants::EVENT_JSON_PREFIX,
+    constants::{EVENT_BORSH_PREFIX, EVENT_JSON_PREFIX},
     errors::*,
     invalid_input,
     storage::{
@@ -39,6 +40,42 @@ impl EventBatch {
             .push((event_type, operation, account_id, extra_data));
     }
 
+    /// Replace all queued `DATA_UPDATE` events with one event at the first
+    /// one's position. It keeps the first event's operation and fields and
+    /// adds a `writes` array of every `{path, value}` in queue order.
+    pub fn consolidate_data_updates(&mut self) {
+        let is_data_update = |(event_type, ..): &(String, String, AccountId, Value)| {
+            event_type == crate::constants::EVENT_TYPE_DATA_UPDATE
+        };
+        if self.events.iter().filter(|e| is_data_update(e)).count() < 2 {
+            return;
+        }
+
+        let mut writes = Vec::new();
+        let mut merged: Option<(usize, (String, String, AccountId, Value))> = None;
+        let mut rest = Vec::with_capacity(self.events.len());
+        for event in std::mem::take(&mut self.events) {
+            if !is_data_update(&event) {
+                rest.push(event);
+                continue;
+            }
+            let path = event.3.get("path").cloned().unwrap_or(Value::Null);
+            let value = event.3.get("value").cloned().unwrap_or(Value::Null);
+            writes.push(serde_json::json!({ "path": path, "value": value }));
+            if merged.is_none() {
+                merged = Some((rest.len(), event));
+            }
+        }
+
+        if let Some((index, (event_type, operation, account_id, mut extra))) = merged {
+            if let Some(fields) = extra.as_object_mut() {
+                fields.insert("writes".to_string(), Value::Array(writes));
+            }
+            rest.insert(index, (event_type, operation, account_id, extra));
+        }
+        self.events = rest;
+    }
+
     pub fn emit(&mut self) -> Result<(), SocialError> {
         if self.events.is_empty() {
             return Ok(());
@@ -46,6 +83,7 @@ impl EventBatch {
 
         use std::collections::{HashMap, VecDeque};
         let mut partition_cache: HashMap<String, u16> = HashMap::new();
+        let encoding = read_event_encoding();
 
         // Take ownership so failures won't silently drop remaining events.
         let events: VecDeque<(String, String, AccountId, Value)> =
@@ -82,10 +120,16 @@ impl EventBatch {
                     }],
                 );
 
-                let json = serde_json::to_string(&event)
-                    .map_err(|_| invalid_input!("Failed to serialize event"))?;
-
-                env::log_str(&format!("{EVENT_JSON_PREFIX}{json}"));
+                if encoding.emits_json() {
+                    let json = serde_json::to_string(&event)
+                        .map_err(|_| invalid_input!("Failed to serialize event"))?;
+                    env::log_str(&format!("{EVENT_JSON_PREFIX}{json}"));
+                }
+                if encoding.emits_borsh() {
+                    let encoded = encode_event(&event)
+                        .ok_or_else(|| invalid_input!("Failed to serialize event"))?;
+                    env::log_str(&format!("{EVENT_BORSH_PREFIX}{encoded}"));
+                }
                 Ok(())
             };
 
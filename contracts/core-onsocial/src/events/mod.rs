@@ -1,8 +1,12 @@
+pub(crate) mod borsh;
 pub(crate) mod builder;
+pub(crate) mod config;
 pub(crate) mod emitter;
 pub(crate) mod fields;
 pub(crate) mod types;
 
+pub use borsh::EventEncoding;
 pub(crate) use builder::EventBuilder;
+pub use config::EventConfig;
 pub(crate) use emitter::EventBatch;
 pub(crate) use fields::derived_fields_from_path;
@@ -9,6 +9,9 @@ pub(crate) enum ApiOperationKey<'a> {
     StorageDeposit,
     StorageWithdraw,
     StorageSharedPoolDeposit,
+    StorageSharedPoolWithdraw,
+    StorageAppPoolRegister,
+    StorageAppPoolSponsor,
     StoragePlatformPoolDeposit,
     StorageGroupPoolDeposit,
     StorageGroupSponsorQuotaSet,
@@ -34,6 +37,9 @@ impl ApiOperationKey<'_> {
                 | Self::StorageDeposit
                 | Self::StorageWithdraw
                 | Self::StorageSharedPoolDeposit
+                | Self::StorageSharedPoolWithdraw
+                | Self::StorageAppPoolRegister
+                | Self::StorageAppPoolSponsor
                 | Self::StoragePlatformPoolDeposit
                 | Self::StorageGroupPoolDeposit
                 | Self::StorageGroupSponsorQuotaSet
@@ -66,6 +72,9 @@ pub(crate) fn classify_api_operation_key(key: &str) -> Result<ApiOperationKey<'_
         "storage/deposit" => ApiOperationKey::StorageDeposit,
         "storage/withdraw" => ApiOperationKey::StorageWithdraw,
         "storage/shared_pool_deposit" => ApiOperationKey::StorageSharedPoolDeposit,
+        "storage/shared_pool_withdraw" => ApiOperationKey::StorageSharedPoolWithdraw,
+        "storage/app_pool_register" => ApiOperationKey::StorageAppPoolRegister,
+        "storage/app_pool_sponsor" => ApiOperationKey::StorageAppPoolSponsor,
         "storage/platform_pool_deposit" => ApiOperationKey::StoragePlatformPoolDeposit,
         "storage/group_pool_deposit" => ApiOperationKey::StorageGroupPoolDeposit,
         "storage/group_sponsor_quota_set" => ApiOperationKey::StorageGroupSponsorQuotaSet,
@@ -11,6 +11,15 @@ pub enum Action {
     Set {
         data: Value,
     },
+    /// All-or-nothing `Set` for data paths: the whole batch is validated and
+    /// its storage checked before anything is written.
+    SetAtomic {
+        data: Value,
+    },
+    /// Soft-delete every entry under `path` (`post/*` or a subtree root).
+    DeleteSubtree {
+        path: String,
+    },
 
     CreateGroup {
         group_id: String,
@@ -83,6 +92,20 @@ pub enum Action {
         group_id: String,
         proposal_id: String,
     },
+    /// Settles an active proposal after its voting period: rejected for
+    /// missing quorum, otherwise passed or rejected on the final tally.
+    FinalizeExpiredProposal {
+        group_id: String,
+        proposal_id: String,
+    },
+    /// Follow `delegate`'s ballot on proposals the caller has not voted on.
+    DelegateVote {
+        group_id: String,
+        delegate: AccountId,
+    },
+    UndelegateVote {
+        group_id: String,
+    },
 
     SetPermission {
         grantee: AccountId,
@@ -90,6 +113,12 @@ pub enum Action {
         level: u8,
         expires_at: Option<U64>,
     },
+    /// Removes `owner`'s expired account permissions; callable by anyone.
+    SweepExpiredPermissions {
+        owner: AccountId,
+        limit: u32,
+        from_key: Option<String>,
+    },
     SetKeyPermission {
         public_key: PublicKey,
         path: String,
@@ -103,6 +132,8 @@ impl Action {
     pub fn action_type(&self) -> &'static str {
         match self {
             Self::Set { .. } => "set",
+            Self::SetAtomic { .. } => "set_atomic",
+            Self::DeleteSubtree { .. } => "delete_subtree",
             Self::CreateGroup { .. } => "create_group",
             Self::JoinGroup { .. } => "join_group",
             Self::LeaveGroup { .. } => "leave_group",
@@ -119,7 +150,11 @@ impl Action {
             Self::VoteOnProposal { .. } => "vote_on_proposal",
             Self::CancelProposal { .. } => "cancel_proposal",
             Self::ExpireProposal { .. } => "expire_proposal",
+            Self::FinalizeExpiredProposal { .. } => "finalize_expired_proposal",
+            Self::DelegateVote { .. } => "delegate_vote",
+            Self::UndelegateVote { .. } => "undelegate_vote",
             Self::SetPermission { .. } => "set_permission",
+            Self::SweepExpiredPermissions { .. } => "sweep_expired_permissions",
             Self::SetKeyPermission { .. } => "set_key_permission",
         }
     }
@@ -164,4 +199,21 @@ pub struct Options {
     /// Refund unused deposit to the payer instead of saving it to actor storage.
     #[serde(default)]
     pub refund_unused_deposit: bool,
+    /// Reject execution after this block timestamp (ms). Signed as part of the
+    /// delegate args, so a relayer cannot extend it.
+    #[serde(default)]
+    pub deadline_ms: Option<U64>,
+    /// Compare-and-swap guard for `Set`: data key -> hex sha256 of its current
+    /// canonical JSON, or null if the key must not exist yet.
+    #[serde(default)]
+    pub expected_value_hashes: Option<std::collections::BTreeMap<String, Option<String>>>,
+    /// Registered app pool that covers this request's new bytes before the
+    /// payer's own tiers, up to the app's per-user limit. The app must have
+    /// allowed the target account via `storage/app_pool_sponsor`.
+    #[serde(default)]
+    pub storage_sponsor: Option<AccountId>,
+    /// Record this request's writes in their `parent`'s reverse index
+    /// (see `get_referencers`). The writer pays for the index entry.
+    #[serde(default)]
+    pub index_references: bool,
 }
@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+
+use near_sdk::AccountId;
+use near_sdk::serde_json::Value;
+
+use crate::SocialError;
+use crate::protocol::operation::{ApiOperationKey, classify_api_operation_key};
+use crate::state::models::{SharedStoragePool, SocialPlatform};
+use crate::validation::{Path, validate_json_value_simple};
+
+/// Bytes a batch adds for one storage payer, split by what may sponsor them.
+#[derive(Default)]
+struct PayerBytes {
+    total: u64,
+    by_group: BTreeMap<String, u64>,
+}
+
+impl SocialPlatform {
+    /// Checks every entry of a `SetAtomic` batch without writing anything.
+    ///
+    /// Collects all path, permission, and size failures, then checks that the
+    /// batch's new bytes fit what pools, balances, and `attached_balance` can
+    /// cover. Pool coverage is estimated generously, so a batch that passes
+    /// may still fail during commit; `execute` then reverts the whole receipt.
+    pub(crate) fn preflight_atomic_set(
+        &self,
+        data: &Value,
+        target_account: &AccountId,
+        actor_id: &AccountId,
+        storage_sponsor: Option<&AccountId>,
+        attached_balance: u128,
+    ) -> Result<(), SocialError> {
+        let data_obj = crate::protocol::operation::require_non_empty_object(data)?;
+        if data_obj.len() > self.config.max_batch_size as usize {
+            return Err(crate::invalid_input!("Batch size exceeded"));
+        }
+
+        let mut errors = Vec::new();
+        let mut payers: BTreeMap<AccountId, PayerBytes> = BTreeMap::new();
+
+        for (key, value) in data_obj {
+            match self.preflight_atomic_entry(key, value, target_account, actor_id) {
+                Ok(Some((payer, group_id, bytes))) => {
+                    let entry = payers.entry(payer).or_default();
+                    entry.total = entry.total.saturating_add(bytes);
+                    if let Some(group_id) = group_id {
+                        let group_bytes = entry.by_group.entry(group_id).or_default();
+                        *group_bytes = group_bytes.saturating_add(bytes);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => errors.push(format!("{}: {}", key, e)),
+            }
+        }
+
+        if errors.is_empty() {
+            let mut shortfall: u128 = 0;
+            for (payer, bytes) in &payers {
+                shortfall = shortfall.saturating_add(self.atomic_balance_shortfall(
+                    payer,
+                    bytes,
+                    storage_sponsor,
+                ));
+            }
+            if shortfall > attached_balance {
+                errors.push(format!(
+                    "storage: batch needs {} more yoctoNEAR than is available",
+                    shortfall - attached_balance
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+        Err(crate::invalid_input!(format!(
+            "Atomic set rejected: {}",
+            errors.join("; ")
+        )))
+    }
+
+    /// Returns `(payer, group_id, new_bytes)` for writes that add storage.
+    fn preflight_atomic_entry(
+        &self,
+        key: &str,
+        value: &Value,
+        target_account: &AccountId,
+        actor_id: &AccountId,
+    ) -> Result<Option<(AccountId, Option<String>, u64)>, SocialError> {
+        let ApiOperationKey::DataPath(path) = classify_api_operation_key(key)? else {
+            return Err(crate::invalid_input!(
+                "Only data paths are allowed in an atomic set"
+            ));
+        };
+
+        let path = Path::new(target_account, path, self)?;
+        validate_json_value_simple(value)?;
+
+        let full_path = path.full_path();
+        let (storage_path, group_id) =
+            match crate::storage::utils::extract_group_id_from_path(full_path) {
+                Some(_) => {
+                    let info =
+                        crate::domain::groups::permissions::kv::classify_group_path(full_path)
+                            .ok_or_else(|| crate::invalid_input!("Invalid group path format"))?;
+                    if info.kind == crate::domain::groups::permissions::kv::GroupPathKind::Config {
+                        return Err(crate::invalid_input!("Group config namespace is reserved"));
+                    }
+                    let normalized = info.normalized.as_str();
+                    let (group_id, content_path) =
+                        crate::validation::require_groups_path(normalized)?;
+                    if crate::domain::groups::GroupStorage::get_group_config(self, group_id)
+                        .is_none()
+                    {
+                        return Err(crate::invalid_input!("Group does not exist"));
+                    }
+                    if !crate::domain::groups::permissions::kv::can_write(
+                        self,
+                        group_id,
+                        actor_id.as_str(),
+                        normalized,
+                    ) {
+                        return Err(crate::permission_denied!("write", normalized));
+                    }
+                    (
+                        format!("{}/groups/{}/{}", actor_id, group_id, content_path),
+                        Some(group_id.to_string()),
+                    )
+                }
+                None => (full_path.to_string(), None),
+            };
+
+        let bytes = self.estimate_write_bytes(&storage_path, value)?;
+        if bytes == 0 {
+            return Ok(None);
+        }
+        let payer = self.resolve_payer_account(&storage_path)?;
+        Ok(Some((payer, group_id, bytes)))
+    }
+
+    /// Yocto the payer's balance must still supply after every pool that
+    /// could sponsor these bytes has been counted at full capacity.
+    fn atomic_balance_shortfall(
+        &self,
+        payer: &AccountId,
+        bytes: &PayerBytes,
+        storage_sponsor: Option<&AccountId>,
+    ) -> u128 {
+        let mut storage = self.user_storage.get(payer).cloned().unwrap_or_default();
+
+        let app_bytes = storage_sponsor
+            .filter(|app_id| {
+                storage.can_track_app_pool(app_id) && self.is_app_pool_member(app_id, payer)
+            })
+            .and_then(|app_id| {
+                let limit = self.app_storage_pools.get(app_id)?.max_bytes_per_user;
+                let used = self
+                    .app_pool_usage
+                    .get(&Self::app_pool_usage_key(app_id, payer))
+                    .copied()
+                    .unwrap_or(0);
+                let pool_available = self.shared_storage_pools.get(app_id)?.available_bytes();
+                Some(limit.saturating_sub(used).min(pool_available))
+            })
+            .unwrap_or(0);
+
+        let platform_available = self
+            .shared_storage_pools
+            .get(&Self::platform_pool_account())
+            .map(|pool| pool.available_bytes())
+            .unwrap_or(0);
+        let platform_bytes = if platform_available == 0 {
+            0
+        } else if storage.platform_sponsored {
+            storage.refill_platform_allowance(&self.config);
+            storage.platform_allowance
+        } else {
+            self.config
+                .platform_onboarding_bytes
+                .min(self.config.platform_allowance_max_bytes)
+        }
+        .min(platform_available);
+
+        let shared_bytes = storage
+            .shared_storage
+            .as_ref()
+            .map(|shared| {
+                let pool_available = self
+                    .shared_storage_pools
+                    .get(&shared.pool_id)
+                    .map(|pool| pool.available_bytes())
+                    .unwrap_or(0);
+                shared
+                    .max_bytes
+                    .saturating_sub(shared.used_bytes)
+                    .min(pool_available)
+            })
+            .unwrap_or(0);
+
+        let group_bytes: u64 = bytes
+            .by_group
+            .iter()
+            .map(|(group_id, group_bytes)| {
+                SharedStoragePool::group_pool_key(group_id)
+                    .ok()
+                    .and_then(|key| self.shared_storage_pools.get(&key))
+                    .map(|pool| pool.available_bytes().min(*group_bytes))
+                    .unwrap_or(0)
+            })
+            .sum();
+
+        let self_paid = bytes
+            .total
+            .saturating_sub(app_bytes)
+            .saturating_sub(platform_bytes)
+            .saturating_sub(shared_bytes)
+            .saturating_sub(group_bytes);
+        if self_paid == 0 {
+            return 0;
+        }
+
+        let needed = storage
+            .storage_balance_needed()
+            .saturating_add(crate::storage::calculate_storage_balance_needed(self_paid));
+        needed.saturating_sub(storage.available_balance())
+    }
+}
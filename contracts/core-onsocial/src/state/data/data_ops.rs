@@ -59,7 +59,6 @@ impl SocialPlatform {
             data_ctx.value,
             self.config.max_value_bytes as usize,
             "Serialization failed",
-            "Value payload too large",
         )?;
         let data_entry = crate::state::models::DataEntry {
             value: crate::state::models::DataValue::Value(serialized_value),
@@ -68,6 +67,12 @@ impl SocialPlatform {
 
         if data_ctx.value.is_null() {
             let deleted = if let Some(entry) = self.get_entry(data_ctx.full_path) {
+                self.sync_reference_index(
+                    data_ctx.full_path,
+                    Some(&entry),
+                    data_ctx.value,
+                    ctx.attached_balance.as_deref_mut(),
+                )?;
                 crate::storage::soft_delete_entry(self, data_ctx.full_path, entry)?
             } else {
                 false
@@ -99,13 +104,17 @@ impl SocialPlatform {
             .with_field("payer_id", ctx.payer_id.to_string())
             .emit(ctx.event_batch);
 
-            let sponsor_outcome = self
-                .insert_entry_with_fallback(
-                    data_ctx.full_path,
-                    data_entry,
-                    ctx.attached_balance.as_deref_mut(),
-                )?
-                .1;
+            let (previous_entry, sponsor_outcome) = self.insert_entry_with_fallback(
+                data_ctx.full_path,
+                data_entry,
+                ctx.attached_balance.as_deref_mut(),
+            )?;
+            self.sync_reference_index(
+                data_ctx.full_path,
+                previous_entry.as_ref(),
+                data_ctx.value,
+                ctx.attached_balance.as_deref_mut(),
+            )?;
 
             if let Some(crate::state::operations::SponsorOutcome::GroupSpend {
                 group_id,
@@ -1,6 +1,10 @@
-use near_sdk::{AccountId, json_types::U64};
+use std::collections::HashMap;
+
+use near_sdk::serde_json::{Value, json};
+use near_sdk::{AccountId, env, json_types::U64};
 
 use crate::EntryView;
+use crate::constants::MAX_GET_MANY_KEYS;
 use crate::state::models::SocialPlatform;
 
 impl SocialPlatform {
@@ -11,6 +15,47 @@ impl SocialPlatform {
             .collect()
     }
 
+    /// Resolves each account's keys as `get` would, keyed by full path.
+    /// Missing entries map to `null`; with metadata, each value is the full
+    /// entry view.
+    pub fn get_many(
+        &self,
+        requests: Vec<(AccountId, Vec<String>)>,
+        include_metadata: bool,
+    ) -> HashMap<String, Value> {
+        let total_keys: usize = requests.iter().map(|(_, keys)| keys.len()).sum();
+        if total_keys > MAX_GET_MANY_KEYS {
+            env::panic_str(&format!(
+                "Too many keys: {} (max {})",
+                total_keys, MAX_GET_MANY_KEYS
+            ));
+        }
+
+        let mut results = HashMap::new();
+        for (account_id, keys) in requests {
+            for key in keys {
+                let entry = self.get_one_internal(key, Some(&account_id));
+                let full_key = if entry.full_key.is_empty() {
+                    format!("{}/{}", account_id, entry.requested_key)
+                } else {
+                    entry.full_key.clone()
+                };
+                let value = if include_metadata {
+                    json!({
+                        "value": entry.value,
+                        "block_height": entry.block_height,
+                        "deleted": entry.deleted,
+                        "corrupted": entry.corrupted,
+                    })
+                } else {
+                    entry.value.unwrap_or(Value::Null)
+                };
+                results.insert(full_key, value);
+            }
+        }
+        results
+    }
+
     pub fn get_one(&self, key: String, account_id: Option<AccountId>) -> EntryView {
         self.get_one_internal(key, account_id.as_ref())
     }
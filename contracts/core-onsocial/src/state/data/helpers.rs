@@ -22,6 +22,28 @@ impl SocialPlatform {
         Ok(())
     }
 
+    /// Rejects the whole batch if any guarded key no longer holds the value
+    /// the caller last read.
+    fn require_expected_values(
+        &self,
+        account_id: &AccountId,
+        expected: &std::collections::BTreeMap<String, Option<String>>,
+    ) -> Result<(), SocialError> {
+        for (key, expected_hash) in expected {
+            let path = crate::validation::Path::new(account_id, key, self)?;
+            let current_hash = self
+                .storage_get(path.full_path())
+                .map(|v| crate::validation::json_value_hash(&v));
+            if current_hash.as_deref() != expected_hash.as_deref() {
+                return Err(SocialError::PreconditionFailed(format!(
+                    "Value at {} has changed",
+                    path.full_path()
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Executes set operations, consuming storage costs from the provided balance.
     pub(crate) fn execute_set_operations_with_balance(
         &mut self,
@@ -34,17 +56,48 @@ impl SocialPlatform {
 
         let data_obj = crate::protocol::operation::require_non_empty_object(&op.data)?;
         self.require_batch_size_within_limit(data_obj.len())?;
+        if let Some(expected) = &op.options.expected_value_hashes {
+            self.require_expected_values(op.target_account, expected)?;
+        }
 
-        for (key, value) in data_obj {
+        if let Some(app_id) = op
+            .options
+            .storage_sponsor
+            .as_ref()
+            .filter(|app_id| !self.app_storage_pools.contains_key(*app_id))
+        {
+            return Err(crate::invalid_input!(format!(
+                "No storage pool registered for app: {}",
+                app_id
+            )));
+        }
+        if let Some(app_id) = op
+            .options
+            .storage_sponsor
+            .as_ref()
+            .filter(|app_id| !self.is_app_pool_member(app_id, op.target_account))
+        {
+            return Err(crate::unauthorized!(
+                format!("storage_sponsor {}", app_id),
+                op.target_account.to_string()
+            ));
+        }
+
+        self.storage_sponsor = op.options.storage_sponsor.clone();
+        self.index_references = op.options.index_references;
+        let result = data_obj.iter().try_for_each(|(key, value)| {
             let mut ctx = ApiOperationContext {
-                event_batch,
-                attached_balance,
+                event_batch: &mut *event_batch,
+                attached_balance: &mut *attached_balance,
                 processed_accounts: &mut processed_accounts,
                 actor_id: verified.actor_id.clone(),
                 payer_id: verified.payer_id.clone(),
             };
-            self.process_api_operation(key, value, op.target_account, verified, &mut ctx)?;
-        }
+            self.process_api_operation(key, value, op.target_account, verified, &mut ctx)
+        });
+        self.storage_sponsor = None;
+        self.index_references = false;
+        result?;
 
         self.finalize_unused_attached_deposit(
             attached_balance,
@@ -59,6 +112,9 @@ impl SocialPlatform {
             }),
         )?;
 
+        if crate::events::config::read_event_config().consolidate_data_updates {
+            event_batch.consolidate_data_updates();
+        }
         event_batch.emit()?;
         Ok(())
     }
@@ -83,6 +139,15 @@ impl SocialPlatform {
             ApiOperationKey::StorageSharedPoolDeposit => {
                 self.handle_api_shared_pool_deposit(value, account_id, ctx)
             }
+            ApiOperationKey::StorageSharedPoolWithdraw => {
+                self.handle_api_shared_pool_withdraw(value, account_id, &verified.actor_id, ctx)
+            }
+            ApiOperationKey::StorageAppPoolRegister => {
+                self.handle_api_app_pool_register(value, account_id, ctx)
+            }
+            ApiOperationKey::StorageAppPoolSponsor => {
+                self.handle_api_app_pool_sponsor(value, account_id, ctx)
+            }
             ApiOperationKey::StoragePlatformPoolDeposit => {
                 self.handle_api_platform_pool_deposit(value, account_id, ctx)
             }
@@ -1,3 +1,4 @@
+mod atomic;
 mod data_ops;
 mod get;
 pub(crate) mod helpers;
@@ -241,6 +241,29 @@ impl SocialPlatform {
         result
     }
 
+    pub(super) fn execute_action_delegate_vote(
+        &mut self,
+        group_id: &str,
+        delegate: &AccountId,
+        ctx: &mut ExecuteContext,
+    ) -> Result<(), SocialError> {
+        self.prepare_group_storage(ctx);
+        let result = self.delegate_vote(group_id.to_string(), delegate, &ctx.actor_id);
+        self.cleanup_group_storage();
+        result
+    }
+
+    pub(super) fn execute_action_undelegate_vote(
+        &mut self,
+        group_id: &str,
+        ctx: &mut ExecuteContext,
+    ) -> Result<(), SocialError> {
+        self.prepare_group_storage(ctx);
+        let result = self.undelegate_vote(group_id.to_string(), &ctx.actor_id);
+        self.cleanup_group_storage();
+        result
+    }
+
     // Permissionless: status write is paid from the proposer's bond, which
     // `update_proposal_status` unlocks before re-charging the tiny status diff.
     pub(super) fn execute_action_expire_proposal(
@@ -264,4 +287,27 @@ impl SocialPlatform {
         self.cleanup_group_storage();
         result
     }
+
+    // Permissionless; paid from the proposer's bond like expiry.
+    pub(super) fn execute_action_finalize_expired_proposal(
+        &mut self,
+        group_id: &str,
+        proposal_id: &str,
+        ctx: &mut ExecuteContext,
+    ) -> Result<&'static str, SocialError> {
+        self.prepare_group_storage(ctx);
+
+        let proposal_path = format!("groups/{}/proposals/{}", group_id, proposal_id);
+        if let Some(proposer) = self
+            .storage_get(&proposal_path)
+            .and_then(|v| v.get("proposer").and_then(|s| s.as_str()).map(String::from))
+            .and_then(|s| s.parse::<AccountId>().ok())
+        {
+            self.set_execution_payer(proposer);
+        }
+
+        let result = self.finalize_expired_proposal(group_id.to_string(), proposal_id.to_string());
+        self.cleanup_group_storage();
+        result
+    }
 }
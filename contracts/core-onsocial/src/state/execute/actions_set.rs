@@ -1,12 +1,12 @@
-use near_sdk::serde_json::Value;
+use near_sdk::serde_json::{Map, Value, json};
 use near_sdk::{AccountId, env};
 
-use crate::SocialError;
 use crate::events::EventBatch;
 use crate::state::data::helpers::SetOperation;
 use crate::state::execute::ExecuteContext;
 use crate::state::models::SocialPlatform;
 use crate::state::set_context::VerifiedContext;
+use crate::{SocialError, invalid_input, permission_denied};
 
 impl SocialPlatform {
     pub(super) fn execute_action_set(
@@ -62,4 +62,98 @@ impl SocialPlatform {
             &mut ctx.attached_balance,
         )
     }
+
+    /// Rejects the batch with every collected error before any write lands.
+    pub(super) fn execute_action_set_atomic(
+        &mut self,
+        target_account: &AccountId,
+        data: Value,
+        ctx: &mut ExecuteContext,
+    ) -> Result<(), SocialError> {
+        self.preflight_atomic_set(
+            &data,
+            target_account,
+            &ctx.actor_id,
+            ctx.options.storage_sponsor.as_ref(),
+            ctx.attached_balance,
+        )?;
+        crate::domain::authz::cross_account::validate_cross_account_permissions_simple(
+            self,
+            &data,
+            target_account,
+            &ctx.actor_id,
+            &env::signer_account_pk(),
+        )?;
+        self.execute_action_set(target_account, data, ctx)
+    }
+
+    /// Soft-deletes the target's entries under `path` through the regular set
+    /// path, so storage refunds and per-leaf DATA_UPDATE events are unchanged.
+    ///
+    /// `post/*` matches everything below `post/`; `post` also matches the
+    /// `post` entry itself. At most `max_batch_size` entries go per call;
+    /// `has_more` tells the caller to repeat.
+    pub(super) fn execute_action_delete_subtree(
+        &mut self,
+        target_account: &AccountId,
+        path: &str,
+        ctx: &mut ExecuteContext,
+    ) -> Result<Value, SocialError> {
+        let account_prefix = format!("{}/", target_account);
+        let path = path.trim().trim_start_matches('/');
+        let path = path.strip_prefix(account_prefix.as_str()).unwrap_or(path);
+        let (root, wildcard) = match path.strip_suffix('*') {
+            Some(root) => (root.trim_end_matches('/'), true),
+            None => (path.trim_end_matches('/'), false),
+        };
+        if root.is_empty() {
+            return Err(invalid_input!("Subtree path is required"));
+        }
+        if root == "groups" || root.starts_with("groups/") {
+            return Err(invalid_input!("Group paths cannot be deleted by subtree"));
+        }
+        if ["storage", "permission", "status"]
+            .iter()
+            .any(|ns| root == *ns || root.starts_with(&format!("{}/", ns)))
+        {
+            return Err(invalid_input!(
+                "Reserved paths cannot be deleted by subtree"
+            ));
+        }
+
+        let root_full = format!("{}{}", account_prefix, root);
+        let subtree_prefix = format!("{}/", root_full);
+        if !crate::domain::groups::permissions::kv::has_permissions_or_key_for_actor(
+            self,
+            target_account.as_str(),
+            &subtree_prefix,
+            crate::domain::groups::permissions::kv::types::WRITE,
+            ctx.actor_id.as_str(),
+            &env::signer_account_pk(),
+        ) {
+            return Err(permission_denied!("write", &subtree_prefix));
+        }
+
+        let limit = self.config.max_batch_size as usize;
+        let mut keys = Vec::new();
+        if !wildcard && self.key_index.contains_key(&root_full) {
+            keys.push(root_full.clone());
+        }
+        keys.extend(self.keys_with_prefix(&subtree_prefix, limit + 1));
+        let has_more = keys.len() > limit;
+        keys.truncate(limit);
+
+        if keys.is_empty() {
+            return Ok(json!({ "deleted": 0, "has_more": false }));
+        }
+
+        let deleted = keys.len();
+        let data: Map<String, Value> = keys
+            .into_iter()
+            .map(|key| (key[account_prefix.len()..].to_string(), Value::Null))
+            .collect();
+        self.execute_action_set(target_account, Value::Object(data), ctx)?;
+
+        Ok(json!({ "deleted": deleted, "has_more": has_more }))
+    }
 }
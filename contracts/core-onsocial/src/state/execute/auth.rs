@@ -28,6 +28,8 @@ impl SocialPlatform {
 
         let options = options.unwrap_or_default();
 
+        Self::require_before_deadline(&options)?;
+
         let mut ctx = self.build_execute_context(options.clone());
 
         let target_account = target_account.unwrap_or_else(|| ctx.actor_id.clone());
@@ -39,6 +41,20 @@ impl SocialPlatform {
         Ok(result)
     }
 
+    /// Bounds how long a relayer may hold a signed request before submitting it.
+    fn require_before_deadline(options: &Options) -> Result<(), SocialError> {
+        if let Some(deadline_ms) = options.deadline_ms {
+            let now_ms = env::block_timestamp_ms();
+            if now_ms > deadline_ms.0 {
+                return Err(SocialError::Expired(format!(
+                    "Request deadline {} passed at {}",
+                    deadline_ms.0, now_ms
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Predecessor-only context construction (NEP-366 compatible).
     fn build_execute_context(&self, options: Options) -> ExecuteContext {
         let predecessor = env::predecessor_account_id();
@@ -13,6 +13,9 @@ impl SocialPlatform {
     /// - Void operations return `null`
     /// - CreateGroup returns the group_id string
     /// - CreateProposal returns the proposal_id string
+    /// - DeleteSubtree returns `{deleted, has_more}`
+    /// - SweepExpiredPermissions returns `{swept, next_key}`
+    /// - FinalizeExpiredProposal returns the final proposal status string
     pub(super) fn dispatch_action(
         &mut self,
         action: &Action,
@@ -25,6 +28,15 @@ impl SocialPlatform {
                 Ok(Value::Null)
             }
 
+            Action::SetAtomic { data } => {
+                self.execute_action_set_atomic(target_account, data.clone(), ctx)?;
+                Ok(Value::Null)
+            }
+
+            Action::DeleteSubtree { path } => {
+                self.execute_action_delete_subtree(target_account, path, ctx)
+            }
+
             Action::CreateGroup { group_id, config } => {
                 self.execute_action_create_group(group_id, config.clone(), ctx)?;
                 Ok(json!(group_id))
@@ -159,6 +171,25 @@ impl SocialPlatform {
                 Ok(Value::Null)
             }
 
+            Action::FinalizeExpiredProposal {
+                group_id,
+                proposal_id,
+            } => {
+                let status =
+                    self.execute_action_finalize_expired_proposal(group_id, proposal_id, ctx)?;
+                Ok(json!(status))
+            }
+
+            Action::DelegateVote { group_id, delegate } => {
+                self.execute_action_delegate_vote(group_id, delegate, ctx)?;
+                Ok(Value::Null)
+            }
+
+            Action::UndelegateVote { group_id } => {
+                self.execute_action_undelegate_vote(group_id, ctx)?;
+                Ok(Value::Null)
+            }
+
             Action::SetPermission {
                 grantee,
                 path,
@@ -169,6 +200,12 @@ impl SocialPlatform {
                 Ok(Value::Null)
             }
 
+            Action::SweepExpiredPermissions {
+                owner,
+                limit,
+                from_key,
+            } => self.sweep_expired_permissions(owner, from_key.as_deref(), *limit),
+
             Action::SetKeyPermission {
                 public_key,
                 path,
@@ -1,4 +1,6 @@
+use crate::domain::groups::GroupStorage;
 use crate::state::models::SocialPlatform;
+use near_sdk::AccountId;
 use near_sdk::json_types::U64;
 use near_sdk::serde_json::Value;
 
@@ -108,6 +110,92 @@ impl SocialPlatform {
         }
     }
 
+    /// Up to `limit` indexed keys under `prefix`, in lexicographic order.
+    pub(crate) fn keys_with_prefix(&self, prefix: &str, limit: usize) -> Vec<String> {
+        self.iter_keys_with_prefix(prefix)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Indexed keys under `prefix`, in lexicographic order, read lazily.
+    pub(crate) fn iter_keys_with_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> Box<dyn Iterator<Item = &'a String> + 'a> {
+        let start = prefix.to_string();
+        match prefix_upper_bound(prefix) {
+            Some(end) => Box::new(self.key_index.range(start..end).map(|(k, _)| k)),
+            None => Box::new(
+                self.key_index
+                    .range(start..)
+                    .map(|(k, _)| k)
+                    .take_while(move |k| k.starts_with(prefix)),
+            ),
+        }
+    }
+
+    /// One page of `account_id`'s live entries in key order, plus the cursor to
+    /// resume from. Scans at most `limit` keys; content the account wrote into
+    /// a private group it no longer belongs to is skipped.
+    pub fn export_account(
+        &self,
+        account_id: &AccountId,
+        from_key: Option<&str>,
+        limit: u32,
+    ) -> (Vec<(String, Value)>, Option<String>) {
+        let limit = limit.min(50) as usize;
+        if limit == 0 {
+            return (vec![], from_key.map(String::from));
+        }
+
+        let prefix = format!("{}/", account_id);
+        let start = match from_key {
+            Some(cursor) if cursor.starts_with(&prefix) => cursor.to_string(),
+            _ => prefix.clone(),
+        };
+        let Some(end) = prefix_upper_bound(&prefix) else {
+            return (vec![], None);
+        };
+
+        let scanned: Vec<String> = self
+            .key_index
+            .range(start..end)
+            .filter(|(k, _)| Some(k.as_str()) != from_key)
+            .take(limit + 1)
+            .map(|(k, _)| k.clone())
+            .collect();
+        let has_more = scanned.len() > limit;
+
+        let page: Vec<String> = scanned.into_iter().take(limit).collect();
+        let next_key = if has_more { page.last().cloned() } else { None };
+        let entries = page
+            .into_iter()
+            .filter(|key| self.is_exportable(account_id, key))
+            .filter_map(|key| self.resolve_value(&key).map(|value| (key, value)))
+            .collect();
+
+        (entries, next_key)
+    }
+
+    fn is_exportable(&self, account_id: &AccountId, full_path: &str) -> bool {
+        let Some(group_rel) = full_path
+            .strip_prefix(account_id.as_str())
+            .and_then(|rest| rest.strip_prefix("/groups/"))
+        else {
+            return true;
+        };
+        let Some(group_id) = group_rel.split('/').next() else {
+            return true;
+        };
+        let is_private = GroupStorage::get_group_config(self, group_id)
+            .and_then(|cfg| cfg.get("is_private").and_then(|v| v.as_bool()))
+            .unwrap_or(false);
+        !is_private
+            || GroupStorage::is_member(self, group_id, account_id)
+            || GroupStorage::is_owner(self, group_id, account_id)
+    }
+
     /// Count keys matching prefix. Scans up to 1000 keys to bound gas.
     pub fn count_keys(&self, prefix: &str) -> u32 {
         let end = prefix_upper_bound(prefix);
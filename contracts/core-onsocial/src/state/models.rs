@@ -1,9 +1,10 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use near_sdk::store::{LookupMap, TreeMap};
+use near_sdk::store::{LookupMap, LookupSet, TreeMap};
 use near_sdk::{AccountId, env};
 use near_sdk_macros::NearSchema;
 
-use crate::config::GovernanceConfig;
+use crate::config::{GovernanceConfig, GovernanceConfigV1};
+use crate::storage::StorageKey;
 
 #[derive(
     NearSchema, BorshDeserialize, BorshSerialize, serde::Serialize, serde::Deserialize, Clone,
@@ -112,6 +113,24 @@ pub struct GroupSponsorAccount {
     pub last_refill_ns: u64,
 }
 
+/// Sponsorship terms for an app's shared pool. Funds and usage live in the
+/// app's `shared_storage_pools` entry.
+#[derive(
+    NearSchema,
+    BorshDeserialize,
+    BorshSerialize,
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Default,
+    Debug,
+)]
+#[abi(json, borsh)]
+pub struct AppStoragePool {
+    /// Most bytes the pool covers for any one payer at a time.
+    pub max_bytes_per_user: u64,
+}
+
 #[derive(
     NearSchema,
     BorshDeserialize,
@@ -215,9 +234,78 @@ pub struct SocialPlatform {
     pub group_sponsor_quotas: LookupMap<String, GroupSponsorAccount>,
     pub group_sponsor_defaults: LookupMap<String, GroupSponsorDefault>,
     pub key_index: TreeMap<String, u64>,
+    /// Account proposed via `propose_manager`; becomes manager only after it calls `accept_manager`.
+    pub pending_manager: Option<AccountId>,
+    /// Apps whose shared pool sponsors writes that name them in `Options::storage_sponsor`.
+    pub app_storage_pools: LookupMap<AccountId, AppStoragePool>,
+    /// Bytes each app pool currently covers per payer, keyed `app_id|payer`.
+    pub app_pool_usage: LookupMap<String, u64>,
+    /// Accounts each app lets name it in `storage_sponsor`, keyed `app_id|account`.
+    pub app_pool_members: LookupSet<String>,
+    /// Reverse index: parent path -> paths whose value named it as `parent`.
+    pub referencers: LookupMap<String, Vec<String>>,
+    /// Group content by creation time, keyed `group_id|created_ms|path`.
+    pub group_content_index: TreeMap<String, u64>,
+    /// Creation time (ms) of each indexed group content path.
+    pub group_content_created: LookupMap<String, u64>,
     /// Temporary override for storage payer during proposal execution.
     /// When set, group path storage is charged to this account instead of predecessor.
     /// This ensures proposers pay for execution costs from their deposited balance.
     #[borsh(skip)]
     pub execution_payer: Option<AccountId>,
+    /// App pool named by the current request's `storage_sponsor`, if any.
+    #[borsh(skip)]
+    pub storage_sponsor: Option<AccountId>,
+    /// Whether the current request opted into `Options::index_references`.
+    #[borsh(skip)]
+    pub index_references: bool,
+    /// Set during `set_maintenance` so the manager may write any account's data paths.
+    #[borsh(skip)]
+    pub maintenance_write: bool,
+}
+
+/// `SocialPlatform` as persisted by the first deployment; read only by `migrate`.
+/// Not a prefix of the current layout: `config` is stored as `GovernanceConfigV1`
+/// and the fields after `key_index` are absent. `migrate` tries the current layout
+/// first and falls back to decoding this, converting with `From`.
+#[derive(BorshDeserialize)]
+pub(crate) struct SocialPlatformV1 {
+    version: String,
+    status: ContractStatus,
+    manager: AccountId,
+    config: GovernanceConfigV1,
+    shared_storage_pools: LookupMap<AccountId, SharedStoragePool>,
+    user_storage: LookupMap<AccountId, crate::storage::Storage>,
+    group_pool_usage: LookupMap<String, u64>,
+    group_sponsor_quotas: LookupMap<String, GroupSponsorAccount>,
+    group_sponsor_defaults: LookupMap<String, GroupSponsorDefault>,
+    key_index: TreeMap<String, u64>,
+}
+
+impl From<SocialPlatformV1> for SocialPlatform {
+    fn from(v1: SocialPlatformV1) -> Self {
+        Self {
+            version: v1.version,
+            status: v1.status,
+            manager: v1.manager,
+            config: v1.config.into(),
+            shared_storage_pools: v1.shared_storage_pools,
+            user_storage: v1.user_storage,
+            group_pool_usage: v1.group_pool_usage,
+            group_sponsor_quotas: v1.group_sponsor_quotas,
+            group_sponsor_defaults: v1.group_sponsor_defaults,
+            key_index: v1.key_index,
+            pending_manager: None,
+            app_storage_pools: LookupMap::new(StorageKey::AppStoragePools),
+            app_pool_usage: LookupMap::new(StorageKey::AppPoolUsage),
+            app_pool_members: LookupSet::new(StorageKey::AppPoolMembers),
+            referencers: LookupMap::new(StorageKey::Referencers),
+            group_content_index: TreeMap::new(StorageKey::GroupContentIndex),
+            group_content_created: LookupMap::new(StorageKey::GroupContentCreated),
+            execution_payer: None,
+            storage_sponsor: None,
+            index_references: false,
+            maintenance_write: false,
+        }
+    }
 }
@@ -30,4 +30,75 @@ impl SocialPlatform {
 
         self.assert_storage_covered_with_platform(storage)
     }
+
+    /// Reject growth past the configured per-account caps.
+    ///
+    /// Only categories that grew during this write are checked, so accounts
+    /// already above a lowered cap can still shrink their usage.
+    pub(super) fn assert_account_storage_cap(
+        &self,
+        storage: &crate::storage::Storage,
+        sponsored_before: u64,
+        self_paid_before: u64,
+    ) -> Result<(), SocialError> {
+        let sponsored = storage.covered_bytes();
+        let cap = self.config.max_sponsored_bytes_per_account;
+        if sponsored > sponsored_before && cap.is_some_and(|cap| sponsored > cap) {
+            return Err(SocialError::AccountStorageCapExceeded(format!(
+                "sponsored bytes {} exceed cap {}",
+                sponsored,
+                cap.unwrap_or_default()
+            )));
+        }
+
+        let self_paid = storage.self_paid_bytes();
+        let cap = self.config.max_self_paid_bytes_per_account;
+        if self_paid > self_paid_before && cap.is_some_and(|cap| self_paid > cap) {
+            return Err(SocialError::AccountStorageCapExceeded(format!(
+                "self-paid bytes {} exceed cap {}",
+                self_paid,
+                cap.unwrap_or_default()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Run `update` on contract-side index state and bill the byte change to
+    /// the payer of `path`, using the same pool fallback as a data write.
+    pub(crate) fn charge_index_update(
+        &mut self,
+        path: &str,
+        mut attached_balance: Option<&mut u128>,
+        update: impl FnOnce(&mut Self),
+    ) -> Result<(), SocialError> {
+        let payer = self.resolve_payer_account(path)?;
+        let mut storage = self.user_storage.get(&payer).cloned().unwrap_or_default();
+        let sponsored_before = storage.covered_bytes();
+        let self_paid_before = storage.self_paid_bytes();
+
+        storage.storage_tracker.start_tracking();
+        update(self);
+        storage.storage_tracker.stop_tracking();
+        let delta = storage.storage_tracker.delta();
+        storage.storage_tracker.reset();
+
+        match delta.cmp(&0) {
+            std::cmp::Ordering::Greater => {
+                storage.used_bytes = storage.used_bytes.saturating_add(delta as u64);
+                self.allocate_storage_from_pools(&mut storage, path, &payer, delta as u64);
+                self.assert_account_storage_cap(&storage, sponsored_before, self_paid_before)?;
+            }
+            std::cmp::Ordering::Less => {
+                let freed = delta.unsigned_abs() as u64;
+                storage.used_bytes = storage.used_bytes.saturating_sub(freed);
+                self.deallocate_storage_to_pools(&mut storage, path, &payer, freed);
+            }
+            std::cmp::Ordering::Equal => {}
+        }
+
+        self.ensure_storage_covered(&mut storage, &mut attached_balance)?;
+        self.user_storage.insert(payer, storage);
+        Ok(())
+    }
 }
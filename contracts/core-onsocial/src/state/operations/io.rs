@@ -18,6 +18,73 @@ impl SocialPlatform {
         Ok(self.insert_entry_with_fallback(full_path, entry, None)?.0)
     }
 
+    /// Bytes a write of `value` at `full_path` would add, mirroring
+    /// `insert_entry_with_fallback`: new keys pay key, entry, and record
+    /// overhead; overwrites pay only growth. Nulls are tombstones and add nothing.
+    pub fn estimate_write_bytes(
+        &self,
+        full_path: &str,
+        value: &near_sdk::serde_json::Value,
+    ) -> Result<u64, SocialError> {
+        self.estimate_write_delta(full_path, value)
+            .map(|(added, _)| added)
+    }
+
+    /// `(added, freed)` bytes for a write of `value` at `full_path`. Overwrites
+    /// with a smaller value and nulls over live entries free the difference.
+    pub fn estimate_write_delta(
+        &self,
+        full_path: &str,
+        value: &near_sdk::serde_json::Value,
+    ) -> Result<(u64, u64), SocialError> {
+        let key = self
+            .resolve_storage_key(full_path)
+            .ok_or_else(|| SocialError::InvalidInput("Invalid path format".to_string()))?;
+        let existing = near_sdk::env::storage_read(key.as_bytes());
+
+        let data_value = if value.is_null() {
+            let live = existing
+                .as_deref()
+                .and_then(|data| borsh::from_slice::<DataEntry>(data).ok())
+                .is_some_and(|entry| {
+                    !matches!(entry.value, crate::state::models::DataValue::Deleted(_))
+                });
+            if !live {
+                return Ok((0, 0));
+            }
+            crate::state::models::DataValue::Deleted(near_sdk::env::block_height())
+        } else {
+            crate::state::models::DataValue::Value(crate::validation::serialize_json_with_max_len(
+                value,
+                self.config.max_value_bytes as usize,
+                "Serialization failed",
+            )?)
+        };
+        let entry = DataEntry {
+            value: data_value,
+            block_height: near_sdk::env::block_height(),
+        };
+        let entry_len = borsh::to_vec(&entry)
+            .map_err(|_| SocialError::InvalidInput("Serialization failed".to_string()))?
+            .len() as u64;
+
+        Ok(match existing {
+            Some(old) => {
+                let old_len = old.len() as u64;
+                (
+                    entry_len.saturating_sub(old_len),
+                    old_len.saturating_sub(entry_len),
+                )
+            }
+            None => (
+                (key.len() as u64)
+                    .saturating_add(entry_len)
+                    .saturating_add(crate::constants::STORAGE_RECORD_OVERHEAD_BYTES),
+                0,
+            ),
+        })
+    }
+
     /// Storage payment priority:
     /// 1. Platform Pool (rate-limited free tier)
     /// 2. Group Pool (path-driven for group content)
@@ -52,6 +119,8 @@ impl SocialPlatform {
         storage.storage_tracker.stop_tracking();
 
         let delta = storage.storage_tracker.delta();
+        let sponsored_before = storage.covered_bytes();
+        let self_paid_before = storage.self_paid_bytes();
 
         let mut sponsor_outcome: Option<super::SponsorOutcome> = None;
         match delta.cmp(&0) {
@@ -80,6 +149,9 @@ impl SocialPlatform {
 
         storage.storage_tracker.reset();
 
+        if delta > 0 {
+            self.assert_account_storage_cap(&storage, sponsored_before, self_paid_before)?;
+        }
         self.ensure_storage_covered(&mut storage, &mut attached_balance)?;
 
         self.user_storage.insert(account_id, storage);
@@ -8,6 +8,15 @@ impl SocialPlatform {
         format!("{}|{}", payer.as_str(), group_id)
     }
 
+    /// Key format: `app_id|payer`
+    #[inline(always)]
+    pub(crate) fn app_pool_usage_key(
+        app_id: &near_sdk::AccountId,
+        payer: &near_sdk::AccountId,
+    ) -> String {
+        format!("{}|{}", app_id.as_str(), payer.as_str())
+    }
+
     /// Key format: `group_id|payer` (inverted from `group_usage_key`)
     #[inline(always)]
     pub(crate) fn group_sponsor_quota_key(payer: &near_sdk::AccountId, group_id: &str) -> String {
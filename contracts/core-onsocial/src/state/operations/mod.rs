@@ -2,5 +2,6 @@ mod accounting;
 mod io;
 mod keys;
 mod pools;
+mod references;
 
 pub(crate) use pools::SponsorOutcome;
@@ -0,0 +1,95 @@
+use near_sdk::serde_json::Value;
+
+use crate::errors::SocialError;
+use crate::state::models::{DataEntry, DataValue, SocialPlatform};
+
+impl SocialPlatform {
+    /// Keep the reverse index for `path` in step with a write of `new_value`.
+    ///
+    /// Deletes always drop `path` from its old parent's list. Sets only touch
+    /// the index when the request opted in via `Options::index_references`.
+    /// Index bytes are charged to the payer of `path`.
+    pub(crate) fn sync_reference_index(
+        &mut self,
+        path: &str,
+        old_entry: Option<&DataEntry>,
+        new_value: &Value,
+        mut attached_balance: Option<&mut u128>,
+    ) -> Result<(), SocialError> {
+        if !new_value.is_null() && !self.index_references {
+            return Ok(());
+        }
+
+        let old_parent = old_entry.and_then(|entry| entry_parent(entry, path));
+        let new_parent = parent_path(new_value, path);
+        if old_parent == new_parent {
+            return Ok(());
+        }
+
+        if let Some(parent) = old_parent {
+            self.update_referencers(path, &parent, attached_balance.as_deref_mut(), |list| {
+                list.retain(|p| p != path)
+            })?;
+        }
+        if let Some(parent) = new_parent {
+            let cap = crate::constants::MAX_REFERENCERS_PER_PATH;
+            self.update_referencers(path, &parent, attached_balance, |list| {
+                if list.len() < cap && !list.iter().any(|p| p == path) {
+                    list.push(path.to_string());
+                }
+            })?;
+        }
+        Ok(())
+    }
+
+    fn update_referencers(
+        &mut self,
+        path: &str,
+        parent: &str,
+        attached_balance: Option<&mut u128>,
+        update: impl FnOnce(&mut Vec<String>),
+    ) -> Result<(), SocialError> {
+        let mut list = self.referencers.get(parent).cloned().unwrap_or_default();
+        update(&mut list);
+
+        self.charge_index_update(path, attached_balance, |platform| {
+            if list.is_empty() {
+                platform.referencers.remove(parent);
+            } else {
+                platform.referencers.insert(parent.to_string(), list);
+            }
+        })
+    }
+
+    /// Paths that named `path` as their `parent`, oldest first.
+    pub fn get_referencers(&self, path: &str, offset: u32, limit: u32) -> Vec<String> {
+        self.referencers
+            .get(path)
+            .map(|list| {
+                list.iter()
+                    .skip(offset as usize)
+                    .take(limit as usize)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn entry_parent(entry: &DataEntry, path: &str) -> Option<String> {
+    let DataValue::Value(bytes) = &entry.value else {
+        return None;
+    };
+    let value: Value = near_sdk::serde_json::from_slice(bytes).ok()?;
+    parent_path(&value, path)
+}
+
+/// `parent` of `value` when it is a full `{account}/...` path other than `path`.
+fn parent_path(value: &Value, path: &str) -> Option<String> {
+    let parent = value.get("parent")?.as_str()?;
+    let (owner, rel) = crate::storage::utils::parse_path(parent)?;
+    if rel.is_empty() || parent == path || owner.parse::<near_sdk::AccountId>().is_err() {
+        return None;
+    }
+    Some(parent.to_string())
+}
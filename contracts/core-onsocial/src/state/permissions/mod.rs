@@ -1,4 +1,5 @@
 mod api_handlers;
 mod set;
+mod sweep;
 
 pub(crate) use set::{SetKeyPermission, SetPermission};
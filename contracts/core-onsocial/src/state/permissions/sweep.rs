@@ -0,0 +1,88 @@
+use near_sdk::serde_json::{Value, json};
+use near_sdk::{AccountId, env};
+
+use crate::SocialError;
+use crate::domain::groups::permissions::kv::types::{NONE, parse_permission_value};
+use crate::events::{EventBatch, EventBuilder};
+use crate::state::models::{DataValue, SocialPlatform};
+
+impl SocialPlatform {
+    /// Soft-deletes `owner`'s account permissions whose `expires_at` has
+    /// passed, scanning at most `limit` (capped at 50) indexed grants after
+    /// `from_key`. Freed bytes are credited back to `owner`.
+    ///
+    /// Returns `{swept, next_key}`; `next_key` is null once the scan is done.
+    pub fn sweep_expired_permissions(
+        &mut self,
+        owner: &AccountId,
+        from_key: Option<&str>,
+        limit: u32,
+    ) -> Result<Value, SocialError> {
+        let prefix = format!("{}/permissions/", owner);
+        let keys: Vec<String> = self
+            .list_keys(&prefix, from_key, limit, false)
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect();
+        let next_key = (keys.len() == limit.min(50) as usize)
+            .then(|| keys.last().cloned())
+            .flatten();
+
+        let now = env::block_timestamp();
+        let mut event_batch = EventBatch::new();
+        let mut swept: u32 = 0;
+        for key in &keys {
+            // Missing or already revoked: drop the stale index entry.
+            let Some(entry) = self.get_entry(key) else {
+                self.key_index_remove(key);
+                continue;
+            };
+            let DataValue::Value(bytes) = &entry.value else {
+                self.key_index_remove(key);
+                continue;
+            };
+            let Some((_, expires_at)) = std::str::from_utf8(bytes)
+                .ok()
+                .and_then(parse_permission_value)
+            else {
+                continue;
+            };
+            if expires_at == 0 || expires_at > now {
+                continue;
+            }
+
+            crate::storage::soft_delete_entry(self, key, entry)?;
+            self.key_index_remove(key);
+            swept += 1;
+
+            let (grantee, subpath) = key[prefix.len()..]
+                .split_once('/')
+                .unwrap_or((&key[prefix.len()..], ""));
+            let Ok(grantee) = grantee.parse::<AccountId>() else {
+                continue;
+            };
+            let path = if subpath.is_empty() {
+                owner.to_string()
+            } else {
+                format!("{}/{}", owner, subpath)
+            };
+            EventBuilder::new(
+                crate::constants::EVENT_TYPE_PERMISSION_UPDATE,
+                "revoke",
+                owner.clone(),
+            )
+            .with_target(&grantee)
+            .with_path(&path)
+            .with_value(Value::Null)
+            .with_field("level", NONE)
+            .with_field("expires_at", expires_at.to_string())
+            .with_field("deleted", true)
+            .with_field("granted", false)
+            .with_field("reason", "expired")
+            .emit(&mut event_batch);
+        }
+        event_batch.emit()?;
+
+        Ok(json!({ "swept": swept, "next_key": next_key }))
+    }
+}
@@ -3,7 +3,7 @@ use crate::state::models::{ContractStatus, DataEntry, SocialPlatform};
 use crate::{
     config::GovernanceConfig, errors::*, invalid_input, storage::StorageKey, unauthorized,
 };
-use near_sdk::store::TreeMap;
+use near_sdk::store::{LookupSet, TreeMap};
 use near_sdk::{AccountId, NearToken, Promise, env, serde_json::Value, store::LookupMap};
 
 pub struct UnusedDepositEventMeta<'a> {
@@ -38,7 +38,17 @@ impl SocialPlatform {
             group_sponsor_quotas: LookupMap::new(StorageKey::GroupSponsorQuotas),
             group_sponsor_defaults: LookupMap::new(StorageKey::GroupSponsorDefaults),
             key_index: TreeMap::new(StorageKey::KeyIndex),
+            pending_manager: None,
+            app_storage_pools: LookupMap::new(StorageKey::AppStoragePools),
+            app_pool_usage: LookupMap::new(StorageKey::AppPoolUsage),
+            app_pool_members: LookupSet::new(StorageKey::AppPoolMembers),
+            referencers: LookupMap::new(StorageKey::Referencers),
+            group_content_index: TreeMap::new(StorageKey::GroupContentIndex),
+            group_content_created: LookupMap::new(StorageKey::GroupContentCreated),
             execution_payer: None,
+            storage_sponsor: None,
+            index_references: false,
+            maintenance_write: false,
         }
     }
 
@@ -58,9 +68,10 @@ impl SocialPlatform {
         let serialized =
             serde_json::to_vec(value).map_err(|_| invalid_input!("Serialization failed"))?;
 
-        if serialized.len() > self.config.max_value_bytes as usize {
-            return Err(invalid_input!("Value payload too large"));
-        }
+        crate::validation::require_value_within_limit(
+            serialized.len(),
+            self.config.max_value_bytes as usize,
+        )?;
 
         let entry = DataEntry {
             value: crate::state::models::DataValue::Value(serialized),
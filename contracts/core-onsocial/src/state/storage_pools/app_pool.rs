@@ -0,0 +1,209 @@
+use near_sdk::AccountId;
+use serde_json::Value;
+
+use crate::SocialError;
+use crate::events::EventBuilder;
+use crate::state::models::{AppStoragePool, SocialPlatform};
+use crate::state::set_context::ApiOperationContext;
+
+impl SocialPlatform {
+    /// Register (or update) `account_id` as an app whose shared pool sponsors
+    /// writes that name it in `storage_sponsor`. Funding uses `shared_pool_deposit`.
+    pub(crate) fn handle_api_app_pool_register(
+        &mut self,
+        value: &Value,
+        account_id: &AccountId,
+        ctx: &mut ApiOperationContext,
+    ) -> Result<(), SocialError> {
+        let max_bytes_per_user = value
+            .get("max_bytes_per_user")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| {
+                crate::invalid_input!("max_bytes_per_user required for app_pool_register")
+            })?;
+
+        if max_bytes_per_user == 0 {
+            return Err(crate::invalid_input!(
+                "max_bytes_per_user must be greater than zero"
+            ));
+        }
+
+        let mut storage = self
+            .user_storage
+            .get(account_id)
+            .cloned()
+            .unwrap_or_default();
+
+        storage.storage_tracker.start_tracking();
+        let previous = self
+            .app_storage_pools
+            .insert(account_id.clone(), AppStoragePool { max_bytes_per_user });
+        storage.storage_tracker.stop_tracking();
+        let delta = storage.storage_tracker.delta();
+        storage.storage_tracker.reset();
+
+        if delta > 0 {
+            storage.used_bytes = storage.used_bytes.saturating_add(delta as u64);
+            storage.assert_storage_covered()?;
+        }
+
+        self.user_storage.insert(account_id.clone(), storage);
+
+        EventBuilder::new(
+            crate::constants::EVENT_TYPE_STORAGE_UPDATE,
+            "app_pool_register",
+            account_id.clone(),
+        )
+        .with_field("pool_id", account_id.to_string())
+        .with_field("max_bytes_per_user", max_bytes_per_user)
+        .with_field(
+            "previous_max_bytes_per_user",
+            previous.map(|p| p.max_bytes_per_user),
+        )
+        .emit(ctx.event_batch);
+
+        Ok(())
+    }
+
+    /// Allow (or stop allowing) `target_id` to name the caller's app pool in
+    /// `storage_sponsor`. Writes from accounts the app has not allowed are rejected.
+    pub(crate) fn handle_api_app_pool_sponsor(
+        &mut self,
+        value: &Value,
+        account_id: &AccountId,
+        ctx: &mut ApiOperationContext,
+    ) -> Result<(), SocialError> {
+        let target_id_str = value
+            .get("target_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| crate::invalid_input!("target_id required for app_pool_sponsor"))?;
+
+        let target_id: AccountId = crate::validation::parse_account_id_str(
+            target_id_str,
+            crate::invalid_input!("Invalid target_id account ID"),
+        )?;
+
+        let enabled: bool = value
+            .get("enabled")
+            .and_then(|v| v.as_bool())
+            .ok_or_else(|| crate::invalid_input!("enabled required for app_pool_sponsor"))?;
+
+        if !self.app_storage_pools.contains_key(account_id) {
+            return Err(crate::invalid_input!(format!(
+                "No storage pool registered for app: {}",
+                account_id
+            )));
+        }
+
+        let mut storage = self
+            .user_storage
+            .get(account_id)
+            .cloned()
+            .unwrap_or_default();
+
+        let member_key = Self::app_pool_usage_key(account_id, &target_id);
+        storage.storage_tracker.start_tracking();
+        if enabled {
+            self.app_pool_members.insert(member_key);
+        } else {
+            self.app_pool_members.remove(&member_key);
+        }
+        storage.storage_tracker.stop_tracking();
+        let delta = storage.storage_tracker.delta();
+        storage.storage_tracker.reset();
+
+        if delta > 0 {
+            storage.used_bytes = storage.used_bytes.saturating_add(delta as u64);
+            storage.assert_storage_covered()?;
+        } else if delta < 0 {
+            storage.used_bytes = storage
+                .used_bytes
+                .saturating_sub(delta.unsigned_abs() as u64);
+        }
+
+        self.user_storage.insert(account_id.clone(), storage);
+
+        EventBuilder::new(
+            crate::constants::EVENT_TYPE_STORAGE_UPDATE,
+            "app_pool_sponsor",
+            account_id.clone(),
+        )
+        .with_field("pool_id", account_id.to_string())
+        .with_field("target_id", target_id.to_string())
+        .with_field("enabled", enabled)
+        .emit(ctx.event_batch);
+
+        Ok(())
+    }
+
+    /// Whether `account_id` may name `app_id` in `storage_sponsor`.
+    pub(crate) fn is_app_pool_member(&self, app_id: &AccountId, account_id: &AccountId) -> bool {
+        app_id == account_id
+            || self
+                .app_pool_members
+                .contains(&Self::app_pool_usage_key(app_id, account_id))
+    }
+
+    /// Withdraw unused funds from the caller's own shared pool to the actor.
+    /// Bytes in use and bytes promised via `share_storage` stay reserved.
+    pub(crate) fn handle_api_shared_pool_withdraw(
+        &mut self,
+        value: &Value,
+        account_id: &AccountId,
+        actor_id: &AccountId,
+        ctx: &mut ApiOperationContext,
+    ) -> Result<(), SocialError> {
+        let amount: Option<u128> = value
+            .get("amount")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<u128>().ok());
+
+        if let Some(requested) = amount {
+            Self::require_positive_amount(requested)?;
+        }
+
+        let mut pool = self
+            .shared_storage_pools
+            .get(account_id)
+            .cloned()
+            .ok_or_else(|| crate::invalid_input!("Shared pool not found"))?;
+
+        let reserved = crate::storage::calculate_storage_balance_needed(
+            pool.used_bytes.saturating_add(pool.shared_bytes),
+        );
+        let available = pool.storage_balance.saturating_sub(reserved);
+        let withdraw_amount = amount.unwrap_or(available);
+
+        if withdraw_amount == 0 {
+            return Err(crate::invalid_input!("Nothing to withdraw"));
+        }
+
+        if withdraw_amount > available {
+            return Err(crate::invalid_input!(
+                "Withdrawal amount exceeds available pool balance"
+            ));
+        }
+
+        let previous_pool_balance = pool.storage_balance;
+        pool.storage_balance = pool.storage_balance.saturating_sub(withdraw_amount);
+        let new_pool_balance = pool.storage_balance;
+        self.shared_storage_pools.insert(account_id.clone(), pool);
+
+        near_sdk::Promise::new(actor_id.clone())
+            .transfer(near_sdk::NearToken::from_yoctonear(withdraw_amount))
+            .detach();
+
+        EventBuilder::new(
+            crate::constants::EVENT_TYPE_STORAGE_UPDATE,
+            "pool_withdraw",
+            account_id.clone(),
+        )
+        .with_field("pool_id", account_id.to_string())
+        .with_field("amount", withdraw_amount.to_string())
+        .with_field("previous_pool_balance", previous_pool_balance.to_string())
+        .with_field("new_pool_balance", new_pool_balance.to_string())
+        .emit(ctx.event_batch);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+mod app_pool;
 mod deposit;
 mod group_pool;
 mod group_sponsor;
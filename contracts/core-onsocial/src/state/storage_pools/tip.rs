@@ -46,13 +46,7 @@ impl SocialPlatform {
         let sender_previous_balance = sender_storage.balance.0;
 
         // Available = balance - locked - storage_cost(effective_bytes)
-        let covered_bytes = sender_storage
-            .shared_storage
-            .as_ref()
-            .map(|s| s.used_bytes)
-            .unwrap_or(0)
-            .saturating_add(sender_storage.group_pool_used_bytes)
-            .saturating_add(sender_storage.platform_pool_used_bytes);
+        let covered_bytes = sender_storage.covered_bytes();
 
         let used_balance = crate::storage::calculate_storage_balance_needed(
             crate::storage::calculate_effective_bytes(sender_storage.used_bytes, covered_bytes),
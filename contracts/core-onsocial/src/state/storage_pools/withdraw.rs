@@ -28,13 +28,7 @@ impl SocialPlatform {
 
         let previous_balance = storage.balance.0;
 
-        let covered_bytes = storage
-            .shared_storage
-            .as_ref()
-            .map(|s| s.used_bytes)
-            .unwrap_or(0)
-            .saturating_add(storage.group_pool_used_bytes)
-            .saturating_add(storage.platform_pool_used_bytes);
+        let covered_bytes = storage.covered_bytes();
 
         let used_balance = crate::storage::calculate_storage_balance_needed(
             crate::storage::calculate_effective_bytes(storage.used_bytes, covered_bytes),
@@ -13,15 +13,13 @@ use near_sdk_macros::NearSchema;
 ///
 /// # Invariant
 /// `available_balance() >= storage_balance_needed(used_bytes - covered_bytes())`
+///
+/// # Layout
+/// Fields are only ever appended. Records written before a trailing field
+/// existed still decode (see the `BorshDeserialize` impl), so `user_storage`
+/// entries upgrade lazily on their next write.
 #[derive(
-    NearSchema,
-    BorshDeserialize,
-    BorshSerialize,
-    serde::Serialize,
-    serde::Deserialize,
-    Clone,
-    Default,
-    Debug,
+    NearSchema, BorshSerialize, serde::Serialize, serde::Deserialize, Clone, Default, Debug,
 )]
 #[abi(json, borsh)]
 pub struct Storage {
@@ -42,14 +40,71 @@ pub struct Storage {
     pub platform_last_refill_ns: u64,
     #[serde(default)]
     pub locked_balance: U128,
+    #[serde(default)]
+    pub app_pool_used_bytes: u64,
+    /// App pools currently covering some of `app_pool_used_bytes`.
+    #[serde(default)]
+    pub app_pool_ids: Vec<AccountId>,
     #[serde(skip)]
     #[borsh(skip)]
     pub storage_tracker: crate::storage::tracker::StorageTracker,
 }
 
+/// `Storage` as written before app storage pools.
+#[derive(BorshDeserialize)]
+struct StorageV1 {
+    balance: U128,
+    used_bytes: u64,
+    shared_storage: Option<AccountSharedStorage>,
+    group_pool_used_bytes: u64,
+    platform_pool_used_bytes: u64,
+    platform_sponsored: bool,
+    platform_first_write_ns: Option<u64>,
+    platform_allowance: u64,
+    platform_last_refill_ns: u64,
+    locked_balance: U128,
+}
+
+impl BorshDeserialize for Storage {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let v1 = StorageV1::deserialize_reader(reader)?;
+
+        // A V1 record ends here; anything after it is the app-pool tail.
+        let mut first = [0u8; 1];
+        let (app_pool_used_bytes, app_pool_ids) = if reader.read(&mut first)? == 0 {
+            (0, Vec::new())
+        } else {
+            let mut bytes = [0u8; 8];
+            bytes[0] = first[0];
+            reader.read_exact(&mut bytes[1..])?;
+            (
+                u64::from_le_bytes(bytes),
+                Vec::<AccountId>::deserialize_reader(reader)?,
+            )
+        };
+
+        Ok(Self {
+            balance: v1.balance,
+            used_bytes: v1.used_bytes,
+            shared_storage: v1.shared_storage,
+            group_pool_used_bytes: v1.group_pool_used_bytes,
+            platform_pool_used_bytes: v1.platform_pool_used_bytes,
+            platform_sponsored: v1.platform_sponsored,
+            platform_first_write_ns: v1.platform_first_write_ns,
+            platform_allowance: v1.platform_allowance,
+            platform_last_refill_ns: v1.platform_last_refill_ns,
+            locked_balance: v1.locked_balance,
+            app_pool_used_bytes,
+            app_pool_ids,
+            storage_tracker: Default::default(),
+        })
+    }
+}
+
 impl Storage {
+    /// Bytes covered by platform, group, app, or shared pools.
     #[inline(always)]
-    fn covered_bytes(&self) -> u64 {
+    pub fn covered_bytes(&self) -> u64 {
         let sponsor_bytes = self
             .shared_storage
             .as_ref()
@@ -58,6 +113,21 @@ impl Storage {
         sponsor_bytes
             .saturating_add(self.group_pool_used_bytes)
             .saturating_add(self.platform_pool_used_bytes)
+            .saturating_add(self.app_pool_used_bytes)
+    }
+
+    /// Whether `app_id` may sponsor this account: it already does, or the
+    /// account is below `MAX_APP_POOLS_PER_ACCOUNT` sponsoring apps.
+    #[inline(always)]
+    pub fn can_track_app_pool(&self, app_id: &AccountId) -> bool {
+        self.app_pool_ids.contains(app_id)
+            || self.app_pool_ids.len() < crate::constants::MAX_APP_POOLS_PER_ACCOUNT
+    }
+
+    /// Bytes charged to the account's own balance.
+    #[inline(always)]
+    pub fn self_paid_bytes(&self) -> u64 {
+        self.used_bytes.saturating_sub(self.covered_bytes())
     }
 
     #[inline(always)]
@@ -9,4 +9,10 @@ pub enum StorageKey {
     GroupSponsorQuotas,
     GroupSponsorDefaults,
     KeyIndex,
+    AppStoragePools,
+    AppPoolUsage,
+    AppPoolMembers,
+    Referencers,
+    GroupContentIndex,
+    GroupContentCreated,
 }
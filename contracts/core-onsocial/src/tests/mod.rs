@@ -1,41 +1,62 @@
 pub mod test_utils;
 
 pub mod unit {
+    pub mod account_storage_cap_test;
     pub mod account_validation_test;
     pub mod accounting_test;
     pub mod advanced_functionalities_test;
     pub mod api_edge_cases_test;
+    pub mod app_storage_pool_test;
+    pub mod conditional_set_test;
     pub mod contract_lifecycle_test;
     pub mod custom_proposal_test;
+    pub mod delete_subtree_test;
     pub mod enhanced_permissions_test;
     pub mod error_message_test;
     pub mod event_builder_writes_test;
+    pub mod event_consolidation_test;
     pub mod event_emission_test;
+    pub mod event_encoding_test;
+    pub mod execute_deadline_test;
     pub mod expire_proposal_test;
+    pub mod export_account_test;
+    pub mod finalize_expired_proposal_test;
     pub mod get_api_test;
     pub mod governance_status_test;
     pub mod governance_test;
     pub mod grants_test;
+    pub mod group_content_range_test;
     pub mod group_sponsor_quota_test;
+    pub mod group_storage_warning_test;
     pub mod group_test;
+    pub mod has_any_permission_test;
     pub mod io_operations_test;
     pub mod key_index_test;
     pub mod kv_eval_test;
     pub mod kv_types_test;
+    pub mod maintenance_write_test;
+    pub mod manager_transfer_test;
     pub mod members;
     pub mod membership_test;
     pub mod proposal_index_test;
+    pub mod referencers_test;
+    pub mod reserved_path_test;
     pub mod sdk_parity_test;
+    pub mod set_atomic_test;
     pub mod stats_test;
     pub mod storage_tip_test;
     pub mod storage_tracker_helpers_test;
     pub mod storage_tracking_test;
+    pub mod sweep_permissions_test;
+    pub mod value_size_limit_test;
+    pub mod vote_delegation_test;
     pub mod voting;
     pub mod voting_config_test;
     pub mod voting_edge_cases;
     pub mod voting_group_updates;
     pub mod voting_proposal_types;
     pub mod wnear_test;
+    pub mod write_cost_estimate_test;
 }
 
 pub mod workflow {
@@ -403,6 +403,22 @@ pub fn expire_proposal_request(group_id: String, proposal_id: String) -> crate::
     }
 }
 
+#[cfg(test)]
+pub fn finalize_expired_proposal_request(
+    group_id: String,
+    proposal_id: String,
+) -> crate::protocol::Request {
+    use crate::protocol::{Action, Request};
+    Request {
+        target_account: None,
+        action: Action::FinalizeExpiredProposal {
+            group_id,
+            proposal_id,
+        },
+        options: None,
+    }
+}
+
 #[cfg(test)]
 pub fn set_permission_request(
     grantee: AccountId,
@@ -474,13 +490,14 @@ pub fn test_add_member_bypass_proposals_with_timestamp(
         "added_by": added_by.as_str(),
         "is_creator": false
     });
+    let member_path = format!("groups/{}/members/{}", group_id, member_id.as_str());
     contract
         .platform
-        .storage_set(
-            &format!("groups/{}/members/{}", group_id, member_id.as_str()),
-            &member_data,
-        )
+        .storage_set(&member_path, &member_data)
         .expect("Test setup: failed to add member");
+    contract
+        .platform
+        .key_index_insert(&member_path, near_sdk::env::block_height());
 
     // Mirror production behavior: grant default /content WRITE for all members,
     // while keeping global role (group-root) optional.
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod account_storage_cap_tests {
+    use crate::SocialError;
+    use crate::config::ConfigUpdate;
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::json;
+    use near_sdk::test_utils::accounts;
+    use near_sdk::{NearToken, testing_env};
+
+    const CAP_BYTES: u64 = 2_000;
+
+    fn setup_capped_contract() -> crate::Contract {
+        let manager = accounts(0);
+        testing_env!(get_context(manager.clone()).build());
+        let mut contract = init_live_contract();
+
+        testing_env!(get_context_with_deposit(manager, 1).build());
+        contract
+            .update_config(ConfigUpdate {
+                max_self_paid_bytes_per_account: Some(CAP_BYTES),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let alice = accounts(1);
+        let deposit = NearToken::from_near(5).as_yoctonear();
+        testing_env!(get_context_with_deposit(alice, deposit).build());
+        contract
+            .execute_admin(set_request(json!({
+                "storage/deposit": { "amount": deposit.to_string() }
+            })))
+            .unwrap();
+        contract
+    }
+
+    fn write_post(contract: &mut crate::Contract, index: usize) -> Result<(), SocialError> {
+        testing_env!(get_context(accounts(1)).build());
+        contract
+            .execute(set_request(json!({
+                format!("posts/{}", index): "x".repeat(400)
+            })))
+            .map(|_| ())
+    }
+
+    #[test]
+    fn test_writes_up_to_cap_then_reject_overflow() {
+        let mut contract = setup_capped_contract();
+
+        let mut accepted = 0;
+        let err = loop {
+            match write_post(&mut contract, accepted) {
+                Ok(()) => accepted += 1,
+                Err(e) => break e,
+            }
+            assert!(accepted < 100, "cap was never enforced");
+        };
+
+        assert!(accepted > 0, "writes below the cap must succeed");
+        assert!(
+            matches!(err, SocialError::AccountStorageCapExceeded(_)),
+            "expected AccountStorageCapExceeded, got {:?}",
+            err
+        );
+
+        let view = contract.get_account_storage_cap(accounts(1));
+        assert_eq!(view["max_self_paid_bytes"], json!(CAP_BYTES));
+        assert!(view["self_paid_bytes"].as_u64().unwrap() <= CAP_BYTES);
+    }
+
+    #[test]
+    fn test_manager_raising_cap_allows_more_writes() {
+        let mut contract = setup_capped_contract();
+
+        let mut index = 0;
+        while write_post(&mut contract, index).is_ok() {
+            index += 1;
+        }
+
+        testing_env!(get_context_with_deposit(accounts(0), 1).build());
+        contract
+            .update_config(ConfigUpdate {
+                max_self_paid_bytes_per_account: Some(CAP_BYTES * 4),
+                ..Default::default()
+            })
+            .unwrap();
+
+        write_post(&mut contract, index + 1).unwrap();
+        assert_eq!(
+            contract.get_config().max_self_paid_bytes_per_account,
+            Some(CAP_BYTES * 4)
+        );
+    }
+
+    #[test]
+    fn test_zero_patch_removes_cap() {
+        let mut contract = setup_capped_contract();
+
+        testing_env!(get_context_with_deposit(accounts(0), 1).build());
+        contract
+            .update_config(ConfigUpdate {
+                max_self_paid_bytes_per_account: Some(0),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(
+            contract
+                .get_config()
+                .max_self_paid_bytes_per_account
+                .is_none()
+        );
+
+        for index in 0..10 {
+            write_post(&mut contract, index).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_view_reports_uncapped_usage() {
+        let manager = accounts(0);
+        testing_env!(get_context(manager).build());
+        let contract = init_live_contract();
+
+        let view = contract.get_account_storage_cap(accounts(1));
+        assert_eq!(view["used_bytes"], json!(0));
+        assert!(view["max_sponsored_bytes"].is_null());
+        assert!(view["max_self_paid_bytes"].is_null());
+    }
+}
@@ -26,6 +26,7 @@ mod test_advanced_functionalities {
         // Use refund_unused_deposit: true so only 2 NEAR is deposited (not 5)
         let options = Some(crate::Options {
             refund_unused_deposit: true,
+            ..Default::default()
         });
         let result = contract.execute_admin(set_request_with_options(deposit_data, options));
         assert!(result.is_ok(), "Storage deposit should succeed");
@@ -88,6 +89,7 @@ mod test_advanced_functionalities {
         // Use refund_unused_deposit: true so only 2 NEAR is deposited (not 3)
         let options = Some(crate::Options {
             refund_unused_deposit: true,
+            ..Default::default()
         });
         let result = contract.execute_admin(set_request_with_options(deposit_data, options));
         assert!(result.is_ok());
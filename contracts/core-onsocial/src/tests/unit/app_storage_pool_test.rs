@@ -0,0 +1,311 @@
+// --- App Storage Pool Tests ---
+// Apps register a pool, fund it, and cover writes from accounts they allow
+// that name them as sponsor.
+
+#[cfg(test)]
+mod app_storage_pool_tests {
+    use crate::Options;
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::json;
+    use near_sdk::test_utils::accounts;
+    use near_sdk::{AccountId, NearToken, testing_env};
+
+    const MAX_BYTES_PER_USER: u64 = 2_000;
+
+    fn sponsored_by(app: &AccountId) -> Option<Options> {
+        Some(Options {
+            storage_sponsor: Some(app.clone()),
+            ..Default::default()
+        })
+    }
+
+    fn register_and_fund(contract: &mut crate::Contract, app: &AccountId, amount: u128) {
+        testing_env!(get_context_with_deposit(app.clone(), amount).build());
+        contract
+            .execute_admin(set_request(json!({
+                "storage/shared_pool_deposit": {
+                    "pool_id": app.to_string(),
+                    "amount": amount.to_string()
+                }
+            })))
+            .unwrap();
+
+        testing_env!(
+            get_context_with_deposit(app.clone(), NearToken::from_millinear(10).as_yoctonear())
+                .build()
+        );
+        contract
+            .execute_admin(set_request(json!({
+                "storage/deposit": { "amount": NearToken::from_millinear(10).as_yoctonear().to_string() },
+                "storage/app_pool_register": { "max_bytes_per_user": MAX_BYTES_PER_USER }
+            })))
+            .unwrap();
+    }
+
+    fn allow(contract: &mut crate::Contract, app: &AccountId, user: &AccountId, enabled: bool) {
+        testing_env!(get_context(app.clone()).build());
+        contract
+            .execute_admin(set_request(json!({
+                "storage/app_pool_sponsor": { "target_id": user.to_string(), "enabled": enabled }
+            })))
+            .unwrap();
+    }
+
+    fn pool_used_bytes(contract: &crate::Contract, app: &AccountId) -> u64 {
+        contract.get_storage_pool(app.clone()).unwrap()["used_bytes"]
+            .as_u64()
+            .unwrap()
+    }
+
+    #[test]
+    fn sponsored_write_debits_the_named_app_pool() {
+        let mut contract = init_live_contract();
+        let app = accounts(2);
+        let user = accounts(0);
+        register_and_fund(&mut contract, &app, NearToken::from_near(1).as_yoctonear());
+        allow(&mut contract, &app, &user, true);
+
+        testing_env!(get_context(user.clone()).build());
+        contract
+            .execute(set_request_with_options(
+                json!({ "profile/name": "Alice" }),
+                sponsored_by(&app),
+            ))
+            .expect("app pool should cover the write");
+
+        let storage = contract.get_storage_balance(user.clone()).unwrap();
+        assert!(storage.app_pool_used_bytes > 0);
+        assert_eq!(storage.balance.0, 0, "user balance must not be charged");
+        assert_eq!(storage.platform_pool_used_bytes, 0);
+        assert_eq!(
+            pool_used_bytes(&contract, &app),
+            storage.app_pool_used_bytes
+        );
+        assert_eq!(
+            contract.get_storage_pool(app.clone()).unwrap()["max_bytes_per_user"],
+            json!(MAX_BYTES_PER_USER)
+        );
+
+        // Deleting under the same sponsor hands the bytes back to the app.
+        contract
+            .execute(set_request_with_options(
+                json!({ "profile/name": null }),
+                sponsored_by(&app),
+            ))
+            .unwrap();
+        let after = contract.get_storage_balance(user).unwrap();
+        assert!(after.app_pool_used_bytes < storage.app_pool_used_bytes);
+        assert_eq!(pool_used_bytes(&contract, &app), after.app_pool_used_bytes);
+    }
+
+    #[test]
+    fn unsponsored_delete_refunds_the_app_pool() {
+        let mut contract = init_live_contract();
+        let app = accounts(2);
+        let user = accounts(0);
+        register_and_fund(&mut contract, &app, NearToken::from_near(1).as_yoctonear());
+        allow(&mut contract, &app, &user, true);
+
+        testing_env!(get_context(user.clone()).build());
+        contract
+            .execute(set_request_with_options(
+                json!({ "profile/name": "Alice" }),
+                sponsored_by(&app),
+            ))
+            .unwrap();
+        let before = contract.get_storage_balance(user.clone()).unwrap();
+        assert_eq!(before.app_pool_ids, vec![app.clone()]);
+
+        // The delete does not name the app, yet its bytes still go back.
+        contract
+            .execute(set_request(json!({ "profile/name": null })))
+            .unwrap();
+
+        let after = contract.get_storage_balance(user).unwrap();
+        assert!(after.app_pool_used_bytes < before.app_pool_used_bytes);
+        assert_eq!(pool_used_bytes(&contract, &app), after.app_pool_used_bytes);
+        assert_eq!(after.platform_pool_used_bytes, 0);
+    }
+
+    #[test]
+    fn write_beyond_per_user_limit_falls_back_to_payer() {
+        let mut contract = init_live_contract();
+        let app = accounts(2);
+        let user = accounts(0);
+        register_and_fund(&mut contract, &app, NearToken::from_near(1).as_yoctonear());
+        allow(&mut contract, &app, &user, true);
+
+        testing_env!(get_context(user.clone()).build());
+        let too_big = "x".repeat(MAX_BYTES_PER_USER as usize);
+        let result = contract.execute(set_request_with_options(
+            json!({ "posts/1": too_big }),
+            sponsored_by(&app),
+        ));
+
+        assert!(
+            result.is_err(),
+            "unfunded user must not exceed the app limit"
+        );
+        assert_eq!(pool_used_bytes(&contract, &app), 0);
+    }
+
+    #[test]
+    fn unregistered_sponsor_is_rejected() {
+        let mut contract = init_live_contract();
+        let user = accounts(0);
+        testing_env!(
+            get_context_with_deposit(user.clone(), NearToken::from_near(1).as_yoctonear()).build()
+        );
+
+        let err = contract
+            .execute(set_request_with_options(
+                json!({ "profile/name": "Alice" }),
+                sponsored_by(&accounts(3)),
+            ))
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("No storage pool registered"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn account_the_app_has_not_allowed_is_rejected() {
+        let mut contract = init_live_contract();
+        let app = accounts(2);
+        let user = accounts(0);
+        register_and_fund(&mut contract, &app, NearToken::from_near(1).as_yoctonear());
+
+        testing_env!(
+            get_context_with_deposit(user.clone(), NearToken::from_near(1).as_yoctonear()).build()
+        );
+        let err = contract
+            .execute(set_request_with_options(
+                json!({ "profile/name": "Alice" }),
+                sponsored_by(&app),
+            ))
+            .unwrap_err();
+        assert!(err.to_string().contains("Unauthorized"), "{}", err);
+        assert_eq!(pool_used_bytes(&contract, &app), 0);
+
+        // Once allowed the write is sponsored; revoking closes the pool again.
+        allow(&mut contract, &app, &user, true);
+        testing_env!(get_context(user.clone()).build());
+        contract
+            .execute(set_request_with_options(
+                json!({ "profile/name": "Alice" }),
+                sponsored_by(&app),
+            ))
+            .unwrap();
+        assert!(pool_used_bytes(&contract, &app) > 0);
+
+        allow(&mut contract, &app, &user, false);
+        testing_env!(get_context(user.clone()).build());
+        assert!(
+            contract
+                .execute(set_request_with_options(
+                    json!({ "profile/bio": "hi" }),
+                    sponsored_by(&app),
+                ))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn only_app_manages_its_sponsored_accounts() {
+        let mut contract = init_live_contract();
+        let app = accounts(2);
+        let user = accounts(0);
+        register_and_fund(&mut contract, &app, NearToken::from_near(1).as_yoctonear());
+
+        testing_env!(get_context(user.clone()).build());
+        assert!(
+            contract
+                .execute(set_request_for(
+                    app.clone(),
+                    json!({
+                        "storage/app_pool_sponsor": { "target_id": user.to_string(), "enabled": true }
+                    })
+                ))
+                .is_err()
+        );
+        assert!(!contract.platform.is_app_pool_member(&app, &user));
+    }
+
+    #[test]
+    fn only_owner_withdraws_unused_pool_funds() {
+        let mut contract = init_live_contract();
+        let app = accounts(2);
+        let user = accounts(0);
+        let funded = NearToken::from_near(1).as_yoctonear();
+        register_and_fund(&mut contract, &app, funded);
+        allow(&mut contract, &app, &user, true);
+
+        testing_env!(get_context(user.clone()).build());
+        contract
+            .execute(set_request_with_options(
+                json!({ "profile/name": "Alice" }),
+                sponsored_by(&app),
+            ))
+            .unwrap();
+
+        // Another account cannot drain the app's pool.
+        testing_env!(get_context(user.clone()).build());
+        assert!(
+            contract
+                .execute(set_request_for(
+                    app.clone(),
+                    json!({ "storage/shared_pool_withdraw": {} })
+                ))
+                .is_err()
+        );
+
+        testing_env!(get_context(app.clone()).build());
+        contract
+            .execute_admin(set_request(json!({ "storage/shared_pool_withdraw": {} })))
+            .expect("owner withdraws the remainder");
+
+        let used = pool_used_bytes(&contract, &app);
+        let view = contract.get_storage_pool(app.clone()).unwrap();
+        let remaining: u128 = view["storage_balance"].as_str().unwrap().parse().unwrap();
+        assert_eq!(
+            remaining,
+            crate::storage::calculate_storage_balance_needed(used)
+        );
+        assert!(remaining < funded);
+
+        // Nothing left beyond what live sponsored bytes still need.
+        assert!(
+            contract
+                .execute_admin(set_request(json!({ "storage/shared_pool_withdraw": {} })))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn storage_records_from_before_app_pools_still_decode() {
+        let storage = crate::storage::Storage {
+            balance: near_sdk::json_types::U128(42),
+            used_bytes: 700,
+            platform_pool_used_bytes: 100,
+            locked_balance: near_sdk::json_types::U128(7),
+            app_pool_used_bytes: 300,
+            ..Default::default()
+        };
+        let bytes = borsh::to_vec(&storage).unwrap();
+
+        let decoded: crate::storage::Storage = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(decoded.app_pool_used_bytes, 300);
+
+        // Pre-upgrade records end at `locked_balance`; the app-pool tail is
+        // the u64 byte count plus an empty id list.
+        let legacy: crate::storage::Storage =
+            borsh::from_slice(&bytes[..bytes.len() - 12]).unwrap();
+        assert_eq!(legacy.balance.0, 42);
+        assert_eq!(legacy.used_bytes, 700);
+        assert_eq!(legacy.platform_pool_used_bytes, 100);
+        assert_eq!(legacy.locked_balance.0, 7);
+        assert_eq!(legacy.app_pool_used_bytes, 0);
+    }
+}
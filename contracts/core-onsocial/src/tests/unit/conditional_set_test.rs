@@ -0,0 +1,110 @@
+// --- Conditional Set Tests ---
+// Compare-and-swap writes via `Options.expected_value_hashes`.
+
+#[cfg(test)]
+mod conditional_set_tests {
+    use crate::tests::test_utils::*;
+    use crate::validation::json_value_hash;
+    use crate::{Options, SocialError};
+    use near_sdk::serde_json::{Value, json};
+    use near_sdk::test_utils::accounts;
+    use near_sdk::testing_env;
+    use std::collections::BTreeMap;
+
+    fn expect(key: &str, hash: Option<String>) -> Option<Options> {
+        let mut expected = BTreeMap::new();
+        expected.insert(key.to_string(), hash);
+        Some(Options {
+            expected_value_hashes: Some(expected),
+            ..Default::default()
+        })
+    }
+
+    fn setup() -> crate::Contract {
+        let mut contract = init_live_contract();
+        testing_env!(
+            get_context_with_deposit(accounts(0), 1_000_000_000_000_000_000_000_000).build()
+        );
+        contract
+            .execute(set_request(
+                json!({ "doc/title": { "text": "v1", "rev": 1 } }),
+            ))
+            .unwrap();
+        contract
+    }
+
+    fn stored(contract: &crate::Contract) -> Value {
+        contract
+            .platform
+            .storage_get(&format!("{}/doc/title", accounts(0)))
+            .unwrap()
+    }
+
+    #[test]
+    fn matching_hash_applies_write() {
+        let mut contract = setup();
+        let hash = json_value_hash(&stored(&contract));
+
+        contract
+            .execute(set_request_with_options(
+                json!({ "doc/title": { "text": "v2", "rev": 2 } }),
+                expect("doc/title", Some(hash)),
+            ))
+            .expect("write with current hash must succeed");
+
+        assert_eq!(stored(&contract), json!({ "text": "v2", "rev": 2 }));
+    }
+
+    #[test]
+    fn second_write_with_same_expected_hash_fails() {
+        let mut contract = setup();
+        let hash = json_value_hash(&stored(&contract));
+
+        contract
+            .execute(set_request_with_options(
+                json!({ "doc/title": { "text": "from alice", "rev": 2 } }),
+                expect("doc/title", Some(hash.clone())),
+            ))
+            .unwrap();
+
+        let err = contract
+            .execute(set_request_with_options(
+                json!({ "doc/title": { "text": "from bob", "rev": 2 } }),
+                expect("doc/title", Some(hash)),
+            ))
+            .expect_err("stale hash must be rejected");
+        assert!(
+            matches!(err, SocialError::PreconditionFailed(_)),
+            "unexpected error: {:?}",
+            err
+        );
+        assert_eq!(stored(&contract)["text"], json!("from alice"));
+    }
+
+    #[test]
+    fn hash_ignores_object_key_order() {
+        let a = json!({ "text": "v1", "rev": 1 });
+        let b: Value = near_sdk::serde_json::from_str(r#"{"rev":1,"text":"v1"}"#).unwrap();
+        assert_eq!(json_value_hash(&a), json_value_hash(&b));
+    }
+
+    #[test]
+    fn null_expectation_requires_absent_key() {
+        let mut contract = setup();
+
+        contract
+            .execute(set_request_with_options(
+                json!({ "doc/body": "first" }),
+                expect("doc/body", None),
+            ))
+            .expect("create-if-absent must succeed");
+
+        let err = contract
+            .execute(set_request_with_options(
+                json!({ "doc/body": "second" }),
+                expect("doc/body", None),
+            ))
+            .expect_err("key now exists");
+        assert!(matches!(err, SocialError::PreconditionFailed(_)));
+    }
+}
@@ -502,4 +502,72 @@ mod contract_lifecycle_tests {
             "Manager should be third account"
         );
     }
+
+    #[test]
+    fn test_migrate_upgrades_state_from_first_deployment() {
+        let contract_account = near_sdk::test_utils::accounts(0);
+        near_sdk::testing_env!(get_context(contract_account.clone()).build());
+
+        let mut contract = Contract::new();
+        contract.platform.status = ContractStatus::Live;
+        contract.platform.config.max_batch_size = 42;
+        let p = &contract.platform;
+        let c = &p.config;
+        let v1_state = near_sdk::borsh::to_vec(&(
+            &p.version,
+            &p.status,
+            &p.manager,
+            (
+                c.max_key_length,
+                c.max_path_depth,
+                c.max_batch_size,
+                c.max_value_bytes,
+                c.platform_onboarding_bytes,
+                c.platform_daily_refill_bytes,
+                c.platform_allowance_max_bytes,
+            ),
+            &p.shared_storage_pools,
+            &p.user_storage,
+            &p.group_pool_usage,
+            &p.group_sponsor_quotas,
+            &p.group_sponsor_defaults,
+            &p.key_index,
+        ))
+        .unwrap();
+        near_sdk::env::storage_write(b"STATE", &v1_state);
+
+        let migrated = Contract::migrate();
+
+        assert_eq!(migrated.platform.status, ContractStatus::Live);
+        assert_eq!(migrated.platform.manager, contract_account);
+        assert_eq!(migrated.platform.config.max_batch_size, 42);
+        assert_eq!(
+            migrated.platform.config.reserved_prefixes,
+            GovernanceConfig::default().reserved_prefixes
+        );
+        assert_eq!(
+            migrated.platform.config.max_sponsored_bytes_per_account,
+            None
+        );
+        assert!(migrated.platform.pending_manager.is_none());
+    }
+
+    #[test]
+    fn test_migrate_keeps_current_state() {
+        let contract_account = near_sdk::test_utils::accounts(0);
+        near_sdk::testing_env!(get_context(contract_account.clone()).build());
+
+        let mut contract = Contract::new();
+        contract.platform.pending_manager = Some(test_account(1));
+        contract.platform.config.reserved_prefixes = vec!["ops/".to_string()];
+        near_sdk::env::storage_write(b"STATE", &near_sdk::borsh::to_vec(&contract).unwrap());
+
+        let migrated = Contract::migrate();
+
+        assert_eq!(migrated.platform.pending_manager, Some(test_account(1)));
+        assert_eq!(
+            migrated.platform.config.reserved_prefixes,
+            vec!["ops/".to_string()]
+        );
+    }
 }
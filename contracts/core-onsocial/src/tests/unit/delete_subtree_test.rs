@@ -0,0 +1,163 @@
+// --- Delete Subtree Tests ---
+// DeleteSubtree soft-deletes indexed entries below a path in one request,
+// through the regular set path.
+
+#[cfg(test)]
+mod delete_subtree_tests {
+    use crate::constants::EVENT_JSON_PREFIX;
+    use crate::domain::groups::permissions::kv::types::WRITE;
+    use crate::protocol::{Action, Request};
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::{self, Value, json};
+    use near_sdk::test_utils::get_logs;
+    use near_sdk::{AccountId, testing_env};
+
+    const DEPOSIT: u128 = 10_000_000_000_000_000_000_000_000;
+
+    fn delete_subtree_request(target: Option<AccountId>, path: &str) -> Request {
+        Request {
+            target_account: target,
+            action: Action::DeleteSubtree {
+                path: path.to_string(),
+            },
+            options: None,
+        }
+    }
+
+    /// alice owns post/1, post/2, post/drafts, post/drafts/3 and profile/name.
+    fn setup() -> (crate::Contract, AccountId) {
+        let alice = test_account(0);
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        let mut contract = init_live_contract();
+        contract
+            .execute(set_request(json!({
+                "post/1": "one",
+                "post/2": "two",
+                "post/drafts": "index",
+                "post/drafts/3": "three",
+                "profile/name": "Alice",
+            })))
+            .unwrap();
+        (contract, alice)
+    }
+
+    fn has(contract: &crate::Contract, account: &AccountId, key: &str) -> bool {
+        contract
+            .get_one(key.to_string(), Some(account.clone()))
+            .value
+            .is_some()
+    }
+
+    #[test]
+    fn partial_depth_deletion_keeps_siblings() {
+        let (mut contract, alice) = setup();
+
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        // A wildcard takes the children but leaves the root entry.
+        let result = contract
+            .execute(delete_subtree_request(None, "post/drafts/*"))
+            .unwrap();
+        assert_eq!(result, json!({ "deleted": 1, "has_more": false }));
+        assert!(!has(&contract, &alice, "post/drafts/3"));
+        assert!(has(&contract, &alice, "post/drafts"));
+        assert!(has(&contract, &alice, "post/1"));
+
+        let result = contract
+            .execute(delete_subtree_request(None, "post/drafts"))
+            .unwrap();
+        assert_eq!(result, json!({ "deleted": 1, "has_more": false }));
+        assert!(!has(&contract, &alice, "post/drafts"));
+        assert!(has(&contract, &alice, "post/1"));
+        assert!(has(&contract, &alice, "profile/name"));
+    }
+
+    #[test]
+    fn account_prefixed_root_emits_per_leaf() {
+        let (mut contract, alice) = setup();
+
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        let result = contract
+            .execute(delete_subtree_request(None, &format!("{}/post", alice)))
+            .unwrap();
+        assert_eq!(result["deleted"], json!(4));
+
+        let removed = get_logs()
+            .iter()
+            .filter_map(|l| l.strip_prefix(EVENT_JSON_PREFIX))
+            .map(|j| serde_json::from_str::<Value>(j).unwrap())
+            .filter(|e| e["event"] == "DATA_UPDATE" && e["data"][0]["operation"] == "remove")
+            .count();
+        assert_eq!(removed, 4);
+        assert_eq!(contract.count_keys(format!("{}/post/", alice)), 0);
+        assert!(has(&contract, &alice, "profile/name"));
+    }
+
+    #[test]
+    fn deletion_capped_per_call() {
+        let (mut contract, alice) = setup();
+        let limit = contract.get_config().max_batch_size as usize;
+
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        for chunk in (0..limit + 2).collect::<Vec<_>>().chunks(limit) {
+            let data: serde_json::Map<String, Value> = chunk
+                .iter()
+                .map(|i| (format!("feed/{:03}", i), json!(i)))
+                .collect();
+            contract.execute(set_request(Value::Object(data))).unwrap();
+        }
+
+        let first = contract
+            .execute(delete_subtree_request(None, "feed/*"))
+            .unwrap();
+        assert_eq!(first, json!({ "deleted": limit, "has_more": true }));
+        let second = contract
+            .execute(delete_subtree_request(None, "feed/*"))
+            .unwrap();
+        assert_eq!(second, json!({ "deleted": 2, "has_more": false }));
+        assert_eq!(contract.count_keys(format!("{}/feed/", alice)), 0);
+    }
+
+    #[test]
+    fn permission_denied_without_write_grant() {
+        let (mut contract, alice) = setup();
+        let bob = test_account(1);
+
+        testing_env!(get_context_with_deposit(bob.clone(), DEPOSIT).build());
+        let err = contract
+            .execute(delete_subtree_request(Some(alice.clone()), "post/*"))
+            .unwrap_err();
+        assert!(format!("{:?}", err).contains("write"), "{:?}", err);
+        assert!(has(&contract, &alice, "post/1"));
+
+        // A write grant on the subtree lets bob clear it.
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        contract
+            .execute_admin(set_permission_request(
+                bob.clone(),
+                "post/".to_string(),
+                WRITE,
+                None,
+            ))
+            .unwrap();
+        testing_env!(get_context_with_deposit(bob, DEPOSIT).build());
+        contract
+            .execute(delete_subtree_request(Some(alice.clone()), "post/*"))
+            .unwrap();
+        assert!(!has(&contract, &alice, "post/1"));
+    }
+
+    #[test]
+    fn reserved_and_group_paths_rejected() {
+        let (mut contract, alice) = setup();
+
+        testing_env!(get_context_with_deposit(alice, DEPOSIT).build());
+        for path in ["", "*", "groups/g/*", "storage/*", "permission"] {
+            assert!(
+                contract
+                    .execute(delete_subtree_request(None, path))
+                    .is_err(),
+                "{path}"
+            );
+        }
+    }
+}
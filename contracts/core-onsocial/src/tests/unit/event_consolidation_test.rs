@@ -0,0 +1,125 @@
+// --- Event Consolidation Tests ---
+// `EventConfig::consolidate_data_updates` folds a set's DATA_UPDATEs into one event.
+
+#[cfg(test)]
+mod event_consolidation_tests {
+    use crate::Contract;
+    use crate::constants::{EVENT_JSON_PREFIX, EVENT_TYPE_DATA_UPDATE};
+    use crate::events::EventConfig;
+    use crate::events::types::{Event, EventData};
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::{self, Value, json};
+    use near_sdk::test_utils::{accounts, get_logs};
+    use near_sdk::{NearToken, testing_env};
+
+    fn setup(consolidate: bool) -> Contract {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = init_live_contract();
+        testing_env!(get_context_with_deposit(accounts(0), 1).build());
+        contract
+            .set_event_config(EventConfig {
+                consolidate_data_updates: consolidate,
+            })
+            .unwrap();
+        contract
+    }
+
+    fn batch_set_data_updates(contract: &mut Contract) -> Vec<EventData> {
+        testing_env!(
+            get_context_with_deposit(accounts(1), NearToken::from_near(1).as_yoctonear()).build()
+        );
+        contract
+            .execute(set_request(json!({
+                "post/1": { "text": "one" },
+                "post/2": { "text": "two" },
+                "profile/name": "Bob",
+            })))
+            .unwrap();
+
+        get_logs()
+            .iter()
+            .filter_map(|l| l.strip_prefix(EVENT_JSON_PREFIX))
+            .map(|j| serde_json::from_str::<Event>(j).unwrap())
+            .filter(|e| e.event == EVENT_TYPE_DATA_UPDATE)
+            .flat_map(|e| e.data)
+            .collect()
+    }
+
+    #[test]
+    fn per_leaf_events_by_default() {
+        let mut contract = setup(false);
+        assert!(!contract.get_event_config().consolidate_data_updates);
+
+        let events = batch_set_data_updates(&mut contract);
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|e| !e.extra.contains_key("writes")));
+    }
+
+    #[test]
+    fn consolidated_event_lists_every_write() {
+        let mut contract = setup(true);
+        let events = batch_set_data_updates(&mut contract);
+        assert_eq!(events.len(), 1, "expected a single DATA_UPDATE");
+
+        let event = &events[0];
+        let bob = accounts(1);
+        let writes = event.extra["writes"].as_array().unwrap();
+        let mut paths: Vec<&str> = writes.iter().map(|w| w["path"].as_str().unwrap()).collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                format!("{}/post/1", bob),
+                format!("{}/post/2", bob),
+                format!("{}/profile/name", bob),
+            ]
+        );
+        assert!(writes.contains(&json!({
+            "path": format!("{}/profile/name", bob),
+            "value": "Bob",
+        })));
+
+        // Fields the indexer reads from every DATA_UPDATE.
+        assert_eq!(event.operation, "set");
+        assert_eq!(event.author, bob.as_str());
+        assert!(event.partition_id.is_some());
+        let representative = event.extra["path"].as_str().unwrap();
+        assert!(paths.contains(&representative));
+        assert_eq!(event.extra["actor_id"], Value::from(bob.as_str()));
+        assert_eq!(event.extra["payer_id"], Value::from(bob.as_str()));
+    }
+
+    #[test]
+    fn single_write_is_left_unchanged() {
+        let mut contract = setup(true);
+        testing_env!(
+            get_context_with_deposit(accounts(1), NearToken::from_near(1).as_yoctonear()).build()
+        );
+        contract
+            .execute(set_request(json!({ "profile/name": "Bob" })))
+            .unwrap();
+
+        let data_updates: Vec<Event> = get_logs()
+            .iter()
+            .filter_map(|l| l.strip_prefix(EVENT_JSON_PREFIX))
+            .map(|j| serde_json::from_str::<Event>(j).unwrap())
+            .filter(|e| e.event == EVENT_TYPE_DATA_UPDATE)
+            .collect();
+        assert_eq!(data_updates.len(), 1);
+        assert!(!data_updates[0].data[0].extra.contains_key("writes"));
+    }
+
+    #[test]
+    fn only_manager_changes_event_config() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = init_live_contract();
+        testing_env!(get_context_with_deposit(accounts(1), 1).build());
+        assert!(
+            contract
+                .set_event_config(EventConfig {
+                    consolidate_data_updates: true,
+                })
+                .is_err()
+        );
+    }
+}
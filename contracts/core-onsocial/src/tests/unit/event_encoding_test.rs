@@ -0,0 +1,125 @@
+// --- Event Encoding Tests ---
+// Opt-in EVENT_BORSH logs must carry exactly the fields of the JSON event.
+
+#[cfg(test)]
+mod event_encoding_tests {
+    use crate::Contract;
+    use crate::constants::{EVENT_BORSH_PREFIX, EVENT_JSON_PREFIX};
+    use crate::events::EventEncoding;
+    use crate::events::borsh::{BorshEvent, BorshValue};
+    use crate::events::types::Event;
+    use crate::tests::test_utils::*;
+    use near_sdk::base64::{Engine, engine::general_purpose::STANDARD};
+    use near_sdk::serde_json::{self, Value, json};
+    use near_sdk::test_utils::{accounts, get_logs};
+    use near_sdk::testing_env;
+
+    fn setup(encoding: EventEncoding) -> Contract {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = init_live_contract();
+        testing_env!(get_context_with_deposit(accounts(0), 1).build());
+        contract.set_event_encoding(encoding).unwrap();
+        contract
+    }
+
+    /// Creates a group and returns the JSON and borsh logs it produced.
+    fn create_group_logs(contract: &mut Contract) -> (Vec<Event>, Vec<Event>) {
+        testing_env!(get_context_with_deposit(accounts(1), test_deposits::ten_near()).build());
+        contract
+            .execute(create_group_request(
+                "encoded".to_string(),
+                json!({"is_private": false}),
+            ))
+            .unwrap();
+
+        let logs = get_logs();
+        let json_events = logs
+            .iter()
+            .filter_map(|l| l.strip_prefix(EVENT_JSON_PREFIX))
+            .map(|j| serde_json::from_str(j).unwrap())
+            .collect();
+        let borsh_events = logs
+            .iter()
+            .filter_map(|l| l.strip_prefix(EVENT_BORSH_PREFIX))
+            .map(|b| {
+                let bytes = STANDARD.decode(b).unwrap();
+                Event::from(borsh::from_slice::<BorshEvent>(&bytes).unwrap())
+            })
+            .collect();
+        (json_events, borsh_events)
+    }
+
+    #[test]
+    fn json_only_by_default() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = init_live_contract();
+        assert_eq!(contract.get_event_encoding(), EventEncoding::Json);
+
+        let (json_events, borsh_events) = create_group_logs(&mut contract);
+        assert!(!json_events.is_empty());
+        assert!(borsh_events.is_empty());
+    }
+
+    #[test]
+    fn borsh_event_round_trips_to_json_fields() {
+        let mut contract = setup(EventEncoding::JsonAndBorsh);
+        let (json_events, borsh_events) = create_group_logs(&mut contract);
+
+        assert!(!json_events.is_empty());
+        assert_eq!(json_events.len(), borsh_events.len());
+        for (json_event, borsh_event) in json_events.iter().zip(&borsh_events) {
+            assert_eq!(
+                serde_json::to_value(json_event).unwrap(),
+                serde_json::to_value(borsh_event).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn borsh_only_suppresses_json() {
+        let mut contract = setup(EventEncoding::Borsh);
+        assert_eq!(contract.get_event_encoding(), EventEncoding::Borsh);
+
+        let (json_events, borsh_events) = create_group_logs(&mut contract);
+        assert!(json_events.is_empty());
+        assert!(borsh_events.iter().any(|e| e.event == "GROUP_UPDATE"));
+    }
+
+    #[test]
+    fn switching_back_to_json_clears_setting() {
+        let mut contract = setup(EventEncoding::Borsh);
+        testing_env!(get_context_with_deposit(accounts(0), 1).build());
+        contract.set_event_encoding(EventEncoding::Json).unwrap();
+        assert_eq!(contract.get_event_encoding(), EventEncoding::Json);
+
+        let (json_events, borsh_events) = create_group_logs(&mut contract);
+        assert!(!json_events.is_empty());
+        assert!(borsh_events.is_empty());
+    }
+
+    #[test]
+    fn set_event_encoding_requires_manager() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = init_live_contract();
+
+        testing_env!(get_context_with_deposit(accounts(1), 1).build());
+        assert!(contract.set_event_encoding(EventEncoding::Borsh).is_err());
+        assert_eq!(contract.get_event_encoding(), EventEncoding::Json);
+    }
+
+    #[test]
+    fn borsh_value_preserves_json_types() {
+        let value = json!({
+            "n": null,
+            "flag": true,
+            "count": 42,
+            "delta": -7,
+            "ratio": 0.5,
+            "name": "x",
+            "list": [1, "a", {"nested": false}]
+        });
+        let encoded = borsh::to_vec(&BorshValue::from(&value)).unwrap();
+        let decoded: BorshValue = borsh::from_slice(&encoded).unwrap();
+        assert_eq!(Value::from(decoded), value);
+    }
+}
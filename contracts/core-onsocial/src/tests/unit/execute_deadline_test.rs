@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod execute_deadline_tests {
+    use crate::tests::test_utils::*;
+    use crate::{Options, SocialError};
+    use near_sdk::json_types::U64;
+    use near_sdk::serde_json::json;
+    use near_sdk::test_utils::accounts;
+    use near_sdk::testing_env;
+
+    const NS_PER_MS: u64 = 1_000_000;
+    const BASE_MS: u64 = TEST_BASE_TIMESTAMP / NS_PER_MS;
+
+    fn deadline_options(deadline_ms: u64) -> Option<Options> {
+        Some(Options {
+            deadline_ms: Some(U64(deadline_ms)),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_request_within_deadline_is_accepted() {
+        let mut contract = init_live_contract();
+        let alice = accounts(0);
+        testing_env!(
+            get_context_with_deposit(alice.clone(), 1_000_000_000_000_000_000_000_000).build()
+        );
+
+        let result = contract.execute(set_request_with_options(
+            json!({ "profile/name": "Alice" }),
+            deadline_options(BASE_MS + 60_000),
+        ));
+
+        assert!(
+            result.is_ok(),
+            "In-window request should succeed: {:?}",
+            result.err()
+        );
+    }
+
+    #[test]
+    fn test_request_at_deadline_is_accepted() {
+        let mut contract = init_live_contract();
+        let alice = accounts(0);
+        testing_env!(
+            get_context_with_deposit(alice.clone(), 1_000_000_000_000_000_000_000_000).build()
+        );
+
+        let result = contract.execute(set_request_with_options(
+            json!({ "profile/name": "Alice" }),
+            deadline_options(BASE_MS),
+        ));
+
+        assert!(result.is_ok(), "Deadline is inclusive: {:?}", result.err());
+    }
+
+    #[test]
+    fn test_request_past_deadline_is_rejected() {
+        let mut contract = init_live_contract();
+        let alice = accounts(0);
+        testing_env!(
+            get_context_with_deposit(alice.clone(), 1_000_000_000_000_000_000_000_000).build()
+        );
+
+        let result = contract.execute(set_request_with_options(
+            json!({ "profile/name": "Alice" }),
+            deadline_options(BASE_MS - 1),
+        ));
+
+        assert!(
+            matches!(result, Err(SocialError::Expired(_))),
+            "Past-deadline request should be rejected with Expired"
+        );
+        let stored =
+            contract_get_values_map(&contract, vec!["profile/name".to_string()], Some(alice));
+        assert!(stored.is_empty(), "Expired request must not write data");
+    }
+
+    #[test]
+    fn test_admin_request_past_deadline_is_rejected() {
+        let mut contract = init_live_contract();
+        let alice = accounts(0);
+        testing_env!(get_context_with_deposit(alice, 1_000_000_000_000_000_000_000_000).build());
+
+        let result = contract.execute_admin(set_request_with_options(
+            json!({ "profile/name": "Alice" }),
+            deadline_options(BASE_MS - 1),
+        ));
+
+        assert!(matches!(result, Err(SocialError::Expired(_))));
+    }
+}
@@ -0,0 +1,101 @@
+// --- Export Account Tests ---
+// `export_account` paging and private-group visibility.
+
+#[cfg(test)]
+mod export_account_tests {
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::json;
+    use near_sdk::test_utils::accounts;
+    use near_sdk::{AccountId, testing_env};
+
+    const DEPOSIT: u128 = 10_000_000_000_000_000_000_000_000;
+
+    fn write(contract: &mut crate::Contract, who: &AccountId, key: &str, val: &str) {
+        testing_env!(get_context_with_deposit(who.clone(), DEPOSIT).build());
+        contract.execute(set_request(json!({ key: val }))).unwrap();
+    }
+
+    #[test]
+    fn pages_cover_every_entry_once_in_key_order() {
+        let mut contract = init_live_contract();
+        let alice = accounts(0);
+        for key in ["profile/name", "profile/bio", "post/1", "post/2", "post/3"] {
+            write(&mut contract, &alice, key, "v");
+        }
+        write(&mut contract, &accounts(1), "profile/name", "other");
+        write(&mut contract, &alice, "post/2", "");
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        contract
+            .execute(set_request(json!({ "post/3": null })))
+            .unwrap();
+
+        let mut exported = Vec::new();
+        let mut cursor = None;
+        let mut pages = 0;
+        loop {
+            let (entries, next) = contract.export_account(alice.clone(), cursor, 2);
+            exported.extend(entries);
+            pages += 1;
+            match next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        assert_eq!(pages, 2);
+        let keys: Vec<String> = exported.iter().map(|(k, _)| k.clone()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                format!("{}/post/1", alice),
+                format!("{}/post/2", alice),
+                format!("{}/profile/bio", alice),
+                format!("{}/profile/name", alice),
+            ]
+        );
+        assert_eq!(exported[1].1, json!(""));
+    }
+
+    #[test]
+    fn excludes_private_group_content_after_leaving() {
+        let mut contract = init_live_contract();
+        let (alice, bob) = (accounts(0), accounts(1));
+
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        contract
+            .execute(create_group_request(
+                "priv".to_string(),
+                json!({"is_private": true}),
+            ))
+            .unwrap();
+        contract
+            .execute(create_group_request(
+                "open".to_string(),
+                json!({"is_private": false}),
+            ))
+            .unwrap();
+        test_add_member_bypass_proposals(&mut contract, "priv", &bob, 0, &alice);
+        test_add_member_bypass_proposals(&mut contract, "open", &bob, 0, &alice);
+
+        write(&mut contract, &bob, "groups/priv/content/post/1", "secret");
+        write(&mut contract, &bob, "groups/open/content/post/1", "hello");
+        write(&mut contract, &bob, "profile/name", "Bob");
+
+        let (entries, _) = contract.export_account(bob.clone(), None, 50);
+        assert_eq!(entries.len(), 3);
+
+        test_remove_member_bypass_proposals(&mut contract, "priv", &bob);
+        test_remove_member_bypass_proposals(&mut contract, "open", &bob);
+
+        let (entries, next) = contract.export_account(bob.clone(), None, 50);
+        assert!(next.is_none());
+        let keys: Vec<String> = entries.into_iter().map(|(k, _)| k).collect();
+        assert_eq!(
+            keys,
+            vec![
+                format!("{}/groups/open/content/post/1", bob),
+                format!("{}/profile/name", bob),
+            ]
+        );
+    }
+}
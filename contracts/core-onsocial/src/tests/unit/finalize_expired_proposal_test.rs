@@ -0,0 +1,175 @@
+// --- Finalize Expired Proposal Tests ---
+// Permissionless `finalize_expired_proposal`: deadline gate, quorum-failure
+// rejection with bond release, and tally-based settlement once quorum is met.
+
+#[cfg(test)]
+mod finalize_expired_proposal_tests {
+    use crate::constants::DEFAULT_VOTING_PERIOD;
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::json;
+    use near_sdk::test_utils::{VMContextBuilder, accounts, get_logs};
+    use near_sdk::{AccountId, NearToken, testing_env};
+
+    fn ctx_at(account: AccountId, deposit: u128, ts: u64) -> VMContextBuilder {
+        let mut b = VMContextBuilder::new();
+        b.current_account_id(accounts(0))
+            .signer_account_id(account.clone())
+            .predecessor_account_id(account)
+            .block_timestamp(ts)
+            .attached_deposit(NearToken::from_yoctonear(deposit));
+        b
+    }
+
+    /// Member-driven group with three members and one active proposal, where
+    /// the proposer's own vote (1/3) is below the 51% quorum.
+    /// Returns (contract, proposal_id, owner, bob, third).
+    fn setup_active_proposal() -> (crate::Contract, String, AccountId, AccountId, AccountId) {
+        let mut contract = init_live_contract();
+        let owner = accounts(0);
+        let bob = accounts(1);
+        let third = accounts(2);
+
+        testing_env!(get_context_with_deposit(owner.clone(), test_deposits::ten_near()).build());
+        contract
+            .execute(create_group_request(
+                "fing".to_string(),
+                json!({"member_driven": true, "is_private": true}),
+            ))
+            .unwrap();
+
+        test_add_member_bypass_proposals(&mut contract, "fing", &bob, 0, &owner);
+        test_add_member_bypass_proposals(&mut contract, "fing", &third, 0, &owner);
+
+        testing_env!(
+            get_context_with_deposit(owner.clone(), test_deposits::proposal_creation()).build()
+        );
+        let proposal_id = contract
+            .execute(create_proposal_request(
+                "fing".to_string(),
+                "custom_proposal".to_string(),
+                json!({"title": "t", "description": "d", "custom_data": {}}),
+                None,
+            ))
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        (contract, proposal_id, owner, bob, third)
+    }
+
+    fn proposal_status(contract: &crate::Contract, proposal_id: &str) -> String {
+        contract
+            .platform
+            .storage_get(&format!("groups/fing/proposals/{}", proposal_id))
+            .and_then(|v| v.get("status").and_then(|s| s.as_str()).map(String::from))
+            .expect("proposal must exist")
+    }
+
+    fn locked_balance(contract: &crate::Contract, account: &AccountId) -> u128 {
+        contract
+            .platform
+            .get_account_storage(account.as_str())
+            .map(|s| s.locked_balance.0)
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn cannot_finalize_before_voting_period_elapses() {
+        let (mut contract, proposal_id, _owner, bob, _third) = setup_active_proposal();
+
+        testing_env!(ctx_at(bob, 0, TEST_BASE_TIMESTAMP + DEFAULT_VOTING_PERIOD).build());
+        let err = contract
+            .execute(finalize_expired_proposal_request(
+                "fing".to_string(),
+                proposal_id.clone(),
+            ))
+            .expect_err("must fail before period elapses");
+        assert!(
+            err.to_string().contains("Voting period has not elapsed"),
+            "unexpected error: {}",
+            err
+        );
+        assert_eq!(proposal_status(&contract, &proposal_id), "active");
+    }
+
+    #[test]
+    fn quorum_not_met_rejects_and_releases_bond() {
+        let (mut contract, proposal_id, owner, _bob, third) = setup_active_proposal();
+        let locked_before = locked_balance(&contract, &owner);
+        assert!(locked_before > 0, "proposal bond must be locked");
+
+        testing_env!(ctx_at(third, 0, TEST_BASE_TIMESTAMP + DEFAULT_VOTING_PERIOD + 1).build());
+        let result = contract
+            .execute(finalize_expired_proposal_request(
+                "fing".to_string(),
+                proposal_id.clone(),
+            ))
+            .expect("finalize must succeed");
+
+        assert_eq!(result, json!("rejected_quorum"));
+        assert_eq!(proposal_status(&contract, &proposal_id), "rejected_quorum");
+        assert!(
+            locked_balance(&contract, &owner) < locked_before,
+            "proposal bond must be released"
+        );
+
+        let logs = get_logs();
+        assert!(
+            logs.iter()
+                .filter(|l| l.starts_with("EVENT_JSON:"))
+                .any(|l| l.contains("GROUP_UPDATE") && l.contains("rejected_quorum")),
+            "GROUP_UPDATE with final status must be emitted: {:?}",
+            logs
+        );
+
+        testing_env!(
+            ctx_at(
+                accounts(3),
+                0,
+                TEST_BASE_TIMESTAMP + DEFAULT_VOTING_PERIOD + 2
+            )
+            .build()
+        );
+        let err = contract
+            .execute(finalize_expired_proposal_request(
+                "fing".to_string(),
+                proposal_id,
+            ))
+            .expect_err("second finalize must fail");
+        assert!(
+            err.to_string()
+                .contains("Only active proposals can be finalized"),
+            "unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn quorum_met_at_expiry_applies_tally() {
+        let (mut contract, proposal_id, _owner, bob, third) = setup_active_proposal();
+
+        // Bob votes no: 2/3 participation meets quorum, but 1/2 yes misses the
+        // majority. Third could still tip it, so the proposal stays Active.
+        testing_env!(get_context_with_deposit(bob, test_deposits::ten_near()).build());
+        contract
+            .execute(vote_proposal_request(
+                "fing".to_string(),
+                proposal_id.clone(),
+                false,
+            ))
+            .unwrap();
+        assert_eq!(proposal_status(&contract, &proposal_id), "active");
+
+        testing_env!(ctx_at(third, 0, TEST_BASE_TIMESTAMP + DEFAULT_VOTING_PERIOD + 1).build());
+        let result = contract
+            .execute(finalize_expired_proposal_request(
+                "fing".to_string(),
+                proposal_id.clone(),
+            ))
+            .expect("finalize must succeed");
+
+        assert_eq!(result, json!("rejected"));
+        assert_eq!(proposal_status(&contract, &proposal_id), "rejected");
+    }
+}
@@ -298,4 +298,84 @@ mod test_get_api {
         println!("✓ Blockchain transparency: all data publicly readable");
         println!("  Note: 'Private' groups control membership, not data visibility");
     }
+
+    #[test]
+    fn test_get_many_overlapping_and_missing_paths() {
+        let alice = test_account(0);
+        let bob = test_account(1);
+        let mut contract = init_live_contract();
+
+        near_sdk::testing_env!(
+            get_context_with_deposit(alice.clone(), 5_000_000_000_000_000_000_000_000).build()
+        );
+        contract
+            .execute(set_request(json!({ "profile/name": "Alice" })))
+            .unwrap();
+        near_sdk::testing_env!(
+            get_context_with_deposit(bob.clone(), 5_000_000_000_000_000_000_000_000).build()
+        );
+        contract
+            .execute(set_request(json!({ "profile/name": "Bob" })))
+            .unwrap();
+
+        // Alice's name is requested twice (relative and full); bob's bio is missing.
+        let result = contract.get_many(
+            vec![
+                (alice.clone(), vec!["profile/name".to_string()]),
+                (
+                    bob.clone(),
+                    vec!["profile/name".to_string(), "profile/bio".to_string()],
+                ),
+                (alice.clone(), vec![format!("{}/profile/name", alice)]),
+            ],
+            None,
+        );
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[&format!("{}/profile/name", alice)], json!("Alice"));
+        assert_eq!(result[&format!("{}/profile/name", bob)], json!("Bob"));
+        assert_eq!(
+            result[&format!("{}/profile/bio", bob)],
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn test_get_many_include_metadata() {
+        let alice = test_account(0);
+        near_sdk::testing_env!(
+            get_context_with_deposit(alice.clone(), 5_000_000_000_000_000_000_000_000).build()
+        );
+        let mut contract = init_live_contract();
+        contract
+            .execute(set_request(json!({ "profile/name": "Alice" })))
+            .unwrap();
+
+        let result = contract.get_many(
+            vec![(
+                alice.clone(),
+                vec!["profile/name".to_string(), "profile/missing".to_string()],
+            )],
+            Some(true),
+        );
+
+        let entry = &result[&format!("{}/profile/name", alice)];
+        assert_eq!(entry["value"], json!("Alice"));
+        assert!(entry["block_height"].is_string());
+        assert_eq!(entry["deleted"], json!(false));
+
+        let missing = &result[&format!("{}/profile/missing", alice)];
+        assert!(missing["value"].is_null());
+        assert!(missing["block_height"].is_null());
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many keys")]
+    fn test_get_many_enforces_key_limit() {
+        let contract = init_live_contract();
+        let keys: Vec<String> = (0..=crate::constants::MAX_GET_MANY_KEYS)
+            .map(|i| format!("posts/{}", i))
+            .collect();
+        contract.get_many(vec![(test_account(0), keys)], None);
+    }
 }
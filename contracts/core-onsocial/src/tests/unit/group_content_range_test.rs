@@ -0,0 +1,172 @@
+// --- Group Content Range Tests ---
+// `get_group_content` pages group content by creation time.
+
+#[cfg(test)]
+mod group_content_range_tests {
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::{Value, json};
+    use near_sdk::test_utils::accounts;
+    use near_sdk::{AccountId, testing_env};
+
+    const NS_PER_MS: u64 = 1_000_000;
+    const BASE_MS: u64 = TEST_BASE_TIMESTAMP / NS_PER_MS;
+    const DEPOSIT: u128 = 10_000_000_000_000_000_000_000_000;
+
+    fn setup(is_private: bool) -> (crate::Contract, AccountId) {
+        let mut contract = init_live_contract();
+        let owner = accounts(0);
+        testing_env!(get_context_with_deposit(owner.clone(), DEPOSIT).build());
+        contract
+            .execute(create_group_request(
+                "club".to_string(),
+                json!({ "member_driven": false, "is_private": is_private }),
+            ))
+            .unwrap();
+        (contract, owner)
+    }
+
+    fn post_at(contract: &mut crate::Contract, author: &AccountId, id: u32, offset_ms: u64) {
+        testing_env!(
+            get_context_with_deposit(author.clone(), DEPOSIT)
+                .block_timestamp((BASE_MS + offset_ms) * NS_PER_MS)
+                .build()
+        );
+        contract
+            .execute(set_request(json!({
+                format!("groups/club/posts/{}", id): { "text": format!("post {}", id) }
+            })))
+            .unwrap();
+    }
+
+    fn paths(entries: &[Value]) -> Vec<String> {
+        entries
+            .iter()
+            .map(|e| e["path"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn range_is_start_inclusive_and_end_exclusive() {
+        let (mut contract, owner) = setup(false);
+        for (id, offset) in [(1, 0), (2, 10), (3, 20), (4, 30)] {
+            post_at(&mut contract, &owner, id, offset);
+        }
+
+        let page = contract
+            .get_group_content(
+                "club".to_string(),
+                Some(BASE_MS + 10),
+                Some(BASE_MS + 30),
+                50,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            paths(&page),
+            vec![
+                format!("{}/groups/club/posts/2", owner),
+                format!("{}/groups/club/posts/3", owner),
+            ]
+        );
+        assert_eq!(page[0]["created_at"], json!(BASE_MS + 10));
+        assert_eq!(page[0]["value"]["text"], json!("post 2"));
+
+        let all = contract
+            .get_group_content("club".to_string(), None, None, 50, None)
+            .unwrap();
+        assert_eq!(all.len(), 4);
+    }
+
+    #[test]
+    fn limit_pages_oldest_first() {
+        let (mut contract, owner) = setup(false);
+        for id in 0..5 {
+            post_at(&mut contract, &owner, id, u64::from(id) * 10);
+        }
+
+        let first = contract
+            .get_group_content("club".to_string(), None, None, 2, None)
+            .unwrap();
+        assert_eq!(
+            paths(&first),
+            vec![
+                format!("{}/groups/club/posts/0", owner),
+                format!("{}/groups/club/posts/1", owner),
+            ]
+        );
+
+        let next_start = first[1]["created_at"].as_u64().unwrap() + 1;
+        let second = contract
+            .get_group_content("club".to_string(), Some(next_start), None, 2, None)
+            .unwrap();
+        assert_eq!(
+            paths(&second),
+            vec![
+                format!("{}/groups/club/posts/2", owner),
+                format!("{}/groups/club/posts/3", owner),
+            ]
+        );
+    }
+
+    #[test]
+    fn updates_keep_creation_time_and_deletes_drop_entries() {
+        let (mut contract, owner) = setup(false);
+        post_at(&mut contract, &owner, 1, 0);
+        post_at(&mut contract, &owner, 2, 10);
+        post_at(&mut contract, &owner, 1, 50);
+
+        let all = contract
+            .get_group_content("club".to_string(), None, None, 50, None)
+            .unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0]["created_at"], json!(BASE_MS));
+
+        testing_env!(get_context(owner.clone()).build());
+        contract
+            .execute(set_request(json!({ "groups/club/posts/1": null })))
+            .unwrap();
+        let remaining = contract
+            .get_group_content("club".to_string(), None, None, 50, None)
+            .unwrap();
+        assert_eq!(
+            paths(&remaining),
+            vec![format!("{}/groups/club/posts/2", owner)]
+        );
+    }
+
+    #[test]
+    fn private_group_content_is_listed_only_for_member_viewer_id() {
+        let (mut contract, owner) = setup(true);
+        post_at(&mut contract, &owner, 1, 0);
+
+        let outsider = accounts(1);
+        assert!(
+            contract
+                .get_group_content("club".to_string(), None, None, 50, Some(outsider))
+                .is_err()
+        );
+        assert!(
+            contract
+                .get_group_content("club".to_string(), None, None, 50, None)
+                .is_err()
+        );
+        // `viewer_id` is not authenticated: naming a member lists the content.
+        assert_eq!(
+            contract
+                .get_group_content("club".to_string(), None, None, 50, Some(owner))
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn unknown_group_is_rejected() {
+        let contract = init_live_contract();
+        assert!(
+            contract
+                .get_group_content("missing".to_string(), None, None, 50, None)
+                .is_err()
+        );
+    }
+}
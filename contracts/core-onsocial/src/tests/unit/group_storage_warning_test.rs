@@ -0,0 +1,125 @@
+// --- Group Storage Warning Tests ---
+// Content writes emit one GROUP_UPDATE storage_warning per threshold crossing.
+
+#[cfg(test)]
+mod group_storage_warning_tests {
+    use crate::constants::EVENT_JSON_PREFIX;
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::{self, Value, json};
+    use near_sdk::test_utils::get_logs;
+    use near_sdk::{AccountId, NearToken, testing_env};
+
+    const GROUP: &str = "quota";
+    const DEPOSIT: u128 = 10_000_000_000_000_000_000_000_000;
+
+    /// Group with a 10_000-byte pool that warns at 30% usage.
+    fn setup() -> (crate::Contract, AccountId) {
+        let alice = test_account(0);
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        let mut contract = init_live_contract();
+        contract
+            .execute(create_group_request(
+                GROUP.to_string(),
+                json!({"is_private": false, "storage_warning_bps": 3_000}),
+            ))
+            .unwrap();
+
+        let pool_deposit = NearToken::from_millinear(100).as_yoctonear();
+        testing_env!(get_context_with_deposit(alice.clone(), pool_deposit).build());
+        contract
+            .execute_admin(set_request(json!({
+                "storage/group_pool_deposit": {
+                    "group_id": GROUP,
+                    "amount": pool_deposit.to_string()
+                }
+            })))
+            .unwrap();
+        (contract, alice)
+    }
+
+    /// Writes (or deletes, for null) a post and returns the warnings it emitted.
+    fn write_post(
+        contract: &mut crate::Contract,
+        alice: &AccountId,
+        id: u32,
+        value: Value,
+    ) -> Vec<Value> {
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        contract
+            .execute(set_request(json!({
+                format!("groups/{}/content/post/{}", GROUP, id): value
+            })))
+            .unwrap();
+        get_logs()
+            .iter()
+            .filter_map(|l| l.strip_prefix(EVENT_JSON_PREFIX))
+            .map(|j| serde_json::from_str::<Value>(j).unwrap())
+            .filter(|e| e["event"] == "GROUP_UPDATE")
+            .flat_map(|e| e["data"].as_array().unwrap().clone())
+            .filter(|d| d["operation"] == "storage_warning")
+            .collect()
+    }
+
+    fn post() -> Value {
+        json!({ "text": "x".repeat(1_200) })
+    }
+
+    #[test]
+    fn warns_once_per_crossing() {
+        let (mut contract, alice) = setup();
+
+        assert!(write_post(&mut contract, &alice, 1, post()).is_empty());
+        assert!(write_post(&mut contract, &alice, 2, post()).is_empty());
+
+        let warnings = write_post(&mut contract, &alice, 3, post());
+        assert_eq!(warnings.len(), 1);
+        let warning = &warnings[0];
+        assert_eq!(warning["group_id"], GROUP);
+        assert_eq!(warning["quota_bytes"], "10000");
+        assert_eq!(warning["threshold_bps"], 3_000);
+        let used: u64 = warning["used_bytes"].as_str().unwrap().parse().unwrap();
+        let remaining: u64 = warning["remaining_bytes"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(used >= 3_000);
+        assert_eq!(used + remaining, 10_000);
+
+        // Still above the threshold: no repeat.
+        assert!(write_post(&mut contract, &alice, 4, post()).is_empty());
+    }
+
+    #[test]
+    fn dropping_below_threshold_rearms_warning() {
+        let (mut contract, alice) = setup();
+        for id in 1..=2 {
+            write_post(&mut contract, &alice, id, post());
+        }
+        assert_eq!(write_post(&mut contract, &alice, 3, post()).len(), 1);
+
+        // Deleting posts frees pool bytes and clears the flag silently.
+        assert!(write_post(&mut contract, &alice, 3, Value::Null).is_empty());
+        assert!(write_post(&mut contract, &alice, 2, Value::Null).is_empty());
+
+        assert!(write_post(&mut contract, &alice, 5, post()).is_empty());
+        assert_eq!(write_post(&mut contract, &alice, 6, post()).len(), 1);
+    }
+
+    #[test]
+    fn invalid_threshold_rejected() {
+        let alice = test_account(0);
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        let mut contract = init_live_contract();
+        for bps in [json!(0), json!(10_001), json!("9000")] {
+            assert!(
+                contract
+                    .execute(create_group_request(
+                        "bad".to_string(),
+                        json!({"storage_warning_bps": bps}),
+                    ))
+                    .is_err()
+            );
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// --- Has Any Permission Tests ---
+// Batched permission view: one result per path, matching has_permission.
+
+#[cfg(test)]
+mod has_any_permission_tests {
+    use crate::domain::groups::permissions::kv::types::{MODERATE, WRITE};
+    use crate::tests::test_utils::*;
+    use near_sdk::json_types::U64;
+    use near_sdk::serde_json::json;
+    use near_sdk::{AccountId, testing_env};
+
+    const DEPOSIT: u128 = 10_000_000_000_000_000_000_000_000;
+
+    fn grant(
+        contract: &mut crate::Contract,
+        owner: &AccountId,
+        grantee: &AccountId,
+        path: &str,
+        level: u8,
+        expires_at: Option<U64>,
+    ) {
+        testing_env!(get_context_with_deposit(owner.clone(), DEPOSIT).build());
+        contract
+            .execute_admin(set_permission_request(
+                grantee.clone(),
+                path.to_string(),
+                level,
+                expires_at,
+            ))
+            .unwrap();
+    }
+
+    fn paths(list: &[&str]) -> Vec<String> {
+        list.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn mixes_granted_revoked_and_expired_paths() {
+        let (alice, bob) = (test_account(0), test_account(1));
+        testing_env!(get_context(alice.clone()).build());
+        let mut contract = init_live_contract();
+
+        grant(&mut contract, &alice, &bob, "post/", WRITE, None);
+        grant(&mut contract, &alice, &bob, "profile/", WRITE, None);
+        grant(&mut contract, &alice, &bob, "profile/", 0, None);
+        let expiry = TEST_BASE_TIMESTAMP + 1_000;
+        grant(
+            &mut contract,
+            &alice,
+            &bob,
+            "media/",
+            WRITE,
+            Some(U64(expiry)),
+        );
+
+        let checked = paths(&["post/1", "profile/name", "media/a", "settings/x"]);
+        let before =
+            contract.has_any_permission(alice.clone(), bob.clone(), checked.clone(), WRITE);
+        assert_eq!(before, vec![true, false, true, false]);
+
+        let mut context = get_context(alice.clone());
+        context.block_timestamp(expiry + 1);
+        testing_env!(context.build());
+        let after = contract.has_any_permission(alice.clone(), bob.clone(), checked.clone(), WRITE);
+        assert_eq!(after, vec![true, false, false, false]);
+
+        let single: Vec<bool> = checked
+            .iter()
+            .map(|p| contract.has_permission(alice.clone(), bob.clone(), p.clone(), WRITE))
+            .collect();
+        assert_eq!(after, single);
+    }
+
+    #[test]
+    fn owner_always_granted_on_own_paths() {
+        let alice = test_account(0);
+        testing_env!(get_context(alice.clone()).build());
+        let contract = init_live_contract();
+
+        let result = contract.has_any_permission(
+            alice.clone(),
+            alice,
+            paths(&["post/1", "profile/name", "anything/deep/path"]),
+            MODERATE,
+        );
+        assert_eq!(result, vec![true, true, true]);
+    }
+
+    #[test]
+    fn group_paths_checked_against_group() {
+        let (alice, bob) = (test_account(0), test_account(1));
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        let mut contract = init_live_contract();
+        contract
+            .execute(create_group_request(
+                "perm".to_string(),
+                json!({"member_driven": false, "is_private": false}),
+            ))
+            .unwrap();
+        contract
+            .execute(add_group_member_request("perm".to_string(), bob.clone()))
+            .unwrap();
+
+        // Owner-equals-grantee only short-circuits account paths.
+        let result = contract.has_any_permission(
+            bob.clone(),
+            bob.clone(),
+            paths(&["groups/perm/content/post", "groups/perm/config", "post/1"]),
+            WRITE,
+        );
+        assert_eq!(result, vec![true, false, true]);
+
+        let group_owner = contract.has_any_permission(
+            bob,
+            alice,
+            paths(&["groups/perm/config", "post/1"]),
+            WRITE,
+        );
+        assert_eq!(group_owner, vec![true, false]);
+    }
+
+    #[test]
+    fn empty_path_list_returns_empty() {
+        let alice = test_account(0);
+        testing_env!(get_context(alice.clone()).build());
+        let contract = init_live_contract();
+        assert!(
+            contract
+                .has_any_permission(alice, test_account(1), vec![], WRITE)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many paths")]
+    fn too_many_paths_rejected() {
+        let alice = test_account(0);
+        testing_env!(get_context(alice.clone()).build());
+        let contract = init_live_contract();
+        let paths = (0..=crate::constants::MAX_PERMISSION_CHECK_PATHS)
+            .map(|i| format!("post/{i}"))
+            .collect();
+        contract.has_any_permission(alice, test_account(1), paths, WRITE);
+    }
+}
@@ -0,0 +1,134 @@
+// --- Maintenance Write Tests ---
+// `set_maintenance` lets the manager repair data while the contract is read-only.
+
+#[cfg(test)]
+mod maintenance_write_tests {
+    use crate::Contract;
+    use crate::constants::{EVENT_JSON_PREFIX, EVENT_TYPE_CONTRACT_UPDATE};
+    use crate::events::types::Event;
+    use crate::state::models::ContractStatus;
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::{self, json};
+    use near_sdk::test_utils::{accounts, get_logs};
+    use near_sdk::{NearToken, testing_env};
+
+    fn read_only_contract() -> Contract {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = init_live_contract();
+        testing_env!(get_context_with_deposit(contract.platform.manager.clone(), 1).build());
+        contract.enter_read_only().unwrap();
+        contract
+    }
+
+    fn one_near() -> u128 {
+        NearToken::from_near(1).as_yoctonear()
+    }
+
+    #[test]
+    fn normal_writes_are_rejected_while_read_only() {
+        let mut contract = read_only_contract();
+        let manager = contract.platform.manager.clone();
+
+        testing_env!(get_context_with_deposit(accounts(1), one_near()).build());
+        let err = contract
+            .execute(set_request(json!({ "profile/name": "Bob" })))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Contract is read-only");
+
+        // The manager gets no exemption on the ordinary entry points.
+        testing_env!(get_context_with_deposit(manager, one_near()).build());
+        assert!(
+            contract
+                .execute(set_request(json!({ "profile/name": "Fixed" })))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn manager_maintenance_write_succeeds_and_is_logged() {
+        let mut contract = read_only_contract();
+        let manager = contract.platform.manager.clone();
+
+        testing_env!(get_context_with_deposit(manager.clone(), one_near()).build());
+        contract
+            .set_maintenance(manager.clone(), json!({ "profile/name": "Fixed" }), None)
+            .expect("manager may write while read-only");
+        assert_eq!(contract.platform.status, ContractStatus::ReadOnly);
+
+        let stored = contract.get(vec![format!("{}/profile/name", manager)], None);
+        assert_eq!(stored[0].value, Some(json!("Fixed")));
+
+        let maintenance: Vec<Event> = get_logs()
+            .iter()
+            .filter_map(|l| l.strip_prefix(EVENT_JSON_PREFIX))
+            .map(|j| serde_json::from_str::<Event>(j).unwrap())
+            .filter(|e| {
+                e.event == EVENT_TYPE_CONTRACT_UPDATE
+                    && e.data
+                        .first()
+                        .is_some_and(|d| d.operation == "maintenance_set")
+            })
+            .collect();
+        assert_eq!(maintenance.len(), 1);
+        let data = &maintenance[0].data[0];
+        assert_eq!(data.author, manager.as_str());
+        assert_eq!(data.extra["target_id"], json!(manager.as_str()));
+        assert_eq!(data.extra["status"], json!("ReadOnly"));
+        assert_eq!(data.extra["keys"], json!(["profile/name"]));
+    }
+
+    #[test]
+    fn manager_maintenance_rewrites_another_accounts_key() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = init_live_contract();
+        let manager = contract.platform.manager.clone();
+        let user = accounts(1);
+
+        testing_env!(get_context_with_deposit(user.clone(), one_near()).build());
+        contract
+            .execute(set_request(json!({ "profile/name": "Corrupted" })))
+            .unwrap();
+
+        testing_env!(get_context_with_deposit(manager.clone(), 1).build());
+        contract.enter_read_only().unwrap();
+
+        testing_env!(get_context_with_deposit(manager.clone(), one_near()).build());
+        contract
+            .set_maintenance(user.clone(), json!({ "profile/name": "Fixed" }), None)
+            .expect("manager may rewrite another account's key");
+
+        let stored = contract.get(vec![format!("{}/profile/name", user)], None);
+        assert_eq!(stored[0].value, Some(json!("Fixed")));
+
+        // The override covers data paths only, not the account's storage.
+        assert!(
+            contract
+                .set_maintenance(user, json!({ "storage/withdraw": {} }), None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn non_manager_cannot_use_maintenance_path() {
+        let mut contract = read_only_contract();
+
+        testing_env!(get_context_with_deposit(accounts(1), one_near()).build());
+        assert!(
+            contract
+                .set_maintenance(accounts(1), json!({ "profile/name": "Bob" }), None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn maintenance_is_rejected_before_activation() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Contract::new();
+        testing_env!(get_context_with_deposit(accounts(0), one_near()).build());
+        assert!(
+            contract
+                .set_maintenance(accounts(0), json!({ "profile/name": "Early" }), None)
+                .is_err()
+        );
+    }
+}
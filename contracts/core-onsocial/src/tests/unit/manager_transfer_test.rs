@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod manager_transfer_tests {
+    use crate::SocialError;
+    use crate::tests::test_utils::*;
+    use near_sdk::test_utils::{accounts, get_logs};
+    use near_sdk::testing_env;
+
+    fn emitted_operation(log: &str) -> bool {
+        log.starts_with("EVENT_JSON:") && log.contains("CONTRACT_UPDATE")
+    }
+
+    #[test]
+    fn test_propose_then_accept_transfers_manager() {
+        let manager = accounts(0);
+        let new_manager = accounts(1);
+        testing_env!(get_context(manager.clone()).build());
+        let mut contract = init_live_contract();
+
+        testing_env!(get_context_with_deposit(manager.clone(), 1).build());
+        contract.propose_manager(new_manager.clone()).unwrap();
+        assert_eq!(contract.platform.manager, manager);
+        assert_eq!(
+            contract.get_contract_info().pending_manager,
+            Some(new_manager.clone())
+        );
+        let logs = get_logs();
+        assert!(
+            logs.iter()
+                .any(|l| emitted_operation(l) && l.contains("propose_manager"))
+        );
+
+        testing_env!(get_context_with_deposit(new_manager.clone(), 1).build());
+        contract.accept_manager().unwrap();
+        assert_eq!(contract.platform.manager, new_manager);
+        assert!(contract.platform.pending_manager.is_none());
+        let logs = get_logs();
+        assert!(
+            logs.iter()
+                .any(|l| emitted_operation(l) && l.contains("accept_manager"))
+        );
+    }
+
+    #[test]
+    fn test_propose_then_cancel_keeps_manager() {
+        let manager = accounts(0);
+        let new_manager = accounts(1);
+        testing_env!(get_context(manager.clone()).build());
+        let mut contract = init_live_contract();
+
+        testing_env!(get_context_with_deposit(manager.clone(), 1).build());
+        contract.propose_manager(new_manager.clone()).unwrap();
+        contract.cancel_manager_transfer().unwrap();
+        assert_eq!(contract.platform.manager, manager);
+        assert!(contract.platform.pending_manager.is_none());
+        let logs = get_logs();
+        assert!(
+            logs.iter()
+                .any(|l| emitted_operation(l) && l.contains("cancel_manager_transfer"))
+        );
+
+        testing_env!(get_context_with_deposit(new_manager, 1).build());
+        assert!(matches!(
+            contract.accept_manager(),
+            Err(SocialError::Unauthorized(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_accept_by_wrong_account_is_rejected() {
+        let manager = accounts(0);
+        testing_env!(get_context(manager.clone()).build());
+        let mut contract = init_live_contract();
+
+        testing_env!(get_context_with_deposit(manager.clone(), 1).build());
+        contract.propose_manager(accounts(1)).unwrap();
+
+        testing_env!(get_context_with_deposit(accounts(2), 1).build());
+        assert!(matches!(
+            contract.accept_manager(),
+            Err(SocialError::Unauthorized(_, _))
+        ));
+        assert_eq!(contract.platform.manager, manager);
+        assert_eq!(contract.platform.pending_manager, Some(accounts(1)));
+    }
+
+    #[test]
+    fn test_only_manager_can_propose_or_cancel() {
+        let manager = accounts(0);
+        testing_env!(get_context(manager.clone()).build());
+        let mut contract = init_live_contract();
+
+        testing_env!(get_context_with_deposit(accounts(1), 1).build());
+        assert!(matches!(
+            contract.propose_manager(accounts(1)),
+            Err(SocialError::Unauthorized(_, _))
+        ));
+        assert!(matches!(
+            contract.cancel_manager_transfer(),
+            Err(SocialError::Unauthorized(_, _))
+        ));
+    }
+}
@@ -0,0 +1,221 @@
+// === MEMBER LISTING TESTS ===
+// Paged get_group_members view: ordering, page boundaries and role filters.
+
+use crate::domain::groups::permissions::kv::types::{MANAGE, MODERATE};
+use crate::tests::test_utils::*;
+use near_sdk::AccountId;
+use near_sdk::test_utils::accounts;
+use serde_json::{Value, json};
+
+#[cfg(test)]
+mod listing_tests {
+    use super::*;
+
+    const GROUP: &str = "listing";
+
+    fn member(i: usize) -> AccountId {
+        format!("member{:02}.near", i).parse().unwrap()
+    }
+
+    /// Group owned by accounts(0) with `count` plain members.
+    fn setup_group(count: usize) -> crate::Contract {
+        let mut contract = init_live_contract();
+        let owner = accounts(0);
+        near_sdk::testing_env!(
+            get_context_with_deposit(owner.clone(), test_deposits::ten_near()).build()
+        );
+        contract
+            .execute(create_group_request(
+                GROUP.to_string(),
+                json!({"member_driven": false, "is_private": false}),
+            ))
+            .unwrap();
+        for i in 0..count {
+            test_add_member_bypass_proposals(&mut contract, GROUP, &member(i), 0, &owner);
+        }
+        contract
+    }
+
+    fn ids(page: &[Value]) -> Vec<String> {
+        page.iter()
+            .map(|m| m["account_id"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn pages_concatenate_to_full_member_list() {
+        let contract = setup_group(60);
+
+        let first = contract.get_group_members(GROUP.to_string(), 0, 25, None);
+        let second = contract.get_group_members(GROUP.to_string(), 25, 25, None);
+        let third = contract.get_group_members(GROUP.to_string(), 50, 25, None);
+        assert_eq!(first.len(), 25);
+        assert_eq!(second.len(), 25);
+        assert_eq!(third.len(), 11, "60 members + owner");
+
+        let all: Vec<String> = [first, second, third].iter().flat_map(|p| ids(p)).collect();
+        let mut sorted = all.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(all, sorted, "pages are ordered and disjoint");
+        assert!(all.contains(&accounts(0).to_string()));
+    }
+
+    #[test]
+    fn page_boundaries() {
+        let contract = setup_group(60);
+
+        let capped = contract.get_group_members(GROUP.to_string(), 0, 1_000, None);
+        assert_eq!(capped.len(), 50, "limit is capped");
+
+        let last = contract.get_group_members(GROUP.to_string(), 60, 10, None);
+        assert_eq!(last.len(), 1);
+        assert!(
+            contract
+                .get_group_members(GROUP.to_string(), 61, 10, None)
+                .is_empty()
+        );
+        assert!(
+            contract
+                .get_group_members(GROUP.to_string(), 0, 0, None)
+                .is_empty()
+        );
+        assert!(
+            contract
+                .get_group_members("missing".to_string(), 0, 10, None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn role_filter_applies_before_pagination() {
+        let mut contract = setup_group(10);
+        near_sdk::testing_env!(
+            get_context_with_deposit(accounts(0), test_deposits::ten_near()).build()
+        );
+        let config_path = format!("groups/{}/config", GROUP);
+        for (i, level) in [(3, MANAGE), (7, MODERATE), (8, MODERATE)] {
+            contract
+                .execute_admin(set_permission_request(
+                    member(i),
+                    config_path.clone(),
+                    level,
+                    None,
+                ))
+                .unwrap();
+        }
+
+        let owners = contract.get_group_members(GROUP.to_string(), 0, 10, Some("owner".into()));
+        assert_eq!(ids(&owners), vec![accounts(0).to_string()]);
+        assert_eq!(owners[0]["can_manage"], json!(true));
+
+        let admins = contract.get_group_members(GROUP.to_string(), 0, 10, Some("admin".into()));
+        assert_eq!(ids(&admins), vec![member(3).to_string()]);
+        assert_eq!(admins[0]["can_manage"], json!(true));
+
+        let moderators =
+            contract.get_group_members(GROUP.to_string(), 0, 10, Some("moderator".into()));
+        assert_eq!(
+            ids(&moderators),
+            vec![member(7).to_string(), member(8).to_string()]
+        );
+        assert_eq!(moderators[0]["can_manage"], json!(false));
+        assert_eq!(moderators[0]["can_moderate"], json!(true));
+
+        let second_moderator =
+            contract.get_group_members(GROUP.to_string(), 1, 10, Some("moderator".into()));
+        assert_eq!(ids(&second_moderator), vec![member(8).to_string()]);
+
+        let members = contract.get_group_members(GROUP.to_string(), 0, 50, Some("member".into()));
+        assert_eq!(members.len(), 7);
+        assert!(members.iter().all(|m| m["can_moderate"] == json!(false)));
+
+        assert!(
+            contract
+                .get_group_members(GROUP.to_string(), 0, 10, Some("unknown".into()))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn removed_members_are_not_listed() {
+        let mut contract = setup_group(0);
+        let owner = accounts(0);
+        near_sdk::testing_env!(
+            get_context_with_deposit(owner.clone(), test_deposits::ten_near()).build()
+        );
+        for i in 1..=3 {
+            contract
+                .execute(add_group_member_request(GROUP.to_string(), accounts(i)))
+                .unwrap();
+        }
+        contract
+            .execute(remove_group_member_request(GROUP.to_string(), accounts(2)))
+            .unwrap();
+
+        let listed = contract.get_group_members(GROUP.to_string(), 0, 10, None);
+        assert_eq!(
+            ids(&listed),
+            vec![
+                accounts(0).to_string(),
+                accounts(1).to_string(),
+                accounts(3).to_string()
+            ]
+        );
+        assert_eq!(listed[1]["level"], json!(0));
+        assert_eq!(listed[1]["role"], json!("member"));
+    }
+
+    #[test]
+    fn index_group_members_backfills_members_missing_from_the_index() {
+        let mut contract = setup_group(3);
+        // Members who joined before the index was kept have no entry.
+        for i in [0, 2] {
+            contract
+                .platform
+                .key_index_remove(&format!("groups/{}/members/{}", GROUP, member(i)));
+        }
+        assert_eq!(
+            contract
+                .get_group_members(GROUP.to_string(), 0, 10, None)
+                .len(),
+            2
+        );
+
+        let outsider: AccountId = "outsider.near".parse().unwrap();
+        let batch = vec![member(0), member(2), outsider];
+
+        near_sdk::testing_env!(get_context_with_deposit(member(1), 1).build());
+        assert!(
+            contract
+                .index_group_members(GROUP.to_string(), batch.clone())
+                .is_err()
+        );
+
+        let manager = contract.platform.manager.clone();
+        near_sdk::testing_env!(get_context_with_deposit(manager, 1).build());
+        assert_eq!(
+            contract
+                .index_group_members(GROUP.to_string(), batch.clone())
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            contract
+                .index_group_members(GROUP.to_string(), batch)
+                .unwrap(),
+            0
+        );
+
+        let listed = contract.get_group_members(GROUP.to_string(), 0, 10, None);
+        assert_eq!(
+            ids(&listed),
+            vec![
+                accounts(0).to_string(),
+                member(0).to_string(),
+                member(1).to_string(),
+                member(2).to_string()
+            ]
+        );
+    }
+}
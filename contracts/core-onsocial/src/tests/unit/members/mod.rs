@@ -5,6 +5,7 @@
 pub mod blacklist;
 pub mod core_operations; // Basic add/remove/leave operations
 pub mod join_requests; // Join request workflows, approvals, rejections
+pub mod listing; // Paged member listing and role filters
 pub mod permissions; // Permission granting, path-specific permissions // Blacklist/unblacklist operations
 
 // New test categories for comprehensive coverage
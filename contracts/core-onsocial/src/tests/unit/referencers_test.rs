@@ -0,0 +1,168 @@
+// --- Reverse Reference Index Tests ---
+// Opt-in `parent` indexing and `get_referencers` pagination.
+
+#[cfg(test)]
+mod referencers_tests {
+    use crate::Options;
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::{Value, json};
+    use near_sdk::test_utils::accounts;
+    use near_sdk::{AccountId, NearToken, testing_env};
+
+    const PARENT: &str = "alice/post/1";
+
+    fn indexed() -> Option<Options> {
+        Some(Options {
+            index_references: true,
+            ..Default::default()
+        })
+    }
+
+    fn reply(
+        contract: &mut crate::Contract,
+        author: &AccountId,
+        id: u32,
+        options: Option<Options>,
+    ) {
+        testing_env!(
+            get_context_with_deposit(author.clone(), NearToken::from_near(1).as_yoctonear())
+                .build()
+        );
+        contract
+            .execute(set_request_with_options(
+                json!({ format!("post/reply{}", id): { "text": "hi", "parent": PARENT } }),
+                options,
+            ))
+            .unwrap();
+    }
+
+    fn write(contract: &mut crate::Contract, author: &AccountId, data: Value) {
+        testing_env!(get_context(author.clone()).build());
+        contract
+            .execute(set_request_with_options(data, indexed()))
+            .unwrap();
+    }
+
+    #[test]
+    fn indexed_replies_are_listed_under_parent() {
+        let mut contract = init_live_contract();
+        reply(&mut contract, &accounts(1), 1, indexed());
+        reply(&mut contract, &accounts(2), 1, indexed());
+
+        assert_eq!(
+            contract.get_referencers(PARENT.to_string(), None, None),
+            vec![
+                format!("{}/post/reply1", accounts(1)),
+                format!("{}/post/reply1", accounts(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_without_opt_in_are_not_indexed() {
+        let mut contract = init_live_contract();
+        reply(&mut contract, &accounts(1), 1, None);
+
+        assert!(
+            contract
+                .get_referencers(PARENT.to_string(), None, None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn referencers_paginate_by_offset_and_limit() {
+        let mut contract = init_live_contract();
+        let bob = accounts(1);
+        for id in 0..5 {
+            reply(&mut contract, &bob, id, indexed());
+        }
+
+        let page =
+            |offset, limit| contract.get_referencers(PARENT.to_string(), Some(limit), Some(offset));
+        assert_eq!(
+            page(0, 2),
+            vec![
+                format!("{}/post/reply0", bob),
+                format!("{}/post/reply1", bob)
+            ]
+        );
+        assert_eq!(page(4, 2), vec![format!("{}/post/reply4", bob)]);
+        assert!(page(5, 2).is_empty());
+    }
+
+    #[test]
+    fn index_bytes_are_charged_to_the_referencer() {
+        let mut contract = init_live_contract();
+        let bob = accounts(1);
+        let carol = accounts(2);
+        reply(&mut contract, &bob, 1, None);
+        reply(&mut contract, &carol, 1, indexed());
+
+        let used = |account: &AccountId| {
+            contract
+                .get_storage_balance(account.clone())
+                .unwrap()
+                .used_bytes
+        };
+        assert!(
+            used(&carol) > used(&bob),
+            "referencer should pay for its index entry"
+        );
+    }
+
+    #[test]
+    fn deleting_or_reparenting_a_reply_updates_the_index() {
+        let mut contract = init_live_contract();
+        let bob = accounts(1);
+        reply(&mut contract, &bob, 1, indexed());
+        reply(&mut contract, &bob, 2, indexed());
+        let bytes_indexed = contract
+            .get_storage_balance(bob.clone())
+            .unwrap()
+            .used_bytes;
+
+        write(&mut contract, &bob, json!({ "post/reply1": null }));
+        assert_eq!(
+            contract.get_referencers(PARENT.to_string(), None, None),
+            vec![format!("{}/post/reply2", bob)]
+        );
+
+        write(
+            &mut contract,
+            &bob,
+            json!({ "post/reply2": { "text": "moved", "parent": "alice/post/2" } }),
+        );
+        assert!(
+            contract
+                .get_referencers(PARENT.to_string(), None, None)
+                .is_empty()
+        );
+        assert_eq!(
+            contract.get_referencers("alice/post/2".to_string(), None, None),
+            vec![format!("{}/post/reply2", bob)]
+        );
+        assert!(contract.get_storage_balance(bob).unwrap().used_bytes < bytes_indexed);
+    }
+
+    #[test]
+    fn relative_parent_is_ignored() {
+        let mut contract = init_live_contract();
+        let bob = accounts(1);
+        testing_env!(
+            get_context_with_deposit(bob.clone(), NearToken::from_near(1).as_yoctonear()).build()
+        );
+        contract
+            .execute(set_request_with_options(
+                json!({ "post/reply": { "text": "hi", "parent": "post123" } }),
+                indexed(),
+            ))
+            .unwrap();
+
+        assert!(
+            contract
+                .get_referencers("post123".to_string(), None, None)
+                .is_empty()
+        );
+    }
+}
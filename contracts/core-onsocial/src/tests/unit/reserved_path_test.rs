@@ -0,0 +1,121 @@
+// --- Reserved Path Tests ---
+// `set` rejects writes under `GovernanceConfig::reserved_prefixes` for non-managers.
+
+#[cfg(test)]
+mod reserved_path_tests {
+    use crate::config::ConfigUpdate;
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::json;
+    use near_sdk::test_utils::accounts;
+    use near_sdk::{NearToken, testing_env};
+
+    fn one_near() -> u128 {
+        NearToken::from_near(1).as_yoctonear()
+    }
+
+    fn reserve(contract: &mut crate::Contract, prefixes: Vec<String>) -> Result<(), String> {
+        let manager = contract.platform.manager.clone();
+        testing_env!(get_context_with_deposit(manager, 1).build());
+        contract
+            .update_config(ConfigUpdate {
+                reserved_prefixes: Some(prefixes),
+                ..Default::default()
+            })
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn group_internals_are_reserved_by_default() {
+        let mut contract = init_live_contract();
+        testing_env!(get_context_with_deposit(accounts(1), one_near()).build());
+
+        let err = contract
+            .execute(set_request(
+                json!({ "groups/club/members/bob": { "level": 3 } }),
+            ))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Path is reserved");
+
+        // Config writes are caught before the group permission check.
+        assert!(
+            contract
+                .execute(set_request(
+                    json!({ "groups/club/config": { "owner": "bob" } })
+                ))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn manager_extended_prefix_rejects_user_but_not_adjacent_paths() {
+        let mut contract = init_live_contract();
+        let mut prefixes = contract.get_config().reserved_prefixes;
+        prefixes.push("system/".to_string());
+        reserve(&mut contract, prefixes).unwrap();
+
+        testing_env!(get_context_with_deposit(accounts(1), one_near()).build());
+        assert!(
+            contract
+                .execute(set_request(json!({ "system/flags": "on" })))
+                .is_err()
+        );
+        assert!(
+            contract
+                .execute(set_request(json!({ "system": { "flags": "on" } })))
+                .is_err(),
+            "the prefix root is reserved too"
+        );
+
+        contract
+            .execute(set_request(json!({
+                "systems/flags": "on",
+                "profile/system": "on",
+            })))
+            .expect("adjacent paths stay writable");
+    }
+
+    #[test]
+    fn manager_may_write_reserved_prefix() {
+        let mut contract = init_live_contract();
+        let mut prefixes = contract.get_config().reserved_prefixes;
+        prefixes.push("system".to_string());
+        reserve(&mut contract, prefixes).unwrap();
+
+        let manager = contract.platform.manager.clone();
+        testing_env!(get_context_with_deposit(manager, one_near()).build());
+        contract
+            .execute(set_request(json!({ "system/flags": "on" })))
+            .unwrap();
+    }
+
+    #[test]
+    fn atomic_set_also_rejects_reserved_paths() {
+        let mut contract = init_live_contract();
+        testing_env!(get_context_with_deposit(accounts(1), one_near()).build());
+        let request = crate::Request {
+            target_account: None,
+            action: crate::Action::SetAtomic {
+                data: json!({ "profile/name": "Bob", "groups/club/stats/posts": 9 }),
+            },
+            options: None,
+        };
+        assert!(contract.execute(request).is_err());
+        assert!(
+            contract.get(vec![format!("{}/profile/name", accounts(1))], None)[0]
+                .value
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn reserved_prefixes_can_only_be_extended() {
+        let mut contract = init_live_contract();
+        let err = reserve(&mut contract, vec!["system".to_string()]).unwrap_err();
+        assert!(err.contains("can only be extended"), "{}", err);
+
+        let mut prefixes = contract.get_config().reserved_prefixes;
+        prefixes.push("../escape".to_string());
+        let err = reserve(&mut contract, prefixes).unwrap_err();
+        assert!(err.contains("Invalid reserved prefix"), "{}", err);
+    }
+}
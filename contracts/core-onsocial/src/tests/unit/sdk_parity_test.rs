@@ -20,6 +20,8 @@ const FIXTURE_JSON: &str = include_str!("../../../tests/fixtures/sdk-parity.json
 /// `Action` in `src/protocol/types.rs`.
 const ALL_ACTION_TYPES: &[&str] = &[
     "set",
+    "set_atomic",
+    "delete_subtree",
     "create_group",
     "join_group",
     "leave_group",
@@ -36,7 +38,11 @@ const ALL_ACTION_TYPES: &[&str] = &[
     "vote_on_proposal",
     "cancel_proposal",
     "expire_proposal",
+    "finalize_expired_proposal",
+    "delegate_vote",
+    "undelegate_vote",
     "set_permission",
+    "sweep_expired_permissions",
     "set_key_permission",
 ];
 
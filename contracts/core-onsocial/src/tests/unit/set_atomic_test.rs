@@ -0,0 +1,135 @@
+// --- Set Atomic Tests ---
+// All-or-nothing batches: every failure is reported and nothing is written.
+
+#[cfg(test)]
+mod set_atomic_tests {
+    use crate::protocol::{Action, Request};
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::{Value, json};
+    use near_sdk::test_utils::accounts;
+    use near_sdk::{AccountId, NearToken, testing_env};
+
+    fn set_atomic_request(data: Value) -> Request {
+        Request {
+            target_account: None,
+            action: Action::SetAtomic { data },
+            options: None,
+        }
+    }
+
+    fn stored(contract: &crate::Contract, account: &AccountId, key: &str) -> Option<Value> {
+        contract
+            .platform
+            .storage_get(&format!("{}/{}", account, key))
+    }
+
+    fn composite_post(body_len: usize) -> Value {
+        json!({
+            "post/p1": { "text": "x".repeat(body_len) },
+            "post/index/latest": "p1",
+            "post/counter": 1,
+        })
+    }
+
+    #[test]
+    fn writes_every_path_when_batch_is_valid() {
+        let mut contract = init_live_contract();
+        let alice = accounts(0);
+        testing_env!(
+            get_context_with_deposit(alice.clone(), NearToken::from_near(1).as_yoctonear()).build()
+        );
+
+        contract
+            .execute(set_atomic_request(composite_post(10)))
+            .expect("valid batch must commit");
+
+        assert_eq!(
+            stored(&contract, &alice, "post/index/latest"),
+            Some(json!("p1"))
+        );
+        assert_eq!(stored(&contract, &alice, "post/counter"), Some(json!(1)));
+        assert!(stored(&contract, &alice, "post/p1").is_some());
+    }
+
+    #[test]
+    fn reports_all_failures_and_writes_nothing() {
+        let mut contract = init_live_contract();
+        let alice = accounts(0);
+        testing_env!(
+            get_context_with_deposit(alice.clone(), NearToken::from_near(1).as_yoctonear()).build()
+        );
+        let too_big = "x".repeat(contract.get_config().max_value_bytes as usize + 1);
+
+        let err = contract
+            .execute(set_atomic_request(json!({
+                "post/a": "one",
+                "post/b": "two",
+                "post/c": too_big,
+                "groups/missing/content/post/1": "four",
+                "post/e": "five",
+            })))
+            .expect_err("batch with bad entries must fail");
+
+        let msg = err.to_string();
+        assert!(msg.contains("post/c"), "missing size failure: {}", msg);
+        assert!(
+            msg.contains("groups/missing/content/post/1"),
+            "missing group failure: {}",
+            msg
+        );
+        for key in ["post/a", "post/b", "post/c", "post/e"] {
+            assert!(
+                stored(&contract, &alice, key).is_none(),
+                "{} was written",
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn storage_shortfall_mid_batch_writes_nothing() {
+        let mut contract = init_live_contract();
+        let alice = accounts(0);
+
+        // Enough balance for roughly two of the five 1 KB values.
+        let deposit = NearToken::from_millinear(25).as_yoctonear();
+        testing_env!(get_context_with_deposit(alice.clone(), deposit).build());
+        contract
+            .execute_admin(set_request(json!({
+                "storage/deposit": { "amount": deposit.to_string() }
+            })))
+            .unwrap();
+
+        testing_env!(get_context(alice.clone()).build());
+        let data: near_sdk::serde_json::Map<String, Value> = (1..=5)
+            .map(|i| (format!("post/{}", i), json!("x".repeat(1_000))))
+            .collect();
+        let err = contract
+            .execute(set_atomic_request(Value::Object(data)))
+            .expect_err("batch larger than balance must fail");
+
+        assert!(err.to_string().contains("storage"), "unexpected: {}", err);
+        for i in 1..=5 {
+            assert!(stored(&contract, &alice, &format!("post/{}", i)).is_none());
+        }
+    }
+
+    #[test]
+    fn rejects_reserved_operation_keys() {
+        let mut contract = init_live_contract();
+        let alice = accounts(0);
+        testing_env!(
+            get_context_with_deposit(alice.clone(), NearToken::from_near(1).as_yoctonear()).build()
+        );
+
+        let err = contract
+            .execute(set_atomic_request(json!({
+                "post/a": "one",
+                "storage/deposit": { "amount": "1" },
+            })))
+            .expect_err("operation keys are not data paths");
+
+        assert!(err.to_string().contains("Only data paths"), "{}", err);
+        assert!(stored(&contract, &alice, "post/a").is_none());
+    }
+}
@@ -109,6 +109,7 @@ mod storage_tracking_tests {
         // Use refund_unused_deposit: true to get old refund behavior
         let options = Some(crate::Options {
             refund_unused_deposit: true,
+            ..Default::default()
         });
         let result = contract.execute_admin(set_request_with_options(deposit_data, options));
         assert!(result.is_ok(), "Deposit with excess should succeed");
@@ -1563,6 +1564,7 @@ mod storage_tracking_tests {
         // Use refund_unused_deposit: true so excess 0.4 NEAR is refunded, not added to storage
         let options = Some(crate::Options {
             refund_unused_deposit: true,
+            ..Default::default()
         });
         let result = contract.execute_admin(set_request_with_options(deposit_data, options));
         assert!(result.is_ok(), "First deposit should succeed");
@@ -0,0 +1,181 @@
+// --- Sweep Expired Permissions Tests ---
+// Anyone can clear an owner's expired account grants; active grants stay.
+
+#[cfg(test)]
+mod sweep_permissions_tests {
+    use crate::constants::EVENT_JSON_PREFIX;
+    use crate::domain::groups::permissions::kv::types::WRITE;
+    use crate::protocol::{Action, Request};
+    use crate::tests::test_utils::*;
+    use near_sdk::json_types::U64;
+    use near_sdk::serde_json::{self, Value};
+    use near_sdk::test_utils::get_logs;
+    use near_sdk::{AccountId, testing_env};
+
+    const DEPOSIT: u128 = 10_000_000_000_000_000_000_000_000;
+    const EXPIRY: u64 = TEST_BASE_TIMESTAMP + 1_000;
+
+    fn sweep_request(owner: &AccountId, limit: u32, from_key: Option<String>) -> Request {
+        Request {
+            target_account: None,
+            action: Action::SweepExpiredPermissions {
+                owner: owner.clone(),
+                limit,
+                from_key,
+            },
+            options: None,
+        }
+    }
+
+    fn grant(
+        contract: &mut crate::Contract,
+        owner: &AccountId,
+        grantee: &AccountId,
+        path: &str,
+        expires_at: Option<u64>,
+    ) {
+        testing_env!(get_context_with_deposit(owner.clone(), DEPOSIT).build());
+        contract
+            .execute_admin(set_permission_request(
+                grantee.clone(),
+                path.to_string(),
+                WRITE,
+                expires_at.map(U64),
+            ))
+            .unwrap();
+    }
+
+    /// Runs a sweep from a third-party account after every grant with
+    /// `EXPIRY` has lapsed.
+    fn sweep(
+        contract: &mut crate::Contract,
+        owner: &AccountId,
+        limit: u32,
+        from_key: Option<String>,
+    ) -> Value {
+        let mut context = get_context(test_account(5));
+        context.block_timestamp(EXPIRY + 1);
+        testing_env!(context.build());
+        contract
+            .execute(sweep_request(owner, limit, from_key))
+            .unwrap()
+    }
+
+    fn can_write(
+        contract: &crate::Contract,
+        owner: &AccountId,
+        grantee: &AccountId,
+        path: &str,
+    ) -> bool {
+        contract.has_permission(owner.clone(), grantee.clone(), path.to_string(), WRITE)
+    }
+
+    #[test]
+    fn only_expired_grants_are_removed() {
+        let alice = test_account(0);
+        let (bob, carol, dave) = (test_account(1), test_account(2), test_account(3));
+        testing_env!(get_context(alice.clone()).build());
+        let mut contract = init_live_contract();
+
+        grant(&mut contract, &alice, &bob, "post/", None);
+        grant(&mut contract, &alice, &bob, "profile/", Some(EXPIRY));
+        grant(&mut contract, &alice, &carol, "post/", Some(EXPIRY));
+        grant(
+            &mut contract,
+            &alice,
+            &dave,
+            "media/",
+            Some(EXPIRY + 10_000),
+        );
+        let used_before = contract
+            .get_storage_balance(alice.clone())
+            .unwrap()
+            .used_bytes;
+
+        let result = sweep(&mut contract, &alice, 50, None);
+        assert_eq!(result["swept"], 2);
+        assert!(result["next_key"].is_null());
+
+        assert!(can_write(&contract, &alice, &bob, "post/1"));
+        assert!(!can_write(&contract, &alice, &bob, "profile/name"));
+        assert!(!can_write(&contract, &alice, &carol, "post/1"));
+        assert!(can_write(&contract, &alice, &dave, "media/a"));
+
+        let used_after = contract
+            .get_storage_balance(alice.clone())
+            .unwrap()
+            .used_bytes;
+        assert!(used_after < used_before, "{used_after} >= {used_before}");
+
+        let swept_events: Vec<Value> = get_logs()
+            .iter()
+            .filter_map(|l| l.strip_prefix(EVENT_JSON_PREFIX))
+            .map(|j| serde_json::from_str::<Value>(j).unwrap())
+            .filter(|e| e["event"] == "PERMISSION_UPDATE")
+            .flat_map(|e| e["data"].as_array().unwrap().clone())
+            .collect();
+        assert_eq!(swept_events.len(), 2);
+        for event in &swept_events {
+            assert_eq!(event["granted"], false);
+            assert_eq!(event["reason"], "expired");
+            assert_eq!(event["author"], alice.as_str());
+        }
+
+        // Nothing left to sweep.
+        assert_eq!(sweep(&mut contract, &alice, 50, None)["swept"], 0);
+    }
+
+    #[test]
+    fn scan_is_bounded_and_resumable() {
+        let alice = test_account(0);
+        testing_env!(get_context(alice.clone()).build());
+        let mut contract = init_live_contract();
+        for i in 1..=4 {
+            grant(
+                &mut contract,
+                &alice,
+                &test_account(i),
+                "post/",
+                Some(EXPIRY),
+            );
+        }
+        grant(&mut contract, &alice, &test_account(1), "profile/", None);
+
+        // Keys sort by grantee, so the first page holds both of bob's grants.
+        let first = sweep(&mut contract, &alice, 3, None);
+        assert_eq!(first["swept"], 2);
+        let cursor = first["next_key"].as_str().unwrap().to_string();
+
+        let second = sweep(&mut contract, &alice, 3, Some(cursor));
+        assert_eq!(second["swept"], 2);
+        assert!(second["next_key"].is_null());
+
+        for i in 1..=4 {
+            assert!(!can_write(&contract, &alice, &test_account(i), "post/1"));
+        }
+        assert!(can_write(
+            &contract,
+            &alice,
+            &test_account(1),
+            "profile/name"
+        ));
+    }
+
+    #[test]
+    fn revoked_grants_leave_the_index() {
+        let (alice, bob) = (test_account(0), test_account(1));
+        testing_env!(get_context(alice.clone()).build());
+        let mut contract = init_live_contract();
+        grant(&mut contract, &alice, &bob, "post/", Some(EXPIRY));
+
+        let prefix = format!("{}/permissions/", alice);
+        assert_eq!(contract.platform.keys_with_prefix(&prefix, 10).len(), 1);
+
+        testing_env!(get_context_with_deposit(alice.clone(), DEPOSIT).build());
+        contract
+            .execute_admin(set_permission_request(bob, "post/".to_string(), 0, None))
+            .unwrap();
+        assert!(contract.platform.keys_with_prefix(&prefix, 10).is_empty());
+        assert_eq!(sweep(&mut contract, &alice, 50, None)["swept"], 0);
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod value_size_limit_tests {
+    use crate::SocialError;
+    use crate::config::ConfigUpdate;
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::json;
+    use near_sdk::test_utils::accounts;
+    use near_sdk::{NearToken, testing_env};
+
+    const LIMIT: u32 = 12 * 1024;
+
+    fn setup_with_limit() -> crate::Contract {
+        let manager = accounts(0);
+        testing_env!(get_context(manager.clone()).build());
+        let mut contract = init_live_contract();
+
+        testing_env!(get_context_with_deposit(manager, 1).build());
+        contract
+            .update_config(ConfigUpdate {
+                max_value_bytes: Some(LIMIT),
+                ..Default::default()
+            })
+            .unwrap();
+        contract
+    }
+
+    /// Writes a JSON string whose serialized form is exactly `len` bytes.
+    fn write_value_of_len(contract: &mut crate::Contract, len: usize) -> Result<(), SocialError> {
+        let deposit = NearToken::from_near(5).as_yoctonear();
+        testing_env!(get_context_with_deposit(accounts(1), deposit).build());
+        contract
+            .execute(set_request(json!({ "profile/bio": "x".repeat(len - 2) })))
+            .map(|_| ())
+    }
+
+    #[test]
+    fn value_at_configured_limit_is_accepted() {
+        let mut contract = setup_with_limit();
+        write_value_of_len(&mut contract, LIMIT as usize).expect("value at limit must be stored");
+    }
+
+    #[test]
+    fn value_over_configured_limit_reports_sizes() {
+        let mut contract = setup_with_limit();
+        let err = write_value_of_len(&mut contract, LIMIT as usize + 1)
+            .expect_err("value over limit must be rejected");
+        match err {
+            SocialError::ValueTooLarge { actual, max } => {
+                assert_eq!(actual, LIMIT as u64 + 1);
+                assert_eq!(max, LIMIT as u64);
+            }
+            other => panic!("expected ValueTooLarge, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn max_value_bytes_cannot_be_lowered() {
+        let mut contract = setup_with_limit();
+        testing_env!(get_context_with_deposit(accounts(0), 1).build());
+        let err = contract
+            .update_config(ConfigUpdate {
+                max_value_bytes: Some(LIMIT - 1),
+                ..Default::default()
+            })
+            .expect_err("lowering a safety limit must fail");
+        assert!(err.to_string().contains("can only be increased"));
+    }
+}
@@ -0,0 +1,468 @@
+// --- Vote Delegation Tests ---
+// Liquid democracy: a delegate's ballot carries its delegators' weight, direct votes
+// override that share, and cycles / over-long chains are rejected.
+
+#[cfg(test)]
+mod vote_delegation_tests {
+    use crate::protocol::{Action, Request};
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::{Value, json};
+    use near_sdk::test_utils::accounts;
+    use near_sdk::{AccountId, testing_env};
+
+    const GROUP: &str = "delg";
+
+    fn delegate_request(delegate: &AccountId) -> Request {
+        Request {
+            target_account: None,
+            action: Action::DelegateVote {
+                group_id: GROUP.to_string(),
+                delegate: delegate.clone(),
+            },
+            options: None,
+        }
+    }
+
+    fn undelegate_request() -> Request {
+        Request {
+            target_account: None,
+            action: Action::UndelegateVote {
+                group_id: GROUP.to_string(),
+            },
+            options: None,
+        }
+    }
+
+    /// Member-driven group owned by accounts(0) with accounts(1..=5) as members.
+    fn setup_group(config: Value) -> crate::Contract {
+        let mut contract = init_live_contract();
+        let owner = accounts(0);
+
+        testing_env!(get_context_with_deposit(owner.clone(), test_deposits::ten_near()).build());
+        contract
+            .execute(create_group_request(GROUP.to_string(), config))
+            .unwrap();
+        for i in 1..=5 {
+            test_add_member_bypass_proposals(&mut contract, GROUP, &accounts(i), 0, &owner);
+        }
+        contract
+    }
+
+    fn member_driven_group() -> crate::Contract {
+        setup_group(json!({"member_driven": true, "is_private": true}))
+    }
+
+    fn delegate(contract: &mut crate::Contract, from: &AccountId, to: &AccountId) {
+        testing_env!(get_context_with_deposit(from.clone(), test_deposits::ten_near()).build());
+        contract.execute(delegate_request(to)).unwrap();
+    }
+
+    fn create_proposal(contract: &mut crate::Contract) -> String {
+        testing_env!(get_context_for_proposal(accounts(0)).build());
+        contract
+            .execute(create_proposal_request(
+                GROUP.to_string(),
+                "custom_proposal".to_string(),
+                json!({"title": "t", "description": "d", "custom_data": {}}),
+                Some(false),
+            ))
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    fn vote(contract: &mut crate::Contract, voter: &AccountId, proposal_id: &str, approve: bool) {
+        testing_env!(get_context_with_deposit(voter.clone(), test_deposits::ten_near()).build());
+        contract
+            .execute(vote_proposal_request(
+                GROUP.to_string(),
+                proposal_id.to_string(),
+                approve,
+            ))
+            .unwrap();
+    }
+
+    fn tally(contract: &crate::Contract, proposal_id: &str) -> (u64, u64) {
+        let tally = contract
+            .get_proposal_tally(GROUP.to_string(), proposal_id.to_string())
+            .unwrap();
+        (
+            tally["total_votes"].as_u64().unwrap(),
+            tally["yes_votes"].as_u64().unwrap(),
+        )
+    }
+
+    #[test]
+    fn delegated_vote_follows_delegate() {
+        let mut contract = member_driven_group();
+        let (bob, carol) = (accounts(1), accounts(2));
+        delegate(&mut contract, &bob, &carol);
+
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &carol, &proposal_id, false);
+
+        assert_eq!(tally(&contract, &proposal_id), (2, 0));
+        let ballot = contract
+            .get_vote(GROUP.to_string(), proposal_id.clone(), carol)
+            .unwrap();
+        assert_eq!(ballot["approve"], json!(false));
+        assert_eq!(ballot["delegated_weight"], json!(1));
+
+        // Delegators get no ballot of their own; their weight sits on the delegate's.
+        assert!(
+            contract
+                .get_vote(GROUP.to_string(), proposal_id, bob)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn delegated_votes_follow_chain_and_can_pass_proposal() {
+        let mut contract = member_driven_group();
+        let (bob, carol, dave) = (accounts(1), accounts(2), accounts(3));
+        delegate(&mut contract, &bob, &carol);
+        delegate(&mut contract, &carol, &dave);
+
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &accounts(0), &proposal_id, true);
+        vote(&mut contract, &dave, &proposal_id, true);
+
+        // Owner + dave + carol + bob = 4 of 6 members, all yes.
+        assert_eq!(tally(&contract, &proposal_id), (4, 4));
+        let ballot = contract
+            .get_vote(GROUP.to_string(), proposal_id.clone(), dave)
+            .unwrap();
+        assert_eq!(ballot["delegated_weight"], json!(2));
+        assert!(
+            contract
+                .get_vote(GROUP.to_string(), proposal_id.clone(), bob)
+                .is_none()
+        );
+
+        let proposal = contract
+            .get_proposal(GROUP.to_string(), proposal_id)
+            .unwrap();
+        assert_eq!(proposal["status"], json!("executed"));
+    }
+
+    #[test]
+    fn direct_vote_takes_precedence_over_delegation() {
+        let mut contract = member_driven_group();
+        let (bob, carol) = (accounts(1), accounts(2));
+        delegate(&mut contract, &bob, &carol);
+
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &bob, &proposal_id, true);
+        vote(&mut contract, &carol, &proposal_id, false);
+
+        assert_eq!(tally(&contract, &proposal_id), (2, 1));
+        let record = contract
+            .get_vote(GROUP.to_string(), proposal_id.clone(), bob)
+            .unwrap();
+        assert_eq!(record["approve"], json!(true));
+        let ballot = contract
+            .get_vote(GROUP.to_string(), proposal_id, carol)
+            .unwrap();
+        assert!(ballot.get("delegated_weight").is_none());
+    }
+
+    #[test]
+    fn direct_vote_overrides_delegated_ballot() {
+        let mut contract = member_driven_group();
+        let (bob, carol) = (accounts(1), accounts(2));
+        delegate(&mut contract, &bob, &carol);
+
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &carol, &proposal_id, false);
+        assert_eq!(tally(&contract, &proposal_id), (2, 0));
+
+        vote(&mut contract, &bob, &proposal_id, true);
+        assert_eq!(tally(&contract, &proposal_id), (2, 1));
+
+        let record = contract
+            .get_vote(GROUP.to_string(), proposal_id.clone(), bob)
+            .unwrap();
+        assert_eq!(record["approve"], json!(true));
+        assert_eq!(record["overrides"], json!(carol.as_str()));
+        let ballot = contract
+            .get_vote(GROUP.to_string(), proposal_id, carol.clone())
+            .unwrap();
+        assert_eq!(ballot["delegated_weight"], json!(0));
+
+        let override_event = near_sdk::test_utils::get_logs()
+            .into_iter()
+            .find(|log| log.contains("\"vote_cast\""))
+            .unwrap();
+        assert!(override_event.contains(&format!("\"overrides\":\"{}\"", carol)));
+    }
+
+    #[test]
+    fn override_along_chain_moves_only_the_overriders_share() {
+        let mut contract = member_driven_group();
+        let (bob, carol, dave) = (accounts(1), accounts(2), accounts(3));
+        delegate(&mut contract, &bob, &carol);
+        delegate(&mut contract, &carol, &dave);
+
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &dave, &proposal_id, true);
+        assert_eq!(tally(&contract, &proposal_id), (3, 3));
+
+        // Carol takes herself and bob back from dave's ballot.
+        vote(&mut contract, &carol, &proposal_id, false);
+        assert_eq!(tally(&contract, &proposal_id), (3, 1));
+        let ballot = |contract: &crate::Contract, voter: &AccountId| {
+            contract
+                .get_vote(GROUP.to_string(), proposal_id.clone(), voter.clone())
+                .unwrap()["delegated_weight"]
+                .clone()
+        };
+        assert_eq!(ballot(&contract, &dave), json!(0));
+        assert_eq!(ballot(&contract, &carol), json!(1));
+
+        // Bob's override now comes out of carol's ballot.
+        vote(&mut contract, &bob, &proposal_id, true);
+        assert_eq!(tally(&contract, &proposal_id), (3, 2));
+        assert_eq!(ballot(&contract, &carol), json!(0));
+    }
+
+    #[test]
+    fn delegation_made_after_the_delegate_voted_is_not_retracted() {
+        let mut contract = member_driven_group();
+        let (bob, carol) = (accounts(1), accounts(2));
+
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &carol, &proposal_id, true);
+        delegate(&mut contract, &bob, &carol);
+
+        vote(&mut contract, &bob, &proposal_id, false);
+        assert_eq!(tally(&contract, &proposal_id), (2, 1));
+    }
+
+    #[test]
+    fn undelegating_after_delegate_voted_does_not_count_twice() {
+        let mut contract = member_driven_group();
+        let (bob, carol) = (accounts(1), accounts(2));
+        delegate(&mut contract, &bob, &carol);
+
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &carol, &proposal_id, false);
+
+        testing_env!(get_context_with_deposit(bob.clone(), test_deposits::ten_near()).build());
+        contract.execute(undelegate_request()).unwrap();
+        vote(&mut contract, &bob, &proposal_id, true);
+
+        // Bob's vote moves out of carol's ballot rather than adding to it.
+        assert_eq!(tally(&contract, &proposal_id), (2, 1));
+        let record = contract
+            .get_vote(GROUP.to_string(), proposal_id.clone(), bob)
+            .unwrap();
+        assert_eq!(record["overrides"], json!(carol.as_str()));
+        let ballot = contract
+            .get_vote(GROUP.to_string(), proposal_id, carol)
+            .unwrap();
+        assert_eq!(ballot["delegated_weight"], json!(0));
+    }
+
+    #[test]
+    fn redelegating_after_delegate_voted_does_not_count_twice() {
+        let mut contract = member_driven_group();
+        let (bob, carol, dave) = (accounts(1), accounts(2), accounts(3));
+        delegate(&mut contract, &bob, &carol);
+
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &carol, &proposal_id, false);
+        delegate(&mut contract, &bob, &dave);
+        vote(&mut contract, &dave, &proposal_id, true);
+
+        // Bob stays on carol's ballot; dave only carries himself.
+        assert_eq!(tally(&contract, &proposal_id), (3, 1));
+        let ballot = contract
+            .get_vote(GROUP.to_string(), proposal_id, dave)
+            .unwrap();
+        assert!(ballot.get("delegated_weight").is_none());
+    }
+
+    #[test]
+    fn direct_ballot_still_cannot_be_changed() {
+        let mut contract = member_driven_group();
+        let carol = accounts(2);
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &carol, &proposal_id, true);
+
+        testing_env!(get_context_with_deposit(carol, test_deposits::ten_near()).build());
+        let err = contract
+            .execute(vote_proposal_request(GROUP.to_string(), proposal_id, false))
+            .unwrap_err();
+        assert!(format!("{:?}", err).contains("already voted"), "{:?}", err);
+    }
+
+    #[test]
+    fn delegate_with_many_delegators_casts_one_ballot() {
+        let mut contract = member_driven_group();
+        let owner = accounts(0);
+        let carol = accounts(2);
+
+        // 20 direct delegators, each with 5 of their own: 120 delegated votes.
+        testing_env!(get_context_with_deposit(owner.clone(), test_deposits::ten_near()).build());
+        let mut delegators = Vec::new();
+        for i in 0..20 {
+            let direct: AccountId = format!("d{}.near", i).parse().unwrap();
+            test_add_member_bypass_proposals(&mut contract, GROUP, &direct, 0, &owner);
+            for j in 0..5 {
+                let nested: AccountId = format!("d{}-{}.near", i, j).parse().unwrap();
+                test_add_member_bypass_proposals(&mut contract, GROUP, &nested, 0, &owner);
+                delegators.push((nested, direct.clone()));
+            }
+            delegators.push((direct, carol.clone()));
+        }
+        for (from, to) in &delegators {
+            delegate(&mut contract, from, to);
+        }
+
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &carol, &proposal_id, true);
+
+        assert_eq!(tally(&contract, &proposal_id), (121, 121));
+        let ballot = contract
+            .get_vote(GROUP.to_string(), proposal_id, carol)
+            .unwrap();
+        assert_eq!(ballot["delegated_weight"], json!(120));
+
+        let vote_events = near_sdk::test_utils::get_logs()
+            .iter()
+            .filter(|log| log.contains("\"vote_cast\""))
+            .count();
+        assert_eq!(vote_events, 1);
+    }
+
+    #[test]
+    fn undelegate_stops_following_delegate() {
+        let mut contract = member_driven_group();
+        let (bob, carol) = (accounts(1), accounts(2));
+        delegate(&mut contract, &bob, &carol);
+        assert!(
+            contract
+                .get_vote_delegation(GROUP.to_string(), bob.clone())
+                .is_some()
+        );
+
+        testing_env!(get_context_with_deposit(bob.clone(), test_deposits::ten_near()).build());
+        contract.execute(undelegate_request()).unwrap();
+        assert!(
+            contract
+                .get_vote_delegation(GROUP.to_string(), bob.clone())
+                .is_none()
+        );
+
+        let proposal_id = create_proposal(&mut contract);
+        vote(&mut contract, &carol, &proposal_id, true);
+
+        assert_eq!(tally(&contract, &proposal_id), (1, 1));
+        assert!(
+            contract
+                .get_vote(GROUP.to_string(), proposal_id, bob.clone())
+                .is_none()
+        );
+
+        testing_env!(get_context_with_deposit(bob, test_deposits::ten_near()).build());
+        assert!(contract.execute(undelegate_request()).is_err());
+    }
+
+    #[test]
+    fn get_vote_delegations_lists_current_delegators() {
+        let mut contract = member_driven_group();
+        let (bob, carol, dave) = (accounts(1), accounts(2), accounts(3));
+        delegate(&mut contract, &bob, &carol);
+        delegate(&mut contract, &dave, &carol);
+        assert_eq!(
+            contract.get_vote_delegations(GROUP.to_string(), carol.clone()),
+            vec![bob.clone(), dave.clone()]
+        );
+
+        // Re-delegating moves bob off carol's list.
+        delegate(&mut contract, &bob, &dave);
+        assert_eq!(
+            contract.get_vote_delegations(GROUP.to_string(), carol.clone()),
+            vec![dave.clone()]
+        );
+        assert_eq!(
+            contract.get_vote_delegations(GROUP.to_string(), dave.clone()),
+            vec![bob]
+        );
+
+        testing_env!(get_context_with_deposit(dave.clone(), test_deposits::ten_near()).build());
+        contract.execute(undelegate_request()).unwrap();
+        assert!(
+            contract
+                .get_vote_delegations(GROUP.to_string(), carol)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn delegation_cycle_rejected() {
+        let mut contract = member_driven_group();
+        let (bob, carol) = (accounts(1), accounts(2));
+        delegate(&mut contract, &bob, &carol);
+
+        testing_env!(get_context_with_deposit(carol.clone(), test_deposits::ten_near()).build());
+        let err = contract.execute(delegate_request(&bob)).unwrap_err();
+        assert!(format!("{:?}", err).contains("cycle"), "{:?}", err);
+    }
+
+    #[test]
+    fn indirect_delegation_cycle_rejected() {
+        let mut contract = member_driven_group();
+        let (bob, carol) = (accounts(1), accounts(2));
+        delegate(&mut contract, &bob, &carol);
+        delegate(&mut contract, &carol, &accounts(0));
+
+        testing_env!(get_context_with_deposit(accounts(0), test_deposits::ten_near()).build());
+        let err = contract.execute(delegate_request(&bob)).unwrap_err();
+        assert!(format!("{:?}", err).contains("cycle"), "{:?}", err);
+    }
+
+    #[test]
+    fn delegation_chain_capped_by_max_depth() {
+        let mut contract = member_driven_group();
+        let (bob, carol, dave, eve) = (accounts(1), accounts(2), accounts(3), accounts(4));
+        delegate(&mut contract, &bob, &carol);
+        delegate(&mut contract, &carol, &dave);
+
+        // Extending either end of a depth-2 chain exceeds the default cap.
+        testing_env!(get_context_with_deposit(eve.clone(), test_deposits::ten_near()).build());
+        let err = contract.execute(delegate_request(&bob)).unwrap_err();
+        assert!(format!("{:?}", err).contains("maximum depth"), "{:?}", err);
+
+        testing_env!(get_context_with_deposit(dave.clone(), test_deposits::ten_near()).build());
+        let err = contract.execute(delegate_request(&eve)).unwrap_err();
+        assert!(format!("{:?}", err).contains("maximum depth"), "{:?}", err);
+    }
+
+    #[test]
+    fn delegation_disabled_when_max_depth_zero() {
+        let mut contract = setup_group(json!({
+            "member_driven": true,
+            "is_private": true,
+            "voting_config": {"max_delegation_depth": 0}
+        }));
+
+        testing_env!(get_context_with_deposit(accounts(1), test_deposits::ten_near()).build());
+        let err = contract
+            .execute(delegate_request(&accounts(2)))
+            .unwrap_err();
+        assert!(format!("{:?}", err).contains("disabled"), "{:?}", err);
+    }
+
+    #[test]
+    fn cannot_delegate_to_self_or_non_member() {
+        let mut contract = member_driven_group();
+        let bob = accounts(1);
+        let outsider: AccountId = "outsider.near".parse().unwrap();
+
+        testing_env!(get_context_with_deposit(bob.clone(), test_deposits::ten_near()).build());
+        assert!(contract.execute(delegate_request(&bob)).is_err());
+        assert!(contract.execute(delegate_request(&outsider)).is_err());
+    }
+}
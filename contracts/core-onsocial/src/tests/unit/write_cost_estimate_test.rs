@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod write_cost_estimate_tests {
+    use crate::SocialError;
+    use crate::tests::test_utils::*;
+    use near_sdk::serde_json::{Value, json};
+    use near_sdk::test_utils::accounts;
+    use near_sdk::{AccountId, NearToken, testing_env};
+
+    fn setup_funded(alice: &AccountId) -> crate::Contract {
+        let mut contract = init_live_contract();
+        let deposit = NearToken::from_near(5).as_yoctonear();
+        testing_env!(get_context_with_deposit(alice.clone(), deposit).build());
+        contract
+            .execute_admin(set_request(json!({
+                "storage/deposit": { "amount": deposit.to_string() }
+            })))
+            .unwrap();
+        contract
+    }
+
+    fn used_bytes(contract: &crate::Contract, account: &AccountId) -> u64 {
+        contract
+            .get_storage_balance(account.clone())
+            .map(|s| s.used_bytes)
+            .unwrap_or(0)
+    }
+
+    fn actual_charge(contract: &mut crate::Contract, alice: &AccountId, value: &str) -> u128 {
+        let before = used_bytes(contract, alice);
+        testing_env!(get_context(alice.clone()).build());
+        contract
+            .execute(set_request(json!({ "profile/bio": value })))
+            .unwrap();
+        let grown = used_bytes(contract, alice).saturating_sub(before);
+        crate::storage::calculate_storage_balance_needed(grown)
+    }
+
+    #[test]
+    fn test_estimate_matches_new_key_charge() {
+        let alice = accounts(0);
+        let mut contract = setup_funded(&alice);
+
+        let estimate = contract
+            .estimate_write_cost("profile/bio".into(), json!("hello"), Some(alice.clone()))
+            .unwrap();
+        let charged = actual_charge(&mut contract, &alice, "hello");
+
+        assert!(charged > 0);
+        assert_eq!(estimate.0, charged);
+    }
+
+    #[test]
+    fn test_estimate_matches_overwrite_charge() {
+        let alice = accounts(0);
+        let mut contract = setup_funded(&alice);
+        actual_charge(&mut contract, &alice, "short");
+
+        let longer = "a much longer biography than before";
+        let estimate = contract
+            .estimate_write_cost("profile/bio".into(), json!(longer), Some(alice.clone()))
+            .unwrap();
+        let charged = actual_charge(&mut contract, &alice, longer);
+
+        assert_eq!(estimate.0, charged);
+        assert_eq!(
+            estimate.0,
+            crate::storage::calculate_storage_balance_needed((longer.len() - "short".len()) as u64)
+        );
+    }
+
+    #[test]
+    fn test_estimate_for_shrinking_overwrite_is_zero() {
+        let alice = accounts(0);
+        let mut contract = setup_funded(&alice);
+        actual_charge(&mut contract, &alice, "a fairly long value");
+
+        let estimate = contract
+            .estimate_write_cost("profile/bio".into(), json!("tiny"), Some(alice.clone()))
+            .unwrap();
+
+        assert_eq!(estimate.0, 0);
+        assert_eq!(actual_charge(&mut contract, &alice, "tiny"), 0);
+    }
+
+    #[test]
+    fn test_estimate_rejects_oversized_value() {
+        let alice = accounts(0);
+        let contract = setup_funded(&alice);
+        let too_big = "x".repeat(contract.get_config().max_value_bytes as usize + 1);
+
+        let result =
+            contract.estimate_write_cost("profile/bio".into(), json!(too_big), Some(alice));
+
+        assert!(matches!(result, Err(SocialError::ValueTooLarge { .. })));
+    }
+
+    #[test]
+    fn test_estimate_group_path_requires_account() {
+        let alice = accounts(0);
+        let contract = setup_funded(&alice);
+
+        let result = contract.estimate_write_cost("groups/dev/posts/1".into(), json!("hi"), None);
+
+        assert!(matches!(result, Err(SocialError::InvalidInput(_))));
+    }
+
+    fn set_cost(
+        contract: &crate::Contract,
+        account: &AccountId,
+        data: Value,
+    ) -> (u64, u64, i64, u128) {
+        let cost = contract.estimate_set_cost(account.clone(), data).unwrap();
+        (
+            cost["added_bytes"].as_u64().unwrap(),
+            cost["freed_bytes"].as_u64().unwrap(),
+            cost["net_bytes"].as_i64().unwrap(),
+            cost["required_deposit"].as_str().unwrap().parse().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_set_cost_for_fresh_write() {
+        let alice = accounts(0);
+        let mut contract = setup_funded(&alice);
+        let data = json!({ "profile/bio": "hello", "profile/name": "Alice" });
+
+        let (added, freed, net, required) = set_cost(&contract, &alice, data.clone());
+        assert_eq!(freed, 0);
+        assert_eq!(net, added as i64);
+        assert_eq!(required, 0, "funded account needs no deposit");
+        let (unfunded_added, _, _, unfunded_required) =
+            set_cost(&contract, &accounts(1), data.clone());
+        assert_eq!(
+            unfunded_required,
+            crate::storage::calculate_storage_balance_needed(unfunded_added)
+        );
+
+        let before = used_bytes(&contract, &alice);
+        testing_env!(get_context(alice.clone()).build());
+        contract.execute(set_request(data)).unwrap();
+        assert_eq!(used_bytes(&contract, &alice) - before, added);
+    }
+
+    #[test]
+    fn test_set_cost_for_shrinking_overwrite() {
+        let alice = accounts(0);
+        let mut contract = setup_funded(&alice);
+        actual_charge(&mut contract, &alice, "a fairly long value");
+
+        let (added, freed, net, required) =
+            set_cost(&contract, &alice, json!({ "profile/bio": "tiny" }));
+        assert_eq!(added, 0);
+        assert_eq!(freed, ("a fairly long value".len() - "tiny".len()) as u64);
+        assert_eq!(net, -(freed as i64));
+        assert_eq!(required, 0);
+
+        let before = used_bytes(&contract, &alice);
+        actual_charge(&mut contract, &alice, "tiny");
+        assert_eq!(before - used_bytes(&contract, &alice), freed);
+    }
+
+    #[test]
+    fn test_set_cost_for_noop_rewrite() {
+        let alice = accounts(0);
+        let mut contract = setup_funded(&alice);
+        actual_charge(&mut contract, &alice, "same");
+
+        let cost = set_cost(&contract, &alice, json!({ "profile/bio": "same" }));
+        assert_eq!(cost, (0, 0, 0, 0));
+    }
+}
@@ -1,4 +1,5 @@
-use near_sdk::serde_json::Value;
+use near_sdk::env;
+use near_sdk::serde_json::{Map, Value};
 
 use crate::{SocialError, invalid_input};
 
@@ -15,3 +16,29 @@ pub fn validate_json_value_simple(value: &Value) -> Result<(), SocialError> {
     }
     Ok(())
 }
+
+/// Recursively sorts object keys so equal values serialize identically.
+pub fn canonicalize_json_value(value: &Value) -> Value {
+    match value {
+        Value::Object(obj) => {
+            let mut keys: Vec<&String> = obj.keys().collect();
+            keys.sort();
+            let mut sorted = Map::new();
+            for key in keys {
+                sorted.insert(key.clone(), canonicalize_json_value(&obj[key]));
+            }
+            Value::Object(sorted)
+        }
+        Value::Array(items) => Value::Array(items.iter().map(canonicalize_json_value).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Hex sha256 of the canonical serialization; the token used by CAS writes.
+pub fn json_value_hash(value: &Value) -> String {
+    let bytes = near_sdk::serde_json::to_vec(&canonicalize_json_value(value)).unwrap_or_default();
+    env::sha256(&bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
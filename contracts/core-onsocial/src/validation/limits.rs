@@ -3,15 +3,22 @@ use near_sdk::serde_json::Value;
 
 use crate::{SocialError, invalid_input};
 
+pub fn require_value_within_limit(len: usize, max_bytes: usize) -> Result<(), SocialError> {
+    if len > max_bytes {
+        return Err(SocialError::ValueTooLarge {
+            actual: len as u64,
+            max: max_bytes as u64,
+        });
+    }
+    Ok(())
+}
+
 pub fn serialize_json_with_max_len(
     value: &Value,
     max_bytes: usize,
     serialize_err: &'static str,
-    too_large_err: &'static str,
 ) -> Result<Vec<u8>, SocialError> {
     let bytes = serde_json::to_vec(value).map_err(|_| invalid_input!(serialize_err))?;
-    if bytes.len() > max_bytes {
-        return Err(invalid_input!(too_large_err));
-    }
+    require_value_within_limit(bytes.len(), max_bytes)?;
     Ok(bytes)
 }
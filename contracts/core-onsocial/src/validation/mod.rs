@@ -9,7 +9,7 @@ mod view_key;
 pub(crate) use account::{parse_account_id_str, parse_account_id_str_opt, parse_account_id_value};
 pub(crate) use group::validate_group_id;
 pub(crate) use group_path::require_groups_path;
-pub(crate) use json::validate_json_value_simple;
-pub(crate) use limits::serialize_json_with_max_len;
-pub(crate) use path::{Path, is_safe_path};
+pub(crate) use json::{json_value_hash, validate_json_value_simple};
+pub(crate) use limits::{require_value_within_limit, serialize_json_with_max_len};
+pub(crate) use path::{Path, is_safe_path, require_unreserved_path};
 pub(crate) use view_key::resolve_view_key;
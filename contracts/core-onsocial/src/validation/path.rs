@@ -88,6 +88,43 @@ pub fn validate_and_normalize_path(
     Ok(full_path)
 }
 
+/// Rejects `set` writes under a `GovernanceConfig::reserved_prefixes` entry
+/// unless `actor` is the manager. Account paths match on the part after the
+/// owner, so `alice/groups/x/config` and `groups/x/config` are both caught.
+pub fn require_unreserved_path(
+    full_path: &str,
+    actor: &AccountId,
+    platform: &SocialPlatform,
+) -> Result<(), SocialError> {
+    if *actor == platform.manager {
+        return Ok(());
+    }
+    let rel = if full_path.starts_with("groups/") {
+        full_path
+    } else {
+        full_path.split_once('/').map_or(full_path, |(_, rel)| rel)
+    };
+    if platform
+        .config
+        .reserved_prefixes
+        .iter()
+        .any(|prefix| matches_reserved_prefix(prefix, rel))
+    {
+        return Err(invalid_input!("Path is reserved"));
+    }
+    Ok(())
+}
+
+/// Segment-wise prefix match; `*` stands for any single segment.
+fn matches_reserved_prefix(prefix: &str, path: &str) -> bool {
+    let mut segments = path.split('/');
+    prefix.trim_end_matches('/').split('/').all(|want| {
+        segments
+            .next()
+            .is_some_and(|seg| want == "*" || want == seg)
+    })
+}
+
 /// Returns true if the path is safe (no traversal attacks).
 /// Rejects paths that are empty, start with `/`, contain `..`, `\\`, or single-dot segments.
 #[inline]
@@ -37,6 +37,8 @@ const MAX_ACCEPTED_FTS: usize = 20;
 const NONCE_PREFIX: u8 = 0xA0;
 /// Domain prefix for oracle attestations.
 const DOMAIN_PREFIX: &str = "onsocial:intent";
+/// Blocks a height-stamped oracle attestation stays valid (~10 minutes).
+const ORACLE_MAX_BLOCK_AGE: u64 = 600;
 
 #[derive(NearSchema, near_sdk::FunctionError)]
 #[abi(json)]
@@ -377,6 +379,7 @@ impl OnsocialIntents {
             NONCE_PREFIX,
             &self.oracle_pks,
             DOMAIN_PREFIX,
+            Some(ORACLE_MAX_BLOCK_AGE),
         )
         .map_err(|e| IntentError::AuthFailed(format!("{e:?}")))?;
 
@@ -12,6 +12,10 @@ pub struct OracleAuth {
     pub public_key: PublicKey,
     pub nonce: U64,
     pub expires_at_ms: U64,
+    /// Block the oracle signed at. When present it is signed too, and the
+    /// attestation is rejected once the chain is `max_block_age` past it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_height: Option<U64>,
     pub signature: Base64VecU8,
 }
 
@@ -22,12 +26,17 @@ pub struct OracleContext {
 }
 
 /// Verifies an allowlisted oracle signature and returns the nonce tuple.
+///
+/// With `max_block_age`, attestations carrying a `block_height` are only
+/// valid for that many blocks, independent of nonce state. Attestations
+/// without a height are checked as before.
 pub fn authenticate_oracle(
     att: &OracleAuth,
     action_json: &Value,
     nonce_prefix: u8,
     oracle_pks: &[PublicKey],
     domain_prefix_base: &str,
+    max_block_age: Option<u64>,
 ) -> Result<OracleContext, AuthError> {
     if !oracle_pks.iter().any(|pk| pk == &att.public_key) {
         return Err(AuthError::Unauthorized(
@@ -44,6 +53,8 @@ pub fn authenticate_oracle(
         public_key: &att.public_key,
         nonce: att.nonce.0,
         expires_at_ms: att.expires_at_ms.0,
+        block_height: att.block_height.map(|h| h.0),
+        max_block_age,
         signature: &att.signature.0,
         action: action_json,
     })?;
@@ -60,39 +71,56 @@ struct VerifyParams<'a> {
     public_key: &'a PublicKey,
     nonce: u64,
     expires_at_ms: u64,
+    block_height: Option<u64>,
+    max_block_age: Option<u64>,
     signature: &'a [u8],
     action: &'a Value,
 }
 
-fn verify_signature(params: VerifyParams<'_>) -> Result<(), AuthError> {
+/// Checks validity windows, then the ed25519 signature.
+///
+/// Every early return depends only on public inputs (lengths, key type,
+/// heights, timestamps); the signature itself is checked by the host's
+/// `ed25519_verify`, so no failure reason leaks secret-dependent timing.
+fn verify_signature(params: VerifyParams<'_>) -> Result<(), VerifyError> {
     let now_ms = env::block_timestamp_ms();
     if params.expires_at_ms != 0 && now_ms > params.expires_at_ms {
-        return Err(AuthError::PayloadExpired);
+        return Err(VerifyError::Expired);
+    }
+    if let Some(height) = params.block_height {
+        let current = env::block_height();
+        if height > current {
+            return Err(VerifyError::BlockHeightAhead);
+        }
+        if params
+            .max_block_age
+            .is_some_and(|max| current - height > max)
+        {
+            return Err(VerifyError::BlockHeightStale);
+        }
     }
 
     if params.public_key.curve_type() != CurveType::ED25519 {
-        return Err(AuthError::InvalidInput(
-            "Only ed25519 public keys are supported".into(),
-        ));
+        return Err(VerifyError::UnsupportedKeyType);
     }
     let pk_bytes = ed25519_public_key_bytes(params.public_key.as_bytes())?;
     let sig_bytes = ed25519_signature_bytes(params.signature)?;
 
     let pk_str = String::from(params.public_key);
     let contract_id = env::current_account_id();
-    let payload = build_signing_payload(
-        params.target_account.as_str(),
-        &pk_str,
-        params.nonce,
-        params.expires_at_ms,
-        params.action,
-    );
-    let message =
-        build_signing_message(params.domain_prefix, contract_id.as_str(), &payload);
+    let signed = build_signing_message_for(&SigningRequest {
+        domain_prefix: params.domain_prefix,
+        contract_id: contract_id.as_str(),
+        target_account: params.target_account.as_str(),
+        public_key: &pk_str,
+        nonce: params.nonce,
+        expires_at_ms: params.expires_at_ms,
+        block_height: params.block_height,
+        action: params.action,
+    });
 
-    let message_hash = env::sha256_array(&message);
-    if !env::ed25519_verify(&sig_bytes, message_hash, &pk_bytes) {
-        return Err(AuthError::SignatureInvalid);
+    if !env::ed25519_verify(&sig_bytes, signed.hash(), &pk_bytes) {
+        return Err(VerifyError::SignatureMismatch);
     }
 
     Ok(())
@@ -152,21 +180,58 @@ pub mod nonce {
 
 #[derive(Debug, Clone)]
 pub enum AuthError {
-    InvalidInput(String),
     Unauthorized(String, String),
-    SignatureInvalid,
+    Verify(VerifyError),
     NonceStale,
-    PayloadExpired,
+}
+
+/// Why a signed payload failed verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyError {
+    /// Only ed25519 keys are accepted.
+    UnsupportedKeyType,
+    /// Raw key was neither 32 bytes nor 33 tagged bytes.
+    InvalidPublicKeyLength(usize),
+    InvalidSignatureLength(usize),
+    SignatureMismatch,
+    /// Past `expires_at_ms`.
+    Expired,
+    /// Signed `block_height` is above the current block.
+    BlockHeightAhead,
+    /// Signed `block_height` is more than `max_block_age` blocks old.
+    BlockHeightStale,
+}
+
+impl From<VerifyError> for AuthError {
+    fn from(e: VerifyError) -> Self {
+        Self::Verify(e)
+    }
+}
+
+impl std::fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedKeyType => write!(f, "only ed25519 public keys are supported"),
+            Self::InvalidPublicKeyLength(len) => {
+                write!(f, "invalid ed25519 public key length {len}")
+            }
+            Self::InvalidSignatureLength(len) => {
+                write!(f, "invalid ed25519 signature length {len}")
+            }
+            Self::SignatureMismatch => write!(f, "invalid ed25519 signature"),
+            Self::Expired => write!(f, "signed payload expired"),
+            Self::BlockHeightAhead => write!(f, "signed block height is ahead of the chain"),
+            Self::BlockHeightStale => write!(f, "signed block height too old"),
+        }
+    }
 }
 
 impl std::fmt::Display for AuthError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::InvalidInput(msg) => write!(f, "invalid input: {msg}"),
             Self::Unauthorized(op, acc) => write!(f, "Unauthorized: {op} by {acc}"),
-            Self::SignatureInvalid => write!(f, "invalid ed25519 signature"),
+            Self::Verify(e) => e.fmt(f),
             Self::NonceStale => write!(f, "nonce too low"),
-            Self::PayloadExpired => write!(f, "signed payload expired"),
         }
     }
 }
@@ -174,30 +239,47 @@ impl std::fmt::Display for AuthError {
 impl std::error::Error for AuthError {}
 
 /// Accepts raw 32-byte keys or 33-byte tagged keys.
-fn ed25519_public_key_bytes(pk_raw: &[u8]) -> Result<[u8; 32], AuthError> {
-    match pk_raw.len() {
-        32 => pk_raw
-            .try_into()
-            .map_err(|_| AuthError::InvalidInput("Invalid ed25519 public key bytes".into())),
-        33 => pk_raw
-            .get(1..)
-            .ok_or_else(|| AuthError::InvalidInput("Invalid ed25519 public key bytes".into()))?
-            .try_into()
-            .map_err(|_| AuthError::InvalidInput("Invalid ed25519 public key bytes".into())),
-        _ => Err(AuthError::InvalidInput(
-            "Invalid ed25519 public key bytes".into(),
-        )),
-    }
-}
-
-fn ed25519_signature_bytes(signature: &[u8]) -> Result<[u8; 64], AuthError> {
+pub(crate) fn ed25519_public_key_bytes(pk_raw: &[u8]) -> Result<[u8; 32], VerifyError> {
+    let raw = match pk_raw.len() {
+        33 => &pk_raw[1..],
+        _ => pk_raw,
+    };
+    raw.try_into()
+        .map_err(|_| VerifyError::InvalidPublicKeyLength(pk_raw.len()))
+}
+
+pub(crate) fn ed25519_signature_bytes(signature: &[u8]) -> Result<[u8; 64], VerifyError> {
     signature
         .try_into()
-        .map_err(|_| AuthError::InvalidInput("Invalid ed25519 signature bytes".into()))
+        .map_err(|_| VerifyError::InvalidSignatureLength(signature.len()))
+}
+
+/// Options for [`canonicalize_json_value_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct CanonicalOptions {
+    /// Render floats with no fractional part as integers (`1.0` → `1`,
+    /// `-0.0` → `0`) for signers whose JSON libraries can't tell them apart.
+    /// Off by default: numbers keep the form they were parsed with.
+    pub integral_floats_as_integers: bool,
+}
+
+/// Canonical form used for signing, with the default [`CanonicalOptions`].
+pub(crate) fn canonicalize_json_value(value: &Value) -> Value {
+    canonicalize_json_value_with(value, CanonicalOptions::default())
 }
 
-/// Recursively sorts object keys for deterministic signing.
-fn canonicalize_json_value(value: &Value) -> Value {
+/// Canonical form used for signing.
+///
+/// Guarantees, so off-chain signers in any language can reproduce the bytes
+/// `serde_json::to_vec` produces from the result:
+/// - object keys are sorted by their UTF-8 bytes, at every depth;
+/// - array order is preserved, never sorted;
+/// - strings, booleans and nulls are unchanged;
+/// - numbers are unchanged unless `opts` says otherwise.
+///
+/// NaN and Infinity need no handling: `serde_json` refuses to parse them,
+/// so they can't appear in a `Value`. Canonicalizing is idempotent.
+pub(crate) fn canonicalize_json_value_with(value: &Value, opts: CanonicalOptions) -> Value {
     match value {
         Value::Object(map) => {
             let mut keys: Vec<&String> = map.keys().collect();
@@ -205,31 +287,104 @@ fn canonicalize_json_value(value: &Value) -> Value {
             let mut out = Map::new();
             for key in keys {
                 if let Some(v) = map.get(key) {
-                    out.insert(key.clone(), canonicalize_json_value(v));
+                    out.insert(key.clone(), canonicalize_json_value_with(v, opts));
                 }
             }
             Value::Object(out)
         }
-        Value::Array(arr) => Value::Array(arr.iter().map(canonicalize_json_value).collect()),
+        Value::Array(arr) => Value::Array(
+            arr.iter()
+                .map(|v| canonicalize_json_value_with(v, opts))
+                .collect(),
+        ),
+        Value::Number(n) if opts.integral_floats_as_integers && n.is_f64() => {
+            integral_float(n.as_f64().unwrap_or(f64::NAN)).unwrap_or_else(|| value.clone())
+        }
         Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => value.clone(),
     }
 }
 
+/// `f` as an integer `Value` when it is whole and fits in `i64`/`u64`.
+fn integral_float(f: f64) -> Option<Value> {
+    if f.fract() != 0.0 {
+        return None;
+    }
+    if f >= 0.0 && f < u64::MAX as f64 {
+        Some(Value::from(f as u64))
+    } else if f < 0.0 && f >= i64::MIN as f64 {
+        Some(Value::from(f as i64))
+    } else {
+        None
+    }
+}
+
+/// Everything a signer commits to. [`build_signing_message_for`] turns it
+/// into the exact bytes that are signed, so verifiers and off-chain signers
+/// share one definition.
+pub(crate) struct SigningRequest<'a> {
+    pub domain_prefix: &'a str,
+    /// Contract that verifies the signature.
+    pub contract_id: &'a str,
+    pub target_account: &'a str,
+    /// `ed25519:<base58>` form.
+    pub public_key: &'a str,
+    pub nonce: u64,
+    /// Deadline in block-timestamp millis; `0` never expires.
+    pub expires_at_ms: u64,
+    /// Signed only when present, so height-less requests keep their bytes.
+    pub block_height: Option<u64>,
+    /// Canonicalized before signing. Use an array for multi-action requests.
+    pub action: &'a Value,
+}
+
+/// Exact bytes of a signed request.
+pub(crate) struct SigningMessage {
+    pub message: Vec<u8>,
+}
+
+impl SigningMessage {
+    /// SHA-256 of the message; this is what the ed25519 signature covers.
+    pub fn hash(&self) -> [u8; 32] {
+        env::sha256_array(&self.message)
+    }
+}
+
+/// `"{domain_prefix}:{contract_id}" 0x00 json(payload)`, where the payload
+/// keeps its fixed field order and only `action` is canonicalized.
+pub(crate) fn build_signing_message_for(req: &SigningRequest<'_>) -> SigningMessage {
+    let payload = build_signing_payload(
+        req.target_account,
+        req.public_key,
+        req.nonce,
+        req.expires_at_ms,
+        req.block_height,
+        req.action,
+    );
+    SigningMessage {
+        message: build_signing_message(req.domain_prefix, req.contract_id, &payload),
+    }
+}
+
 fn build_signing_payload(
     target_account: &str,
     public_key_str: &str,
     nonce: u64,
     expires_at_ms: u64,
+    block_height: Option<u64>,
     action: &Value,
 ) -> Value {
-    json!({
+    let mut payload = json!({
         "target_account": target_account,
         "public_key": public_key_str,
         "nonce": nonce.to_string(),
         "expires_at_ms": expires_at_ms.to_string(),
         "action": canonicalize_json_value(action),
         "delegate_action": Option::<Value>::None,
-    })
+    });
+    if let Some(height) = block_height {
+        payload["block_height"] = Value::String(height.to_string());
+    }
+    payload
 }
 
 fn build_signing_message(domain_prefix: &str, contract_id: &str, payload: &Value) -> Vec<u8> {
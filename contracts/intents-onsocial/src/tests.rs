@@ -149,6 +149,7 @@ fn claim_with_unknown_oracle_key_rejected() {
         public_key: "ed25519:11111111111111111111111111111111".parse().unwrap(),
         nonce: JU64(1),
         expires_at_ms: JU64(0),
+        block_height: None,
         signature: Base64VecU8(vec![0u8; 64]),
     };
     let err = c
@@ -365,3 +366,351 @@ fn cancel_ft_offer_paths_through_ft_branch() {
     assert_eq!(o.status, OfferStatus::Cancelled);
     assert_eq!(c.get_ft_escrow_locked(usdc()).0, 0);
 }
+
+// --- Signing canonicalization ---
+
+use oracle::{CanonicalOptions, canonicalize_json_value, canonicalize_json_value_with};
+use serde_json::Value;
+
+/// xorshift64; deterministic so failures reproduce.
+fn next_rand(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+fn arbitrary_value(rng: &mut u64, depth: u32) -> Value {
+    let pick = if depth == 0 {
+        next_rand(rng) % 5
+    } else {
+        next_rand(rng) % 7
+    };
+    match pick {
+        0 => Value::Null,
+        1 => Value::Bool(next_rand(rng).is_multiple_of(2)),
+        2 => serde_json::json!(next_rand(rng) % 1_000),
+        3 => serde_json::json!((next_rand(rng) % 1_000) as f64 / 4.0),
+        4 => Value::String(format!("s{}", next_rand(rng) % 100)),
+        5 => Value::Array(
+            (0..next_rand(rng) % 4)
+                .map(|_| arbitrary_value(rng, depth - 1))
+                .collect(),
+        ),
+        _ => Value::Object(
+            (0..next_rand(rng) % 5)
+                .map(|_| {
+                    (
+                        format!("k{}", next_rand(rng) % 10),
+                        arbitrary_value(rng, depth - 1),
+                    )
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Serializes `value` with object keys emitted in a pseudo-random order.
+fn shuffled_json(value: &Value, rng: &mut u64) -> String {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            for i in (1..entries.len()).rev() {
+                entries.swap(i, (next_rand(rng) % (i as u64 + 1)) as usize);
+            }
+            let fields: Vec<String> = entries
+                .into_iter()
+                .map(|(k, v)| format!("{}:{}", Value::from(k.as_str()), shuffled_json(v, rng)))
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(|v| shuffled_json(v, rng)).collect();
+            format!("[{}]", items.join(","))
+        }
+        other => other.to_string(),
+    }
+}
+
+fn canonical_bytes(value: &Value, opts: CanonicalOptions) -> Vec<u8> {
+    serde_json::to_vec(&canonicalize_json_value_with(value, opts)).unwrap()
+}
+
+#[test]
+fn canonicalization_is_idempotent() {
+    let mut rng = 0x9e37_79b9_7f4a_7c15;
+    for opts in [
+        CanonicalOptions::default(),
+        CanonicalOptions {
+            integral_floats_as_integers: true,
+        },
+    ] {
+        for _ in 0..500 {
+            let once = canonicalize_json_value_with(&arbitrary_value(&mut rng, 4), opts);
+            assert_eq!(canonicalize_json_value_with(&once, opts), once);
+        }
+    }
+}
+
+#[test]
+fn key_order_does_not_change_canonical_bytes() {
+    let mut rng = 0x2545_f491_4f6c_dd1d;
+    for _ in 0..500 {
+        let value = arbitrary_value(&mut rng, 4);
+        let reordered: Value = serde_json::from_str(&shuffled_json(&value, &mut rng)).unwrap();
+        assert_eq!(
+            canonical_bytes(&reordered, CanonicalOptions::default()),
+            canonical_bytes(&value, CanonicalOptions::default()),
+        );
+    }
+}
+
+#[test]
+fn array_order_changes_canonical_bytes() {
+    let a: Value = serde_json::from_str(r#"{"b":[1,2],"a":{"y":1,"x":2}}"#).unwrap();
+    let b: Value = serde_json::from_str(r#"{"a":{"x":2,"y":1},"b":[2,1]}"#).unwrap();
+
+    assert_ne!(
+        canonical_bytes(&a, CanonicalOptions::default()),
+        canonical_bytes(&b, CanonicalOptions::default()),
+    );
+    assert_eq!(
+        serde_json::to_string(&canonicalize_json_value(&a)).unwrap(),
+        r#"{"a":{"x":2,"y":1},"b":[1,2]}"#
+    );
+}
+
+#[test]
+fn integral_floats_render_as_integers_only_when_asked() {
+    let value: Value = serde_json::from_str(r#"[1.0, -0.0, 2.5, -3.0, 7, 1e+300]"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&canonicalize_json_value(&value)).unwrap(),
+        "[1.0,-0.0,2.5,-3.0,7,1e+300]"
+    );
+    let normalized = canonicalize_json_value_with(
+        &value,
+        CanonicalOptions {
+            integral_floats_as_integers: true,
+        },
+    );
+    assert_eq!(
+        serde_json::to_string(&normalized).unwrap(),
+        "[1,0,2.5,-3,7,1e+300]"
+    );
+}
+
+// --- Signing message ---
+
+fn sample_signing_request<'a>(action: &'a Value) -> oracle::SigningRequest<'a> {
+    oracle::SigningRequest {
+        domain_prefix: "onsocial:oracle:v1",
+        contract_id: "intents.onsocial.near",
+        target_account: "alice.near",
+        public_key: "ed25519:11111111111111111111111111111111",
+        nonce: 7,
+        expires_at_ms: 1_700_000_000_000,
+        block_height: None,
+        action,
+    }
+}
+
+#[test]
+fn signing_message_bytes_are_pinned() {
+    let action = serde_json::json!([
+        { "type": "claim", "offer_id": "1" },
+        { "type": "noop" },
+    ]);
+
+    let signed = oracle::build_signing_message_for(&sample_signing_request(&action));
+
+    let expected = concat!(
+        "onsocial:oracle:v1:intents.onsocial.near\0",
+        r#"{"target_account":"alice.near","#,
+        r#""public_key":"ed25519:11111111111111111111111111111111","#,
+        r#""nonce":"7","expires_at_ms":"1700000000000","#,
+        r#""action":[{"offer_id":"1","type":"claim"},{"type":"noop"}],"#,
+        r#""delegate_action":null}"#,
+    );
+    assert_eq!(String::from_utf8(signed.message.clone()).unwrap(), expected);
+    let hash_hex: String = signed.hash().iter().map(|b| format!("{b:02x}")).collect();
+    assert_eq!(
+        hash_hex,
+        "99f6ec712a4fb1d799be5ac58524f07ed4034289e02c6daa4c174742247e68a3"
+    );
+}
+
+#[test]
+fn signing_message_ignores_action_key_order_but_not_action_order() {
+    let a: Value =
+        serde_json::from_str(r#"[{"type":"claim","offer_id":"1"},{"type":"noop"}]"#).unwrap();
+    let b: Value =
+        serde_json::from_str(r#"[{"offer_id":"1","type":"claim"},{"type":"noop"}]"#).unwrap();
+    let swapped: Value =
+        serde_json::from_str(r#"[{"type":"noop"},{"offer_id":"1","type":"claim"}]"#).unwrap();
+
+    let hash =
+        |action: &Value| oracle::build_signing_message_for(&sample_signing_request(action)).hash();
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(hash(&a), hash(&swapped));
+}
+
+// --- Oracle block-height window ---
+
+fn height_stamped_attestation(block_height: Option<u64>) -> OracleAuth {
+    use near_sdk::json_types::Base64VecU8;
+    OracleAuth {
+        public_key: "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp"
+            .parse()
+            .unwrap(),
+        nonce: U64(1),
+        expires_at_ms: U64(0),
+        block_height: block_height.map(U64),
+        // Never valid: the window is checked before the signature.
+        signature: Base64VecU8(vec![0u8; 64]),
+    }
+}
+
+fn authenticate_at(
+    current_height: u64,
+    att: &OracleAuth,
+    max_block_age: Option<u64>,
+) -> oracle::AuthError {
+    testing_env!(ctx(accounts(0), 0).block_height(current_height).build());
+    let Err(err) = oracle::authenticate_oracle(
+        att,
+        &serde_json::json!({ "method": "claim_offer" }),
+        NONCE_PREFIX,
+        std::slice::from_ref(&att.public_key),
+        DOMAIN_PREFIX,
+        max_block_age,
+    ) else {
+        panic!("zero signature must not verify");
+    };
+    err
+}
+
+#[test]
+fn oracle_attestation_inside_block_window_reaches_signature_check() {
+    let att = height_stamped_attestation(Some(1_000));
+    assert!(matches!(
+        authenticate_at(
+            1_000 + ORACLE_MAX_BLOCK_AGE,
+            &att,
+            Some(ORACLE_MAX_BLOCK_AGE)
+        ),
+        oracle::AuthError::Verify(oracle::VerifyError::SignatureMismatch)
+    ));
+}
+
+#[test]
+fn oracle_attestation_past_block_window_is_stale() {
+    let att = height_stamped_attestation(Some(1_000));
+    assert!(matches!(
+        authenticate_at(
+            1_001 + ORACLE_MAX_BLOCK_AGE,
+            &att,
+            Some(ORACLE_MAX_BLOCK_AGE)
+        ),
+        oracle::AuthError::Verify(oracle::VerifyError::BlockHeightStale)
+    ));
+    assert!(matches!(
+        authenticate_at(1_001 + ORACLE_MAX_BLOCK_AGE, &att, None),
+        oracle::AuthError::Verify(oracle::VerifyError::SignatureMismatch)
+    ));
+}
+
+#[test]
+fn oracle_attestation_without_height_ignores_block_window() {
+    let att = height_stamped_attestation(None);
+    assert!(matches!(
+        authenticate_at(u64::MAX / 2, &att, Some(ORACLE_MAX_BLOCK_AGE)),
+        oracle::AuthError::Verify(oracle::VerifyError::SignatureMismatch)
+    ));
+}
+
+#[test]
+fn signing_message_commits_to_block_height() {
+    let action = serde_json::json!({ "method": "claim_offer" });
+    let unstamped = sample_signing_request(&action);
+    let stamped = oracle::SigningRequest {
+        block_height: Some(1_000),
+        ..sample_signing_request(&action)
+    };
+
+    let message = oracle::build_signing_message_for(&stamped).message;
+    assert!(
+        String::from_utf8(message)
+            .unwrap()
+            .ends_with(r#","block_height":"1000"}"#)
+    );
+    assert_ne!(
+        oracle::build_signing_message_for(&stamped).hash(),
+        oracle::build_signing_message_for(&unstamped).hash()
+    );
+}
+
+// --- Verify failure reasons ---
+
+fn verify_error(current_height: u64, att: &OracleAuth) -> oracle::VerifyError {
+    match authenticate_at(current_height, att, Some(ORACLE_MAX_BLOCK_AGE)) {
+        oracle::AuthError::Verify(e) => e,
+        other => panic!("expected a verify error, got {other:?}"),
+    }
+}
+
+#[test]
+fn verify_errors_name_each_failure() {
+    use near_sdk::json_types::Base64VecU8;
+    use oracle::VerifyError;
+
+    let valid_shape = height_stamped_attestation(Some(1_000));
+    assert_eq!(
+        verify_error(1_000, &valid_shape),
+        VerifyError::SignatureMismatch
+    );
+    assert_eq!(
+        verify_error(999, &valid_shape),
+        VerifyError::BlockHeightAhead
+    );
+
+    let expired = OracleAuth {
+        expires_at_ms: U64(1),
+        ..valid_shape.clone()
+    };
+    assert_eq!(verify_error(1_000, &expired), VerifyError::Expired);
+
+    let short_signature = OracleAuth {
+        signature: Base64VecU8(vec![0u8; 63]),
+        ..valid_shape.clone()
+    };
+    assert_eq!(
+        verify_error(1_000, &short_signature),
+        VerifyError::InvalidSignatureLength(63)
+    );
+
+    let secp_key = OracleAuth {
+        public_key: "secp256k1:2AXDGYSE4f2sz7tvMMzyHvUfcoJmxudvdhBcmiUSo6ijwfYmfZYsKRxboQMPh3R4kUhXRVdtSXFXMheka4Rc4P2"
+            .parse()
+            .unwrap(),
+        ..valid_shape
+    };
+    assert_eq!(
+        verify_error(1_000, &secp_key),
+        VerifyError::UnsupportedKeyType
+    );
+}
+
+#[test]
+fn ed25519_key_bytes_accept_raw_and_tagged_keys_only() {
+    use oracle::{VerifyError, ed25519_public_key_bytes};
+
+    assert_eq!(ed25519_public_key_bytes(&[7u8; 32]), Ok([7u8; 32]));
+    let mut tagged = vec![0u8];
+    tagged.extend_from_slice(&[7u8; 32]);
+    assert_eq!(ed25519_public_key_bytes(&tagged), Ok([7u8; 32]));
+    assert_eq!(
+        ed25519_public_key_bytes(&[7u8; 31]),
+        Err(VerifyError::InvalidPublicKeyLength(31))
+    );
+}
@@ -1,14 +1,43 @@
-use near_sdk::{env, near, AccountId, Gas, NearToken, Promise};
+use near_sdk::{assert_one_yocto, env, near, AccountId, Gas, NearToken, PanicOnDefault, Promise};
+
+/// Gas forwarded to each `update_config` call.
+const UPDATE_CONFIG_GAS: Gas = Gas::from_tgas(50);
+/// Targets per `update_core_config_many`; keeps the fan-out inside 300 TGas.
+const MAX_CORE_TARGETS: usize = 4;
 
 #[near(contract_state)]
-#[derive(Default)]
-pub struct ManagerProxy {}
+#[derive(PanicOnDefault)]
+pub struct ManagerProxy {
+    /// Only account allowed to proxy config updates.
+    owner: AccountId,
+}
 
 #[near]
 impl ManagerProxy {
+    /// Only the contract account can initialize, so a third party cannot
+    /// claim ownership between deploy and init.
+    #[private]
     #[init]
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(owner_id: AccountId) -> Self {
+        Self { owner: owner_id }
+    }
+
+    /// Re-initializes state written before the proxy had an owner.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate(owner_id: AccountId) -> Self {
+        Self { owner: owner_id }
+    }
+
+    pub fn get_owner(&self) -> AccountId {
+        self.owner.clone()
+    }
+
+    #[payable]
+    pub fn set_owner(&mut self, new_owner: AccountId) {
+        assert_one_yocto();
+        self.assert_owner();
+        self.owner = new_owner;
     }
 
     /// Calls `update_config` on the target core-onsocial contract.
@@ -19,16 +48,167 @@ impl ManagerProxy {
         core_account_id: AccountId,
         update: near_sdk::serde_json::Value,
     ) -> Promise {
-        let args = near_sdk::serde_json::json!({ "update": update });
-        let Ok(args) = near_sdk::serde_json::to_vec(&args) else {
-            env::panic_str("Failed to serialize update_config args");
+        self.assert_owner();
+        update_config_call(core_account_id, &update_config_args(update))
+    }
+
+    /// Calls `update_config` with the same update on each listed core contract.
+    ///
+    /// The calls run in parallel and are not atomic: some targets may apply
+    /// the update while others fail. The joined promise reports every result.
+    pub fn update_core_config_many(
+        &self,
+        core_account_ids: Vec<AccountId>,
+        update: near_sdk::serde_json::Value,
+    ) -> Promise {
+        self.assert_owner();
+        if core_account_ids.len() > MAX_CORE_TARGETS {
+            env::panic_str(&format!(
+                "At most {MAX_CORE_TARGETS} core contracts per call"
+            ));
+        }
+        for (i, id) in core_account_ids.iter().enumerate() {
+            if core_account_ids[..i].contains(id) {
+                env::panic_str(&format!("Duplicate core contract: {id}"));
+            }
+        }
+
+        let args = update_config_args(update);
+        let mut calls = core_account_ids
+            .into_iter()
+            .map(|id| update_config_call(id, &args));
+        let Some(first) = calls.next() else {
+            env::panic_str("core_account_ids must not be empty");
+        };
+        calls.fold(first, Promise::and)
+    }
+}
+
+impl ManagerProxy {
+    fn assert_owner(&self) {
+        if env::predecessor_account_id() != self.owner {
+            env::panic_str("Only the owner can call this method");
+        }
+    }
+}
+
+fn update_config_args(update: near_sdk::serde_json::Value) -> Vec<u8> {
+    let args = near_sdk::serde_json::json!({ "update": update });
+    let Ok(args) = near_sdk::serde_json::to_vec(&args) else {
+        env::panic_str("Failed to serialize update_config args");
+    };
+    args
+}
+
+fn update_config_call(core_account_id: AccountId, args: &[u8]) -> Promise {
+    Promise::new(core_account_id).function_call(
+        "update_config".to_string(),
+        args.to_vec(),
+        NearToken::from_yoctonear(1),
+        UPDATE_CONFIG_GAS,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::serde_json::{json, Value};
+    use near_sdk::test_utils::{accounts, get_created_receipts, VMContextBuilder};
+    use near_sdk::testing_env;
+
+    /// `(receiver, method, args)` of every function call the contract created.
+    /// MockAction is not re-exported, so calls are read through its serde form.
+    fn created_calls() -> Vec<(AccountId, String, Value)> {
+        let bytes = |v: &Value| -> Vec<u8> {
+            v.as_array()
+                .unwrap()
+                .iter()
+                .map(|b| b.as_u64().unwrap() as u8)
+                .collect()
         };
+        get_created_receipts()
+            .into_iter()
+            .flat_map(|r| {
+                let receiver = r.receiver_id.clone();
+                r.actions.into_iter().map(move |a| (receiver.clone(), a))
+            })
+            .filter_map(|(receiver, action)| {
+                let action = near_sdk::serde_json::to_value(action).unwrap();
+                let call = action.get("FunctionCallWeight")?;
+                Some((
+                    receiver,
+                    String::from_utf8(bytes(&call["method_name"])).unwrap(),
+                    near_sdk::serde_json::from_slice(&bytes(&call["args"])).unwrap(),
+                ))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn update_core_config_many_calls_every_target() {
+        testing_env!(context(accounts(0)).build());
+        let proxy = ManagerProxy::new(accounts(0));
+        let targets = vec![accounts(1), accounts(2), accounts(3)];
+
+        let _ = proxy.update_core_config_many(targets.clone(), json!({ "max_batch_size": 150 }));
+
+        let calls = created_calls();
+        let receivers: Vec<AccountId> = calls.iter().map(|(r, _, _)| r.clone()).collect();
+        assert_eq!(receivers, targets);
+        for (_, method, args) in calls {
+            assert_eq!(method, "update_config");
+            assert_eq!(args, json!({ "update": { "max_batch_size": 150 } }));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Duplicate core contract")]
+    fn update_core_config_many_rejects_duplicates() {
+        testing_env!(context(accounts(0)).build());
+        let _ = ManagerProxy::new(accounts(0))
+            .update_core_config_many(vec![accounts(1), accounts(1)], json!({}));
+    }
+
+    #[test]
+    #[should_panic(expected = "At most 4 core contracts")]
+    fn update_core_config_many_caps_targets() {
+        testing_env!(context(accounts(0)).build());
+        let targets = (0..5)
+            .map(|i| format!("core{i}.near").parse().unwrap())
+            .collect();
+        let _ = ManagerProxy::new(accounts(0)).update_core_config_many(targets, json!({}));
+    }
+
+    fn context(predecessor: AccountId) -> VMContextBuilder {
+        let mut builder = VMContextBuilder::new();
+        builder
+            .predecessor_account_id(predecessor)
+            .attached_deposit(NearToken::from_yoctonear(1));
+        builder
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the owner")]
+    fn update_core_config_rejects_non_owner() {
+        testing_env!(context(accounts(0)).build());
+        let proxy = ManagerProxy::new(accounts(0));
+
+        testing_env!(context(accounts(1)).build());
+        let _ = proxy.update_core_config(accounts(2), json!({}));
+    }
+
+    #[test]
+    fn owner_can_update_and_hand_over() {
+        testing_env!(context(accounts(0)).build());
+        let mut proxy = ManagerProxy::new(accounts(0));
+        assert_eq!(proxy.get_owner(), accounts(0));
+        let _ = proxy.update_core_config(accounts(2), json!({ "max_batch_size": 150 }));
+        assert_eq!(created_calls().len(), 1);
+
+        proxy.set_owner(accounts(1));
+        assert_eq!(proxy.get_owner(), accounts(1));
 
-        Promise::new(core_account_id).function_call(
-            "update_config".to_string(),
-            args,
-            NearToken::from_yoctonear(1),
-            Gas::from_tgas(50),
-        )
+        testing_env!(context(accounts(1)).build());
+        let _ = proxy.update_core_config(accounts(2), json!({}));
     }
 }
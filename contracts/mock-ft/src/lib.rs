@@ -5,7 +5,7 @@
 //! - ft_transfer (unlock/claim refunds)
 //! - ft_balance_of (view balance)
 //! - burn (social-spend deflationary routing)
-//! - storage_deposit (required for receivers)
+//! - storage_deposit / storage_unregister (NEP-145 receiver registration)
 
 use near_sdk::json_types::U128;
 use near_sdk::store::LookupMap;
@@ -14,6 +14,7 @@ use near_sdk::{env, near, AccountId, Gas, NearToken, PanicOnDefault, Promise, Pr
 const GAS_FOR_RESOLVE: Gas = Gas::from_tgas(10);
 const GAS_FOR_FT_ON_TRANSFER: Gas = Gas::from_tgas(250);
 const GAS_FOR_NESTED_FT_ON_TRANSFER: Gas = Gas::from_tgas(60);
+const STORAGE_BALANCE_BOUND: u128 = 1_250_000_000_000_000_000_000; // ~0.00125 NEAR
 
 fn gas_for_ft_on_transfer() -> Gas {
     let remaining = env::prepaid_gas()
@@ -32,10 +33,17 @@ pub struct MockFT {
     balances: LookupMap<AccountId, u128>,
     total_supply: u128,
     decimals: u8,
-    /// Test helper: if set, the next ft_transfer will fail
-    fail_next_transfer: bool,
-    /// Tracks registered accounts (NEP-145 mock)
-    registered: LookupMap<AccountId, bool>,
+    /// Test helper: number of upcoming ft_transfer / ft_transfer_call calls that will fail
+    fail_transfers_remaining: u32,
+    /// Test helper: custom panic message for simulated failures
+    fail_message: Option<String>,
+    /// Registered accounts and the storage deposit each paid (NEP-145 mock)
+    registered: LookupMap<AccountId, u128>,
+    /// Test helper: if set, transfers to unregistered receivers are allowed
+    allow_unregistered_receivers: bool,
+    /// Test helper: if set, the next ft_transfer_call skips ft_on_transfer and
+    /// resolves as if the receiver returned this many unused tokens
+    next_on_transfer_return: Option<U128>,
 }
 
 #[near(serializers = [json])]
@@ -53,13 +61,16 @@ impl MockFT {
         let mut balances = LookupMap::new(b"b");
         balances.insert(owner_id.clone(), total_supply.0);
         let mut registered = LookupMap::new(b"r");
-        registered.insert(owner_id, true);
+        registered.insert(owner_id, 0);
         Self {
             balances,
             total_supply: total_supply.0,
             decimals,
-            fail_next_transfer: false,
+            fail_transfers_remaining: 0,
+            fail_message: None,
             registered,
+            allow_unregistered_receivers: false,
+            next_on_transfer_return: None,
         }
     }
 
@@ -68,28 +79,26 @@ impl MockFT {
     // =========================================================================
 
     #[payable]
-    pub fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>) {
+    pub fn ft_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        amount: U128,
+        memo: Option<String>,
+    ) -> PromiseOrValue<()> {
         assert_eq!(
             env::attached_deposit(),
             NearToken::from_yoctonear(1),
             "Requires 1 yoctoNEAR"
         );
 
-        // Test helper: fail if flag is set
-        if self.fail_next_transfer {
-            self.fail_next_transfer = false;
-            env::panic_str("MockFT: Simulated transfer failure");
+        // Test helper: fail if configured
+        if let Some(failure) = self.take_simulated_failure() {
+            return PromiseOrValue::Promise(failure);
         }
 
-        // Check receiver is registered (mirrors real NEP-141 behavior)
-        assert!(
-            self.registered.contains_key(&receiver_id),
-            "Receiver {} is not registered",
-            receiver_id
-        );
-
         let sender_id = env::predecessor_account_id();
         self.internal_transfer(&sender_id, &receiver_id, amount.0, memo);
+        PromiseOrValue::Value(())
     }
 
     #[payable]
@@ -105,9 +114,21 @@ impl MockFT {
             NearToken::from_yoctonear(1),
             "Requires 1 yoctoNEAR"
         );
+
+        // Test helper: fail if configured
+        if let Some(failure) = self.take_simulated_failure() {
+            return PromiseOrValue::Promise(failure);
+        }
+
         let sender_id = env::predecessor_account_id();
         self.internal_transfer(&sender_id, &receiver_id, amount.0, memo);
 
+        // Test helper: resolve locally with a preset unused amount
+        if let Some(unused) = self.next_on_transfer_return.take() {
+            let used = self.internal_resolve_transfer(&sender_id, receiver_id, amount.0, unused.0);
+            return PromiseOrValue::Value(U128(used));
+        }
+
         // Call ft_on_transfer on receiver
         Promise::new(receiver_id.clone())
             .function_call(
@@ -183,12 +204,12 @@ impl MockFT {
                     .transfer(NearToken::from_yoctonear(deposit));
             }
         } else {
-            // Register the account
-            self.registered.insert(account_id, true);
+            // Register the account, remembering the deposit for storage_unregister refunds
+            self.registered.insert(account_id, deposit);
         }
 
         StorageBalance {
-            total: U128(1250000000000000000000),
+            total: U128(STORAGE_BALANCE_BOUND),
             available: U128(0),
         }
     }
@@ -196,7 +217,7 @@ impl MockFT {
     pub fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
         if self.registered.contains_key(&account_id) {
             Some(StorageBalance {
-                total: U128(1250000000000000000000), // ~0.00125 NEAR
+                total: U128(STORAGE_BALANCE_BOUND),
                 available: U128(0),
             })
         } else {
@@ -204,6 +225,38 @@ impl MockFT {
         }
     }
 
+    /// Unregisters the caller and refunds its storage deposit. A non-zero token
+    /// balance panics unless `force` is set, in which case the balance is burned.
+    #[payable]
+    pub fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+        assert_eq!(
+            env::attached_deposit(),
+            NearToken::from_yoctonear(1),
+            "Requires 1 yoctoNEAR"
+        );
+        let account_id = env::predecessor_account_id();
+        let Some(deposit) = self.registered.remove(&account_id) else {
+            return false;
+        };
+
+        let balance = self.balances.remove(&account_id).unwrap_or(0);
+        if balance > 0 {
+            assert!(
+                force.unwrap_or(false),
+                "Can't unregister the account with the positive balance without force"
+            );
+            self.total_supply = self.total_supply.saturating_sub(balance);
+        }
+        if deposit > 0 {
+            let _ = Promise::new(account_id).transfer(NearToken::from_yoctonear(deposit));
+        }
+        true
+    }
+
+    pub fn is_registered(&self, account_id: AccountId) -> bool {
+        self.registered.contains_key(&account_id)
+    }
+
     // =========================================================================
     // Test Helpers (not in real FT)
     // =========================================================================
@@ -213,17 +266,48 @@ impl MockFT {
         let current = self.balances.get(&account_id).copied().unwrap_or(0);
         self.balances.insert(account_id.clone(), current + amount.0);
         self.total_supply += amount.0;
-        self.registered.insert(account_id, true);
+        if !self.registered.contains_key(&account_id) {
+            self.registered.insert(account_id, 0);
+        }
+    }
+
+    /// Fail the next `count` (default 1) ft_transfer / ft_transfer_call calls, optionally
+    /// with a custom panic message (for testing callbacks and flaky-token recovery)
+    pub fn set_fail_next_transfer(
+        &mut self,
+        should_fail: bool,
+        count: Option<u32>,
+        message: Option<String>,
+    ) {
+        self.fail_transfers_remaining = if should_fail { count.unwrap_or(1) } else { 0 };
+        self.fail_message = if should_fail { message } else { None };
     }
 
-    /// Set flag to fail the next ft_transfer call (for testing callbacks)
-    pub fn set_fail_next_transfer(&mut self, should_fail: bool) {
-        self.fail_next_transfer = should_fail;
+    /// Allow transfers to unregistered receivers (skips the NEP-145 registration check)
+    pub fn set_allow_unregistered_receivers(&mut self, allow: bool) {
+        self.allow_unregistered_receivers = allow;
+    }
+
+    /// Make the next ft_transfer_call resolve as if the receiver returned `unused`
+    /// (None clears). Lets tests cover partial consumption without a receiver contract.
+    pub fn set_next_on_transfer_return(&mut self, unused: Option<U128>) {
+        self.next_on_transfer_return = unused;
     }
 
     /// Check if fail flag is set (for debugging)
     pub fn get_fail_next_transfer(&self) -> bool {
-        self.fail_next_transfer
+        self.fail_transfers_remaining > 0
+    }
+
+    /// Number of upcoming transfers that will still fail
+    pub fn get_fail_transfers_remaining(&self) -> u32 {
+        self.fail_transfers_remaining
+    }
+
+    /// Panics with the configured message; scheduled by a simulated transfer failure
+    #[private]
+    pub fn simulate_transfer_failure(&self, message: String) {
+        env::panic_str(&message);
     }
 
     /// Mock wNEAR `near_withdraw` — accepts 1 yoctoNEAR, does nothing.
@@ -240,6 +324,20 @@ impl MockFT {
     // Internal
     // =========================================================================
 
+    /// Consumes one simulated failure. The panic happens in a follow-up receipt so the
+    /// counter decrement persists; callers observe the transfer promise as failed.
+    fn take_simulated_failure(&mut self) -> Option<Promise> {
+        if self.fail_transfers_remaining == 0 {
+            return None;
+        }
+        self.fail_transfers_remaining -= 1;
+        let message = self
+            .fail_message
+            .clone()
+            .unwrap_or_else(|| "MockFT: Simulated transfer failure".to_string());
+        Some(Self::ext(env::current_account_id()).simulate_transfer_failure(message))
+    }
+
     fn internal_transfer(
         &mut self,
         sender_id: &AccountId,
@@ -247,6 +345,12 @@ impl MockFT {
         amount: u128,
         _memo: Option<String>,
     ) {
+        // Check receiver is registered (mirrors real NEP-141 behavior)
+        assert!(
+            self.allow_unregistered_receivers || self.registered.contains_key(receiver_id),
+            "Receiver {} is not registered",
+            receiver_id
+        );
         let sender_balance = self.balances.get(sender_id).copied().unwrap_or(0);
         assert!(sender_balance >= amount, "Insufficient balance");
 
@@ -279,18 +383,31 @@ impl MockFT {
             _ => amount.0,
         };
 
+        U128(self.internal_resolve_transfer(&sender_id, receiver_id, amount.0, unused))
+    }
+
+    /// Refunds `unused` (capped at `amount`) from receiver to sender; returns the used amount.
+    fn internal_resolve_transfer(
+        &mut self,
+        sender_id: &AccountId,
+        receiver_id: AccountId,
+        amount: u128,
+        unused: u128,
+    ) -> u128 {
+        let unused = std::cmp::min(unused, amount);
         if unused > 0 {
             // Refund unused tokens
             let receiver_balance = self.balances.get(&receiver_id).copied().unwrap_or(0);
             let refund = std::cmp::min(unused, receiver_balance);
             if refund > 0 {
                 self.balances.insert(receiver_id, receiver_balance - refund);
-                let sender_balance = self.balances.get(&sender_id).copied().unwrap_or(0);
-                self.balances.insert(sender_id, sender_balance + refund);
+                let sender_balance = self.balances.get(sender_id).copied().unwrap_or(0);
+                self.balances
+                    .insert(sender_id.clone(), sender_balance + refund);
             }
         }
 
-        U128(amount.0 - unused)
+        amount - unused
     }
 }
 
@@ -7,6 +7,7 @@ pub struct ContractInfo {
     pub fee_recipient: AccountId,
     pub fee_config: FeeConfig,
     pub approved_nft_contracts: Vec<AccountId>,
+    pub accepted_payment_tokens: Vec<AccountId>,
     pub wnear_account_id: Option<AccountId>,
     pub platform_storage_balance: U128,
 }
@@ -59,7 +60,18 @@ impl Contract {
             lazy_listings: IterableMap::new(StorageKey::LazyListings),
             contract_metadata,
             approved_nft_contracts: IterableSet::new(StorageKey::ApprovedNftContracts),
+            mint_refund_escrows: LookupMap::new(StorageKey::MintRefundEscrows),
+            known_marketplaces: IterableMap::new(StorageKey::KnownMarketplaces),
+            accepted_payment_tokens: IterableSet::new(StorageKey::AcceptedPaymentTokens),
+            price_bounds: LookupMap::new(StorageKey::PriceBounds),
+            creator_earnings: LookupMap::new(StorageKey::CreatorEarnings),
+            ban_appeals: LookupMap::new(StorageKey::BanAppeals),
+            collection_last_mint: LookupMap::new(StorageKey::CollectionLastMint),
             wnear_account_id: None,
+            ft_claimable: LookupMap::new(StorageKey::FtClaimable),
+            token_provenance: LookupMap::new(StorageKey::TokenProvenance),
+            auction_token_ids: IterableSet::new(StorageKey::AuctionTokenIds),
+            collection_offer_buyers: LookupMap::new(StorageKey::CollectionOfferBuyers),
             pending_attached_balance: 0,
         }
     }
@@ -174,6 +186,117 @@ impl Contract {
         events::emit_approved_nft_contract_removed(&self.owner_id, &nft_contract_id);
         Ok(())
     }
+    #[payable]
+    #[handle_result]
+    pub fn add_accepted_payment_token(
+        &mut self,
+        token_contract_id: AccountId,
+    ) -> Result<(), MarketplaceError> {
+        crate::guards::check_one_yocto()?;
+        self.check_contract_owner(&env::predecessor_account_id())?;
+        self.accepted_payment_tokens
+            .insert(token_contract_id.clone());
+        events::emit_payment_token_added(&self.owner_id, &token_contract_id);
+        Ok(())
+    }
+
+    /// Existing listings priced in the token stay up but cannot be bought until it is re-added.
+    #[payable]
+    #[handle_result]
+    pub fn remove_accepted_payment_token(
+        &mut self,
+        token_contract_id: AccountId,
+    ) -> Result<(), MarketplaceError> {
+        crate::guards::check_one_yocto()?;
+        self.check_contract_owner(&env::predecessor_account_id())?;
+        self.accepted_payment_tokens.remove(&token_contract_id);
+        events::emit_payment_token_removed(&self.owner_id, &token_contract_id);
+        Ok(())
+    }
+
+    /// Registers a marketplace contract and whether it honours NEP-199 payouts.
+    #[payable]
+    #[handle_result]
+    pub fn set_known_marketplace(
+        &mut self,
+        marketplace_id: AccountId,
+        respects_royalties: bool,
+    ) -> Result<(), MarketplaceError> {
+        crate::guards::check_one_yocto()?;
+        self.check_contract_owner(&env::predecessor_account_id())?;
+        self.known_marketplaces
+            .insert(marketplace_id.clone(), respects_royalties);
+        events::emit_known_marketplace_set(&self.owner_id, &marketplace_id, respects_royalties);
+        Ok(())
+    }
+
+    #[payable]
+    #[handle_result]
+    pub fn remove_known_marketplace(
+        &mut self,
+        marketplace_id: AccountId,
+    ) -> Result<(), MarketplaceError> {
+        crate::guards::check_one_yocto()?;
+        self.check_contract_owner(&env::predecessor_account_id())?;
+        self.known_marketplaces.remove(&marketplace_id);
+        events::emit_known_marketplace_removed(&self.owner_id, &marketplace_id);
+        Ok(())
+    }
+
+    pub fn get_known_marketplaces(&self) -> Vec<(AccountId, bool)> {
+        self.known_marketplaces
+            .iter()
+            .map(|(id, respects)| (id.clone(), *respects))
+            .collect()
+    }
+
+    /// Bounds apply to new fixed-price listings and price updates; native
+    /// tokens use this contract's own account id.
+    #[payable]
+    #[handle_result]
+    pub fn set_price_bounds(
+        &mut self,
+        nft_contract_id: AccountId,
+        min: Option<U128>,
+        max: Option<U128>,
+    ) -> Result<(), MarketplaceError> {
+        crate::guards::check_one_yocto()?;
+        self.check_contract_owner(&env::predecessor_account_id())?;
+        if min.is_none() && max.is_none() {
+            return Err(MarketplaceError::InvalidInput(
+                "Set min, max, or both — use clear_price_bounds to remove".into(),
+            ));
+        }
+        if let (Some(lo), Some(hi)) = (min, max)
+            && lo.0 > hi.0
+        {
+            return Err(MarketplaceError::InvalidInput(
+                "min must not exceed max".into(),
+            ));
+        }
+        self.price_bounds
+            .insert(nft_contract_id.clone(), PriceBounds { min, max });
+        events::emit_price_bounds_set(&self.owner_id, &nft_contract_id, min, max);
+        Ok(())
+    }
+
+    #[payable]
+    #[handle_result]
+    pub fn clear_price_bounds(
+        &mut self,
+        nft_contract_id: AccountId,
+    ) -> Result<(), MarketplaceError> {
+        crate::guards::check_one_yocto()?;
+        self.check_contract_owner(&env::predecessor_account_id())?;
+        self.price_bounds.remove(&nft_contract_id);
+        events::emit_price_bounds_cleared(&self.owner_id, &nft_contract_id);
+        Ok(())
+    }
+
+    pub fn get_price_bounds(&self, nft_contract_id: AccountId) -> Option<PriceBounds> {
+        self.price_bounds.get(&nft_contract_id).cloned()
+    }
+
     #[payable]
     #[handle_result]
     pub fn update_fee_config(&mut self, update: FeeConfigUpdate) -> Result<(), MarketplaceError> {
@@ -215,6 +338,7 @@ impl Contract {
             fee_recipient: self.fee_recipient.clone(),
             fee_config: self.fee_config.clone(),
             approved_nft_contracts: self.approved_nft_contracts.iter().cloned().collect(),
+            accepted_payment_tokens: self.accepted_payment_tokens.iter().cloned().collect(),
             wnear_account_id: self.wnear_account_id.clone(),
             platform_storage_balance: U128(self.platform_storage_balance),
         }
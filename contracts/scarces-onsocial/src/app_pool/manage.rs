@@ -44,7 +44,19 @@ impl Contract {
                 "Insufficient pool balance".to_string(),
             ));
         }
+        let now = crate::time::now_ms();
+        if let Some(remaining) = pool
+            .remaining_spending_cap(now)
+            .filter(|remaining| amount.0 > *remaining)
+        {
+            self.app_pools.insert(app_id.clone(), pool);
+            return Err(MarketplaceError::InvalidState(format!(
+                "Exceeds daily spending cap: {} remaining",
+                remaining
+            )));
+        }
 
+        pool.record_spend(amount.0, now);
         pool.balance.0 -= amount.0;
         let new_balance = pool.balance.0;
         self.app_pools.insert(app_id.clone(), pool);
@@ -85,6 +97,8 @@ impl Contract {
             primary_sale_bps,
             curated,
             metadata,
+            daily_spending_cap,
+            sponsor_buyer_storage,
         } = params;
 
         if let Some(ref r) = default_royalty {
@@ -112,6 +126,10 @@ impl Contract {
             moderators: Vec::new(),
             curated: curated.unwrap_or(false),
             metadata,
+            daily_spending_cap: daily_spending_cap.filter(|c| c.0 > 0),
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: sponsor_buyer_storage.unwrap_or(true),
         };
 
         self.app_pools.insert(app_id.clone(), pool);
@@ -142,6 +160,8 @@ impl Contract {
             primary_sale_bps,
             curated,
             metadata,
+            daily_spending_cap,
+            sponsor_buyer_storage,
         } = params;
 
         if let Some(max) = max_user_bytes {
@@ -166,6 +186,14 @@ impl Contract {
             pool.curated = c;
         }
 
+        if let Some(cap) = daily_spending_cap {
+            pool.daily_spending_cap = (cap.0 > 0).then_some(cap);
+        }
+
+        if let Some(sponsor) = sponsor_buyer_storage {
+            pool.sponsor_buyer_storage = sponsor;
+        }
+
         // State transition invariant: None preserves metadata; Some("") clears persisted metadata.
         if let Some(m) = metadata {
             if m.is_empty() {
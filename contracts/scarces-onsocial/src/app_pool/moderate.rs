@@ -60,6 +60,12 @@ impl Contract {
         Ok(())
     }
 
+    // Authorization invariant: app moderators are scoped to their own app's pool; only the
+    // platform owner moderates across apps. Collection/app membership is checked separately.
+    fn is_moderation_authority(&self, pool: &AppPool, actor_id: &AccountId) -> bool {
+        actor_id == &self.owner_id || Self::is_app_authority(pool, actor_id)
+    }
+
     pub(crate) fn ban_collection(
         &mut self,
         actor_id: &AccountId,
@@ -71,9 +77,9 @@ impl Contract {
             .app_pools
             .get(app_id)
             .ok_or_else(|| MarketplaceError::NotFound(format!("App pool not found: {}", app_id)))?;
-        if !Self::is_app_authority(pool, actor_id) {
+        if !self.is_moderation_authority(pool, actor_id) {
             return Err(MarketplaceError::Unauthorized(
-                "Only app owner or moderator can ban collections".to_string(),
+                "Only app owner, app moderator, or platform owner can ban collections".to_string(),
             ));
         }
 
@@ -113,9 +119,10 @@ impl Contract {
             .app_pools
             .get(app_id)
             .ok_or_else(|| MarketplaceError::NotFound(format!("App pool not found: {}", app_id)))?;
-        if !Self::is_app_authority(pool, actor_id) {
+        if !self.is_moderation_authority(pool, actor_id) {
             return Err(MarketplaceError::Unauthorized(
-                "Only app owner or moderator can unban collections".to_string(),
+                "Only app owner, app moderator, or platform owner can unban collections"
+                    .to_string(),
             ));
         }
 
@@ -144,4 +151,145 @@ impl Contract {
         events::emit_collection_unbanned(actor_id, collection_id);
         Ok(())
     }
+
+    pub(crate) fn submit_ban_appeal(
+        &mut self,
+        actor_id: &AccountId,
+        collection_id: &str,
+        reason: String,
+    ) -> Result<(), MarketplaceError> {
+        Self::validate_appeal_text("Appeal reason", &reason)?;
+        let collection = self
+            .collections
+            .get(collection_id)
+            .ok_or_else(|| MarketplaceError::NotFound("Collection not found".into()))?;
+        if !collection.can_manage(actor_id) {
+            return Err(MarketplaceError::Unauthorized(
+                "Only the collection creator can appeal a ban".into(),
+            ));
+        }
+        if !collection.banned {
+            return Err(MarketplaceError::InvalidState(
+                "Collection is not banned".into(),
+            ));
+        }
+        let app_id = collection.app_id.clone().ok_or_else(|| {
+            MarketplaceError::InvalidState("Collection does not belong to an app".into())
+        })?;
+        if self
+            .ban_appeals
+            .get(collection_id)
+            .is_some_and(|a| a.status == AppealStatus::Open)
+        {
+            return Err(MarketplaceError::InvalidState(
+                "An appeal is already open for this collection".into(),
+            ));
+        }
+
+        let previous = self.ban_appeals.get(collection_id).cloned();
+        let before = self.storage_usage_flushed();
+        self.ban_appeals.insert(
+            collection_id.to_string(),
+            BanAppeal {
+                app_id: app_id.clone(),
+                creator_id: actor_id.clone(),
+                reason,
+                submitted_at: env::block_timestamp(),
+                status: AppealStatus::Open,
+                resolved_by: None,
+                resolved_at: None,
+                note: None,
+            },
+        );
+        let bytes_used = self.storage_usage_flushed().saturating_sub(before);
+        // Storage/accounting invariant: restore the prior record if the storage charge fails.
+        if let Err(e) = self.charge_storage_waterfall(actor_id, bytes_used, None) {
+            match previous {
+                Some(appeal) => self.ban_appeals.insert(collection_id.to_string(), appeal),
+                None => self.ban_appeals.remove(collection_id),
+            };
+            return Err(e);
+        }
+
+        events::emit_ban_appeal_submitted(actor_id, &app_id, collection_id);
+        Ok(())
+    }
+
+    // State transition invariant: overturning unbans the collection; upholding only closes the appeal.
+    pub(crate) fn resolve_ban_appeal(
+        &mut self,
+        actor_id: &AccountId,
+        collection_id: &str,
+        uphold: bool,
+        note: Option<String>,
+    ) -> Result<(), MarketplaceError> {
+        if let Some(ref n) = note {
+            Self::validate_appeal_text("Appeal note", n)?;
+        }
+        let mut appeal = self
+            .ban_appeals
+            .get(collection_id)
+            .ok_or_else(|| MarketplaceError::NotFound("Ban appeal not found".into()))?
+            .clone();
+        if appeal.status != AppealStatus::Open {
+            return Err(MarketplaceError::InvalidState(
+                "Ban appeal is already resolved".into(),
+            ));
+        }
+        let pool = self.app_pools.get(&appeal.app_id).ok_or_else(|| {
+            MarketplaceError::NotFound(format!("App pool not found: {}", appeal.app_id))
+        })?;
+        if !self.is_moderation_authority(pool, actor_id) {
+            return Err(MarketplaceError::Unauthorized(
+                "Only app owner, app moderator, or platform owner can resolve appeals".to_string(),
+            ));
+        }
+
+        let open_appeal = appeal.clone();
+        appeal.status = if uphold {
+            AppealStatus::Upheld
+        } else {
+            AppealStatus::Overturned
+        };
+        appeal.resolved_by = Some(actor_id.clone());
+        appeal.resolved_at = Some(env::block_timestamp());
+        appeal.note = note;
+
+        let before = self.storage_usage_flushed();
+        let note_for_event = appeal.note.clone();
+        self.ban_appeals.insert(collection_id.to_string(), appeal);
+        let bytes_used = self.storage_usage_flushed().saturating_sub(before);
+        if let Err(e) = self.charge_storage_waterfall(actor_id, bytes_used, None) {
+            self.ban_appeals
+                .insert(collection_id.to_string(), open_appeal);
+            return Err(e);
+        }
+
+        if !uphold
+            && self
+                .collections
+                .get(collection_id)
+                .is_some_and(|c| c.banned)
+        {
+            self.set_collection_banned(collection_id, false);
+            events::emit_collection_unbanned(actor_id, collection_id);
+        }
+        events::emit_ban_appeal_resolved(
+            actor_id,
+            collection_id,
+            uphold,
+            note_for_event.as_deref(),
+        );
+        Ok(())
+    }
+
+    fn validate_appeal_text(field: &str, text: &str) -> Result<(), MarketplaceError> {
+        if text.is_empty() || text.len() > MAX_APPEAL_TEXT_LEN {
+            return Err(MarketplaceError::InvalidInput(format!(
+                "{} must be 1-{} bytes",
+                field, MAX_APPEAL_TEXT_LEN
+            )));
+        }
+        Ok(())
+    }
 }
@@ -1,9 +1,15 @@
-use near_sdk::AccountId;
+use near_sdk::borsh::io::{Read, Result};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 use near_sdk::json_types::U128;
-use near_sdk::near;
+use near_sdk::{AccountId, near};
 
-#[near(serializers = [borsh, json])]
-#[derive(Clone)]
+use crate::storage::legacy::read_appended;
+
+// Upgrade invariant: fields are only appended; pools registered before a
+// trailing field existed still decode (see the `BorshDeserialize` impl).
+#[near(serializers = [json])]
+#[derive(Clone, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
 pub struct AppPool {
     pub owner_id: AccountId,
     pub balance: U128,
@@ -17,6 +23,82 @@ pub struct AppPool {
     pub curated: bool,
     #[serde(default)]
     pub metadata: Option<String>,
+    // Spending invariant: pool-funded storage plus withdrawals, net of storage released back, stay within the cap per window.
+    #[serde(default)]
+    pub daily_spending_cap: Option<U128>,
+    #[serde(default)]
+    pub spent_in_window: U128,
+    #[serde(default)]
+    pub spending_window_start_ms: u64,
+    // Storage invariant: when false, buyers pay their own storage on this app's primary sales.
+    #[serde(default = "crate::default_true")]
+    pub sponsor_buyer_storage: bool,
+}
+
+/// `AppPool` as written before spending caps and buyer-storage opt-out.
+#[derive(BorshDeserialize)]
+#[borsh(crate = "near_sdk::borsh")]
+struct AppPoolV1 {
+    owner_id: AccountId,
+    balance: U128,
+    used_bytes: u64,
+    max_user_bytes: u64,
+    default_royalty: Option<std::collections::HashMap<AccountId, u32>>,
+    primary_sale_bps: u16,
+    moderators: Vec<AccountId>,
+    curated: bool,
+    metadata: Option<String>,
+}
+
+impl BorshDeserialize for AppPool {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let v1 = AppPoolV1::deserialize_reader(reader)?;
+        Ok(Self {
+            owner_id: v1.owner_id,
+            balance: v1.balance,
+            used_bytes: v1.used_bytes,
+            max_user_bytes: v1.max_user_bytes,
+            default_royalty: v1.default_royalty,
+            primary_sale_bps: v1.primary_sale_bps,
+            moderators: v1.moderators,
+            curated: v1.curated,
+            metadata: v1.metadata,
+            daily_spending_cap: read_appended(reader, None)?,
+            spent_in_window: read_appended(reader, U128(0))?,
+            spending_window_start_ms: read_appended(reader, 0)?,
+            sponsor_buyer_storage: read_appended(reader, true)?,
+        })
+    }
+}
+
+impl AppPool {
+    /// Remaining allowance in the current window; `None` when uncapped.
+    pub fn remaining_spending_cap(&self, now_ms: u64) -> Option<u128> {
+        let cap = self.daily_spending_cap?.0;
+        Some(cap.saturating_sub(self.window_spent(now_ms)))
+    }
+
+    fn window_spent(&self, now_ms: u64) -> u128 {
+        if now_ms.saturating_sub(self.spending_window_start_ms) >= crate::SPENDING_WINDOW_MS {
+            0
+        } else {
+            self.spent_in_window.0
+        }
+    }
+
+    /// Lazily opens a new window, then counts `amount` against it.
+    pub(crate) fn record_spend(&mut self, amount: u128, now_ms: u64) {
+        if now_ms.saturating_sub(self.spending_window_start_ms) >= crate::SPENDING_WINDOW_MS {
+            self.spending_window_start_ms = now_ms;
+            self.spent_in_window = U128(0);
+        }
+        self.spent_in_window.0 = self.spent_in_window.0.saturating_add(amount);
+    }
+
+    pub(crate) fn reverse_spend(&mut self, amount: u128, now_ms: u64) {
+        let spent = self.window_spent(now_ms).saturating_sub(amount);
+        self.spent_in_window = U128(spent);
+    }
 }
 
 #[near(serializers = [json])]
@@ -27,4 +109,32 @@ pub struct AppConfig {
     pub primary_sale_bps: Option<u16>,
     pub curated: Option<bool>,
     pub metadata: Option<String>,
+    /// `Some(0)` removes the cap.
+    #[serde(default)]
+    pub daily_spending_cap: Option<U128>,
+    #[serde(default)]
+    pub sponsor_buyer_storage: Option<bool>,
+}
+
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AppealStatus {
+    Open,
+    Upheld,
+    Overturned,
+}
+
+/// Creator appeal against a collection ban, resolved by the collection's app moderators.
+#[near(serializers = [borsh, json])]
+#[derive(Clone)]
+pub struct BanAppeal {
+    pub app_id: AccountId,
+    pub creator_id: AccountId,
+    pub reason: String,
+    pub submitted_at: u64,
+    pub status: AppealStatus,
+    pub resolved_by: Option<AccountId>,
+    pub resolved_at: Option<u64>,
+    pub note: Option<String>,
 }
@@ -2,10 +2,22 @@ use crate::*;
 
 #[near]
 impl Contract {
+    /// `None` when the app is unknown or has no daily spending cap.
+    pub fn get_remaining_spending_cap(&self, app_id: AccountId) -> Option<U128> {
+        self.app_pools
+            .get(&app_id)?
+            .remaining_spending_cap(crate::time::now_ms())
+            .map(U128)
+    }
+
     pub fn get_app_pool(&self, app_id: AccountId) -> Option<AppPool> {
         self.app_pools.get(&app_id).cloned()
     }
 
+    pub fn get_ban_appeal(&self, collection_id: String) -> Option<BanAppeal> {
+        self.ban_appeals.get(&collection_id).cloned()
+    }
+
     pub fn get_app_user_usage(&self, account_id: AccountId, app_id: AccountId) -> u64 {
         let key = format!("{}:{}", account_id, app_id);
         self.app_user_usage.get(&key).copied().unwrap_or(0)
@@ -50,9 +62,12 @@ impl Contract {
             .collect()
     }
 
-    /// Resolution order: collection metadata → app metadata → contract base_uri.
+    /// Resolution order: collection base_uri → collection metadata → app metadata → contract base_uri.
     pub fn resolve_base_uri(&self, collection_id: String) -> Option<String> {
         if let Some(collection) = self.collections.get(&collection_id) {
+            if let Some(ref uri) = collection.base_uri {
+                return Some(uri.clone());
+            }
             if let Some(uri) = Self::extract_base_uri(collection.metadata.as_deref()) {
                 return Some(uri);
             }
@@ -1,6 +1,72 @@
 use crate::*;
 
+/// Allowlist Merkle leaf: `sha256("{account_id}:{allocation}")`.
+pub(crate) fn allowlist_leaf(account_id: &AccountId, allocation: u32) -> [u8; 32] {
+    env::sha256_array(format!("{}:{}", account_id, allocation).as_bytes())
+}
+
+pub(crate) fn parse_merkle_hash(hex: &str) -> Option<[u8; 32]> {
+    if hex.len() != 64 || !hex.is_ascii() {
+        return None;
+    }
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(out)
+}
+
+/// Sorted-pair hashing, so proofs carry no left/right flags.
+pub(crate) fn verify_allowlist_proof(root: &str, leaf: [u8; 32], proof: &[String]) -> bool {
+    let Some(root) = parse_merkle_hash(root) else {
+        return false;
+    };
+    if proof.len() > MAX_ALLOWLIST_PROOF_LEN {
+        return false;
+    }
+    let mut node = leaf;
+    for sibling in proof {
+        let Some(sibling) = parse_merkle_hash(sibling) else {
+            return false;
+        };
+        let (a, b) = if node <= sibling {
+            (node, sibling)
+        } else {
+            (sibling, node)
+        };
+        let mut pair = [0u8; 64];
+        pair[..32].copy_from_slice(&a);
+        pair[32..].copy_from_slice(&b);
+        node = env::sha256_array(pair);
+    }
+    node == root
+}
+
 impl Contract {
+    /// Explicit entry first, then a Merkle proof against the collection root.
+    pub(crate) fn resolve_allowlist_allocation(
+        &self,
+        collection: &LazyCollection,
+        account_id: &AccountId,
+        proof: Option<&crate::protocol::AllowlistProof>,
+    ) -> Result<u32, MarketplaceError> {
+        let key = format!("{}:al:{}", collection.collection_id, account_id);
+        let allocation = self.collection_allowlist.get(&key).copied().unwrap_or(0);
+        if allocation > 0 {
+            return Ok(allocation);
+        }
+        let (Some(root), Some(proof)) = (collection.allowlist_merkle_root.as_deref(), proof) else {
+            return Ok(0);
+        };
+        let leaf = allowlist_leaf(account_id, proof.allocation);
+        if !verify_allowlist_proof(root, leaf, &proof.proof) {
+            return Err(MarketplaceError::Unauthorized(
+                "Invalid allowlist proof".into(),
+            ));
+        }
+        Ok(proof.allocation)
+    }
+
     pub(crate) fn set_allowlist(
         &mut self,
         actor_id: &AccountId,
@@ -20,6 +86,7 @@ impl Contract {
             .clone();
 
         self.check_collection_authority(actor_id, &collection)?;
+        Self::check_collection_editable(&collection)?;
 
         let before = self.storage_usage_flushed();
 
@@ -72,6 +139,7 @@ impl Contract {
             .clone();
 
         self.check_collection_authority(actor_id, &collection)?;
+        Self::check_collection_editable(&collection)?;
 
         let before = self.storage_usage_flushed();
         for account in &accounts {
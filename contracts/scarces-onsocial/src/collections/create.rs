@@ -20,6 +20,8 @@ impl Contract {
                     app_id,
                     transferable,
                     burnable,
+                    redeem_start,
+                    redeem_end,
                 },
             renewable,
             revocation_mode,
@@ -29,6 +31,19 @@ impl Contract {
             max_per_wallet,
             start_price,
             allowlist_price,
+            creators,
+            creators_locked,
+            refund_window_ms,
+            restocking_fee_bps,
+            restock_refunds,
+            base_uri,
+            allowlist_merkle_root,
+            enforce_royalties,
+            track_provenance,
+            reveal_at,
+            placeholder_metadata,
+            mint_cooldown_ms,
+            allowlist_exempt_from_limits,
         } = params;
 
         if collection_id.is_empty() || collection_id.len() > 64 {
@@ -76,6 +91,35 @@ impl Contract {
             }
         }
 
+        crate::validation::validate_redeem_window(redeem_start, redeem_end)?;
+        if (redeem_start.is_some() || redeem_end.is_some()) && max_redeems.is_none() {
+            return Err(MarketplaceError::InvalidInput(
+                "Redemption window requires max_redeems".into(),
+            ));
+        }
+
+        if let Some(ref placeholder) = placeholder_metadata {
+            if placeholder.len() > MAX_METADATA_LEN {
+                return Err(MarketplaceError::InvalidInput(format!(
+                    "Placeholder metadata exceeds max length of {}",
+                    MAX_METADATA_LEN
+                )));
+            }
+            let parsed: TokenMetadata = serde_json::from_str(placeholder).map_err(|_| {
+                MarketplaceError::InvalidInput("Invalid placeholder metadata JSON".into())
+            })?;
+            crate::validation::validate_token_metadata(&parsed)?;
+            if reveal_at.is_some_and(|at| at <= env::block_timestamp()) {
+                return Err(MarketplaceError::InvalidInput(
+                    "reveal_at must be in the future".into(),
+                ));
+            }
+        } else if reveal_at.is_some() {
+            return Err(MarketplaceError::InvalidInput(
+                "reveal_at requires placeholder_metadata".into(),
+            ));
+        }
+
         let merged_royalty = self.merge_royalties(app_id.as_ref(), royalty)?;
         if let Some(ref r) = merged_royalty {
             if r.is_empty() {
@@ -97,6 +141,12 @@ impl Contract {
             }
         }
 
+        if mint_cooldown_ms == Some(0) {
+            return Err(MarketplaceError::InvalidInput(
+                "mint_cooldown_ms must be > 0".into(),
+            ));
+        }
+
         if let Some(sp) = &start_price {
             if sp.0 <= price_near.0 {
                 return Err(MarketplaceError::InvalidInput(
@@ -123,6 +173,18 @@ impl Contract {
             }
         }
 
+        if let Some(ref c) = creators {
+            crate::validation::validate_creator_shares(creator_id, c)?;
+        }
+
+        crate::validation::validate_refund_terms(refund_window_ms, restocking_fee_bps)?;
+        if let Some(ref uri) = base_uri {
+            crate::validation::validate_base_uri(uri)?;
+        }
+        if let Some(ref root) = allowlist_merkle_root {
+            crate::validation::validate_merkle_root(root)?;
+        }
+
         if let Some(ref app) = app_id {
             if let Some(pool) = self.app_pools.get(app) {
                 if pool.curated
@@ -163,6 +225,7 @@ impl Contract {
             max_per_wallet,
             transferable,
             paused: false,
+            frozen: false,
             redeemed_count: 0,
             fully_redeemed_count: 0,
             cancelled: false,
@@ -175,32 +238,42 @@ impl Contract {
             banned: false,
             metadata,
             app_metadata: None,
+            creators,
+            creators_locked,
+            refund_window_ms,
+            restocking_fee_bps,
+            restock_refunds,
+            held_revenue: U128(0),
+            mint_refunded_count: 0,
+            base_uri,
+            allowlist_merkle_root: allowlist_merkle_root.map(|r| r.to_ascii_lowercase()),
+            enforce_royalties,
+            redeem_start,
+            redeem_end,
+            track_provenance,
+            reveal_at,
+            placeholder_metadata,
+            revealed: false,
+            mint_cooldown_ms,
+            allowlist_exempt_from_limits,
         };
+        let creator_accounts = collection.creator_accounts();
 
         let before = self.storage_usage_flushed();
 
         self.collections.insert(collection_id.clone(), collection);
 
-        if !self.collections_by_creator.contains_key(creator_id) {
-            self.collections_by_creator.insert(
-                creator_id.clone(),
-                IterableSet::new(StorageKey::CollectionsByCreatorInner {
-                    account_id_hash: env::sha256(creator_id.as_bytes()),
-                }),
-            );
+        for account in &creator_accounts {
+            self.index_collection_creator(account, &collection_id);
         }
-        self.collections_by_creator
-            .get_mut(creator_id)
-            .unwrap()
-            .insert(collection_id.clone());
 
         let after = self.storage_usage_flushed();
         let bytes_used = after.saturating_sub(before);
 
         // Storage/accounting invariant: rollback collection if storage charge fails.
         if let Err(e) = self.charge_storage_waterfall(creator_id, bytes_used, app_id.as_ref()) {
-            if let Some(set) = self.collections_by_creator.get_mut(creator_id) {
-                set.remove(&collection_id);
+            for account in &creator_accounts {
+                self.unindex_collection_creator(account, &collection_id);
             }
             self.collections.remove(&collection_id);
             return Err(e);
@@ -213,4 +286,32 @@ impl Contract {
         events::emit_collection_created(creator_id, &collection_id, total_supply, price_near);
         Ok(())
     }
+
+    pub(crate) fn index_collection_creator(&mut self, account_id: &AccountId, collection_id: &str) {
+        if !self.collections_by_creator.contains_key(account_id) {
+            self.collections_by_creator.insert(
+                account_id.clone(),
+                IterableSet::new(StorageKey::CollectionsByCreatorInner {
+                    account_id_hash: env::sha256(account_id.as_bytes()),
+                }),
+            );
+        }
+        self.collections_by_creator
+            .get_mut(account_id)
+            .unwrap()
+            .insert(collection_id.to_string());
+    }
+
+    pub(crate) fn unindex_collection_creator(
+        &mut self,
+        account_id: &AccountId,
+        collection_id: &str,
+    ) {
+        if let Some(creator_set) = self.collections_by_creator.get_mut(account_id) {
+            creator_set.remove(collection_id);
+            if creator_set.is_empty() {
+                self.collections_by_creator.remove(account_id);
+            }
+        }
+    }
 }
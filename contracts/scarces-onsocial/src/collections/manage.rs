@@ -14,11 +14,12 @@ impl Contract {
             .ok_or_else(|| MarketplaceError::NotFound("Collection not found".into()))?
             .clone();
 
-        if &collection.creator_id != caller {
+        if !collection.can_manage(caller) {
             return Err(MarketplaceError::Unauthorized(
                 "Only collection creator can update price".into(),
             ));
         }
+        Self::check_collection_editable(&collection)?;
 
         if let Some(sp) = &collection.start_price {
             if new_price_near.0 >= sp.0 {
@@ -56,11 +57,12 @@ impl Contract {
             .ok_or_else(|| MarketplaceError::NotFound("Collection not found".into()))?
             .clone();
 
-        if &collection.creator_id != caller {
+        if !collection.can_manage(caller) {
             return Err(MarketplaceError::Unauthorized(
                 "Only collection creator can update timing".into(),
             ));
         }
+        Self::check_collection_editable(&collection)?;
 
         if collection.start_price.is_some() && (start_time.is_none() || end_time.is_none()) {
             return Err(MarketplaceError::InvalidInput(
@@ -96,6 +98,18 @@ impl Contract {
         true
     }
 
+    // State transition invariant: frozen config rejects edits; pause/resume only gate sales.
+    pub(crate) fn check_collection_editable(
+        collection: &LazyCollection,
+    ) -> Result<(), MarketplaceError> {
+        if collection.frozen {
+            return Err(MarketplaceError::InvalidState(
+                "Collection config is frozen".into(),
+            ));
+        }
+        Ok(())
+    }
+
     // State transition invariant: paused collections remain resumable; cancelled collections are terminal.
     pub(crate) fn pause_collection(
         &mut self,
@@ -154,6 +168,62 @@ impl Contract {
         Ok(())
     }
 
+    pub(crate) fn freeze_collection(
+        &mut self,
+        actor_id: &AccountId,
+        collection_id: &str,
+    ) -> Result<(), MarketplaceError> {
+        let mut collection = self
+            .collections
+            .get(collection_id)
+            .ok_or_else(|| MarketplaceError::NotFound("Collection not found".into()))?
+            .clone();
+
+        self.check_collection_authority(actor_id, &collection)?;
+        Self::check_collection_editable(&collection)?;
+
+        collection.frozen = true;
+        self.collections
+            .insert(collection_id.to_string(), collection);
+
+        events::emit_collection_frozen(actor_id, collection_id);
+        Ok(())
+    }
+
+    // Metadata invariant: allowed on frozen collections; tokens already hold their
+    // real metadata, so revealing only stops views serving the placeholder.
+    pub(crate) fn reveal_collection(
+        &mut self,
+        actor_id: &AccountId,
+        collection_id: &str,
+    ) -> Result<(), MarketplaceError> {
+        let mut collection = self
+            .collections
+            .get(collection_id)
+            .ok_or_else(|| MarketplaceError::NotFound("Collection not found".into()))?
+            .clone();
+
+        self.check_collection_authority(actor_id, &collection)?;
+        if collection.placeholder_metadata.is_none() {
+            return Err(MarketplaceError::InvalidState(
+                "Collection has no placeholder metadata".into(),
+            ));
+        }
+        if collection.revealed {
+            return Err(MarketplaceError::InvalidState(
+                "Collection is already revealed".into(),
+            ));
+        }
+
+        collection.revealed = true;
+        let minted_count = collection.minted_count;
+        self.collections
+            .insert(collection_id.to_string(), collection);
+
+        events::emit_collection_revealed(actor_id, collection_id, minted_count);
+        Ok(())
+    }
+
     pub(crate) fn delete_collection(
         &mut self,
         actor_id: &AccountId,
@@ -176,11 +246,8 @@ impl Contract {
         let before = self.storage_usage_flushed();
         self.collections.remove(collection_id);
 
-        if let Some(creator_set) = self.collections_by_creator.get_mut(&collection.creator_id) {
-            creator_set.remove(collection_id);
-            if creator_set.is_empty() {
-                self.collections_by_creator.remove(&collection.creator_id);
-            }
+        for account in collection.creator_accounts() {
+            self.unindex_collection_creator(&account, collection_id);
         }
 
         let after = self.storage_usage_flushed();
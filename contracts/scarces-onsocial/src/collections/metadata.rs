@@ -14,6 +14,7 @@ impl Contract {
             .ok_or_else(|| MarketplaceError::NotFound("Collection not found".into()))?;
 
         self.check_collection_authority(actor_id, &collection)?;
+        Self::check_collection_editable(&collection)?;
 
         // State transition invariant: None preserves state; Some("") clears; Some(json) replaces.
         if let Some(m) = metadata {
@@ -54,6 +55,105 @@ impl Contract {
         Ok(())
     }
 
+    /// Replaces metadata on minted tokens of a collection. Per-item failures
+    /// are reported in the result array instead of aborting the batch.
+    pub(crate) fn batch_update_token_metadata(
+        &mut self,
+        actor_id: &AccountId,
+        collection_id: &str,
+        updates: Vec<(String, TokenMetadata)>,
+    ) -> Result<Vec<near_sdk::serde_json::Value>, MarketplaceError> {
+        if updates.is_empty() || updates.len() as u32 > MAX_BATCH_METADATA_UPDATE {
+            return Err(MarketplaceError::InvalidInput(format!(
+                "Batch size must be 1-{}",
+                MAX_BATCH_METADATA_UPDATE
+            )));
+        }
+
+        let collection = self
+            .collections
+            .get(collection_id)
+            .cloned()
+            .ok_or_else(MarketplaceError::collection_not_found)?;
+
+        self.check_collection_authority(actor_id, &collection)?;
+        Self::check_collection_editable(&collection)?;
+
+        let before = self.storage_usage_flushed();
+        let mut results = Vec::with_capacity(updates.len());
+        for (token_id, metadata) in updates {
+            let outcome = self.update_token_metadata(&token_id, collection_id, metadata);
+            if outcome.is_ok() {
+                events::emit_token_metadata_updated(actor_id, &token_id, collection_id);
+            }
+            results.push(match outcome {
+                Ok(()) => near_sdk::serde_json::json!({ "token_id": token_id, "updated": true }),
+                Err(e) => near_sdk::serde_json::json!({
+                    "token_id": token_id,
+                    "updated": false,
+                    "error": e.to_string(),
+                }),
+            });
+        }
+        let after = self.storage_usage_flushed();
+
+        match after.cmp(&before) {
+            std::cmp::Ordering::Greater => {
+                self.charge_storage_waterfall(
+                    actor_id,
+                    after - before,
+                    collection.app_id.as_ref(),
+                )?;
+            }
+            std::cmp::Ordering::Less => {
+                self.release_storage_waterfall(
+                    actor_id,
+                    before - after,
+                    collection.app_id.as_ref(),
+                );
+            }
+            std::cmp::Ordering::Equal => {}
+        }
+
+        Ok(results)
+    }
+
+    fn update_token_metadata(
+        &mut self,
+        token_id: &str,
+        collection_id: &str,
+        metadata: TokenMetadata,
+    ) -> Result<(), MarketplaceError> {
+        check_token_in_collection(token_id, collection_id)?;
+
+        let mut token = self
+            .scarces_by_id
+            .get(token_id)
+            .cloned()
+            .ok_or_else(MarketplaceError::token_not_found)?;
+
+        let mut metadata = metadata;
+        // Lifecycle invariant: issuance time is immutable; expiry is managed by RenewToken.
+        metadata.issued_at = token.metadata.issued_at;
+        metadata.expires_at = token.metadata.expires_at;
+        metadata.updated_at = Some(crate::time::now_ms());
+        crate::validation::validate_token_metadata(&metadata)?;
+
+        let metadata_size = near_sdk::serde_json::to_string(&metadata)
+            .map_err(|_| MarketplaceError::InternalError("Failed to serialize metadata".into()))?
+            .len();
+        if metadata_size > MAX_METADATA_LEN {
+            return Err(MarketplaceError::InvalidInput(format!(
+                "Metadata exceeds max length of {} bytes (got {} bytes)",
+                MAX_METADATA_LEN, metadata_size
+            )));
+        }
+
+        token.metadata = metadata;
+        self.scarces_by_id.insert(token_id.to_string(), token);
+        Ok(())
+    }
+
     // Security boundary: app metadata is controlled by app authority and only for collections bound to that app.
     pub(crate) fn set_collection_app_metadata(
         &mut self,
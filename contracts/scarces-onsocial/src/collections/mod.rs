@@ -7,4 +7,6 @@ mod refunds;
 pub mod types;
 mod views;
 
+pub(crate) use allowlist::{allowlist_leaf, parse_merkle_hash, verify_allowlist_proof};
+pub(crate) use purchase::CollectionPurchaseError;
 pub use types::*;
@@ -1,5 +1,36 @@
 use crate::*;
 
+/// A failed collection purchase. `Exhausted` means the collection sold out or the
+/// buyer's allowlist allocation is used up, which buyers may opt to settle as a refund.
+pub(crate) enum CollectionPurchaseError {
+    Exhausted {
+        reason: &'static str,
+        error: MarketplaceError,
+    },
+    Failed(MarketplaceError),
+}
+
+impl CollectionPurchaseError {
+    fn exhausted(reason: &'static str, error: MarketplaceError) -> Self {
+        Self::Exhausted { reason, error }
+    }
+}
+
+impl From<MarketplaceError> for CollectionPurchaseError {
+    fn from(error: MarketplaceError) -> Self {
+        Self::Failed(error)
+    }
+}
+
+impl From<CollectionPurchaseError> for MarketplaceError {
+    fn from(error: CollectionPurchaseError) -> Self {
+        match error {
+            CollectionPurchaseError::Exhausted { error, .. }
+            | CollectionPurchaseError::Failed(error) => error,
+        }
+    }
+}
+
 impl Contract {
     pub(crate) fn purchase_from_collection(
         &mut self,
@@ -7,13 +38,15 @@ impl Contract {
         collection_id: String,
         quantity: u32,
         max_price_per_token: U128,
+        allowlist_proof: Option<crate::protocol::AllowlistProof>,
         deposit: u128,
-    ) -> Result<(), MarketplaceError> {
+    ) -> Result<(), CollectionPurchaseError> {
         if quantity == 0 || quantity > MAX_BATCH_MINT {
             return Err(MarketplaceError::InvalidInput(format!(
                 "Quantity must be 1-{}",
                 MAX_BATCH_MINT
-            )));
+            ))
+            .into());
         }
 
         let collection = self
@@ -27,37 +60,37 @@ impl Contract {
 
         if is_before_start {
             if collection.banned {
-                return Err(MarketplaceError::InvalidState(
-                    "Collection is banned".into(),
-                ));
+                return Err(MarketplaceError::InvalidState("Collection is banned".into()).into());
             }
             if collection.cancelled {
-                return Err(MarketplaceError::InvalidState(
-                    "Collection is cancelled".into(),
-                ));
+                return Err(
+                    MarketplaceError::InvalidState("Collection is cancelled".into()).into(),
+                );
             }
             if collection.paused {
-                return Err(MarketplaceError::InvalidState(
-                    "Collection is paused".into(),
-                ));
+                return Err(MarketplaceError::InvalidState("Collection is paused".into()).into());
             }
             if collection.minted_count >= collection.total_supply {
-                return Err(MarketplaceError::InvalidState("Sold out".into()));
+                return Err(CollectionPurchaseError::exhausted(
+                    "sold_out",
+                    MarketplaceError::InvalidState("Sold out".into()),
+                ));
             }
             if let Some(end) = collection.end_time {
                 if now > end {
-                    return Err(MarketplaceError::InvalidState(
-                        "Collection has ended".into(),
-                    ));
+                    return Err(
+                        MarketplaceError::InvalidState("Collection has ended".into()).into(),
+                    );
                 }
             }
 
-            let al_key = format!("{}:al:{}", collection_id, buyer_id);
-            let allocation = self.collection_allowlist.get(&al_key).copied().unwrap_or(0);
+            let allocation =
+                self.resolve_allowlist_allocation(&collection, buyer_id, allowlist_proof.as_ref())?;
             if allocation == 0 {
                 return Err(MarketplaceError::Unauthorized(
                     "Collection has not started — early access requires allowlist".into(),
-                ));
+                )
+                .into());
             }
 
             let mint_key = format!("{}:{}", collection_id, buyer_id);
@@ -67,22 +100,36 @@ impl Contract {
                 .copied()
                 .unwrap_or(0);
             if already_minted + quantity > allocation {
-                return Err(MarketplaceError::InvalidInput(format!(
+                let error = MarketplaceError::InvalidInput(format!(
                     "Exceeds allowlist allocation: minted {}, requesting {}, allocation {}",
                     already_minted, quantity, allocation
-                )));
+                ));
+                if already_minted >= allocation {
+                    return Err(CollectionPurchaseError::exhausted(
+                        "allowlist_exhausted",
+                        error,
+                    ));
+                }
+                return Err(error.into());
             }
         } else if !self.is_collection_active(&collection) {
-            return Err(MarketplaceError::InvalidState(
-                "Collection is not active for minting".into(),
-            ));
+            let error =
+                MarketplaceError::InvalidState("Collection is not active for minting".into());
+            let sold_out = collection.minted_count >= collection.total_supply
+                && !(collection.banned || collection.cancelled || collection.paused)
+                && collection.end_time.is_none_or(|end| now <= end);
+            if sold_out {
+                return Err(CollectionPurchaseError::exhausted("sold_out", error));
+            }
+            return Err(error.into());
         }
 
         if collection.mint_mode == crate::MintMode::CreatorOnly {
             return Err(MarketplaceError::Unauthorized(
                 "Collection is creator-only — use MintFromCollection or AirdropFromCollection"
                     .into(),
-            ));
+            )
+            .into());
         }
 
         let available = collection.total_supply - collection.minted_count;
@@ -90,11 +137,17 @@ impl Contract {
             return Err(MarketplaceError::InvalidState(format!(
                 "Only {} items remaining",
                 available
-            )));
+            ))
+            .into());
         }
 
-        if let Some(max_per_wallet) = collection.max_per_wallet {
-            let mint_key = format!("{}:{}", collection_id, buyer_id);
+        let mint_key = format!("{}:{}", collection_id, buyer_id);
+        let limits_exempt = collection.allowlist_exempt_from_limits
+            && self
+                .resolve_allowlist_allocation(&collection, buyer_id, allowlist_proof.as_ref())
+                .is_ok_and(|allocation| allocation > 0);
+
+        if let Some(max_per_wallet) = collection.max_per_wallet.filter(|_| !limits_exempt) {
             let already_minted = self
                 .collection_mint_counts
                 .get(&mint_key)
@@ -104,7 +157,22 @@ impl Contract {
                 return Err(MarketplaceError::InvalidInput(format!(
                     "Exceeds per-wallet limit: minted {}, requesting {}, max {}",
                     already_minted, quantity, max_per_wallet
-                )));
+                ))
+                .into());
+            }
+        }
+
+        let now_ms = crate::time::now_ms();
+        let cooldown = collection.mint_cooldown_ms.filter(|_| !limits_exempt);
+        let last_mint = self.collection_last_mint.get(&mint_key).copied();
+        if let (Some(cooldown), Some(last)) = (cooldown, last_mint) {
+            let next_allowed = last.saturating_add(cooldown);
+            if now_ms < next_allowed {
+                return Err(MarketplaceError::InvalidState(format!(
+                    "Mint cooldown active: next purchase allowed at {} ms",
+                    next_allowed
+                ))
+                .into());
             }
         }
 
@@ -121,7 +189,8 @@ impl Contract {
             return Err(MarketplaceError::InvalidInput(format!(
                 "Price per token ({}) exceeds maximum allowed ({})",
                 unit_price, max_price_per_token.0
-            )));
+            ))
+            .into());
         }
 
         let total_price = unit_price
@@ -131,7 +200,8 @@ impl Contract {
             return Err(MarketplaceError::InsufficientDeposit(format!(
                 "Insufficient payment: required {}, got {}",
                 total_price, deposit
-            )));
+            ))
+            .into());
         }
 
         let start_index = collection.minted_count;
@@ -139,6 +209,11 @@ impl Contract {
         let creator_id = collection.creator_id.clone();
         let app_id = collection.app_id.clone();
         let royalty = collection.royalty.clone();
+        let (redeem_start, redeem_end) = (collection.redeem_start, collection.redeem_end);
+        let creator_shares = collection.creators.clone();
+        let refundable_until = collection
+            .refund_window_ms
+            .map(|window| crate::time::now_ms().saturating_add(window));
 
         let token_ids: Vec<String> = (start_index..start_index + quantity)
             .map(|i| format!("{}:{}", collection_id, i + 1))
@@ -160,6 +235,8 @@ impl Contract {
         let ovr = crate::ScarceOverrides {
             royalty,
             paid_price: unit_price,
+            redeem_start,
+            redeem_end,
             ..Default::default()
         };
         let _minted = self.batch_mint(
@@ -170,15 +247,31 @@ impl Contract {
             Some(ovr),
         )?;
 
+        // Storage/accounting invariant: escrow records exist before measuring so the buyer pays for them.
+        if let Some(until) = refundable_until {
+            for tid in &token_ids {
+                self.mint_refund_escrows.insert(
+                    tid.clone(),
+                    MintRefundEscrow {
+                        buyer_id: buyer_id.clone(),
+                        amount: U128(0),
+                        refundable_until: until,
+                    },
+                );
+            }
+        }
+
         if is_before_start || collection.max_per_wallet.is_some() {
-            let mint_key = format!("{}:{}", collection_id, buyer_id);
             let prev = self
                 .collection_mint_counts
                 .get(&mint_key)
                 .copied()
                 .unwrap_or(0);
             self.collection_mint_counts
-                .insert(mint_key, prev + quantity);
+                .insert(mint_key.clone(), prev + quantity);
+        }
+        if cooldown.is_some() {
+            self.collection_last_mint.insert(mint_key.clone(), now_ms);
         }
 
         let after = self.storage_usage_flushed();
@@ -187,7 +280,12 @@ impl Contract {
         let result = match self.route_primary_sale(
             total_price,
             bytes_used,
-            &creator_id,
+            crate::fees::CreatorPayee {
+                creator_id: &creator_id,
+                shares: creator_shares.as_ref(),
+                hold: refundable_until.is_some(),
+                collection_id: Some(&collection_id),
+            },
             buyer_id,
             app_id.as_ref(),
         ) {
@@ -198,14 +296,15 @@ impl Contract {
                         self.untrack_app_owner(&app, buyer_id);
                     }
                     self.scarces_by_id.remove(tid);
+                    self.token_provenance.remove(tid);
                     self.remove_token_from_owner(buyer_id, tid);
+                    self.mint_refund_escrows.remove(tid);
                 }
                 let mut restored = self.collections.get(&collection_id).unwrap().clone();
                 restored.minted_count -= quantity;
                 restored.total_revenue.0 -= total_price;
                 self.collections.insert(collection_id.clone(), restored);
                 if is_before_start || collection.max_per_wallet.is_some() {
-                    let mint_key = format!("{}:{}", collection_id, buyer_id);
                     let cur = self
                         .collection_mint_counts
                         .get(&mint_key)
@@ -214,14 +313,25 @@ impl Contract {
                     if cur <= quantity {
                         self.collection_mint_counts.remove(&mint_key);
                     } else {
-                        self.collection_mint_counts.insert(mint_key, cur - quantity);
+                        self.collection_mint_counts
+                            .insert(mint_key.clone(), cur - quantity);
                     }
                 }
+                if cooldown.is_some() {
+                    match last_mint {
+                        Some(last) => self.collection_last_mint.insert(mint_key, last),
+                        None => self.collection_last_mint.remove(&mint_key),
+                    };
+                }
                 self.pending_attached_balance += deposit;
-                return Err(e);
+                return Err(e.into());
             }
         };
 
+        if refundable_until.is_some() {
+            self.hold_mint_revenue(&collection_id, &token_ids, total_price);
+        }
+
         self.pending_attached_balance += deposit.saturating_sub(total_price);
 
         events::emit_collection_purchase(&events::CollectionPurchase {
@@ -287,6 +397,7 @@ impl Contract {
         let start_index = collection.minted_count;
         let metadata_template = collection.metadata_template.clone();
         let royalty = collection.royalty.clone();
+        let (redeem_start, redeem_end) = (collection.redeem_start, collection.redeem_end);
         let app_id = collection.app_id.clone();
         let creator_id = collection.creator_id.clone();
 
@@ -308,6 +419,8 @@ impl Contract {
         };
         let ovr = crate::ScarceOverrides {
             royalty,
+            redeem_start,
+            redeem_end,
             ..Default::default()
         };
         let _minted = self.batch_mint(
@@ -327,6 +440,7 @@ impl Contract {
                     self.untrack_app_owner(&app, recipient);
                 }
                 self.scarces_by_id.remove(tid);
+                self.token_provenance.remove(tid);
                 self.remove_token_from_owner(recipient, tid);
             }
             let mut restored = self.collections.get(collection_id).unwrap().clone();
@@ -384,6 +498,7 @@ impl Contract {
         let start_index = collection.minted_count;
         let metadata_template = collection.metadata_template.clone();
         let royalty = collection.royalty.clone();
+        let (redeem_start, redeem_end) = (collection.redeem_start, collection.redeem_end);
         let app_id = collection.app_id.clone();
         let creator_id = collection.creator_id.clone();
 
@@ -414,6 +529,8 @@ impl Contract {
             };
             let ovr = crate::ScarceOverrides {
                 royalty: royalty.clone(),
+                redeem_start,
+                redeem_end,
                 ..Default::default()
             };
             let minted_id = self.mint(token_id.clone(), ctx, metadata, Some(ovr))?;
@@ -430,6 +547,7 @@ impl Contract {
                     self.untrack_app_owner(&app, &receivers[i]);
                 }
                 self.scarces_by_id.remove(tid);
+                self.token_provenance.remove(tid);
                 self.remove_token_from_owner(&receivers[i], tid);
             }
             let mut restored = self.collections.get(collection_id).unwrap().clone();
@@ -25,7 +25,8 @@ impl Contract {
 
         let refundable_count = collection
             .minted_count
-            .saturating_sub(collection.fully_redeemed_count);
+            .saturating_sub(collection.fully_redeemed_count)
+            .saturating_sub(collection.mint_refunded_count);
         let required_deposit = refund_per_token
             .0
             .checked_mul(refundable_count as u128)
@@ -124,6 +125,9 @@ impl Contract {
             .clone();
 
         if !collection.cancelled {
+            if collection.refund_window_ms.is_some() {
+                return self.claim_mint_refund(caller, token_id, collection);
+            }
             return Err(MarketplaceError::InvalidState(
                 "Collection is not cancelled".into(),
             ));
@@ -183,4 +187,187 @@ impl Contract {
         events::emit_refund_claimed(caller, token_id, collection_id, refund_amount);
         Ok(())
     }
+
+    // Token accounting guarantee: per-token escrow split carries rounding dust on the first token.
+    pub(crate) fn hold_mint_revenue(
+        &mut self,
+        collection_id: &str,
+        token_ids: &[String],
+        total_price: u128,
+    ) {
+        let count = token_ids.len() as u128;
+        if count == 0 {
+            return;
+        }
+        let per_token = total_price / count;
+        let dust = total_price - per_token * count;
+        for (i, tid) in token_ids.iter().enumerate() {
+            if let Some(escrow) = self.mint_refund_escrows.get_mut(tid) {
+                escrow.amount = U128(if i == 0 { per_token + dust } else { per_token });
+            }
+        }
+        if let Some(collection) = self.collections.get_mut(collection_id) {
+            collection.held_revenue.0 += total_price;
+        }
+    }
+
+    fn claim_mint_refund(
+        &mut self,
+        caller: &AccountId,
+        token_id: &str,
+        mut collection: LazyCollection,
+    ) -> Result<(), MarketplaceError> {
+        let escrow = self
+            .mint_refund_escrows
+            .get(token_id)
+            .cloned()
+            .ok_or_else(|| MarketplaceError::NotFound("No refundable mint for token".into()))?;
+
+        if crate::time::now_ms() > escrow.refundable_until {
+            return Err(MarketplaceError::InvalidState(
+                "Refund window has closed".into(),
+            ));
+        }
+
+        let (owner_id, token_app_id) = {
+            let token = self
+                .scarces_by_id
+                .get(token_id)
+                .ok_or_else(|| MarketplaceError::NotFound("Token not found".into()))?;
+            (token.owner_id.clone(), token.app_id.clone())
+        };
+        if &owner_id != caller || &escrow.buyer_id != caller {
+            return Err(MarketplaceError::Unauthorized(
+                "Only the original buyer holding the token can claim a refund".into(),
+            ));
+        }
+
+        // The escrow holds the full price paid, fees included.
+        let restocking_fee =
+            escrow.amount.0 * collection.restocking_fee_bps as u128 / BASIS_POINTS as u128;
+        let refund_amount = escrow.amount.0 - restocking_fee;
+
+        let before = self.storage_usage_flushed();
+        self.mint_refund_escrows.remove(token_id);
+        let bytes_freed = before.saturating_sub(self.storage_usage_flushed());
+        if bytes_freed > 0 {
+            self.release_storage_waterfall(caller, bytes_freed, collection.app_id.as_ref());
+        }
+        self.destroy_scarce(token_id, &owner_id, token_app_id.as_ref());
+
+        collection.held_revenue.0 -= escrow.amount.0;
+        collection.mint_refunded_count += 1;
+        if collection.restock_refunds {
+            collection.total_supply += 1;
+        }
+        let restocked = collection.restock_refunds;
+        let creator_id = collection.creator_id.clone();
+        let creators = collection.creators.clone();
+        let collection_id = collection.collection_id.clone();
+        self.collections.insert(collection_id.clone(), collection);
+
+        if refund_amount > 0 {
+            let _ = Promise::new(caller.clone()).transfer(NearToken::from_yoctonear(refund_amount));
+        }
+        if restocking_fee > 0 {
+            for (receiver, amount) in crate::fees::routing::split_creator_payment(
+                restocking_fee,
+                &creator_id,
+                creators.as_ref(),
+            ) {
+                self.record_creator_earnings(&receiver, Some(&collection_id), amount, 0);
+                let _ = Promise::new(receiver).transfer(NearToken::from_yoctonear(amount));
+            }
+        }
+
+        events::emit_mint_refund_claimed(
+            caller,
+            token_id,
+            &collection_id,
+            refund_amount,
+            restocking_fee,
+            restocked,
+        );
+        Ok(())
+    }
+
+    pub(crate) fn release_refund_escrow(
+        &mut self,
+        actor_id: &AccountId,
+        collection_id: &str,
+        token_ids: Vec<String>,
+    ) -> Result<(), MarketplaceError> {
+        if token_ids.is_empty() || token_ids.len() > MAX_AIRDROP_RECIPIENTS as usize {
+            return Err(MarketplaceError::InvalidInput(format!(
+                "Token IDs must be 1-{}",
+                MAX_AIRDROP_RECIPIENTS
+            )));
+        }
+
+        let mut collection = self
+            .collections
+            .get(collection_id)
+            .ok_or_else(|| MarketplaceError::NotFound("Collection not found".into()))?
+            .clone();
+
+        self.check_collection_authority(actor_id, &collection)?;
+
+        let now = crate::time::now_ms();
+        for (i, tid) in token_ids.iter().enumerate() {
+            if token_ids[..i].contains(tid) {
+                return Err(MarketplaceError::InvalidInput(format!(
+                    "Duplicate token {} in batch",
+                    tid
+                )));
+            }
+            check_token_in_collection(tid, collection_id)?;
+            let escrow = self.mint_refund_escrows.get(tid).ok_or_else(|| {
+                MarketplaceError::NotFound(format!("No held revenue for token {}", tid))
+            })?;
+            if now <= escrow.refundable_until {
+                return Err(MarketplaceError::InvalidState(format!(
+                    "Refund window for token {} has not closed",
+                    tid
+                )));
+            }
+        }
+
+        let mut released: u128 = 0;
+        for tid in &token_ids {
+            let before = self.storage_usage_flushed();
+            let Some(escrow) = self.mint_refund_escrows.remove(tid) else {
+                continue;
+            };
+            let bytes_freed = before.saturating_sub(self.storage_usage_flushed());
+            if bytes_freed > 0 {
+                self.release_storage_waterfall(
+                    &escrow.buyer_id,
+                    bytes_freed,
+                    collection.app_id.as_ref(),
+                );
+            }
+            released += escrow.amount.0;
+        }
+
+        collection.held_revenue.0 -= released;
+        let creator_id = collection.creator_id.clone();
+        let creators = collection.creators.clone();
+        let app_id = collection.app_id.clone();
+        self.collections
+            .insert(collection_id.to_string(), collection);
+
+        self.distribute_primary_revenue(
+            released,
+            crate::fees::CreatorPayee {
+                creator_id: &creator_id,
+                shares: creators.as_ref(),
+                hold: false,
+                collection_id: Some(collection_id),
+            },
+            app_id.as_ref(),
+        );
+
+        events::emit_refund_escrow_released(actor_id, collection_id, &token_ids, released);
+        Ok(())
+    }
 }
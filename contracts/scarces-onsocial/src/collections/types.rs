@@ -1,6 +1,9 @@
-use near_sdk::AccountId;
+use near_sdk::borsh::io::{Read, Result};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 use near_sdk::json_types::U128;
-use near_sdk::near;
+use near_sdk::{AccountId, near};
+
+use crate::storage::legacy::read_appended;
 
 use crate::scarce::types::ScarceOptions;
 
@@ -11,6 +14,15 @@ pub struct AllowlistEntry {
     pub allocation: u32,
 }
 
+/// Proof that `(buyer, allocation)` is a leaf of the collection's allowlist root.
+#[near(serializers = [json])]
+#[derive(Clone)]
+pub struct AllowlistProof {
+    pub allocation: u32,
+    /// Sibling hashes from leaf to root, hex-encoded.
+    pub proof: Vec<String>,
+}
+
 #[near(serializers = [borsh, json])]
 #[derive(Clone, PartialEq, Default)]
 #[serde(rename_all = "snake_case")]
@@ -31,8 +43,11 @@ pub enum MintMode {
     CreatorOnly,
 }
 
-#[near(serializers = [borsh, json])]
-#[derive(Clone)]
+// Upgrade invariant: fields are only appended; collections created before a
+// trailing field existed still decode (see the `BorshDeserialize` impl).
+#[near(serializers = [json])]
+#[derive(Clone, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
 pub struct LazyCollection {
     pub creator_id: AccountId,
     pub collection_id: String,
@@ -93,6 +108,193 @@ pub struct LazyCollection {
     pub metadata: Option<String>,
     #[serde(default)]
     pub app_metadata: Option<String>,
+    // State transition invariant: `frozen` is terminal and locks config; sales are unaffected.
+    #[serde(default)]
+    pub frozen: bool,
+    // Revenue invariant: co-creator shares (bps) include `creator_id` and sum to BASIS_POINTS.
+    #[serde(default)]
+    pub creators: Option<std::collections::HashMap<AccountId, u32>>,
+    // Access invariant: when locked, only `creator_id` may manage the collection.
+    #[serde(default)]
+    pub creators_locked: bool,
+    #[serde(default)]
+    pub refund_window_ms: Option<u64>,
+    #[serde(default)]
+    pub restocking_fee_bps: u32,
+    // State transition invariant: refunded tokens are burned; restocking adds one unit back to supply.
+    #[serde(default)]
+    pub restock_refunds: bool,
+    // Token accounting guarantee: full mint price escrowed until each token's refund window closes.
+    #[serde(default)]
+    pub held_revenue: U128,
+    // Metadata invariant: relative token `media`/`reference` resolve against this at view time.
+    #[serde(default)]
+    pub base_uri: Option<String>,
+    // Access invariant: hex sha256 root over `{account_id}:{allocation}` leaves; complements per-address entries.
+    #[serde(default)]
+    pub allowlist_merkle_root: Option<String>,
+    // Royalty invariant: direct transfers to or by non-royalty `known_marketplaces` are rejected.
+    #[serde(default)]
+    pub enforce_royalties: bool,
+    // Redemption invariant: stamped onto each minted token; tokens only redeem inside the window.
+    #[serde(default)]
+    pub redeem_start: Option<u64>,
+    #[serde(default)]
+    pub redeem_end: Option<u64>,
+    // Storage invariant: when set, tokens keep their last MAX_PROVENANCE_ENTRIES owners on-chain.
+    #[serde(default)]
+    pub track_provenance: bool,
+    // Metadata invariant: token views serve `placeholder_metadata` until `reveal_at` (ns) passes or `revealed` is set.
+    // Display-only: tokens mint with the real template, which stays readable on-chain.
+    #[serde(default)]
+    pub reveal_at: Option<u64>,
+    #[serde(default)]
+    pub placeholder_metadata: Option<String>,
+    // State transition invariant: `revealed` is terminal.
+    #[serde(default)]
+    pub revealed: bool,
+    // Rate limit invariant: minimum gap between one account's purchases.
+    #[serde(default)]
+    pub mint_cooldown_ms: Option<u64>,
+    // Rate limit invariant: allowlisted buyers skip `max_per_wallet` and `mint_cooldown_ms`; allocations still apply.
+    #[serde(default)]
+    pub allowlist_exempt_from_limits: bool,
+    // Refund accounting invariant: in-window mint refunds burn their token; excluded from refundable supply on cancellation.
+    #[serde(default)]
+    pub mint_refunded_count: u32,
+}
+
+/// `LazyCollection` as written before any field was appended.
+#[derive(BorshDeserialize)]
+#[borsh(crate = "near_sdk::borsh")]
+struct LazyCollectionV1 {
+    creator_id: AccountId,
+    collection_id: String,
+    total_supply: u32,
+    minted_count: u32,
+    metadata_template: String,
+    price_near: U128,
+    start_price: Option<U128>,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    created_at: u64,
+    app_id: Option<AccountId>,
+    royalty: Option<std::collections::HashMap<AccountId, u32>>,
+    renewable: bool,
+    revocation_mode: RevocationMode,
+    max_redeems: Option<u32>,
+    redeemed_count: u32,
+    fully_redeemed_count: u32,
+    burnable: bool,
+    mint_mode: MintMode,
+    max_per_wallet: Option<u32>,
+    transferable: bool,
+    paused: bool,
+    cancelled: bool,
+    refund_pool: U128,
+    refund_per_token: U128,
+    refunded_count: u32,
+    refund_deadline: Option<u64>,
+    total_revenue: U128,
+    allowlist_price: Option<U128>,
+    banned: bool,
+    metadata: Option<String>,
+    app_metadata: Option<String>,
+}
+
+impl BorshDeserialize for LazyCollection {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let v1 = LazyCollectionV1::deserialize_reader(reader)?;
+        Ok(Self {
+            creator_id: v1.creator_id,
+            collection_id: v1.collection_id,
+            total_supply: v1.total_supply,
+            minted_count: v1.minted_count,
+            metadata_template: v1.metadata_template,
+            price_near: v1.price_near,
+            start_price: v1.start_price,
+            start_time: v1.start_time,
+            end_time: v1.end_time,
+            created_at: v1.created_at,
+            app_id: v1.app_id,
+            royalty: v1.royalty,
+            renewable: v1.renewable,
+            revocation_mode: v1.revocation_mode,
+            max_redeems: v1.max_redeems,
+            redeemed_count: v1.redeemed_count,
+            fully_redeemed_count: v1.fully_redeemed_count,
+            burnable: v1.burnable,
+            mint_mode: v1.mint_mode,
+            max_per_wallet: v1.max_per_wallet,
+            transferable: v1.transferable,
+            paused: v1.paused,
+            cancelled: v1.cancelled,
+            refund_pool: v1.refund_pool,
+            refund_per_token: v1.refund_per_token,
+            refunded_count: v1.refunded_count,
+            refund_deadline: v1.refund_deadline,
+            total_revenue: v1.total_revenue,
+            allowlist_price: v1.allowlist_price,
+            banned: v1.banned,
+            metadata: v1.metadata,
+            app_metadata: v1.app_metadata,
+            frozen: read_appended(reader, false)?,
+            creators: read_appended(reader, None)?,
+            creators_locked: read_appended(reader, false)?,
+            refund_window_ms: read_appended(reader, None)?,
+            restocking_fee_bps: read_appended(reader, 0)?,
+            restock_refunds: read_appended(reader, false)?,
+            held_revenue: read_appended(reader, U128(0))?,
+            base_uri: read_appended(reader, None)?,
+            allowlist_merkle_root: read_appended(reader, None)?,
+            enforce_royalties: read_appended(reader, false)?,
+            redeem_start: read_appended(reader, None)?,
+            redeem_end: read_appended(reader, None)?,
+            track_provenance: read_appended(reader, false)?,
+            reveal_at: read_appended(reader, None)?,
+            placeholder_metadata: read_appended(reader, None)?,
+            revealed: read_appended(reader, false)?,
+            mint_cooldown_ms: read_appended(reader, None)?,
+            allowlist_exempt_from_limits: read_appended(reader, false)?,
+            mint_refunded_count: read_appended(reader, 0)?,
+        })
+    }
+}
+
+impl LazyCollection {
+    /// Primary creator followed by any co-creators.
+    pub(crate) fn creator_accounts(&self) -> Vec<AccountId> {
+        let mut accounts = vec![self.creator_id.clone()];
+        if let Some(ref creators) = self.creators {
+            accounts.extend(
+                creators
+                    .keys()
+                    .filter(|account| *account != &self.creator_id)
+                    .cloned(),
+            );
+        }
+        accounts
+    }
+
+    pub(crate) fn is_creator(&self, account_id: &AccountId) -> bool {
+        account_id == &self.creator_id
+            || self
+                .creators
+                .as_ref()
+                .is_some_and(|creators| creators.contains_key(account_id))
+    }
+
+    pub(crate) fn can_manage(&self, account_id: &AccountId) -> bool {
+        account_id == &self.creator_id || (!self.creators_locked && self.is_creator(account_id))
+    }
+
+    pub(crate) fn is_revealed(&self) -> bool {
+        self.placeholder_metadata.is_none()
+            || self.revealed
+            || self
+                .reveal_at
+                .is_some_and(|at| near_sdk::env::block_timestamp() >= at)
+    }
 }
 
 #[near(serializers = [json])]
@@ -124,6 +326,41 @@ pub struct CollectionConfig {
     pub start_price: Option<U128>,
     #[serde(default)]
     pub allowlist_price: Option<U128>,
+    #[serde(default)]
+    pub creators: Option<std::collections::HashMap<AccountId, u32>>,
+    #[serde(default)]
+    pub creators_locked: bool,
+    #[serde(default)]
+    pub refund_window_ms: Option<u64>,
+    #[serde(default)]
+    pub restocking_fee_bps: u32,
+    #[serde(default)]
+    pub restock_refunds: bool,
+    #[serde(default)]
+    pub base_uri: Option<String>,
+    #[serde(default)]
+    pub allowlist_merkle_root: Option<String>,
+    #[serde(default)]
+    pub enforce_royalties: bool,
+    #[serde(default)]
+    pub track_provenance: bool,
+    #[serde(default)]
+    pub reveal_at: Option<u64>,
+    #[serde(default)]
+    pub placeholder_metadata: Option<String>,
+    #[serde(default)]
+    pub mint_cooldown_ms: Option<u64>,
+    #[serde(default)]
+    pub allowlist_exempt_from_limits: bool,
+}
+
+/// Creator proceeds held for a refundable mint until `refundable_until` (ms).
+#[near(serializers = [borsh, json])]
+#[derive(Clone)]
+pub struct MintRefundEscrow {
+    pub buyer_id: AccountId,
+    pub amount: U128,
+    pub refundable_until: u64,
 }
 
 #[near(serializers = [json])]
@@ -160,8 +397,18 @@ pub struct CollectionStats {
     pub burnable: bool,
     pub mint_mode: MintMode,
     pub max_per_wallet: Option<u32>,
+    pub mint_cooldown_ms: Option<u64>,
+    pub allowlist_exempt_from_limits: bool,
     pub transferable: bool,
     pub paused: bool,
+    pub frozen: bool,
     pub banned: bool,
     pub allowlist_price: Option<U128>,
+    pub allowlist_merkle_root: Option<String>,
+    pub creators: Option<std::collections::HashMap<AccountId, u32>>,
+    pub creators_locked: bool,
+    pub refund_window_ms: Option<u64>,
+    pub restocking_fee_bps: u32,
+    pub held_revenue: U128,
+    pub mint_refunded_count: u32,
 }
@@ -153,14 +153,83 @@ impl Contract {
                 burnable: collection.burnable,
                 mint_mode: collection.mint_mode.clone(),
                 max_per_wallet: collection.max_per_wallet,
+                mint_cooldown_ms: collection.mint_cooldown_ms,
+                allowlist_exempt_from_limits: collection.allowlist_exempt_from_limits,
                 transferable: collection.transferable,
                 paused: collection.paused,
+                frozen: collection.frozen,
                 banned: collection.banned,
                 allowlist_price: collection.allowlist_price,
+                allowlist_merkle_root: collection.allowlist_merkle_root.clone(),
+                creators: collection.creators.clone(),
+                creators_locked: collection.creators_locked,
+                refund_window_ms: collection.refund_window_ms,
+                restocking_fee_bps: collection.restocking_fee_bps,
+                held_revenue: collection.held_revenue,
+                mint_refunded_count: collection.mint_refunded_count,
             }
         })
     }
 
+    /// One-call summary for collection pages. Floor only counts unexpired
+    /// fixed-price listings; auctions still count towards `active_listings`.
+    pub fn get_collection_overview(&self, collection_id: String) -> Option<Value> {
+        let collection = self.collections.get(&collection_id)?;
+        let now = env::block_timestamp();
+        let live = |expires_at: Option<u64>| expires_at.is_none_or(|exp| now <= exp);
+
+        let mut floor_price: Option<u128> = None;
+        let mut active_listings: u32 = 0;
+        for sale in self.sales.values() {
+            let SaleType::NativeScarce { token_id } = &sale.sale_type else {
+                continue;
+            };
+            if collection_id_from_token_id(token_id) != collection_id || !live(sale.expires_at) {
+                continue;
+            }
+            active_listings += 1;
+            if sale.auction.is_none() {
+                let price = sale.sale_conditions.0;
+                floor_price = Some(floor_price.map_or(price, |f| f.min(price)));
+            }
+        }
+
+        let mut offer_count: u32 = 0;
+        let mut highest_offer: Option<&CollectionOffer> = None;
+        for offer in self
+            .collection_offers_of(&collection_id)
+            .filter(|o| live(o.expires_at))
+        {
+            offer_count += 1;
+            if highest_offer.is_none_or(|h| offer.amount.0 > h.amount.0) {
+                highest_offer = Some(offer);
+            }
+        }
+
+        let in_mint_window = collection.start_time.is_none_or(|start| now >= start)
+            && collection.end_time.is_none_or(|end| now <= end);
+
+        Some(near_sdk::serde_json::json!({
+            "collection_id": collection.collection_id,
+            "minted_count": collection.minted_count,
+            "total_supply": collection.total_supply,
+            "floor_price": floor_price.map(U128),
+            "active_listings": active_listings,
+            "collection_offer_count": offer_count,
+            "highest_collection_offer": highest_offer.map(|o| near_sdk::serde_json::json!({
+                "buyer_id": o.buyer_id,
+                "amount": o.amount,
+            })),
+            "paused": collection.paused,
+            "in_mint_window": in_mint_window,
+            "is_mintable": self.is_collection_active(collection),
+        }))
+    }
+
+    pub fn get_mint_refund_escrow(&self, token_id: String) -> Option<MintRefundEscrow> {
+        self.mint_refund_escrows.get(&token_id).cloned()
+    }
+
     pub fn get_wallet_mint_count(&self, collection_id: String, account_id: AccountId) -> u32 {
         let key = format!("{}:{}", collection_id, account_id);
         self.collection_mint_counts.get(&key).copied().unwrap_or(0)
@@ -187,6 +256,25 @@ impl Contract {
         self.get_allowlist_allocation(collection_id, account_id) > 0
     }
 
+    /// Checks a Merkle allowlist proof without spending it.
+    pub fn verify_allowlist_proof(
+        &self,
+        collection_id: String,
+        account_id: AccountId,
+        proof: crate::protocol::AllowlistProof,
+    ) -> bool {
+        self.collections
+            .get(&collection_id)
+            .and_then(|c| c.allowlist_merkle_root.as_deref())
+            .is_some_and(|root| {
+                crate::collections::verify_allowlist_proof(
+                    root,
+                    crate::collections::allowlist_leaf(&account_id, proof.allocation),
+                    &proof.proof,
+                )
+            })
+    }
+
     /// Counts all mints, including those during the public phase.
     pub fn get_allowlist_remaining(&self, collection_id: String, account_id: AccountId) -> u32 {
         let al_key = format!("{}:al:{}", collection_id, account_id);
@@ -11,6 +11,7 @@ pub const MIN_POOL_FEE_BPS: u16 = 25;
 pub const MAX_POOL_FEE_BPS: u16 = 100;
 pub const PLATFORM_STORAGE_MIN_RESERVE: u128 = 5_000_000_000_000_000_000_000_000; // 5 NEAR
 pub const DEFAULT_APP_MAX_USER_BYTES: u64 = 50_000;
+pub const SPENDING_WINDOW_MS: u64 = 86_400_000;
 
 pub const BASIS_POINTS: u16 = 10_000; // 100%
 pub const MAX_ROYALTY_BPS: u32 = 5_000; // 50%
@@ -19,20 +20,37 @@ pub const DELIMETER: &str = ":";
 pub const ONE_YOCTO: NearToken = NearToken::from_yoctonear(1);
 
 pub const MAX_COLLECTION_SUPPLY: u32 = 100_000;
+pub const MAX_COLLECTION_CREATORS: usize = 10;
 pub const DEFAULT_REFUND_DEADLINE_NS: u64 = 90 * 24 * 60 * 60 * 1_000_000_000;
 // Refund safety invariant: minimum deadline prevents immediate organizer withdrawal before holder claims.
 #[cfg(not(feature = "sandbox"))]
 pub const MIN_REFUND_DEADLINE_NS: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
 #[cfg(feature = "sandbox")]
 pub const MIN_REFUND_DEADLINE_NS: u64 = 5 * 1_000_000_000; // 5 seconds for integration tests
+pub const MAX_REFUND_WINDOW_MS: u64 = 30 * 24 * 60 * 60 * 1_000;
+pub const MAX_RESTOCKING_FEE_BPS: u32 = 5_000; // 50%
 pub const MAX_METADATA_LEN: usize = 16_384;
+pub const MAX_BASE_URI_LEN: usize = 256;
+pub const BASE_URI_SCHEMES: [&str; 4] = ["https://", "http://", "ipfs://", "ar://"];
+/// Depth bound for allowlist Merkle proofs (2^32 leaves).
+pub const MAX_ALLOWLIST_PROOF_LEN: usize = 32;
+/// Bounds how long anti-snipe extensions can keep an auction open.
+pub const MAX_ANTI_SNIPE_EXTENSIONS: u32 = 20;
 pub const NFT_METADATA_SPEC: &str = "nft-1.0.0";
 pub const NS_PER_MS: u64 = 1_000_000;
 pub const MAX_NEP177_TIMESTAMP_MS: u64 = 1_000_000_000_000_000;
 pub const MAX_BATCH_MINT: u32 = 10;
 pub const MAX_AIRDROP_RECIPIENTS: u32 = 50;
 pub const MAX_BATCH_TRANSFER: u32 = 20;
+pub const MAX_BATCH_LIST: u32 = 20;
+pub const MAX_BATCH_BURN: u32 = 20;
+pub const MAX_BATCH_METADATA_UPDATE: u32 = 20;
+pub const MAX_AUCTION_SETTLE_BATCH: u32 = 20;
+pub const MAX_INDEX_BACKFILL_BATCH: u32 = 100;
+pub const MAX_COLLECTION_OFFERS: u32 = 50;
 pub const MAX_APPROVED_ACCOUNT_IDS_PER_TOKEN: usize = 10;
+pub const MAX_PROVENANCE_ENTRIES: usize = 10;
+pub const MAX_APPEAL_TEXT_LEN: usize = 1_024;
 pub const MAX_APPROVAL_ID_JSON_SAFE: u64 = 9_007_199_254_740_991;
 
 pub const DEFAULT_CALLBACK_GAS: u64 = 50;
@@ -41,4 +59,6 @@ pub const DEFAULT_RESOLVE_PURCHASE_GAS: u64 = 125;
 pub const MAX_RESOLVE_PURCHASE_GAS: u64 = 200;
 pub const GAS_NEAR_WITHDRAW_TGAS: u64 = 15;
 pub const GAS_UNWRAP_CALLBACK_TGAS: u64 = 20;
+pub const GAS_FT_TRANSFER_TGAS: u64 = 10;
+pub const GAS_FT_PAYOUT_CALLBACK_TGAS: u64 = 10;
 pub const GAS_MIGRATE_TGAS: u64 = 200;
@@ -39,6 +39,21 @@ impl Contract {
                 self.unban_collection(actor_id, &app_id, &collection_id)?;
                 Ok(Value::Null)
             }
+            Action::SubmitBanAppeal {
+                collection_id,
+                reason,
+            } => {
+                self.submit_ban_appeal(actor_id, &collection_id, reason)?;
+                Ok(Value::Null)
+            }
+            Action::ResolveBanAppeal {
+                collection_id,
+                uphold,
+                note,
+            } => {
+                self.resolve_ban_appeal(actor_id, &collection_id, uphold, note)?;
+                Ok(Value::Null)
+            }
             _ => unreachable!("dispatch_admin called with non-admin action"),
         }
     }
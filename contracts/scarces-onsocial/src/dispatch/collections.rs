@@ -59,6 +59,14 @@ impl Contract {
                 self.resume_collection(actor_id, &collection_id)?;
                 Ok(Value::Null)
             }
+            Action::FreezeCollection { collection_id } => {
+                self.freeze_collection(actor_id, &collection_id)?;
+                Ok(Value::Null)
+            }
+            Action::RevealCollection { collection_id } => {
+                self.reveal_collection(actor_id, &collection_id)?;
+                Ok(Value::Null)
+            }
             Action::SetAllowlist {
                 collection_id,
                 entries,
@@ -88,10 +96,25 @@ impl Contract {
                 self.set_collection_app_metadata(actor_id, &app_id, &collection_id, metadata)?;
                 Ok(Value::Null)
             }
+            Action::BatchUpdateTokenMetadata {
+                collection_id,
+                updates,
+            } => {
+                let results =
+                    self.batch_update_token_metadata(actor_id, &collection_id, updates)?;
+                Ok(Value::Array(results))
+            }
             Action::WithdrawUnclaimedRefunds { collection_id } => {
                 self.withdraw_unclaimed_refunds(actor_id, &collection_id)?;
                 Ok(Value::Null)
             }
+            Action::ReleaseRefundEscrow {
+                collection_id,
+                token_ids,
+            } => {
+                self.release_refund_escrow(actor_id, &collection_id, token_ids)?;
+                Ok(Value::Null)
+            }
             _ => unreachable!("dispatch_collections called with non-collection action"),
         }
     }
@@ -24,6 +24,7 @@ impl Contract {
             | Action::RevokeScarce { .. }
             | Action::RevokeAllScarce { .. }
             | Action::BurnScarce { .. }
+            | Action::BatchBurn { .. }
             | Action::RenewToken { .. }
             | Action::RevokeToken { .. }
             | Action::RedeemToken { .. }
@@ -36,16 +37,19 @@ impl Contract {
             | Action::AirdropFromCollection { .. }
             | Action::DeleteCollection { .. }
             | Action::PauseCollection { .. }
+            | Action::FreezeCollection { .. }
+            | Action::RevealCollection { .. }
             | Action::ResumeCollection { .. }
             | Action::SetAllowlist { .. }
             | Action::RemoveFromAllowlist { .. }
             | Action::SetCollectionMetadata { .. }
             | Action::SetCollectionAppMetadata { .. }
-            | Action::WithdrawUnclaimedRefunds { .. } => {
-                self.dispatch_collections(action, actor_id)
-            }
+            | Action::BatchUpdateTokenMetadata { .. }
+            | Action::WithdrawUnclaimedRefunds { .. }
+            | Action::ReleaseRefundEscrow { .. } => self.dispatch_collections(action, actor_id),
 
             Action::ListNativeScarce { .. }
+            | Action::BatchListNativeScarce { .. }
             | Action::DelistNativeScarce { .. }
             | Action::ListNativeScarceAuction { .. }
             | Action::SettleAuction { .. }
@@ -56,7 +60,8 @@ impl Contract {
             Action::AcceptOffer { .. }
             | Action::CancelOffer { .. }
             | Action::AcceptCollectionOffer { .. }
-            | Action::CancelCollectionOffer { .. } => self.dispatch_offers(action, actor_id),
+            | Action::CancelCollectionOffer { .. }
+            | Action::MintAndAcceptCollectionOffer { .. } => self.dispatch_offers(action, actor_id),
 
             Action::CreateLazyListing { .. }
             | Action::CancelLazyListing { .. }
@@ -77,6 +82,7 @@ impl Contract {
             | Action::RegisterApp { .. } => self.dispatch_payments(action, actor_id),
 
             Action::StorageWithdraw
+            | Action::ClaimFtPayout { .. }
             | Action::WithdrawAppPool { .. }
             | Action::WithdrawPlatformStorage { .. }
             | Action::SetSpendingCap { .. } => self.dispatch_withdrawals(action, actor_id),
@@ -86,7 +92,9 @@ impl Contract {
             | Action::AddModerator { .. }
             | Action::RemoveModerator { .. }
             | Action::BanCollection { .. }
-            | Action::UnbanCollection { .. } => self.dispatch_admin(action, actor_id),
+            | Action::UnbanCollection { .. }
+            | Action::SubmitBanAppeal { .. }
+            | Action::ResolveBanAppeal { .. } => self.dispatch_admin(action, actor_id),
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::*;
-use near_sdk::serde_json::Value;
+use near_sdk::serde_json::{Value, json};
 
 impl Contract {
     pub(super) fn dispatch_offers(
@@ -28,6 +28,17 @@ impl Contract {
                 self.cancel_collection_offer(actor_id, &collection_id)?;
                 Ok(Value::Null)
             }
+            Action::MintAndAcceptCollectionOffer {
+                collection_id,
+                buyer_id,
+            } => {
+                let (token_id, buyer_id) = self.mint_and_accept_collection_offer(
+                    actor_id,
+                    &collection_id,
+                    buyer_id.as_ref(),
+                )?;
+                Ok(json!({ "token_id": token_id, "buyer_id": buyer_id }))
+            }
             _ => unreachable!("dispatch_offers called with non-offer action"),
         }
     }
@@ -1,3 +1,4 @@
+use crate::collections::CollectionPurchaseError;
 use crate::*;
 use near_sdk::serde_json::Value;
 
@@ -12,16 +13,39 @@ impl Contract {
                 collection_id,
                 quantity,
                 max_price_per_token,
+                allowlist_proof,
+                refund_if_exhausted,
             } => {
                 let deposit = core::mem::take(&mut self.pending_attached_balance);
-                self.purchase_from_collection(
+                match self.purchase_from_collection(
                     actor_id,
-                    collection_id,
+                    collection_id.clone(),
                     quantity,
                     max_price_per_token,
+                    allowlist_proof,
                     deposit,
-                )?;
-                Ok(Value::Null)
+                ) {
+                    Ok(()) => Ok(Value::Null),
+                    // Opted-in buyers settle a sold-out purchase as a full refund instead of a revert.
+                    Err(CollectionPurchaseError::Exhausted { reason, .. })
+                        if refund_if_exhausted =>
+                    {
+                        self.pending_attached_balance += deposit;
+                        events::emit_mint_failed_refund(
+                            actor_id,
+                            &collection_id,
+                            quantity,
+                            deposit,
+                            reason,
+                        );
+                        Ok(near_sdk::serde_json::json!({
+                            "minted": 0,
+                            "refunded": U128(deposit),
+                            "reason": reason,
+                        }))
+                    }
+                    Err(e) => Err(e.into()),
+                }
             }
             Action::PurchaseLazyListing { listing_id } => {
                 let deposit = core::mem::take(&mut self.pending_attached_balance);
@@ -16,6 +16,10 @@ impl Contract {
                 self.list_native_scarce(actor_id, &token_id, price, expires_at)?;
                 Ok(Value::Null)
             }
+            Action::BatchListNativeScarce { listings } => {
+                let sale_ids = self.batch_list_native_scarce(actor_id, listings)?;
+                Ok(near_sdk::serde_json::json!(sale_ids))
+            }
             Action::DelistNativeScarce { token_id } => {
                 self.delist_native_scarce(actor_id, &token_id)?;
                 Ok(Value::Null)
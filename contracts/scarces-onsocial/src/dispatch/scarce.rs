@@ -17,6 +17,7 @@ impl Contract {
                 token_id,
                 memo,
             } => {
+                self.check_royalty_transfer(actor_id, &receiver_id, &token_id)?;
                 self.transfer(actor_id, &receiver_id, &token_id, None, memo)?;
                 Ok(Value::Null)
             }
@@ -47,18 +48,11 @@ impl Contract {
                 token_id,
                 collection_id,
             } => {
-                match collection_id {
-                    Some(cid) => self.burn_scarce(actor_id, &token_id, &cid)?,
-                    None => {
-                        // Storage key invariant: "s" namespace maps to standalone tokens and must route to standalone burn.
-                        let cid = crate::collection_id_from_token_id(&token_id);
-                        if cid.is_empty() || cid == "s" {
-                            self.burn_standalone(actor_id, &token_id)?
-                        } else {
-                            self.burn_scarce(actor_id, &token_id, cid)?
-                        }
-                    }
-                }
+                self.burn_token(actor_id, &token_id, collection_id.as_deref())?;
+                Ok(Value::Null)
+            }
+            Action::BatchBurn { token_ids } => {
+                self.batch_burn(actor_id, token_ids)?;
                 Ok(Value::Null)
             }
             Action::RenewToken {
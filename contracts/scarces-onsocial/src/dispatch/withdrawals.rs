@@ -12,6 +12,10 @@ impl Contract {
                 self.storage_withdraw(actor_id)?;
                 Ok(Value::Null)
             }
+            Action::ClaimFtPayout { ft_contract_id } => {
+                self.claim_ft_payout(actor_id, &ft_contract_id)?;
+                Ok(Value::Null)
+            }
             Action::WithdrawAppPool { app_id, amount } => {
                 self.withdraw_app_pool(actor_id, &app_id, amount)?;
                 Ok(Value::Null)
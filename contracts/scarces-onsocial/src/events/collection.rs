@@ -48,6 +48,22 @@ pub fn emit_collection_purchase(e: &CollectionPurchase) {
         .emit();
 }
 
+pub fn emit_mint_failed_refund(
+    buyer_id: &AccountId,
+    collection_id: &str,
+    quantity: u32,
+    refunded: u128,
+    reason: &str,
+) {
+    EventBuilder::new(COLLECTION, "mint_failed_refund", buyer_id)
+        .field("buyer_id", buyer_id)
+        .field("collection_id", collection_id)
+        .field("quantity", quantity)
+        .field("refunded", refunded)
+        .field("reason", reason)
+        .emit();
+}
+
 pub fn emit_collection_metadata_update(actor_id: &AccountId, collection_id: &str) {
     EventBuilder::new(COLLECTION, "metadata_update", actor_id)
         .field("actor_id", actor_id)
@@ -131,6 +147,36 @@ pub fn emit_refund_claimed(
         .emit();
 }
 
+pub fn emit_mint_refund_claimed(
+    buyer_id: &AccountId,
+    token_id: &str,
+    collection_id: &str,
+    refund_amount: u128,
+    restocking_fee: u128,
+    restocked: bool,
+) {
+    EventBuilder::new(COLLECTION, "mint_refund_claimed", buyer_id)
+        .field("token_id", token_id)
+        .field("collection_id", collection_id)
+        .field("refund_amount", refund_amount)
+        .field("restocking_fee", restocking_fee)
+        .field("restocked", restocked)
+        .emit();
+}
+
+pub fn emit_refund_escrow_released(
+    actor_id: &AccountId,
+    collection_id: &str,
+    token_ids: &[String],
+    amount: u128,
+) {
+    EventBuilder::new(COLLECTION, "refund_escrow_released", actor_id)
+        .field("collection_id", collection_id)
+        .field("token_ids", token_ids)
+        .field("amount", amount)
+        .emit();
+}
+
 pub fn emit_refund_pool_withdrawn(actor_id: &AccountId, collection_id: &str, amount: u128) {
     EventBuilder::new(COLLECTION, "refund_pool_withdrawn", actor_id)
         .field("collection_id", collection_id)
@@ -157,6 +203,19 @@ pub fn emit_collection_resumed(actor_id: &AccountId, collection_id: &str) {
         .emit();
 }
 
+pub fn emit_collection_frozen(actor_id: &AccountId, collection_id: &str) {
+    EventBuilder::new(COLLECTION, "freeze", actor_id)
+        .field("collection_id", collection_id)
+        .emit();
+}
+
+pub fn emit_collection_revealed(actor_id: &AccountId, collection_id: &str, minted_count: u32) {
+    EventBuilder::new(COLLECTION, "reveal", actor_id)
+        .field("collection_id", collection_id)
+        .field("minted_count", minted_count)
+        .emit();
+}
+
 pub fn emit_collection_banned(app_owner: &AccountId, collection_id: &str, reason: Option<&str>) {
     EventBuilder::new(COLLECTION, "ban", app_owner)
         .field("collection_id", collection_id)
@@ -164,6 +223,26 @@ pub fn emit_collection_banned(app_owner: &AccountId, collection_id: &str, reason
         .emit();
 }
 
+pub fn emit_ban_appeal_submitted(creator_id: &AccountId, app_id: &AccountId, collection_id: &str) {
+    EventBuilder::new(COLLECTION, "ban_appeal_submitted", creator_id)
+        .field("app_id", app_id)
+        .field("collection_id", collection_id)
+        .emit();
+}
+
+pub fn emit_ban_appeal_resolved(
+    moderator_id: &AccountId,
+    collection_id: &str,
+    uphold: bool,
+    note: Option<&str>,
+) {
+    EventBuilder::new(COLLECTION, "ban_appeal_resolved", moderator_id)
+        .field("collection_id", collection_id)
+        .field("uphold", uphold)
+        .field_opt("note", note)
+        .emit();
+}
+
 pub fn emit_collection_unbanned(app_owner: &AccountId, collection_id: &str) {
     EventBuilder::new(COLLECTION, "unban", app_owner)
         .field("collection_id", collection_id)
@@ -1,4 +1,5 @@
 use near_sdk::AccountId;
+use near_sdk::json_types::U128;
 
 use super::CONTRACT;
 use super::builder::EventBuilder;
@@ -66,6 +67,54 @@ pub fn emit_approved_nft_contract_added(owner_id: &AccountId, contract_id: &Acco
         .emit();
 }
 
+pub fn emit_payment_token_added(owner_id: &AccountId, token_contract_id: &AccountId) {
+    EventBuilder::new(CONTRACT, "payment_token_added", owner_id)
+        .field("token_contract_id", token_contract_id)
+        .emit();
+}
+
+pub fn emit_payment_token_removed(owner_id: &AccountId, token_contract_id: &AccountId) {
+    EventBuilder::new(CONTRACT, "payment_token_removed", owner_id)
+        .field("token_contract_id", token_contract_id)
+        .emit();
+}
+
+pub fn emit_known_marketplace_set(
+    owner_id: &AccountId,
+    marketplace_id: &AccountId,
+    respects_royalties: bool,
+) {
+    EventBuilder::new(CONTRACT, "known_marketplace_set", owner_id)
+        .field("marketplace_id", marketplace_id)
+        .field("respects_royalties", respects_royalties)
+        .emit();
+}
+
+pub fn emit_known_marketplace_removed(owner_id: &AccountId, marketplace_id: &AccountId) {
+    EventBuilder::new(CONTRACT, "known_marketplace_removed", owner_id)
+        .field("marketplace_id", marketplace_id)
+        .emit();
+}
+
+pub fn emit_price_bounds_set(
+    owner_id: &AccountId,
+    nft_contract_id: &AccountId,
+    min: Option<U128>,
+    max: Option<U128>,
+) {
+    EventBuilder::new(CONTRACT, "price_bounds_set", owner_id)
+        .field("nft_contract_id", nft_contract_id)
+        .field_opt("min", min)
+        .field_opt("max", max)
+        .emit();
+}
+
+pub fn emit_price_bounds_cleared(owner_id: &AccountId, nft_contract_id: &AccountId) {
+    EventBuilder::new(CONTRACT, "price_bounds_cleared", owner_id)
+        .field("nft_contract_id", nft_contract_id)
+        .emit();
+}
+
 pub fn emit_approved_nft_contract_removed(owner_id: &AccountId, contract_id: &AccountId) {
     EventBuilder::new(CONTRACT, "approved_nft_contract_removed", owner_id)
         .field("contract_id", contract_id)
@@ -18,6 +18,7 @@ pub fn emit_lazy_listing_purchased(
     listing_id: &str,
     token_id: &str,
     price: u128,
+    payment_token: Option<&AccountId>,
     result: &crate::fees::PrimarySaleResult,
 ) {
     nep171::emit_mint(buyer_id.as_str(), &[token_id.to_string()], None);
@@ -27,6 +28,7 @@ pub fn emit_lazy_listing_purchased(
         .field("listing_id", listing_id)
         .field("token_id", token_id)
         .field("price", price)
+        .field_opt("payment_token", payment_token)
         .field("creator_payment", result.creator_payment)
         .field("marketplace_fee", result.revenue)
         .field("app_pool_amount", result.app_pool_amount)
@@ -42,8 +44,8 @@ pub fn emit_lazy_listing_cancelled(creator_id: &AccountId, listing_id: &str) {
         .emit();
 }
 
-pub fn emit_lazy_listing_expired(creator_id: &AccountId, listing_id: &str) {
-    EventBuilder::new(LAZY_LISTING, "expired", creator_id)
+pub fn emit_lazy_listing_expired_cleaned(creator_id: &AccountId, listing_id: &str) {
+    EventBuilder::new(LAZY_LISTING, "expired_cleaned", creator_id)
         .field("creator_id", creator_id)
         .field("listing_id", listing_id)
         .emit();
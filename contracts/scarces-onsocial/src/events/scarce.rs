@@ -38,8 +38,11 @@ pub fn emit_scarce_delist(
         .emit();
 }
 
+/// Sole record of a live sale's price changing; indexers build price
+/// history from these rows plus the `list` / `list_native` opening price.
 pub fn emit_scarce_update_price(
     owner_id: &AccountId,
+    sale_id: &str,
     scarce_contract_id: &AccountId,
     token_id: &str,
     old_price: U128,
@@ -47,6 +50,7 @@ pub fn emit_scarce_update_price(
 ) {
     EventBuilder::new(SCARCE, "update_price", owner_id)
         .field("owner_id", owner_id)
+        .field("sale_id", sale_id)
         .field("scarce_contract_id", scarce_contract_id)
         .field("token_id", token_id)
         .field("old_price", old_price)
@@ -139,6 +143,14 @@ pub fn emit_native_scarce_delisted(owner_id: &AccountId, token_id: &str) {
         .emit();
 }
 
+pub fn emit_sale_expired(owner_id: &AccountId, scarce_contract_id: &AccountId, token_id: &str) {
+    EventBuilder::new(SCARCE, "sale_expired", owner_id)
+        .field("owner_id", owner_id)
+        .field("scarce_contract_id", scarce_contract_id)
+        .field("token_id", token_id)
+        .emit();
+}
+
 pub fn emit_auto_delisted(token_id: &str, owner_id: &AccountId, reason: &str) {
     EventBuilder::new(SCARCE, "auto_delist", owner_id)
         .field("token_id", token_id)
@@ -203,6 +215,14 @@ pub fn emit_token_redeemed(
         .emit();
 }
 
+pub fn emit_token_metadata_updated(actor_id: &AccountId, token_id: &str, collection_id: &str) {
+    nep171::emit_metadata_update(&[token_id]);
+    EventBuilder::new(SCARCE, "metadata_update", actor_id)
+        .field("token_id", token_id)
+        .field("collection_id", collection_id)
+        .emit();
+}
+
 pub fn emit_scarce_burned(owner_id: &AccountId, token_id: &str, collection_id: Option<&str>) {
     nep171::emit_burn(owner_id.as_str(), &[token_id], None, None);
     EventBuilder::new(SCARCE, "burn", owner_id)
@@ -256,6 +276,7 @@ pub fn emit_auction_created(
         .field_opt("auction_duration_ns", auction.auction_duration_ns)
         .field("min_bid_increment", auction.min_bid_increment)
         .field("anti_snipe_extension_ns", auction.anti_snipe_extension_ns)
+        .field("anti_snipe_window_ns", auction.anti_snipe_window_ns)
         .emit();
 }
 
@@ -275,6 +296,20 @@ pub fn emit_auction_bid(
         .emit();
 }
 
+pub fn emit_auction_extended(
+    bidder: &AccountId,
+    token_id: &str,
+    new_expires_at: u64,
+    extension_count: u32,
+) {
+    EventBuilder::new(SCARCE, "auction_extended", bidder)
+        .field("bidder", bidder)
+        .field("token_id", token_id)
+        .field("new_expires_at", new_expires_at)
+        .field("extension_count", extension_count)
+        .emit();
+}
+
 pub fn emit_auction_settled(
     winner_id: &AccountId,
     seller_id: &AccountId,
@@ -295,6 +330,22 @@ pub fn emit_auction_settled(
         .emit();
 }
 
+pub fn emit_auction_unsold(
+    seller_id: &AccountId,
+    token_id: &str,
+    reserve_price: U128,
+    highest_bid: u128,
+    refunded_bidder: Option<&AccountId>,
+) {
+    EventBuilder::new(SCARCE, "auction_unsold", seller_id)
+        .field("seller_id", seller_id)
+        .field("token_id", token_id)
+        .field("reserve_price", reserve_price)
+        .field("highest_bid", highest_bid)
+        .field_opt("refunded_bidder", refunded_bidder)
+        .emit();
+}
+
 pub fn emit_auction_cancelled(actor_id: &AccountId, token_id: &str, reason: &str) {
     EventBuilder::new(SCARCE, "auction_cancelled", actor_id)
         .field("actor_id", actor_id)
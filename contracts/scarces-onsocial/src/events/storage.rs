@@ -71,3 +71,25 @@ pub fn emit_wnear_unwrap_failed(account_id: &AccountId, amount: u128) {
         .field("amount", amount)
         .emit();
 }
+
+pub fn emit_ft_payout_failed(
+    account_id: &AccountId,
+    ft_contract_id: &AccountId,
+    amount: u128,
+    claimable: u128,
+) {
+    EventBuilder::new(STORAGE, "ft_payout_failed", account_id)
+        .field("account_id", account_id)
+        .field("ft_contract_id", ft_contract_id)
+        .field("amount", amount)
+        .field("claimable", claimable)
+        .emit();
+}
+
+pub fn emit_ft_payout_claimed(account_id: &AccountId, ft_contract_id: &AccountId, amount: u128) {
+    EventBuilder::new(STORAGE, "ft_payout_claimed", account_id)
+        .field("account_id", account_id)
+        .field("ft_contract_id", ft_contract_id)
+        .field("amount", amount)
+        .emit();
+}
@@ -121,6 +121,11 @@ pub trait ExtWrap {
     fn near_withdraw(&mut self, amount: U128);
 }
 
+#[ext_contract(ext_ft)]
+pub trait ExtFungibleToken {
+    fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>);
+}
+
 #[ext_contract(ext_scarce_approval_receiver)]
 pub trait ExtScarceApprovalReceiver {
     fn nft_on_approve(
@@ -0,0 +1,74 @@
+use crate::*;
+
+impl Contract {
+    /// `ft_transfer` that credits `receiver_id` a claimable balance if the
+    /// transfer fails (e.g. the receiver is not registered on the token).
+    pub(crate) fn ft_payout(
+        ft_contract_id: &AccountId,
+        receiver_id: AccountId,
+        amount: u128,
+    ) -> Promise {
+        external::ext_ft::ext(ft_contract_id.clone())
+            .with_attached_deposit(NearToken::from_yoctonear(1))
+            .with_static_gas(Gas::from_tgas(GAS_FT_TRANSFER_TGAS))
+            .ft_transfer(receiver_id.clone(), U128(amount), None)
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(Gas::from_tgas(GAS_FT_PAYOUT_CALLBACK_TGAS))
+                    .on_ft_payout_resolved(ft_contract_id.clone(), receiver_id, U128(amount)),
+            )
+    }
+
+    pub(crate) fn ft_claimable_key(ft_contract_id: &AccountId, account_id: &AccountId) -> String {
+        format!("{}:{}", ft_contract_id, account_id)
+    }
+
+    pub(crate) fn claim_ft_payout(
+        &mut self,
+        actor_id: &AccountId,
+        ft_contract_id: &AccountId,
+    ) -> Result<(), MarketplaceError> {
+        let key = Self::ft_claimable_key(ft_contract_id, actor_id);
+        let amount = self
+            .ft_claimable
+            .remove(&key)
+            .map(|a| a.0)
+            .filter(|a| *a > 0)
+            .ok_or_else(|| MarketplaceError::NotFound("No claimable FT balance".into()))?;
+
+        let _ = Self::ft_payout(ft_contract_id, actor_id.clone(), amount);
+        events::emit_ft_payout_claimed(actor_id, ft_contract_id, amount);
+        Ok(())
+    }
+}
+
+#[near]
+impl Contract {
+    // Cross-contract guarantee: a failed payout stays owed to the receiver instead of sitting unowned in the contract.
+    #[private]
+    pub fn on_ft_payout_resolved(
+        &mut self,
+        ft_contract_id: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+    ) {
+        if env::promise_results_count() == 1 && env::promise_result_checked(0, 64).is_ok() {
+            return;
+        }
+
+        let key = Self::ft_claimable_key(&ft_contract_id, &receiver_id);
+        let owed = self.ft_claimable.get(&key).map(|a| a.0).unwrap_or(0);
+        let claimable = owed.saturating_add(amount.0);
+        self.ft_claimable.insert(key, U128(claimable));
+
+        events::emit_ft_payout_failed(&receiver_id, &ft_contract_id, amount.0, claimable);
+    }
+
+    /// FT owed to `account_id` from payouts that could not be delivered.
+    pub fn get_ft_claimable(&self, ft_contract_id: AccountId, account_id: AccountId) -> U128 {
+        self.ft_claimable
+            .get(&Self::ft_claimable_key(&ft_contract_id, &account_id))
+            .copied()
+            .unwrap_or(U128(0))
+    }
+}
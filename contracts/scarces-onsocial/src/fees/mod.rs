@@ -1,8 +1,9 @@
+mod ft_payout;
 mod pricing;
-mod routing;
+pub(crate) mod routing;
 pub mod types;
 mod views;
 
-pub(crate) use pricing::{compute_dutch_price, refund_excess};
-pub(crate) use types::PrimarySaleResult;
-pub use types::{FeeConfig, FeeConfigUpdate};
+pub(crate) use pricing::{compute_dutch_price, dutch_price, refund_excess};
+pub(crate) use types::{CreatorPayee, PrimarySaleResult};
+pub use types::{EarningsTotals, FeeConfig, FeeConfigUpdate};
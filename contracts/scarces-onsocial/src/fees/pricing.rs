@@ -1,17 +1,27 @@
 use crate::*;
 pub(crate) fn compute_dutch_price(collection: &LazyCollection) -> u128 {
-    let floor = collection.price_near.0;
-    let start_price = match collection.start_price {
+    dutch_price(
+        collection.price_near.0,
+        collection.start_price,
+        collection.start_time,
+        collection.end_time,
+    )
+}
+
+// Pricing invariant: declines linearly from `start_price` to `floor` over `[start, end]`;
+// any missing bound or a start price at or below the floor yields the floor.
+pub(crate) fn dutch_price(
+    floor: u128,
+    start_price: Option<U128>,
+    start: Option<u64>,
+    end: Option<u64>,
+) -> u128 {
+    let start_price = match start_price {
         Some(sp) if sp.0 > floor => sp.0,
         _ => return floor,
     };
-    let start = match collection.start_time {
-        Some(t) => t,
-        None => return floor,
-    };
-    let end = match collection.end_time {
-        Some(t) => t,
-        None => return floor,
+    let (Some(start), Some(end)) = (start, end) else {
+        return floor;
     };
     let now = env::block_timestamp();
     if now <= start {
@@ -1,4 +1,4 @@
-use super::PrimarySaleResult;
+use super::{CreatorPayee, PrimarySaleResult};
 use crate::*;
 
 impl Contract {
@@ -7,12 +7,34 @@ impl Contract {
         &mut self,
         price: u128,
         bytes_used: u64,
-        creator_id: &AccountId,
+        creator: CreatorPayee<'_>,
         payer_id: &AccountId,
         app_id: Option<&AccountId>,
     ) -> Result<PrimarySaleResult, MarketplaceError> {
-        self.charge_storage_waterfall(payer_id, bytes_used, app_id)?;
+        self.charge_buyer_storage(payer_id, bytes_used, app_id)?;
 
+        // Refundable mints escrow the whole price; fees route on release.
+        if creator.hold {
+            return Ok(PrimarySaleResult {
+                revenue: 0,
+                app_pool_amount: 0,
+                app_commission: 0,
+                creator_payment: 0,
+                app_id: app_id.cloned(),
+            });
+        }
+
+        Ok(self.distribute_primary_revenue(price, creator, app_id))
+    }
+
+    /// Pays out NEAR primary revenue: marketplace fee, app commission, then
+    /// the creator split.
+    pub(crate) fn distribute_primary_revenue(
+        &mut self,
+        price: u128,
+        creator: CreatorPayee<'_>,
+        app_id: Option<&AccountId>,
+    ) -> PrimarySaleResult {
         if price > 0 {
             let (rev, app_amt) = self.route_fee(price, app_id);
 
@@ -29,36 +51,85 @@ impl Contract {
             let total_deductions = rev + app_amt + app_commission;
             let creator_payment = price.saturating_sub(total_deductions);
             if creator_payment > 0 {
-                let _ = Promise::new(creator_id.clone())
-                    .transfer(NearToken::from_yoctonear(creator_payment));
-            } else {
+                for (receiver, amount) in
+                    split_creator_payment(creator_payment, creator.creator_id, creator.shares)
+                {
+                    self.record_creator_earnings(&receiver, creator.collection_id, amount, 0);
+                    let _ = Promise::new(receiver).transfer(NearToken::from_yoctonear(amount));
+                }
+            } else if creator_payment == 0 {
                 env::log_str(&format!(
                     "WARN: creator '{}' payment is 0 (price={}, fees={}, app_commission={})",
-                    creator_id,
+                    creator.creator_id,
                     price,
                     rev + app_amt,
                     app_commission
                 ));
             }
 
-            Ok(PrimarySaleResult {
+            PrimarySaleResult {
                 revenue: rev,
                 app_pool_amount: app_amt,
                 app_commission,
                 creator_payment,
                 app_id: app_id.cloned(),
-            })
+            }
         } else {
-            Ok(PrimarySaleResult {
+            PrimarySaleResult {
                 revenue: 0,
                 app_pool_amount: 0,
                 app_commission: 0,
                 creator_payment: 0,
                 app_id: app_id.cloned(),
-            })
+            }
         }
     }
 
+    /// Primary sale paid in an accepted FT. NEAR-denominated pools can't hold
+    /// FT, so the whole marketplace fee goes to `fee_recipient`; storage is
+    /// still charged through the NEAR waterfall.
+    pub(crate) fn route_ft_primary_sale(
+        &mut self,
+        ft_contract_id: &AccountId,
+        price: u128,
+        bytes_used: u64,
+        creator: CreatorPayee<'_>,
+        payer_id: &AccountId,
+        app_id: Option<&AccountId>,
+    ) -> Result<PrimarySaleResult, MarketplaceError> {
+        self.charge_buyer_storage(payer_id, bytes_used, app_id)?;
+
+        let fee = (price * self.fee_config.total_fee_bps as u128) / BASIS_POINTS as u128;
+        let app_commission = self.calculate_app_commission(price, app_id);
+        let creator_payment = price.saturating_sub(fee + app_commission);
+
+        let app_owner = app_id
+            .and_then(|aid| self.app_pools.get(aid))
+            .map(|pool| pool.owner_id.clone());
+        let mut payments = vec![(self.fee_recipient.clone(), fee)];
+        if let Some(owner) = app_owner {
+            payments.push((owner, app_commission));
+        }
+        payments.extend(split_creator_payment(
+            creator_payment,
+            creator.creator_id,
+            creator.shares,
+        ));
+        for (receiver, amount) in payments {
+            if amount > 0 {
+                let _ = Self::ft_payout(ft_contract_id, receiver, amount);
+            }
+        }
+
+        Ok(PrimarySaleResult {
+            revenue: fee,
+            app_pool_amount: 0,
+            app_commission,
+            creator_payment,
+            app_id: app_id.cloned(),
+        })
+    }
+
     // Cross-function assumption: caller validates sale eligibility (including expiry) before settlement.
     pub(crate) fn settle_secondary_sale(
         &mut self,
@@ -78,7 +149,13 @@ impl Contract {
 
         if let Some(ref token) = token_clone {
             let payout = self.compute_payout(token, seller_id, amount_after_fee, Some(10))?;
-            self.distribute_payout(&payout, amount_after_fee, seller_id);
+            if self.distribute_payout(&payout, amount_after_fee, seller_id) {
+                let collection_id = collection_id_from_token_id(token_id);
+                let collection_id = (!collection_id.is_empty()).then_some(collection_id);
+                for (account, amount) in royalty_shares(token, amount_after_fee) {
+                    self.record_creator_earnings(&account, collection_id, 0, amount);
+                }
+            }
         } else if amount_after_fee > 0 {
             let _ = Promise::new(seller_id.clone())
                 .transfer(NearToken::from_yoctonear(amount_after_fee));
@@ -161,25 +238,26 @@ impl Contract {
     }
 
     // Token accounting guarantee: unallocated payout remainder is sent to fee recipient; zero-total payout falls back to seller.
+    /// Returns `false` when the payout was invalid and everything went to `fallback_recipient`.
     pub(crate) fn distribute_payout(
         &self,
         payout: &Payout,
         amount_after_fee: u128,
         fallback_recipient: &AccountId,
-    ) {
+    ) -> bool {
         let Some(total_payout) = Self::payout_total(payout) else {
             if amount_after_fee > 0 {
                 let _ = Promise::new(fallback_recipient.clone())
                     .transfer(NearToken::from_yoctonear(amount_after_fee));
             }
-            return;
+            return false;
         };
         if total_payout > amount_after_fee {
             if amount_after_fee > 0 {
                 let _ = Promise::new(fallback_recipient.clone())
                     .transfer(NearToken::from_yoctonear(amount_after_fee));
             }
-            return;
+            return false;
         }
         if total_payout > 0 {
             let mut actual_distributed: u128 = 0;
@@ -199,5 +277,74 @@ impl Contract {
             let _ = Promise::new(fallback_recipient.clone())
                 .transfer(NearToken::from_yoctonear(amount_after_fee));
         }
+        true
+    }
+
+    /// Adds NEAR paid to `account_id` to its running totals, and to its
+    /// per-collection totals when the payment came from a collection token.
+    pub(crate) fn record_creator_earnings(
+        &mut self,
+        account_id: &AccountId,
+        collection_id: Option<&str>,
+        primary: u128,
+        royalties: u128,
+    ) {
+        if primary == 0 && royalties == 0 {
+            return;
+        }
+        let keys = std::iter::once(account_id.to_string())
+            .chain(collection_id.map(|cid| format!("{}:{}", account_id, cid)));
+        for key in keys {
+            let mut totals = self.creator_earnings.get(&key).cloned().unwrap_or_default();
+            totals.primary.0 = totals.primary.0.saturating_add(primary);
+            totals.royalties.0 = totals.royalties.0.saturating_add(royalties);
+            self.creator_earnings.insert(key, totals);
+        }
+    }
+}
+
+// Token accounting guarantee: matches the royalty entries `compute_payout` builds for the same balance.
+pub(crate) fn royalty_shares(token: &Scarce, balance: u128) -> Vec<(AccountId, u128)> {
+    token
+        .royalty
+        .iter()
+        .flatten()
+        .filter_map(|(account, bps)| {
+            let amount = (primitive_types::U256::from(balance) * primitive_types::U256::from(*bps)
+                / primitive_types::U256::from(10_000u32))
+            .as_u128();
+            (amount > 0).then(|| (account.clone(), amount))
+        })
+        .collect()
+}
+
+// Token accounting guarantee: rounding dust from co-creator shares goes to the primary creator.
+pub(crate) fn split_creator_payment(
+    payment: u128,
+    creator_id: &AccountId,
+    creator_shares: Option<&std::collections::HashMap<AccountId, u32>>,
+) -> Vec<(AccountId, u128)> {
+    let Some(shares) = creator_shares else {
+        return vec![(creator_id.clone(), payment)];
+    };
+
+    let mut splits = Vec::with_capacity(shares.len());
+    let mut distributed: u128 = 0;
+    for (account, bps) in shares.iter() {
+        if account == creator_id {
+            continue;
+        }
+        let amount = (primitive_types::U256::from(payment) * primitive_types::U256::from(*bps)
+            / primitive_types::U256::from(BASIS_POINTS))
+        .as_u128();
+        if amount > 0 {
+            distributed += amount;
+            splits.push((account.clone(), amount));
+        }
+    }
+    let primary = payment.saturating_sub(distributed);
+    if primary > 0 {
+        splits.insert(0, (creator_id.clone(), primary));
     }
+    splits
 }
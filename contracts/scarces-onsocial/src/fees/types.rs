@@ -8,6 +8,22 @@ pub(crate) struct PrimarySaleResult {
     pub app_id: Option<AccountId>,
 }
 
+pub(crate) struct CreatorPayee<'a> {
+    pub creator_id: &'a AccountId,
+    pub shares: Option<&'a std::collections::HashMap<AccountId, u32>>,
+    // Token accounting guarantee: held sales keep the full price in contract balance under refund escrow; fees and creator shares route on release.
+    pub hold: bool,
+    pub collection_id: Option<&'a str>,
+}
+
+/// Cumulative NEAR paid out to one account, overall or for one collection.
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Default)]
+pub struct EarningsTotals {
+    pub primary: U128,
+    pub royalties: U128,
+}
+
 #[near(serializers = [borsh, json])]
 #[derive(Clone)]
 pub struct FeeConfig {
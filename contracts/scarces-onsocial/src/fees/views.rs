@@ -5,6 +5,39 @@ impl Contract {
     pub fn get_platform_storage_balance(&self) -> U128 {
         U128(self.platform_storage_balance)
     }
+
+    /// Cumulative NEAR paid to `creator_id`: primary-sale proceeds net of fees
+    /// (refund-held proceeds count once released) and royalties from secondary
+    /// sales of this contract's tokens. `by_collection` covers collections the
+    /// account created or co-created. FT-priced sales are not included.
+    pub fn get_creator_earnings(&self, creator_id: AccountId) -> Value {
+        let totals = self
+            .creator_earnings
+            .get(creator_id.as_str())
+            .cloned()
+            .unwrap_or_default();
+
+        let mut by_collection = near_sdk::serde_json::Map::new();
+        if let Some(collection_ids) = self.collections_by_creator.get(&creator_id) {
+            for collection_id in collection_ids.iter() {
+                let key = format!("{}:{}", creator_id, collection_id);
+                if let Some(collection_totals) = self.creator_earnings.get(&key) {
+                    by_collection.insert(
+                        collection_id.clone(),
+                        near_sdk::serde_json::json!(collection_totals),
+                    );
+                }
+            }
+        }
+
+        near_sdk::serde_json::json!({
+            "creator_id": creator_id,
+            "primary": totals.primary,
+            "royalties": totals.royalties,
+            "total": U128(totals.primary.0.saturating_add(totals.royalties.0)),
+            "by_collection": by_collection,
+        })
+    }
 }
 
 impl Contract {
@@ -3,7 +3,8 @@ use crate::*;
 
 #[near]
 impl Contract {
-    /// Cross-contract guarantee: only configured wNEAR contract transfers are accepted and unwrapped before balance credit.
+    /// Cross-contract guarantee: configured wNEAR transfers are unwrapped before balance credit;
+    /// accepted payment tokens may only buy FT-priced lazy listings.
     /// Returns unconsumed amount for NEP-141 refund semantics.
     pub fn ft_on_transfer(
         &mut self,
@@ -11,17 +12,18 @@ impl Contract {
         amount: U128,
         msg: String,
     ) -> PromiseOrValue<U128> {
-        let wnear_id = self
-            .wnear_account_id
-            .as_ref()
-            .unwrap_or_else(|| env::panic_str("wNEAR account not configured"));
-
-        near_sdk::require!(
-            env::predecessor_account_id() == *wnear_id,
-            "Only wNEAR accepted"
-        );
+        let token_id = env::predecessor_account_id();
         near_sdk::require!(amount.0 > 0, "Amount must be positive");
 
+        if self.wnear_account_id.as_ref() != Some(&token_id) {
+            near_sdk::require!(
+                self.accepted_payment_tokens.contains(&token_id),
+                "Only wNEAR or accepted payment tokens"
+            );
+            return PromiseOrValue::Value(self.ft_purchase(&token_id, &sender_id, amount.0, &msg));
+        }
+        let wnear_id = &token_id;
+
         let credit_to: AccountId = if msg.is_empty() {
             sender_id
         } else {
@@ -42,6 +44,25 @@ impl Contract {
             .into()
     }
 
+    // Cross-contract guarantee: any error panics so the token contract refunds the full amount.
+    fn ft_purchase(
+        &mut self,
+        ft_contract_id: &AccountId,
+        buyer_id: &AccountId,
+        amount: u128,
+        msg: &str,
+    ) -> U128 {
+        let action: Action = near_sdk::serde_json::from_str(msg)
+            .unwrap_or_else(|_| env::panic_str("Invalid FT purchase msg"));
+        let Action::PurchaseLazyListing { listing_id } = action else {
+            env::panic_str("Only purchase_lazy_listing can be paid in FT");
+        };
+        match self.purchase_lazy_listing_ft(buyer_id, listing_id, ft_contract_id, amount) {
+            Ok((_, unused)) => U128(unused),
+            Err(e) => env::panic_str(&e.to_string()),
+        }
+    }
+
     // Cross-contract guarantee: success consumes all and credits storage balance; failure returns full amount for refund.
     #[private]
     pub fn on_wnear_unwrapped(&mut self, account_id: AccountId, amount: U128) -> U128 {
@@ -9,18 +9,30 @@ impl Contract {
         let LazyListing {
             metadata,
             price,
+            payment_token,
             options:
                 crate::ScarceOptions {
                     royalty,
                     app_id,
                     transferable,
                     burnable,
+                    redeem_start,
+                    redeem_end,
                 },
             expires_at,
+            start_price,
+            decay_start,
+            decay_end,
         } = params;
         let price = price.0;
         crate::validation::validate_token_metadata(&metadata)?;
 
+        if redeem_start.is_some() || redeem_end.is_some() {
+            return Err(MarketplaceError::InvalidInput(
+                "Redemption windows require a redeemable collection".into(),
+            ));
+        }
+
         let metadata_json = near_sdk::serde_json::to_string(&metadata)
             .map_err(|_| MarketplaceError::InternalError("Failed to serialize metadata".into()))?;
         if metadata_json.len() > MAX_METADATA_LEN {
@@ -40,6 +52,16 @@ impl Contract {
             }
         }
 
+        if let Some(token) = payment_token
+            .as_ref()
+            .filter(|t| !self.accepted_payment_tokens.contains(*t))
+        {
+            return Err(MarketplaceError::InvalidInput(format!(
+                "Payment token not accepted: {}",
+                token
+            )));
+        }
+
         if let Some(exp) = expires_at {
             if exp <= env::block_timestamp() {
                 return Err(MarketplaceError::InvalidInput(
@@ -48,6 +70,26 @@ impl Contract {
             }
         }
 
+        if let Some(sp) = start_price {
+            if sp.0 < price {
+                return Err(MarketplaceError::InvalidInput(
+                    "start_price must be at least price (the end price)".into(),
+                ));
+            }
+            match (decay_start, decay_end) {
+                (Some(start), Some(end)) if end > start => {}
+                _ => {
+                    return Err(MarketplaceError::InvalidInput(
+                        "Declining price requires decay_start before decay_end".into(),
+                    ));
+                }
+            }
+        } else if decay_start.is_some() || decay_end.is_some() {
+            return Err(MarketplaceError::InvalidInput(
+                "Decay window requires start_price".into(),
+            ));
+        }
+
         let listing_app_id = app_id.clone();
 
         let merged_royalty = self.merge_royalties(app_id.as_ref(), royalty)?;
@@ -70,6 +112,10 @@ impl Contract {
             burnable,
             expires_at,
             created_at: env::block_timestamp(),
+            payment_token,
+            start_price,
+            decay_start,
+            decay_end,
         };
 
         // Storage/accounting invariant: rollback listing insert if storage charge fails.
@@ -163,18 +209,22 @@ impl Contract {
         listing_id: &str,
         new_price: u128,
     ) -> Result<(), MarketplaceError> {
-        let creator_id = self
+        let listing = self
             .lazy_listings
             .get(listing_id)
-            .ok_or_else(|| MarketplaceError::NotFound("Lazy listing not found".into()))?
-            .creator_id
-            .clone();
+            .ok_or_else(|| MarketplaceError::NotFound("Lazy listing not found".into()))?;
+        let creator_id = listing.creator_id.clone();
 
         if &creator_id != actor_id {
             return Err(MarketplaceError::Unauthorized(
                 "Only the creator can update listing price".into(),
             ));
         }
+        if listing.start_price.is_some_and(|sp| new_price > sp.0) {
+            return Err(MarketplaceError::InvalidInput(
+                "price must not exceed start_price for a declining listing".into(),
+            ));
+        }
 
         let mut listing = self.lazy_listings.remove(listing_id).unwrap();
         let old_price = listing.price.0;
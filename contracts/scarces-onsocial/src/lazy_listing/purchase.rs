@@ -7,6 +7,40 @@ impl Contract {
         listing_id: String,
         deposit: u128,
     ) -> Result<String, MarketplaceError> {
+        match self.execute_lazy_purchase(buyer_id, listing_id, None, deposit) {
+            Ok((token_id, price)) => {
+                self.pending_attached_balance += deposit.saturating_sub(price);
+                Ok(token_id)
+            }
+            Err(e) => {
+                self.pending_attached_balance += deposit;
+                Err(e)
+            }
+        }
+    }
+
+    /// FT counterpart of `purchase_lazy_listing`, reached from `ft_on_transfer`.
+    /// Returns the token ID and the unused part of `amount`.
+    pub(crate) fn purchase_lazy_listing_ft(
+        &mut self,
+        buyer_id: &AccountId,
+        listing_id: String,
+        ft_contract_id: &AccountId,
+        amount: u128,
+    ) -> Result<(String, u128), MarketplaceError> {
+        let (token_id, price) =
+            self.execute_lazy_purchase(buyer_id, listing_id, Some(ft_contract_id), amount)?;
+        Ok((token_id, amount.saturating_sub(price)))
+    }
+
+    // Payment invariant: `paid` is denominated in `payment_token` (NEAR when `None`) and must match the listing.
+    fn execute_lazy_purchase(
+        &mut self,
+        buyer_id: &AccountId,
+        listing_id: String,
+        payment_token: Option<&AccountId>,
+        paid: u128,
+    ) -> Result<(String, u128), MarketplaceError> {
         {
             let listing = self
                 .lazy_listings
@@ -15,24 +49,35 @@ impl Contract {
 
             if let Some(exp) = listing.expires_at {
                 if env::block_timestamp() > exp {
-                    self.pending_attached_balance += deposit;
                     return Err(MarketplaceError::InvalidState(
                         "Lazy listing has expired".into(),
                     ));
                 }
             }
 
-            if deposit < listing.price.0 {
-                self.pending_attached_balance += deposit;
+            if listing.payment_token.as_ref() != payment_token {
+                return Err(MarketplaceError::InvalidInput(
+                    match listing.payment_token.as_ref() {
+                        Some(token) => format!(
+                            "Lazy listing is priced in {}; pay with ft_transfer_call",
+                            token
+                        ),
+                        None => "Lazy listing is priced in NEAR".into(),
+                    },
+                ));
+            }
+
+            let required = listing.current_price();
+            if paid < required {
                 return Err(MarketplaceError::InsufficientDeposit(format!(
                     "Insufficient payment: required {}, got {}",
-                    listing.price.0, deposit
+                    required, paid
                 )));
             }
         }
 
         let listing = self.lazy_listings.remove(&listing_id).unwrap();
-        let price = listing.price.0;
+        let price = listing.current_price();
 
         let creator_id = listing.creator_id.clone();
         let app_id = listing.app_id.clone();
@@ -62,22 +107,27 @@ impl Contract {
             transferable: Some(transferable),
             burnable: Some(burnable),
             paid_price: price,
+            ..Default::default()
         };
-        if let Err(e) = self.mint(token_id.clone(), ctx, metadata, Some(ovr)) {
-            self.pending_attached_balance += deposit;
-            return Err(e);
-        }
+        self.mint(token_id.clone(), ctx, metadata, Some(ovr))?;
 
         let bytes_used = self.storage_usage_flushed().saturating_sub(before);
 
+        let payee = crate::fees::CreatorPayee {
+            creator_id: &creator_id,
+            shares: None,
+            hold: false,
+            collection_id: None,
+        };
+        let routed = match payment_token {
+            Some(ft) => {
+                self.route_ft_primary_sale(ft, price, bytes_used, payee, buyer_id, app_id.as_ref())
+            }
+            None => self.route_primary_sale(price, bytes_used, payee, buyer_id, app_id.as_ref()),
+        };
+
         // State/accounting invariant: rollback mint state if payment routing fails.
-        let result = match self.route_primary_sale(
-            price,
-            bytes_used,
-            &creator_id,
-            buyer_id,
-            app_id.as_ref(),
-        ) {
+        let result = match routed {
             Ok(r) => r,
             Err(e) => {
                 if let Some(app) = self.resolve_token_app_id(&token_id, app_id.as_ref()) {
@@ -85,55 +135,70 @@ impl Contract {
                 }
                 self.scarces_by_id.remove(&token_id);
                 self.remove_token_from_owner(buyer_id, &token_id);
-                self.pending_attached_balance += deposit;
                 return Err(e);
             }
         };
 
-        self.pending_attached_balance += deposit.saturating_sub(price);
-
         events::emit_lazy_listing_purchased(
             buyer_id,
             &creator_id,
             &listing_id,
             &token_id,
             price,
+            payment_token,
             &result,
         );
 
-        Ok(token_id)
+        Ok((token_id, price))
     }
 }
 
 #[near]
 impl Contract {
-    pub fn cleanup_expired_lazy_listings(&mut self, limit: Option<u64>) -> u64 {
+    // Pagination invariant: `limit` bounds records examined, not removed. Removal swaps
+    // the last record into the freed slot, so the cursor only advances past kept records.
+    pub fn cleanup_expired_lazy_listings(
+        &mut self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> LazyListingCleanup {
         let now = env::block_timestamp();
-        let limit = limit.unwrap_or(20).min(50) as usize;
-
-        let expired: Vec<(String, LazyListingRecord)> = self
-            .lazy_listings
-            .iter()
-            .filter(|(_, l)| l.expires_at.map(|e| e <= now).unwrap_or(false))
-            .take(limit)
-            .map(|(id, l)| (id.clone(), l.clone()))
-            .collect();
-
-        let mut count = 0u64;
-        for (listing_id, listing) in expired {
+        let limit = limit.unwrap_or(20).min(50);
+
+        let mut index = from_index.unwrap_or(0);
+        let mut cleaned = 0u64;
+        for _ in 0..limit {
             // Execution invariant: stop before low-gas threshold to avoid partial iteration failure.
             if env::prepaid_gas().saturating_sub(env::used_gas()) < near_sdk::Gas::from_tgas(5) {
                 break;
             }
-            let creator_id = listing.creator_id.clone();
-            let app_id = listing.app_id.clone();
+            let Some((listing_id, listing)) = self
+                .lazy_listings
+                .iter()
+                .nth(index as usize)
+                .map(|(id, l)| (id.clone(), l.clone()))
+            else {
+                break;
+            };
+            if listing.expires_at.is_none_or(|e| e > now) {
+                index += 1;
+                continue;
+            }
             let before = self.storage_usage_flushed();
             self.lazy_listings.remove(&listing_id);
             let bytes_freed = before.saturating_sub(self.storage_usage_flushed());
-            self.release_storage_waterfall(&creator_id, bytes_freed, app_id.as_ref());
-            events::emit_lazy_listing_expired(&creator_id, &listing_id);
-            count += 1;
+            self.release_storage_waterfall(
+                &listing.creator_id,
+                bytes_freed,
+                listing.app_id.as_ref(),
+            );
+            events::emit_lazy_listing_expired_cleaned(&listing.creator_id, &listing_id);
+            cleaned += 1;
+        }
+
+        LazyListingCleanup {
+            cleaned,
+            next_index: (index < self.lazy_listings.len() as u64).then_some(index),
         }
-        count
     }
 }
@@ -1,11 +1,16 @@
-use near_sdk::AccountId;
+use near_sdk::borsh::io::{Read, Result};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 use near_sdk::json_types::U128;
-use near_sdk::near;
+use near_sdk::{AccountId, near};
 
+use crate::storage::legacy::read_appended;
 use crate::{ScarceOptions, TokenMetadata};
 
-#[near(serializers = [borsh, json])]
-#[derive(Clone)]
+// Upgrade invariant: fields are only appended; listings created before a
+// trailing field existed still decode (see the `BorshDeserialize` impl).
+#[near(serializers = [json])]
+#[derive(Clone, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
 pub struct LazyListingRecord {
     pub creator_id: AccountId,
     pub metadata: TokenMetadata,
@@ -21,6 +26,71 @@ pub struct LazyListingRecord {
     #[serde(default)]
     pub expires_at: Option<u64>,
     pub created_at: u64,
+    // Cross-contract boundary: `Some` prices the listing in this FT; purchases arrive via `ft_on_transfer`.
+    #[serde(default)]
+    pub payment_token: Option<AccountId>,
+    // Pricing invariant: when set, the price declines from `start_price` to `price` over `[decay_start, decay_end]`.
+    #[serde(default)]
+    pub start_price: Option<U128>,
+    #[serde(default)]
+    pub decay_start: Option<u64>,
+    #[serde(default)]
+    pub decay_end: Option<u64>,
+}
+
+/// `LazyListingRecord` as written before FT pricing and declining prices.
+#[derive(BorshDeserialize)]
+#[borsh(crate = "near_sdk::borsh")]
+struct LazyListingRecordV1 {
+    creator_id: AccountId,
+    metadata: TokenMetadata,
+    price: U128,
+    royalty: Option<std::collections::HashMap<AccountId, u32>>,
+    app_id: Option<AccountId>,
+    transferable: bool,
+    burnable: bool,
+    expires_at: Option<u64>,
+    created_at: u64,
+}
+
+impl BorshDeserialize for LazyListingRecord {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let v1 = LazyListingRecordV1::deserialize_reader(reader)?;
+        Ok(Self {
+            creator_id: v1.creator_id,
+            metadata: v1.metadata,
+            price: v1.price,
+            royalty: v1.royalty,
+            app_id: v1.app_id,
+            transferable: v1.transferable,
+            burnable: v1.burnable,
+            expires_at: v1.expires_at,
+            created_at: v1.created_at,
+            payment_token: read_appended(reader, None)?,
+            start_price: read_appended(reader, None)?,
+            decay_start: read_appended(reader, None)?,
+            decay_end: read_appended(reader, None)?,
+        })
+    }
+}
+
+impl LazyListingRecord {
+    pub(crate) fn current_price(&self) -> u128 {
+        crate::fees::dutch_price(
+            self.price.0,
+            self.start_price,
+            self.decay_start,
+            self.decay_end,
+        )
+    }
+}
+
+/// Result of one `cleanup_expired_lazy_listings` pass.
+#[near(serializers = [json])]
+pub struct LazyListingCleanup {
+    pub cleaned: u64,
+    /// Pass back as `from_index` to continue; `None` once the scan reached the end.
+    pub next_index: Option<u64>,
 }
 
 #[near(serializers = [json])]
@@ -28,8 +98,17 @@ pub struct LazyListingRecord {
 pub struct LazyListing {
     pub metadata: TokenMetadata,
     pub price: U128,
+    #[serde(default)]
+    pub payment_token: Option<AccountId>,
     #[serde(flatten)]
     pub options: ScarceOptions,
     #[serde(default)]
     pub expires_at: Option<u64>,
+    /// Declining-price start; `price` is the end price reached at `decay_end` (ns).
+    #[serde(default)]
+    pub start_price: Option<U128>,
+    #[serde(default)]
+    pub decay_start: Option<u64>,
+    #[serde(default)]
+    pub decay_end: Option<u64>,
 }
@@ -6,6 +6,12 @@ impl Contract {
         self.lazy_listings.get(&listing_id).cloned()
     }
 
+    pub fn get_lazy_listing_price(&self, listing_id: String) -> Option<U128> {
+        self.lazy_listings
+            .get(&listing_id)
+            .map(|l| U128(l.current_price()))
+    }
+
     pub fn get_lazy_listings_by_creator(
         &self,
         creator_id: AccountId,
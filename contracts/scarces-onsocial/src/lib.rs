@@ -34,21 +34,22 @@ mod upgrade;
 mod tests;
 
 pub use admin::ContractInfo;
-pub use app_pool::{AppConfig, AppPool};
+pub use app_pool::{AppConfig, AppPool, AppealStatus, BanAppeal};
 pub use collections::{
-    AllowlistEntry, CollectionConfig, CollectionProgress, CollectionStats, LazyCollection,
-    MintMode, RevocationMode,
+    AllowlistEntry, AllowlistProof, CollectionConfig, CollectionProgress, CollectionStats,
+    LazyCollection, MintMode, MintRefundEscrow, RevocationMode,
 };
 pub use constants::*;
 pub use errors::MarketplaceError;
-pub use fees::{FeeConfig, FeeConfigUpdate};
+pub use fees::{EarningsTotals, FeeConfig, FeeConfigUpdate};
 pub(crate) use guards::{check_token_in_collection, collection_id_from_token_id};
-pub use lazy_listing::{LazyListing, LazyListingRecord};
+pub use lazy_listing::{LazyListing, LazyListingCleanup, LazyListingRecord};
 pub use offer::{CollectionOffer, Offer};
 pub use protocol::{Action, Options, Request};
 pub use royalties::Payout;
 pub use sale::{
-    AuctionListing, AuctionState, AuctionView, GasOverrides, PurchasePayoutContext, Sale, SaleType,
+    AuctionListing, AuctionSettleFailure, AuctionSettlement, AuctionState, AuctionView,
+    GasOverrides, NativeListingItem, PriceBounds, PurchasePayoutContext, Sale, SaleType,
 };
 pub use scarce::types::{
     MintContext, RedeemInfo, Scarce, ScarceOptions, ScarceOverrides, TokenMetadata, TokenStatus,
@@ -119,6 +120,41 @@ pub struct Contract {
     // Cross-contract boundary: accepted FT receiver source for unwrap-and-credit flow.
     pub wnear_account_id: Option<AccountId>,
 
+    // Upgrade invariant: fields below were appended after the first deployment; see `ContractV1` in upgrade.rs.
+
+    // Token accounting guarantee: sum of escrow amounts per collection equals `held_revenue`.
+    pub(crate) mint_refund_escrows: LookupMap<String, MintRefundEscrow>,
+
+    // Royalty invariant: marketplaces mapped to `false` cannot receive or move royalty-enforced tokens outside `nft_transfer_payout`.
+    pub known_marketplaces: IterableMap<AccountId, bool>,
+
+    // Cross-contract boundary: FT contracts whose `ft_on_transfer` may pay for FT-priced lazy listings.
+    pub accepted_payment_tokens: IterableSet<AccountId>,
+
+    // Listing invariant: fixed-price sales for a bounded NFT contract stay within its min/max.
+    pub price_bounds: LookupMap<AccountId, PriceBounds>,
+
+    // Token accounting guarantee: NEAR paid to creators and royalty holders, keyed by `account` and `account:collection_id`.
+    pub(crate) creator_earnings: LookupMap<String, EarningsTotals>,
+
+    // Moderation invariant: one appeal per collection; a new appeal replaces a resolved one.
+    pub ban_appeals: LookupMap<String, BanAppeal>,
+
+    // Rate limit invariant: block time (ms) of each account's last purchase, keyed by `collection_id:account_id`.
+    pub(crate) collection_last_mint: LookupMap<String, u64>,
+
+    // Token accounting guarantee: FT payouts whose `ft_transfer` failed, keyed by `ft_contract_id:account_id`; claimable via ClaimFtPayout.
+    pub(crate) ft_claimable: LookupMap<String, U128>,
+
+    // Storage invariant: `(owner, block_timestamp)` per token, oldest first, at most MAX_PROVENANCE_ENTRIES; only for `track_provenance` collections.
+    pub(crate) token_provenance: LookupMap<String, Vec<(AccountId, u64)>>,
+
+    // Index invariant: token IDs of native auctions in `sales`, minus those keeper settlement gave up on; scanned by `settle_ended_auctions`, backfilled after migration by `backfill_auction_index`.
+    pub(crate) auction_token_ids: IterableSet<String>,
+
+    // Index invariant: buyers with an entry in `collection_offers`, per collection, at most MAX_COLLECTION_OFFERS for new offers; backfilled after migration by `backfill_collection_offer_index`.
+    pub(crate) collection_offer_buyers: LookupMap<String, IterableSet<AccountId>>,
+
     // Persistence invariant: transient execution balance is non-persistent and excluded from serialization.
     #[borsh(skip)]
     pub pending_attached_balance: u128,
@@ -21,18 +21,38 @@ impl Contract {
         from_index: Option<u64>,
         limit: Option<u64>,
     ) -> Vec<CollectionOffer> {
-        let prefix = format!("{}\0", collection_id);
         let start = from_index.unwrap_or(0) as usize;
         let limit = limit.unwrap_or(50).min(100) as usize;
 
-        self.collection_offers
-            .iter()
-            .filter(|(k, _)| k.starts_with(&prefix))
+        self.collection_offers_of(&collection_id)
             .skip(start)
             .take(limit)
-            .map(|(_, o)| o.clone())
+            .cloned()
             .collect()
     }
+
+    /// Keeper entry for state migrated from the first deployment: indexes up to
+    /// `limit` collection offers from `from_index`. Returns the index to continue
+    /// from, or `None` once every offer was visited.
+    pub fn backfill_collection_offer_index(&mut self, from_index: u32, limit: u32) -> Option<u32> {
+        let total = self.collection_offers.len();
+        let limit = limit.min(MAX_INDEX_BACKFILL_BATCH);
+        let batch: Vec<(String, AccountId)> = self
+            .collection_offers
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .filter_map(|(key, offer)| {
+                let (collection_id, _) = key.split_once('\0')?;
+                Some((collection_id.to_string(), offer.buyer_id.clone()))
+            })
+            .collect();
+        for (collection_id, buyer_id) in batch {
+            self.index_collection_offer(&collection_id, &buyer_id);
+        }
+        let next = from_index.saturating_add(limit);
+        (next < total).then_some(next)
+    }
 }
 
 impl Contract {
@@ -61,7 +81,9 @@ impl Contract {
             events::emit_collection_offer_cancelled(buyer_id, collection_id, old_offer.amount.0);
             let _ = Promise::new(old_offer.buyer_id)
                 .transfer(NearToken::from_yoctonear(old_offer.amount.0));
+            self.unindex_collection_offer(collection_id, buyer_id);
         }
+        self.make_room_for_collection_offer(collection_id, amount)?;
 
         let offer = CollectionOffer {
             buyer_id: buyer_id.clone(),
@@ -73,9 +95,11 @@ impl Contract {
         // Token accounting invariant: offer amount must exceed its storage footprint.
         let before = self.storage_usage_flushed();
         self.collection_offers.insert(key.clone(), offer);
+        self.index_collection_offer(collection_id, buyer_id);
         let bytes_used = self.storage_usage_flushed().saturating_sub(before);
         let storage_cost = (bytes_used as u128) * storage_byte_cost();
         if amount <= storage_cost {
+            self.unindex_collection_offer(collection_id, buyer_id);
             let removed = self.collection_offers.remove(&key);
             if let Some(o) = removed {
                 let _ = Promise::new(o.buyer_id).transfer(NearToken::from_yoctonear(o.amount.0));
@@ -100,6 +124,7 @@ impl Contract {
             .collection_offers
             .remove(&key)
             .ok_or_else(|| MarketplaceError::NotFound("Collection offer not found".into()))?;
+        self.unindex_collection_offer(collection_id, buyer_id);
 
         let _ = Promise::new(offer.buyer_id).transfer(NearToken::from_yoctonear(offer.amount.0));
 
@@ -107,6 +132,149 @@ impl Contract {
         Ok(())
     }
 
+    /// Mints one token to `actor_id` and fills a live collection offer with it.
+    /// If the offer was cancelled or has expired, the mint stands on its own
+    /// and no buyer is returned.
+    pub(crate) fn mint_and_accept_collection_offer(
+        &mut self,
+        actor_id: &AccountId,
+        collection_id: &str,
+        buyer_id: Option<&AccountId>,
+    ) -> Result<(String, Option<AccountId>), MarketplaceError> {
+        let minted_count = self
+            .collections
+            .get(collection_id)
+            .ok_or_else(|| MarketplaceError::NotFound("Collection not found".into()))?
+            .minted_count;
+        self.mint_from_collection(actor_id, collection_id, 1, None)?;
+        let token_id = format!("{}:{}", collection_id, minted_count + 1);
+
+        let buyer = match buyer_id {
+            Some(buyer) => Some(buyer.clone()).filter(|b| {
+                self.live_collection_offer(collection_id, b).is_some() && b != actor_id
+            }),
+            None => self.best_collection_offer(collection_id, actor_id),
+        };
+        if let Some(ref buyer) = buyer {
+            self.accept_collection_offer(actor_id, collection_id, &token_id, buyer)?;
+        }
+        Ok((token_id, buyer))
+    }
+
+    fn live_collection_offer(
+        &self,
+        collection_id: &str,
+        buyer_id: &AccountId,
+    ) -> Option<&CollectionOffer> {
+        let now = env::block_timestamp();
+        self.collection_offers
+            .get(&collection_offer_key(collection_id, buyer_id))
+            .filter(|o| o.expires_at.is_none_or(|exp| now <= exp))
+    }
+
+    /// Highest unexpired offer not made by `seller_id`; earlier offers win ties.
+    fn best_collection_offer(
+        &self,
+        collection_id: &str,
+        seller_id: &AccountId,
+    ) -> Option<AccountId> {
+        let now = env::block_timestamp();
+        self.collection_offers_of(collection_id)
+            .filter(|o| &o.buyer_id != seller_id && o.expires_at.is_none_or(|exp| now <= exp))
+            .max_by(|a, b| {
+                a.amount
+                    .0
+                    .cmp(&b.amount.0)
+                    .then(b.created_at.cmp(&a.created_at))
+            })
+            .map(|o| o.buyer_id.clone())
+    }
+
+    /// Keeps at most MAX_COLLECTION_OFFERS per collection so offer scans stay
+    /// bounded: a full book refunds its weakest offer (expired first, then the
+    /// lowest, newest on ties) when `amount` beats it.
+    fn make_room_for_collection_offer(
+        &mut self,
+        collection_id: &str,
+        amount: u128,
+    ) -> Result<(), MarketplaceError> {
+        let open_offers = self
+            .collection_offer_buyers
+            .get(collection_id)
+            .map_or(0, |buyers| buyers.len());
+        if open_offers < MAX_COLLECTION_OFFERS {
+            return Ok(());
+        }
+
+        let now = env::block_timestamp();
+        let Some((live, weakest)) = self
+            .collection_offers_of(collection_id)
+            .map(|o| (o.expires_at.is_none_or(|exp| now <= exp), o))
+            .min_by(|(a_live, a), (b_live, b)| {
+                a_live
+                    .cmp(b_live)
+                    .then(a.amount.0.cmp(&b.amount.0))
+                    .then(b.created_at.cmp(&a.created_at))
+            })
+            .map(|(live, o)| (live, o.clone()))
+        else {
+            return Ok(());
+        };
+        if live && amount <= weakest.amount.0 {
+            return Err(MarketplaceError::InvalidState(format!(
+                "Collection has {} open offers; a new offer must exceed {}",
+                MAX_COLLECTION_OFFERS, weakest.amount.0
+            )));
+        }
+
+        self.collection_offers
+            .remove(&collection_offer_key(collection_id, &weakest.buyer_id));
+        self.unindex_collection_offer(collection_id, &weakest.buyer_id);
+        events::emit_collection_offer_cancelled(&weakest.buyer_id, collection_id, weakest.amount.0);
+        let _ =
+            Promise::new(weakest.buyer_id).transfer(NearToken::from_yoctonear(weakest.amount.0));
+        Ok(())
+    }
+
+    /// Offers on `collection_id`, via the per-collection buyer index.
+    pub(crate) fn collection_offers_of<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> impl Iterator<Item = &'a CollectionOffer> + 'a {
+        self.collection_offer_buyers
+            .get(collection_id)
+            .into_iter()
+            .flat_map(|buyers| buyers.iter())
+            .filter_map(move |buyer_id| {
+                self.collection_offers
+                    .get(&collection_offer_key(collection_id, buyer_id))
+            })
+    }
+
+    fn index_collection_offer(&mut self, collection_id: &str, buyer_id: &AccountId) {
+        if !self.collection_offer_buyers.contains_key(collection_id) {
+            self.collection_offer_buyers.insert(
+                collection_id.to_string(),
+                IterableSet::new(StorageKey::CollectionOfferBuyersInner {
+                    collection_id_hash: env::sha256(collection_id.as_bytes()),
+                }),
+            );
+        }
+        self.collection_offer_buyers
+            .get_mut(collection_id)
+            .unwrap()
+            .insert(buyer_id.clone());
+    }
+
+    fn unindex_collection_offer(&mut self, collection_id: &str, buyer_id: &AccountId) {
+        if let Some(buyers) = self.collection_offer_buyers.get_mut(collection_id) {
+            buyers.remove(buyer_id);
+            if buyers.is_empty() {
+                self.collection_offer_buyers.remove(collection_id);
+            }
+        }
+    }
+
     pub(crate) fn accept_collection_offer(
         &mut self,
         owner_id: &AccountId,
@@ -137,6 +305,7 @@ impl Contract {
             .collection_offers
             .remove(&key)
             .ok_or_else(|| MarketplaceError::NotFound("Collection offer not found".into()))?;
+        self.unindex_collection_offer(collection_id, buyer_id);
 
         if let Some(exp) = offer.expires_at {
             if env::block_timestamp() > exp {
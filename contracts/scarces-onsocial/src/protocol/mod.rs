@@ -2,5 +2,5 @@ mod types;
 
 pub use types::*;
 
-pub use crate::collections::AllowlistEntry;
+pub use crate::collections::{AllowlistEntry, AllowlistProof};
 pub use crate::scarce::types::TransferItem;
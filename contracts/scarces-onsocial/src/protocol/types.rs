@@ -3,6 +3,7 @@ use near_sdk::json_types::U128;
 use near_sdk::near;
 
 use super::AllowlistEntry;
+use super::AllowlistProof;
 use super::TransferItem;
 
 #[near(serializers = [json])]
@@ -39,6 +40,9 @@ pub enum Action {
         #[serde(default)]
         collection_id: Option<String>,
     },
+    BatchBurn {
+        token_ids: Vec<String>,
+    },
     RenewToken {
         token_id: String,
         collection_id: String,
@@ -89,6 +93,12 @@ pub enum Action {
     ResumeCollection {
         collection_id: String,
     },
+    FreezeCollection {
+        collection_id: String,
+    },
+    RevealCollection {
+        collection_id: String,
+    },
     SetAllowlist {
         collection_id: String,
         entries: Vec<AllowlistEntry>,
@@ -106,15 +116,26 @@ pub enum Action {
         collection_id: String,
         metadata: Option<String>,
     },
+    BatchUpdateTokenMetadata {
+        collection_id: String,
+        updates: Vec<(String, crate::TokenMetadata)>,
+    },
     WithdrawUnclaimedRefunds {
         collection_id: String,
     },
+    ReleaseRefundEscrow {
+        collection_id: String,
+        token_ids: Vec<String>,
+    },
 
     ListNativeScarce {
         token_id: String,
         price: U128,
         expires_at: Option<u64>,
     },
+    BatchListNativeScarce {
+        listings: Vec<crate::NativeListingItem>,
+    },
     DelistNativeScarce {
         token_id: String,
     },
@@ -154,6 +175,13 @@ pub enum Action {
     CancelCollectionOffer {
         collection_id: String,
     },
+    /// Mints the next token to the creator and sells it into `buyer_id`'s
+    /// collection offer, or the best live offer when omitted.
+    MintAndAcceptCollectionOffer {
+        collection_id: String,
+        #[serde(default)]
+        buyer_id: Option<AccountId>,
+    },
 
     CreateLazyListing {
         #[serde(flatten)]
@@ -175,6 +203,10 @@ pub enum Action {
         collection_id: String,
         quantity: u32,
         max_price_per_token: U128,
+        #[serde(default)]
+        allowlist_proof: Option<AllowlistProof>,
+        #[serde(default)]
+        refund_if_exhausted: bool,
     },
     PurchaseLazyListing {
         listing_id: String,
@@ -217,6 +249,9 @@ pub enum Action {
         cap: Option<U128>,
     },
     StorageWithdraw,
+    ClaimFtPayout {
+        ft_contract_id: AccountId,
+    },
     WithdrawAppPool {
         app_id: AccountId,
         amount: U128,
@@ -251,6 +286,15 @@ pub enum Action {
         app_id: AccountId,
         collection_id: String,
     },
+    SubmitBanAppeal {
+        collection_id: String,
+        reason: String,
+    },
+    ResolveBanAppeal {
+        collection_id: String,
+        uphold: bool,
+        note: Option<String>,
+    },
 }
 
 impl Action {
@@ -273,6 +317,7 @@ impl Action {
                 | Self::MintFromCollection { .. }
                 | Self::AirdropFromCollection { .. }
                 | Self::ListNativeScarce { .. }
+                | Self::BatchListNativeScarce { .. }
                 | Self::ListNativeScarceAuction { .. }
                 | Self::CreateLazyListing { .. }
                 | Self::SettleAuction { .. }
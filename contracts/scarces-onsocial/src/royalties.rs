@@ -2,6 +2,44 @@ use crate::*;
 use std::collections::HashMap;
 
 impl Contract {
+    /// Direct transfers of royalty-enforced collection tokens may not land on,
+    /// or be driven by, a marketplace known to skip payouts. Sales through
+    /// `nft_transfer_payout` and this contract's own markets are unaffected.
+    pub(crate) fn check_royalty_transfer(
+        &self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_id: &str,
+    ) -> Result<(), MarketplaceError> {
+        let cid = collection_id_from_token_id(token_id);
+        if cid.is_empty()
+            || !self
+                .collections
+                .get(cid)
+                .is_some_and(|c| c.enforce_royalties)
+        {
+            return Ok(());
+        }
+        let Some(token) = self.scarces_by_id.get(token_id) else {
+            return Ok(());
+        };
+
+        let skips_royalties =
+            |account: &AccountId| self.known_marketplaces.get(account) == Some(&false);
+        let marketplace = if skips_royalties(receiver_id) {
+            receiver_id
+        } else if sender_id != &token.owner_id && skips_royalties(sender_id) {
+            sender_id
+        } else {
+            return Ok(());
+        };
+
+        Err(MarketplaceError::Unauthorized(format!(
+            "Transfer of {} blocked: collection enforces royalties and marketplace {} does not honour payouts",
+            token_id, marketplace
+        )))
+    }
+
     // Invariant: app default royalty is always included; overlapping recipients are additive.
     pub(crate) fn merge_royalties(
         &self,
@@ -44,19 +82,11 @@ impl Contract {
         let mut payout_map = HashMap::new();
         let mut total_royalty: u128 = 0;
 
-        if let Some(royalty) = &token.royalty {
-            for (account, bps) in royalty.iter() {
-                let amount = (primitive_types::U256::from(balance)
-                    * primitive_types::U256::from(*bps)
-                    / primitive_types::U256::from(10_000u32))
-                .as_u128();
-                if amount > 0 {
-                    payout_map.insert(account.clone(), U128(amount));
-                    total_royalty = total_royalty.checked_add(amount).ok_or_else(|| {
-                        MarketplaceError::InternalError("Royalty payout overflow".to_string())
-                    })?;
-                }
-            }
+        for (account, amount) in crate::fees::routing::royalty_shares(token, balance) {
+            payout_map.insert(account, U128(amount));
+            total_royalty = total_royalty.checked_add(amount).ok_or_else(|| {
+                MarketplaceError::InternalError("Royalty payout overflow".to_string())
+            })?;
         }
 
         if total_royalty > balance {
@@ -15,6 +15,7 @@ impl Contract {
             auction_duration_ns,
             anti_snipe_extension_ns,
             buy_now_price,
+            anti_snipe_window_ns,
         } = params;
         let reserve_price = reserve_price.0;
         let min_bid_increment = min_bid_increment.0;
@@ -64,6 +65,12 @@ impl Contract {
                 ));
             }
         }
+        if anti_snipe_window_ns.is_some_and(|w| w > 0) && anti_snipe_extension_ns == 0 {
+            return Err(MarketplaceError::InvalidInput(
+                "anti_snipe_window_ns requires anti_snipe_extension_ns".into(),
+            ));
+        }
+        let anti_snipe_window_ns = anti_snipe_window_ns.unwrap_or(anti_snipe_extension_ns);
         if let Some(bnp) = buy_now_price {
             if bnp <= reserve_price {
                 return Err(MarketplaceError::InvalidInput(
@@ -81,6 +88,8 @@ impl Contract {
             auction_duration_ns,
             anti_snipe_extension_ns,
             buy_now_price: buy_now_price.map(U128),
+            anti_snipe_window_ns,
+            anti_snipe_extensions: 0,
         };
 
         events::emit_auction_created(owner_id, token_id, &auction, expires_at);
@@ -157,13 +166,24 @@ impl Contract {
         let winning_bid = auction.highest_bid.0;
         let winner = auction.highest_bidder.clone();
 
-        self.remove_sale(env::current_account_id(), token_id.to_string())?;
-
-        if winning_bid >= auction.reserve_price.0 && winning_bid > 0 {
-            let winner_id = winner.ok_or_else(|| {
+        // Fail before any write when the token can no longer change hands.
+        let sold_to = if winning_bid >= auction.reserve_price.0 && winning_bid > 0 {
+            let winner_id = winner.clone().ok_or_else(|| {
                 MarketplaceError::InternalError("highest_bid > 0 but no bidder".into())
             })?;
+            let token = self
+                .scarces_by_id
+                .get(token_id)
+                .ok_or_else(|| MarketplaceError::NotFound("Token not found".into()))?;
+            self.check_transferable(token, token_id, "transfer")?;
+            Some(winner_id)
+        } else {
+            None
+        };
+
+        self.remove_sale(env::current_account_id(), token_id.to_string())?;
 
+        if let Some(winner_id) = sold_to {
             self.transfer(
                 &seller_id,
                 &winner_id,
@@ -184,13 +204,19 @@ impl Contract {
                 result.app_id.as_ref(),
             );
         } else {
-            if let Some(bidder) = winner {
-                if winning_bid > 0 {
-                    let _ = Promise::new(bidder.clone())
-                        .transfer(NearToken::from_yoctonear(winning_bid));
-                }
+            // Reserve not met: the token never left the seller; refund the top bid.
+            let refunded = winner.filter(|_| winning_bid > 0);
+            if let Some(bidder) = &refunded {
+                let _ =
+                    Promise::new(bidder.clone()).transfer(NearToken::from_yoctonear(winning_bid));
             }
-            events::emit_auction_cancelled(&seller_id, token_id, "reserve_not_met");
+            events::emit_auction_unsold(
+                &seller_id,
+                token_id,
+                auction.reserve_price,
+                winning_bid,
+                refunded.as_ref(),
+            );
         }
         Ok(())
     }
@@ -227,6 +253,95 @@ impl Contract {
     }
 }
 
+#[near]
+impl Contract {
+    /// Permissionless keeper entry: examines up to `limit` auction index
+    /// entries from `from_index` and settles those past their end time. Pass
+    /// `next_index` back to continue; it is `None` once the index is exhausted.
+    /// Settling moves later entries into the freed slots, so a sweep that
+    /// settled anything should restart from 0. An auction that cannot settle is
+    /// reported in `failed` and dropped from the index; `SettleAuction` still
+    /// settles it.
+    #[handle_result]
+    pub fn settle_ended_auctions(
+        &mut self,
+        from_index: Option<u32>,
+        limit: u32,
+    ) -> Result<AuctionSettlement, MarketplaceError> {
+        let now = env::block_timestamp();
+        let start = from_index.unwrap_or(0);
+        let indexed = self.auction_token_ids.len();
+        let window: Vec<String> = self
+            .auction_token_ids
+            .iter()
+            .skip(start as usize)
+            .take(limit.min(MAX_AUCTION_SETTLE_BATCH) as usize)
+            .cloned()
+            .collect();
+
+        let keeper = env::predecessor_account_id();
+        let mut settled = 0u32;
+        let mut failed = Vec::new();
+        let mut scanned = 0u32;
+        for token_id in &window {
+            // Execution invariant: stop before low-gas threshold to avoid partial iteration failure.
+            if env::prepaid_gas().saturating_sub(env::used_gas()) < near_sdk::Gas::from_tgas(20) {
+                break;
+            }
+            scanned += 1;
+            let sale_id = Contract::make_sale_id(&env::current_account_id(), token_id);
+            let Some(sale) = self.sales.get(&sale_id) else {
+                self.auction_token_ids.remove(token_id);
+                continue;
+            };
+            if sale.expires_at.is_none_or(|end| now < end) {
+                continue;
+            }
+            // Settlement validates before writing, so a failed auction is left untouched.
+            match self.settle_auction(&keeper, token_id) {
+                Ok(()) => settled += 1,
+                Err(err) => {
+                    self.auction_token_ids.remove(token_id);
+                    failed.push(AuctionSettleFailure {
+                        token_id: token_id.clone(),
+                        error: err.to_string(),
+                    });
+                }
+            }
+        }
+
+        let next = start.saturating_add(scanned);
+        Ok(AuctionSettlement {
+            settled,
+            failed,
+            next_index: (next < indexed).then_some(next),
+        })
+    }
+
+    /// Keeper entry for state migrated from the first deployment: adds native
+    /// auctions among up to `limit` sales from `from_index` to the auction index.
+    /// Returns the index to continue from, or `None` once every sale was visited.
+    pub fn backfill_auction_index(&mut self, from_index: u32, limit: u32) -> Option<u32> {
+        let total = self.sales.len();
+        let batch: Vec<String> = self
+            .sales
+            .values()
+            .skip(from_index as usize)
+            .take(limit.min(MAX_INDEX_BACKFILL_BATCH) as usize)
+            .filter(|sale| sale.auction.is_some())
+            .filter_map(|sale| match &sale.sale_type {
+                SaleType::NativeScarce { token_id } => Some(token_id.clone()),
+                _ => None,
+            })
+            .collect();
+        for token_id in batch {
+            self.auction_token_ids.insert(token_id);
+        }
+        let next = from_index.saturating_add(limit.min(MAX_INDEX_BACKFILL_BATCH));
+        (next < total).then_some(next)
+    }
+}
+
 impl Contract {
     pub(crate) fn place_bid(
         &mut self,
@@ -268,11 +383,7 @@ impl Contract {
             return Err(MarketplaceError::InvalidState("Auction has ended".into()));
         }
 
-        let min_required = if auction.highest_bid.0 == 0 {
-            auction.reserve_price.0.max(auction.min_bid_increment.0)
-        } else {
-            auction.highest_bid.0 + auction.min_bid_increment.0
-        };
+        let min_required = auction.min_next_bid();
         if bid < min_required {
             return Err(MarketplaceError::InsufficientDeposit(format!(
                 "Bid too low: minimum {} yoctoNEAR required",
@@ -292,10 +403,15 @@ impl Contract {
         auction.highest_bidder = Some(bidder.clone());
         auction.bid_count = auction.bid_count.saturating_add(1);
 
-        if auction.anti_snipe_extension_ns > 0 {
+        let mut extended = false;
+        if auction.anti_snipe_extension_ns > 0
+            && auction.anti_snipe_extensions < MAX_ANTI_SNIPE_EXTENSIONS
+        {
             let time_left = expires.saturating_sub(env::block_timestamp());
-            if time_left < auction.anti_snipe_extension_ns {
+            if time_left < auction.anti_snipe_window_ns {
                 sale.expires_at = Some(expires.saturating_add(auction.anti_snipe_extension_ns));
+                auction.anti_snipe_extensions += 1;
+                extended = true;
             }
         }
 
@@ -306,6 +422,14 @@ impl Contract {
         self.sales.insert(sale_id, sale);
 
         events::emit_auction_bid(bidder, &token_id, bid, auction.bid_count, new_expires_at);
+        if let (true, Some(expires_at)) = (extended, new_expires_at) {
+            events::emit_auction_extended(
+                bidder,
+                &token_id,
+                expires_at,
+                auction.anti_snipe_extensions,
+            );
+        }
 
         if let Some(bnp) = auction.buy_now_price {
             if bid >= bnp.0 {
@@ -17,6 +17,10 @@ impl Contract {
             .remove(&sale_id)
             .ok_or_else(|| MarketplaceError::NotFound("No sale found".into()))?;
 
+        if sale.auction.is_some() {
+            self.auction_token_ids.remove(&token_id);
+        }
+
         if let Some(mut owner_set) = self.by_owner_id.remove(&sale.owner_id) {
             owner_set.remove(&sale_id);
             if !owner_set.is_empty() {
@@ -46,6 +50,9 @@ impl Contract {
         };
 
         let sale_id = Contract::make_sale_id(&scarce_contract_id, &token_id);
+        if sale.auction.is_some() && matches!(sale.sale_type, SaleType::NativeScarce { .. }) {
+            self.auction_token_ids.insert(token_id.clone());
+        }
         self.sales.insert(sale_id.clone(), sale.clone());
 
         let mut by_owner_id = self.by_owner_id.remove(&sale.owner_id).unwrap_or_else(|| {
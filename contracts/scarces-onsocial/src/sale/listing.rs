@@ -28,6 +28,7 @@ impl Contract {
                 "Price must be greater than 0".into(),
             ));
         }
+        self.check_price_bounds(&scarce_contract_id, sale_conditions.0)?;
 
         let owner_id = env::predecessor_account_id();
 
@@ -174,6 +175,56 @@ impl Contract {
             vec![sale_conditions],
         );
     }
+
+    /// Permissionless sweep of expired fixed-price sales. Auctions are left
+    /// for settlement. Returns the number of sales removed.
+    pub fn clear_expired_sales(&mut self, limit: u32) -> u64 {
+        let now = env::block_timestamp();
+        let limit = limit.min(50) as usize;
+
+        let expired: Vec<Sale> = self
+            .sales
+            .values()
+            .filter(|sale| sale.auction.is_none() && Self::sale_expired_at(sale, now))
+            .take(limit)
+            .cloned()
+            .collect();
+
+        let mut count = 0u64;
+        for sale in expired {
+            // Execution invariant: stop before low-gas threshold to avoid partial iteration failure.
+            if env::prepaid_gas().saturating_sub(env::used_gas()) < near_sdk::Gas::from_tgas(5) {
+                break;
+            }
+            let (scarce_contract_id, token_id, app_id) = match &sale.sale_type {
+                SaleType::External {
+                    scarce_contract_id,
+                    token_id,
+                    ..
+                } => (scarce_contract_id.clone(), token_id.clone(), None),
+                SaleType::NativeScarce { token_id } => {
+                    let token_app_id = self
+                        .scarces_by_id
+                        .get(token_id)
+                        .and_then(|t| t.app_id.clone());
+                    let app_id = self.resolve_token_app_id(token_id, token_app_id.as_ref());
+                    (env::current_account_id(), token_id.clone(), app_id)
+                }
+            };
+            let before = self.storage_usage_flushed();
+            if self
+                .remove_sale(scarce_contract_id.clone(), token_id.clone())
+                .is_err()
+            {
+                continue;
+            }
+            let bytes_freed = before.saturating_sub(self.storage_usage_flushed());
+            self.release_storage_waterfall(&sale.owner_id, bytes_freed, app_id.as_ref());
+            events::emit_sale_expired(&sale.owner_id, &scarce_contract_id, &token_id);
+            count += 1;
+        }
+        count
+    }
 }
 
 impl Contract {
@@ -204,6 +255,7 @@ impl Contract {
                 "Price must be greater than 0".into(),
             ));
         }
+        self.check_price_bounds(&env::current_account_id(), price.0)?;
 
         self.check_transferable(token, token_id, "list for sale")?;
 
@@ -251,6 +303,64 @@ impl Contract {
         Ok(())
     }
 
+    pub(crate) fn check_price_bounds(
+        &self,
+        nft_contract_id: &AccountId,
+        price: u128,
+    ) -> Result<(), MarketplaceError> {
+        let Some(bounds) = self.price_bounds.get(nft_contract_id) else {
+            return Ok(());
+        };
+        if let Some(min) = bounds.min.filter(|min| price < min.0) {
+            return Err(MarketplaceError::InvalidInput(format!(
+                "Price {} is below the minimum {} for {}",
+                price, min.0, nft_contract_id
+            )));
+        }
+        if let Some(max) = bounds.max.filter(|max| price > max.0) {
+            return Err(MarketplaceError::InvalidInput(format!(
+                "Price {} exceeds the maximum {} for {}",
+                price, max.0, nft_contract_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// All-or-nothing: ownership is checked for every token before any sale
+    /// is written, and any later failure reverts the whole call.
+    pub(crate) fn batch_list_native_scarce(
+        &mut self,
+        owner_id: &AccountId,
+        listings: Vec<crate::NativeListingItem>,
+    ) -> Result<Vec<String>, MarketplaceError> {
+        if listings.is_empty() || listings.len() as u32 > MAX_BATCH_LIST {
+            return Err(MarketplaceError::InvalidInput(format!(
+                "Batch size must be 1-{}",
+                MAX_BATCH_LIST
+            )));
+        }
+        for item in &listings {
+            let owned = self
+                .scarces_by_id
+                .get(&item.token_id)
+                .is_some_and(|t| &t.owner_id == owner_id);
+            if !owned {
+                return Err(MarketplaceError::Unauthorized(format!(
+                    "Caller does not own token {}",
+                    item.token_id
+                )));
+            }
+        }
+
+        let contract_id = env::current_account_id();
+        let mut sale_ids = Vec::with_capacity(listings.len());
+        for item in listings {
+            self.list_native_scarce(owner_id, &item.token_id, item.price, item.expires_at)?;
+            sale_ids.push(Contract::make_sale_id(&contract_id, &item.token_id));
+        }
+        Ok(sale_ids)
+    }
+
     pub(crate) fn delist_native_scarce(
         &mut self,
         owner_id: &AccountId,
@@ -333,12 +443,20 @@ impl Contract {
                 "Price must be greater than 0".into(),
             ));
         }
+        self.check_price_bounds(scarce_contract_id, price.0)?;
         let old_price = sale.sale_conditions;
         let owner_id = sale.owner_id.clone();
         let mut sale = sale.clone();
         sale.sale_conditions = price;
-        self.sales.insert(sale_id, sale);
-        events::emit_scarce_update_price(&owner_id, scarce_contract_id, token_id, old_price, price);
+        self.sales.insert(sale_id.clone(), sale);
+        events::emit_scarce_update_price(
+            &owner_id,
+            &sale_id,
+            scarce_contract_id,
+            token_id,
+            old_price,
+            price,
+        );
         Ok(())
     }
 }
@@ -1,6 +1,9 @@
-use near_sdk::AccountId;
+use near_sdk::borsh::io::{Read, Result};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 use near_sdk::json_types::U128;
-use near_sdk::near;
+use near_sdk::{AccountId, near};
+
+use crate::storage::legacy::read_appended;
 
 #[near(serializers = [borsh, json])]
 #[derive(Clone)]
@@ -26,10 +29,30 @@ pub struct AuctionState {
     pub auction_duration_ns: Option<u64>,
     pub anti_snipe_extension_ns: u64,
     pub buy_now_price: Option<U128>,
+    /// Bids with less than this left on the clock extend the auction.
+    #[serde(default)]
+    pub anti_snipe_window_ns: u64,
+    #[serde(default)]
+    pub anti_snipe_extensions: u32,
 }
 
-#[near(serializers = [borsh, json])]
-#[derive(Clone)]
+impl AuctionState {
+    /// Smallest bid `place_bid` accepts next.
+    pub fn min_next_bid(&self) -> u128 {
+        if self.highest_bid.0 == 0 {
+            self.reserve_price.0.max(self.min_bid_increment.0)
+        } else {
+            self.highest_bid.0.saturating_add(self.min_bid_increment.0)
+        }
+    }
+}
+
+// Upgrade invariant: `auction` stays the last field, so fields appended to
+// `AuctionState` are the tail of the record; listings written before a trailing
+// field existed still decode (see the `BorshDeserialize` impl).
+#[near(serializers = [json])]
+#[derive(Clone, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
 pub struct Sale {
     pub owner_id: AccountId,
     pub sale_conditions: U128,
@@ -39,6 +62,76 @@ pub struct Sale {
     pub auction: Option<AuctionState>,
 }
 
+/// `AuctionState` as written before configurable anti-snipe windows.
+#[derive(BorshDeserialize)]
+#[borsh(crate = "near_sdk::borsh")]
+struct AuctionStateV1 {
+    reserve_price: U128,
+    min_bid_increment: U128,
+    highest_bid: U128,
+    highest_bidder: Option<AccountId>,
+    bid_count: u32,
+    auction_duration_ns: Option<u64>,
+    anti_snipe_extension_ns: u64,
+    buy_now_price: Option<U128>,
+}
+
+#[derive(BorshDeserialize)]
+#[borsh(crate = "near_sdk::borsh")]
+struct SaleV1 {
+    owner_id: AccountId,
+    sale_conditions: U128,
+    sale_type: SaleType,
+    expires_at: Option<u64>,
+    auction: Option<AuctionStateV1>,
+}
+
+impl BorshDeserialize for Sale {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let v1 = SaleV1::deserialize_reader(reader)?;
+        let auction = match v1.auction {
+            Some(a) => Some(AuctionState {
+                reserve_price: a.reserve_price,
+                min_bid_increment: a.min_bid_increment,
+                highest_bid: a.highest_bid,
+                highest_bidder: a.highest_bidder,
+                bid_count: a.bid_count,
+                auction_duration_ns: a.auction_duration_ns,
+                anti_snipe_extension_ns: a.anti_snipe_extension_ns,
+                buy_now_price: a.buy_now_price,
+                // Pre-window auctions extended when a bid landed within one extension of close.
+                anti_snipe_window_ns: read_appended(reader, a.anti_snipe_extension_ns)?,
+                anti_snipe_extensions: read_appended(reader, 0)?,
+            }),
+            None => None,
+        };
+        Ok(Self {
+            owner_id: v1.owner_id,
+            sale_conditions: v1.sale_conditions,
+            sale_type: v1.sale_type,
+            expires_at: v1.expires_at,
+            auction,
+        })
+    }
+}
+
+/// Owner-configured listing price range for one NFT contract.
+#[near(serializers = [borsh, json])]
+#[derive(Clone)]
+pub struct PriceBounds {
+    pub min: Option<U128>,
+    pub max: Option<U128>,
+}
+
+#[near(serializers = [json])]
+#[derive(Clone)]
+pub struct NativeListingItem {
+    pub token_id: String,
+    pub price: U128,
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+}
+
 #[near(serializers = [json])]
 #[derive(Clone)]
 pub struct AuctionListing {
@@ -52,6 +145,9 @@ pub struct AuctionListing {
     pub anti_snipe_extension_ns: u64,
     #[serde(default)]
     pub buy_now_price: Option<U128>,
+    /// Defaults to `anti_snipe_extension_ns`.
+    #[serde(default)]
+    pub anti_snipe_window_ns: Option<u64>,
 }
 
 #[near(serializers = [json])]
@@ -65,9 +161,30 @@ pub struct AuctionView {
     pub bid_count: u32,
     pub expires_at: Option<u64>,
     pub anti_snipe_extension_ns: u64,
+    pub anti_snipe_window_ns: u64,
+    pub anti_snipe_extensions: u32,
     pub buy_now_price: Option<U128>,
     pub is_ended: bool,
     pub reserve_met: bool,
+    pub min_next_bid: U128,
+    /// `None` until the first bid starts a duration-based auction.
+    pub time_remaining_ns: Option<u64>,
+}
+
+/// An ended auction `settle_ended_auctions` could not settle and dropped from its index, with the reason.
+#[near(serializers = [json])]
+pub struct AuctionSettleFailure {
+    pub token_id: String,
+    pub error: String,
+}
+
+/// Result of one `settle_ended_auctions` pass.
+#[near(serializers = [json])]
+pub struct AuctionSettlement {
+    pub settled: u32,
+    pub failed: Vec<AuctionSettleFailure>,
+    /// Index to pass as `from_index` next; `None` once the index is exhausted.
+    pub next_index: Option<u32>,
 }
 
 #[near(serializers = [json])]
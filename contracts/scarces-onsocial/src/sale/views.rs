@@ -7,6 +7,91 @@ impl Contract {
         self.sales.get(&sale_id).cloned()
     }
 
+    /// Dry run of `PurchaseNativeScarce` at `offered_amount`: fee split, royalty
+    /// payouts, seller proceeds and refund. Returns `{"error": ...}` when the
+    /// purchase would be rejected.
+    pub fn simulate_purchase(&self, token_id: String, offered_amount: U128) -> Value {
+        let reject = |reason: &str| near_sdk::serde_json::json!({ "error": reason });
+
+        let sale_id = Contract::make_sale_id(&env::current_account_id(), &token_id);
+        let Some(sale) = self.sales.get(&sale_id) else {
+            return reject("not_listed");
+        };
+        if sale.auction.is_some() {
+            return reject("auction_listing");
+        }
+        if sale.expires_at.is_some_and(|e| env::block_timestamp() > e) {
+            return reject("sale_expired");
+        }
+        let Some(token) = self.scarces_by_id.get(&token_id) else {
+            return reject("not_listed");
+        };
+
+        let price = sale.sale_conditions.0;
+        if offered_amount.0 < price {
+            return near_sdk::serde_json::json!({
+                "error": "offer_below_price",
+                "price": U128(price),
+            });
+        }
+
+        let app_id = self.resolve_token_app_id(&token_id, token.app_id.as_ref());
+        let (total_fee, app_pool_amount, platform_storage_amount, revenue) =
+            self.calculate_fee_split(price, app_id.as_ref());
+        let amount_after_fee = price.saturating_sub(total_fee);
+
+        // Mirrors distribute_payout: an invalid payout falls back to the seller.
+        let seller_id = &sale.owner_id;
+        let mut payouts: Vec<(AccountId, u128)> = match self
+            .compute_payout(token, seller_id, amount_after_fee, Some(10))
+            .ok()
+            .filter(|p| Self::payout_total(p).is_some_and(|t| t > 0 && t <= amount_after_fee))
+        {
+            Some(payout) => {
+                let mut entries: Vec<_> = payout
+                    .payout
+                    .into_iter()
+                    .filter(|(_, amount)| amount.0 > 0)
+                    .map(|(account, amount)| (account, amount.0))
+                    .collect();
+                let distributed: u128 = entries.iter().map(|(_, a)| a).sum();
+                let remainder = amount_after_fee - distributed;
+                if remainder > 0 {
+                    entries.push((self.fee_recipient.clone(), remainder));
+                }
+                entries
+            }
+            None => vec![(seller_id.clone(), amount_after_fee)],
+        };
+        payouts.sort_by(|a, b| a.0.cmp(&b.0));
+        let seller_proceeds = payouts
+            .iter()
+            .filter(|(account, _)| account == seller_id)
+            .map(|(_, amount)| amount)
+            .sum::<u128>();
+
+        near_sdk::serde_json::json!({
+            "token_id": token_id,
+            "seller_id": seller_id,
+            "price": U128(price),
+            "offered_amount": offered_amount,
+            "refund": U128(offered_amount.0 - price),
+            "app_id": app_id,
+            "total_fee": U128(total_fee),
+            "marketplace_fee": U128(revenue),
+            "app_pool_amount": U128(app_pool_amount),
+            "platform_storage_amount": U128(platform_storage_amount),
+            "seller_proceeds": U128(seller_proceeds),
+            "payouts": payouts
+                .into_iter()
+                .map(|(account_id, amount)| near_sdk::serde_json::json!({
+                    "account_id": account_id,
+                    "amount": U128(amount),
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+
     pub fn get_supply_sales(&self) -> u64 {
         self.sales.len() as u64
     }
@@ -25,11 +110,14 @@ impl Contract {
             .unwrap_or(0)
     }
 
+    /// Expired sales are skipped unless `include_expired` is set; pagination
+    /// counts only the sales that are returned.
     pub fn get_sales_by_owner_id(
         &self,
         account_id: AccountId,
         from_index: Option<u64>,
         limit: Option<u64>,
+        include_expired: Option<bool>,
     ) -> Vec<Sale> {
         let Some(sales) = self.by_owner_id.get(&account_id) else {
             return vec![];
@@ -37,12 +125,16 @@ impl Contract {
 
         let start = from_index.unwrap_or(0);
         let limit = limit.unwrap_or(50).min(100);
+        let include_expired = include_expired.unwrap_or(false);
+        let now = env::block_timestamp();
 
         sales
             .iter()
+            .filter_map(|sale_id| self.sales.get(sale_id))
+            .filter(|sale| include_expired || !Self::sale_expired_at(sale, now))
             .skip(start as usize)
             .take(limit as usize)
-            .filter_map(|sale_id| self.sales.get(sale_id).cloned())
+            .cloned()
             .collect()
     }
 
@@ -51,6 +143,7 @@ impl Contract {
         scarce_contract_id: AccountId,
         from_index: Option<u64>,
         limit: Option<u64>,
+        include_expired: Option<bool>,
     ) -> Vec<Sale> {
         let Some(sales) = self.by_scarce_contract_id.get(&scarce_contract_id) else {
             return vec![];
@@ -58,34 +151,43 @@ impl Contract {
 
         let start = from_index.unwrap_or(0);
         let limit = limit.unwrap_or(50).min(100);
+        let include_expired = include_expired.unwrap_or(false);
+        let now = env::block_timestamp();
 
         sales
             .iter()
+            .filter_map(|sale_id| self.sales.get(sale_id))
+            .filter(|sale| include_expired || !Self::sale_expired_at(sale, now))
             .skip(start as usize)
             .take(limit as usize)
-            .filter_map(|sale_id| self.sales.get(sale_id).cloned())
+            .cloned()
             .collect()
     }
 
-    pub fn get_sales(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<Sale> {
+    pub fn get_sales(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+        include_expired: Option<bool>,
+    ) -> Vec<Sale> {
         let start = from_index.unwrap_or(0);
         let limit = limit.unwrap_or(50).min(100);
+        let include_expired = include_expired.unwrap_or(false);
+        let now = env::block_timestamp();
 
         self.sales
-            .iter()
+            .values()
+            .filter(|sale| include_expired || !Self::sale_expired_at(sale, now))
             .skip(start as usize)
             .take(limit as usize)
-            .map(|(_, sale)| sale.clone())
+            .cloned()
             .collect()
     }
 
     pub fn is_sale_expired(&self, scarce_contract_id: AccountId, token_id: String) -> Option<bool> {
         let sale_id = Contract::make_sale_id(&scarce_contract_id, &token_id);
         let sale = self.sales.get(&sale_id)?;
-        Some(
-            sale.expires_at
-                .is_some_and(|exp| env::block_timestamp() > exp),
-        )
+        Some(Self::sale_expired_at(sale, env::block_timestamp()))
     }
 
     pub fn get_expired_sales(
@@ -100,7 +202,7 @@ impl Contract {
         self.sales
             .iter()
             .skip(start)
-            .filter(|(_, sale)| sale.expires_at.is_some_and(|exp| now > exp))
+            .filter(|(_, sale)| Self::sale_expired_at(sale, now))
             .take(limit as usize)
             .map(|(id, sale)| (id.clone(), sale.clone()))
             .collect()
@@ -109,7 +211,57 @@ impl Contract {
     pub fn get_auction(&self, token_id: String) -> Option<AuctionView> {
         let sale_id = Contract::make_sale_id(&env::current_account_id(), &token_id);
         let sale = self.sales.get(&sale_id)?;
+        Self::auction_view(token_id, sale)
+    }
+
+    pub fn get_auctions(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<AuctionView> {
+        let start = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(50).min(100);
+
+        self.sales
+            .iter()
+            .skip(start as usize)
+            .filter(|(_, sale)| sale.auction.is_some())
+            .take(limit as usize)
+            .filter_map(|(_, sale)| Self::auction_view(Self::native_token_id(sale)?, sale))
+            .collect()
+    }
+
+    /// Auctions still accepting bids. Ended-but-unsettled auctions are skipped;
+    /// `from_index` indexes into `sales`, as in `get_expired_sales`.
+    pub fn get_active_auctions(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Vec<AuctionView> {
+        let start = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(50).min(100);
+
+        self.sales
+            .iter()
+            .skip(start as usize)
+            .filter_map(|(_, sale)| Self::auction_view(Self::native_token_id(sale)?, sale))
+            .filter(|view| !view.is_ended)
+            .take(limit as usize)
+            .collect()
+    }
+}
+
+impl Contract {
+    fn native_token_id(sale: &Sale) -> Option<String> {
+        match &sale.sale_type {
+            SaleType::NativeScarce { token_id } => Some(token_id.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn sale_expired_at(sale: &Sale, now: u64) -> bool {
+        sale.expires_at.is_some_and(|exp| now > exp)
+    }
+
+    fn auction_view(token_id: String, sale: &Sale) -> Option<AuctionView> {
         let auction = sale.auction.as_ref()?;
+        let now = env::block_timestamp();
         Some(AuctionView {
             token_id,
             seller_id: sale.owner_id.clone(),
@@ -120,44 +272,14 @@ impl Contract {
             bid_count: auction.bid_count,
             expires_at: sale.expires_at,
             anti_snipe_extension_ns: auction.anti_snipe_extension_ns,
+            anti_snipe_window_ns: auction.anti_snipe_window_ns,
+            anti_snipe_extensions: auction.anti_snipe_extensions,
             buy_now_price: auction.buy_now_price,
-            is_ended: sale.expires_at.is_some_and(|e| env::block_timestamp() >= e),
+            is_ended: sale.expires_at.is_some_and(|e| now >= e),
             reserve_met: auction.highest_bid.0 >= auction.reserve_price.0
                 && auction.highest_bid.0 > 0,
+            min_next_bid: U128(auction.min_next_bid()),
+            time_remaining_ns: sale.expires_at.map(|e| e.saturating_sub(now)),
         })
     }
-
-    pub fn get_auctions(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<AuctionView> {
-        let start = from_index.unwrap_or(0);
-        let limit = limit.unwrap_or(50).min(100);
-
-        self.sales
-            .iter()
-            .skip(start as usize)
-            .filter(|(_, sale)| sale.auction.is_some())
-            .take(limit as usize)
-            .filter_map(|(_, sale)| {
-                let auction = sale.auction.as_ref()?;
-                let token_id = match &sale.sale_type {
-                    SaleType::NativeScarce { token_id } => token_id.clone(),
-                    _ => return None,
-                };
-                Some(AuctionView {
-                    token_id,
-                    seller_id: sale.owner_id.clone(),
-                    reserve_price: auction.reserve_price,
-                    min_bid_increment: auction.min_bid_increment,
-                    highest_bid: auction.highest_bid,
-                    highest_bidder: auction.highest_bidder.clone(),
-                    bid_count: auction.bid_count,
-                    expires_at: sale.expires_at,
-                    anti_snipe_extension_ns: auction.anti_snipe_extension_ns,
-                    buy_now_price: auction.buy_now_price,
-                    is_ended: sale.expires_at.is_some_and(|e| env::block_timestamp() >= e),
-                    reserve_met: auction.highest_bid.0 >= auction.reserve_price.0
-                        && auction.highest_bid.0 > 0,
-                })
-            })
-            .collect()
-    }
 }
@@ -323,6 +323,7 @@ impl Contract {
                     "Price must be greater than 0".to_string(),
                 ));
             }
+            self.check_price_bounds(&scarce_contract_id, price)?;
 
             let sale_id = Contract::make_sale_id(&scarce_contract_id, &token_id);
             if self.sales.contains_key(&sale_id) {
@@ -32,12 +32,7 @@ impl Contract {
             .iter()
             .skip(start)
             .take(limit)
-            .map(|(token_id, token)| external::Token {
-                token_id: token_id.clone(),
-                owner_id: token.owner_id.clone(),
-                metadata: Some(token.metadata.clone()),
-                approved_account_ids: Some(token.approved_account_ids.clone()),
-            })
+            .map(|(token_id, token)| self.token_view(token_id, token))
             .collect()
     }
 
@@ -75,12 +70,7 @@ impl Contract {
             .filter_map(|token_id| {
                 self.scarces_by_id
                     .get(token_id.as_str())
-                    .map(|token| external::Token {
-                        token_id: token_id.clone(),
-                        owner_id: token.owner_id.clone(),
-                        metadata: Some(token.metadata.clone()),
-                        approved_account_ids: Some(token.approved_account_ids.clone()),
-                    })
+                    .map(|token| self.token_view(token_id, token))
             })
             .take(limit)
             .collect()
@@ -116,12 +106,7 @@ impl Contract {
                 let token_id = format!("{}:{}", collection_id, serial);
                 self.scarces_by_id
                     .get(token_id.as_str())
-                    .map(|token| external::Token {
-                        token_id: token_id.clone(),
-                        owner_id: token.owner_id.clone(),
-                        metadata: Some(token.metadata.clone()),
-                        approved_account_ids: Some(token.approved_account_ids.clone()),
-                    })
+                    .map(|token| self.token_view(&token_id, token))
             })
             .skip(start)
             .take(limit)
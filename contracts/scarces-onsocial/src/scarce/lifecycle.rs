@@ -192,8 +192,20 @@ impl Contract {
             ));
         }
 
+        let now = env::block_timestamp();
+        if token.redeem_start.is_some_and(|start| now < start) {
+            return Err(MarketplaceError::InvalidState(
+                "Redemption window has not opened".into(),
+            ));
+        }
+        if token.redeem_end.is_some_and(|end| now > end) {
+            return Err(MarketplaceError::InvalidState(
+                "Redemption window has closed".into(),
+            ));
+        }
+
         let owner_id = token.owner_id.clone();
-        token.redeemed_at = Some(env::block_timestamp());
+        token.redeemed_at = Some(now);
         token.redeem_count += 1;
         let current_count = token.redeem_count;
         self.scarces_by_id.insert(token_id.to_string(), token);
@@ -221,7 +233,7 @@ impl Contract {
         actor_id: &AccountId,
         collection: &LazyCollection,
     ) -> Result<(), MarketplaceError> {
-        if actor_id == &collection.creator_id {
+        if collection.can_manage(actor_id) {
             return Ok(());
         }
         Err(MarketplaceError::Unauthorized(
@@ -229,6 +241,33 @@ impl Contract {
         ))
     }
 
+    // Storage/accounting invariant: freed bytes are released to the owner's storage waterfall.
+    pub(crate) fn destroy_scarce(
+        &mut self,
+        token_id: &str,
+        owner_id: &AccountId,
+        token_app_id: Option<&AccountId>,
+    ) {
+        let before = self.storage_usage_flushed();
+
+        let app_for_index = self.resolve_token_app_id(token_id, token_app_id);
+
+        self.scarces_by_id.remove(token_id);
+        self.token_provenance.remove(token_id);
+
+        self.remove_token_from_owner(owner_id, token_id);
+        self.remove_sale_listing(token_id, owner_id, "burned");
+
+        let bytes_freed = before.saturating_sub(self.storage_usage_flushed());
+        if bytes_freed > 0 {
+            self.release_storage_waterfall(owner_id, bytes_freed, token_app_id);
+        }
+
+        if let Some(app) = app_for_index {
+            self.untrack_app_owner(&app, owner_id);
+        }
+    }
+
     pub(crate) fn burn_scarce(
         &mut self,
         actor_id: &AccountId,
@@ -262,23 +301,7 @@ impl Contract {
             (token.owner_id.clone(), token.app_id.clone())
         };
 
-        let before = self.storage_usage_flushed();
-
-        let app_for_index = self.resolve_token_app_id(token_id, token_app_id.as_ref());
-
-        self.scarces_by_id.remove(token_id);
-
-        self.remove_token_from_owner(&owner_id, token_id);
-        self.remove_sale_listing(token_id, &owner_id, "burned");
-
-        let bytes_freed = before.saturating_sub(self.storage_usage_flushed());
-        if bytes_freed > 0 {
-            self.release_storage_waterfall(&owner_id, bytes_freed, token_app_id.as_ref());
-        }
-
-        if let Some(app) = app_for_index {
-            self.untrack_app_owner(&app, &owner_id);
-        }
+        self.destroy_scarce(token_id, &owner_id, token_app_id.as_ref());
 
         events::emit_scarce_burned(&owner_id, token_id, Some(collection_id));
         Ok(())
@@ -307,25 +330,71 @@ impl Contract {
             (token.owner_id.clone(), token.app_id.clone())
         };
 
-        let before = self.storage_usage_flushed();
+        self.destroy_scarce(token_id, &owner_id, token_app_id.as_ref());
 
-        let app_for_index = self.resolve_token_app_id(token_id, token_app_id.as_ref());
-
-        self.scarces_by_id.remove(token_id);
+        events::emit_scarce_burned(&owner_id, token_id, None);
+        Ok(())
+    }
 
-        self.remove_token_from_owner(&owner_id, token_id);
-        self.remove_sale_listing(token_id, &owner_id, "burned");
+    /// Routes to collection or standalone burn; without an explicit
+    /// `collection_id` the collection is derived from the token ID.
+    pub(crate) fn burn_token(
+        &mut self,
+        actor_id: &AccountId,
+        token_id: &str,
+        collection_id: Option<&str>,
+    ) -> Result<(), MarketplaceError> {
+        match collection_id {
+            Some(cid) => self.burn_scarce(actor_id, token_id, cid),
+            None => {
+                // Storage key invariant: "s" namespace maps to standalone tokens and must route to standalone burn.
+                let cid = crate::collection_id_from_token_id(token_id);
+                if cid.is_empty() || cid == "s" {
+                    self.burn_standalone(actor_id, token_id)
+                } else {
+                    self.burn_scarce(actor_id, token_id, cid)
+                }
+            }
+        }
+    }
 
-        let bytes_freed = before.saturating_sub(self.storage_usage_flushed());
-        if bytes_freed > 0 {
-            self.release_storage_waterfall(&owner_id, bytes_freed, token_app_id.as_ref());
+    /// All-or-nothing: the actor must own every token before anything is
+    /// burned, and any later failure reverts the whole call. NEP-178 approvals
+    /// grant transfer rights only, so they do not authorize a burn.
+    pub(crate) fn batch_burn(
+        &mut self,
+        actor_id: &AccountId,
+        token_ids: Vec<String>,
+    ) -> Result<(), MarketplaceError> {
+        if token_ids.is_empty() || token_ids.len() as u32 > MAX_BATCH_BURN {
+            return Err(MarketplaceError::InvalidInput(format!(
+                "Batch size must be 1-{}",
+                MAX_BATCH_BURN
+            )));
         }
 
-        if let Some(app) = app_for_index {
-            self.untrack_app_owner(&app, &owner_id);
+        for (i, token_id) in token_ids.iter().enumerate() {
+            if token_ids[..i].contains(token_id) {
+                return Err(MarketplaceError::InvalidInput(format!(
+                    "Duplicate token {} in batch",
+                    token_id
+                )));
+            }
+            let token = self
+                .scarces_by_id
+                .get(token_id)
+                .ok_or_else(|| MarketplaceError::NotFound("Token not found".into()))?;
+            if &token.owner_id != actor_id {
+                return Err(MarketplaceError::Unauthorized(format!(
+                    "Only the token owner can burn token {}",
+                    token_id
+                )));
+            }
         }
 
-        events::emit_scarce_burned(&owner_id, token_id, None);
+        for token_id in &token_ids {
+            self.burn_token(actor_id, token_id, None)?;
+        }
         Ok(())
     }
 }
@@ -1,4 +1,5 @@
 use crate::*;
+use near_sdk::serde_json;
 
 #[near]
 impl Contract {
@@ -6,3 +7,57 @@ impl Contract {
         self.contract_metadata.clone()
     }
 }
+
+fn resolve_uri(base_uri: &str, uri: &mut Option<String>) {
+    let Some(path) = uri.as_deref() else {
+        return;
+    };
+    if path.contains("://") || path.starts_with("data:") {
+        return;
+    }
+    *uri = Some(format!(
+        "{}/{}",
+        base_uri.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    ));
+}
+
+impl Contract {
+    // Metadata invariant: unrevealed collections serve their placeholder (keeping the
+    // token's own timing fields) over the real metadata stored at mint; relative `media`/`reference` are resolved against the
+    // collection `base_uri`; absolute URIs and standalone tokens pass through unchanged.
+    pub(crate) fn display_metadata(&self, token_id: &str, token: &Scarce) -> TokenMetadata {
+        let Some(collection) = self.collections.get(collection_id_from_token_id(token_id)) else {
+            return token.metadata.clone();
+        };
+        let mut metadata = match collection.placeholder_metadata.as_deref() {
+            Some(placeholder) if !collection.is_revealed() => {
+                serde_json::from_str::<TokenMetadata>(placeholder)
+                    .map(|p| TokenMetadata {
+                        copies: token.metadata.copies,
+                        issued_at: token.metadata.issued_at,
+                        expires_at: token.metadata.expires_at,
+                        starts_at: token.metadata.starts_at,
+                        updated_at: token.metadata.updated_at,
+                        ..p
+                    })
+                    .unwrap_or_else(|_| token.metadata.clone())
+            }
+            _ => token.metadata.clone(),
+        };
+        if let Some(base_uri) = collection.base_uri.as_deref() {
+            resolve_uri(base_uri, &mut metadata.media);
+            resolve_uri(base_uri, &mut metadata.reference);
+        }
+        metadata
+    }
+
+    pub(crate) fn token_view(&self, token_id: &str, token: &Scarce) -> external::Token {
+        external::Token {
+            token_id: token_id.to_string(),
+            owner_id: token.owner_id.clone(),
+            metadata: Some(self.display_metadata(token_id, token)),
+            approved_account_ids: Some(token.approved_account_ids.clone()),
+        }
+    }
+}
@@ -63,7 +63,10 @@ impl Contract {
             transferable: ovr.transferable,
             burnable: ovr.burnable,
             app_id: ovr.app_id.clone(),
+            redeem_start: ovr.redeem_start,
+            redeem_end: ovr.redeem_end,
         };
+        self.record_provenance(&token_id, &token.owner_id);
 
         self.scarces_by_id.insert(token_id.clone(), token);
         self.add_token_to_owner(&owner_id, &token_id);
@@ -86,8 +89,16 @@ impl Contract {
             app_id,
             transferable,
             burnable,
+            redeem_start,
+            redeem_end,
         } = options;
 
+        if redeem_start.is_some() || redeem_end.is_some() {
+            return Err(MarketplaceError::InvalidInput(
+                "Redemption windows require a redeemable collection".into(),
+            ));
+        }
+
         if let Some(ref app) = app_id {
             if !self.app_pools.contains_key(app) {
                 return Err(MarketplaceError::NotFound("App pool not found".into()));
@@ -115,6 +126,7 @@ impl Contract {
             transferable: Some(transferable),
             burnable: Some(burnable),
             paid_price: 0,
+            ..Default::default()
         };
         self.mint(token_id.clone(), ctx, metadata, Some(ovr))?;
 
@@ -15,6 +15,7 @@ impl Contract {
         check_one_yocto()?;
         let sender_id = env::predecessor_account_id();
 
+        self.check_royalty_transfer(&sender_id, &receiver_id, &token_id)?;
         self.transfer(&sender_id, &receiver_id, &token_id, approval_id, memo)
     }
 
@@ -38,6 +39,7 @@ impl Contract {
         let previous_owner_id = token.owner_id.clone();
         let previous_approvals = token.approved_account_ids.clone();
 
+        self.check_royalty_transfer(&sender_id, &receiver_id, &token_id)?;
         self.transfer(&sender_id, &receiver_id, &token_id, approval_id, memo)?;
 
         let receiver_gas = Gas::from_tgas(DEFAULT_CALLBACK_GAS);
@@ -109,6 +111,7 @@ impl Contract {
         if let Some(approvals) = approved_account_ids {
             token.approved_account_ids = approvals;
         }
+        self.record_provenance(&token_id, &token.owner_id);
 
         self.add_token_to_owner(&previous_owner_id, &token_id);
         self.scarces_by_id.insert(token_id.clone(), token);
@@ -134,12 +137,7 @@ impl Contract {
     pub fn nft_token(&self, token_id: String) -> Option<external::Token> {
         self.scarces_by_id
             .get(&token_id)
-            .map(|token| external::Token {
-                token_id: token_id.clone(),
-                owner_id: token.owner_id.clone(),
-                metadata: Some(token.metadata.clone()),
-                approved_account_ids: Some(token.approved_account_ids.clone()),
-            })
+            .map(|token| self.token_view(&token_id, token))
     }
 }
 
@@ -185,6 +183,7 @@ impl Contract {
 
         token.owner_id = receiver_id.clone();
         token.approved_account_ids.clear();
+        self.record_provenance(token_id, &token.owner_id);
 
         self.add_token_to_owner(receiver_id, token_id);
         self.scarces_by_id.insert(token_id.to_string(), token);
@@ -208,6 +207,29 @@ impl Contract {
         Ok(())
     }
 
+    /// Appends the token's current owner when its collection opted in,
+    /// evicting the oldest entry once MAX_PROVENANCE_ENTRIES is reached.
+    pub(crate) fn record_provenance(&mut self, token_id: &str, owner_id: &AccountId) {
+        let tracked = self
+            .collections
+            .get(collection_id_from_token_id(token_id))
+            .is_some_and(|c| c.track_provenance);
+        if !tracked {
+            return;
+        }
+        let mut provenance = self
+            .token_provenance
+            .get(token_id)
+            .cloned()
+            .unwrap_or_default();
+        if provenance.len() >= MAX_PROVENANCE_ENTRIES {
+            provenance.remove(0);
+        }
+        provenance.push((owner_id.clone(), env::block_timestamp()));
+        self.token_provenance
+            .insert(token_id.to_string(), provenance);
+    }
+
     pub(crate) fn batch_transfer(
         &mut self,
         actor_id: &AccountId,
@@ -221,6 +243,7 @@ impl Contract {
         }
 
         for item in &transfers {
+            self.check_royalty_transfer(actor_id, &item.receiver_id, &item.token_id)?;
             self.transfer(
                 actor_id,
                 &item.receiver_id,
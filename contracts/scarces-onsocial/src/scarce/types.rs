@@ -1,6 +1,9 @@
-use near_sdk::AccountId;
+use near_sdk::borsh::io::{Read, Result};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 use near_sdk::json_types::{Base64VecU8, U128};
-use near_sdk::near;
+use near_sdk::{AccountId, near};
+
+use crate::storage::legacy::read_appended;
 
 #[near(serializers = [borsh, json])]
 #[derive(Clone)]
@@ -30,6 +33,12 @@ pub struct ScarceOptions {
     pub transferable: bool,
     #[serde(default = "crate::default_true")]
     pub burnable: bool,
+    /// Redemption window in block-timestamp nanoseconds; only meaningful
+    /// for redeemable collections.
+    #[serde(default)]
+    pub redeem_start: Option<u64>,
+    #[serde(default)]
+    pub redeem_end: Option<u64>,
 }
 
 #[derive(Clone)]
@@ -46,10 +55,15 @@ pub struct ScarceOverrides {
     pub transferable: Option<bool>,
     pub burnable: Option<bool>,
     pub paid_price: u128,
+    pub redeem_start: Option<u64>,
+    pub redeem_end: Option<u64>,
 }
 
-#[near(serializers = [borsh, json])]
-#[derive(Clone)]
+// Upgrade invariant: fields are only appended; tokens minted before a trailing
+// field existed still decode (see the `BorshDeserialize` impl).
+#[near(serializers = [json])]
+#[derive(Clone, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
 pub struct Scarce {
     pub owner_id: AccountId,
     pub creator_id: AccountId,
@@ -75,6 +89,57 @@ pub struct Scarce {
     pub burnable: Option<bool>,
     #[serde(default)]
     pub app_id: Option<AccountId>,
+    // Redemption invariant: `RedeemToken` is rejected outside `[redeem_start, redeem_end]`.
+    #[serde(default)]
+    pub redeem_start: Option<u64>,
+    #[serde(default)]
+    pub redeem_end: Option<u64>,
+}
+
+/// `Scarce` as written before redemption windows.
+#[derive(BorshDeserialize)]
+#[borsh(crate = "near_sdk::borsh")]
+struct ScarceV1 {
+    owner_id: AccountId,
+    creator_id: AccountId,
+    minter_id: AccountId,
+    metadata: TokenMetadata,
+    approved_account_ids: std::collections::HashMap<AccountId, u64>,
+    royalty: Option<std::collections::HashMap<AccountId, u32>>,
+    revoked_at: Option<u64>,
+    revocation_memo: Option<String>,
+    redeemed_at: Option<u64>,
+    redeem_count: u32,
+    paid_price: U128,
+    refunded: bool,
+    transferable: Option<bool>,
+    burnable: Option<bool>,
+    app_id: Option<AccountId>,
+}
+
+impl BorshDeserialize for Scarce {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let v1 = ScarceV1::deserialize_reader(reader)?;
+        Ok(Self {
+            owner_id: v1.owner_id,
+            creator_id: v1.creator_id,
+            minter_id: v1.minter_id,
+            metadata: v1.metadata,
+            approved_account_ids: v1.approved_account_ids,
+            royalty: v1.royalty,
+            revoked_at: v1.revoked_at,
+            revocation_memo: v1.revocation_memo,
+            redeemed_at: v1.redeemed_at,
+            redeem_count: v1.redeem_count,
+            paid_price: v1.paid_price,
+            refunded: v1.refunded,
+            transferable: v1.transferable,
+            burnable: v1.burnable,
+            app_id: v1.app_id,
+            redeem_start: read_appended(reader, None)?,
+            redeem_end: read_appended(reader, None)?,
+        })
+    }
 }
 
 #[near(serializers = [json])]
@@ -95,6 +160,8 @@ pub struct TokenStatus {
     pub max_redeems: Option<u32>,
     pub is_fully_redeemed: bool,
     pub redeemed_at: Option<u64>,
+    pub redeem_start: Option<u64>,
+    pub redeem_end: Option<u64>,
     pub is_refunded: bool,
     pub paid_price: U128,
 }
@@ -43,6 +43,14 @@ impl Contract {
         Some(max_redeems.is_some_and(|max| token.redeem_count >= max))
     }
 
+    /// Oldest first; empty when the token's collection does not track provenance.
+    pub fn get_token_provenance(&self, token_id: String) -> Vec<(AccountId, u64)> {
+        self.token_provenance
+            .get(&token_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     /// `max_redeems` is `None` for collections without a redemption cap.
     pub fn get_redeem_info(&self, token_id: String) -> Option<RedeemInfo> {
         let token = self.scarces_by_id.get(&token_id)?;
@@ -73,7 +81,7 @@ impl Contract {
             creator_id: token.creator_id.clone(),
             minter_id: token.minter_id.clone(),
             collection_id: collection.map(|_| cid.to_string()),
-            metadata: token.metadata.clone(),
+            metadata: self.display_metadata(&token_id, token),
             royalty: token.royalty.clone(),
             is_valid: !is_revoked && !is_fully_redeemed && !is_expired,
             is_revoked,
@@ -84,6 +92,8 @@ impl Contract {
             max_redeems,
             is_fully_redeemed,
             redeemed_at: token.redeemed_at,
+            redeem_start: token.redeem_start,
+            redeem_end: token.redeem_end,
             is_refunded: token.refunded,
             paid_price: token.paid_price,
         })
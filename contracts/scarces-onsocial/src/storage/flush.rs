@@ -20,6 +20,16 @@ impl Contract {
         self.collection_mint_counts.flush();
         self.collection_allowlist.flush();
         self.approved_nft_contracts.flush();
+        self.known_marketplaces.flush();
+        self.accepted_payment_tokens.flush();
+        self.price_bounds.flush();
+        self.creator_earnings.flush();
+        self.ban_appeals.flush();
+        self.collection_last_mint.flush();
+        self.ft_claimable.flush();
+        self.token_provenance.flush();
+        self.auction_token_ids.flush();
+        self.collection_offer_buyers.flush();
     }
 
     // Persistence invariant: all storage snapshots used for charging/releasing must call this path.
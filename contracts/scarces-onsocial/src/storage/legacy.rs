@@ -0,0 +1,13 @@
+use near_sdk::borsh::BorshDeserialize;
+use near_sdk::borsh::io::{Read, Result};
+
+// Upgrade invariant: stored records only ever gain trailing fields, so a record
+// written by an older layout is a byte prefix of the current one. A field the
+// record ends before decodes as `default`; the record gains it on its next write.
+pub(crate) fn read_appended<T: BorshDeserialize, R: Read>(reader: &mut R, default: T) -> Result<T> {
+    let mut first = [0u8; 1];
+    if reader.read(&mut first)? == 0 {
+        return Ok(default);
+    }
+    T::deserialize_reader(&mut (&first[..]).chain(reader))
+}
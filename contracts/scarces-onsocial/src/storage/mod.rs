@@ -1,5 +1,6 @@
 mod deposit;
 mod flush;
+pub(crate) mod legacy;
 pub mod types;
 mod waterfall;
 
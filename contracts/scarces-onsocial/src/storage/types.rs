@@ -39,6 +39,18 @@ pub enum StorageKey {
     CollectionOffers,
     LazyListings,
     ApprovedNftContracts,
+    MintRefundEscrows,
+    KnownMarketplaces,
+    AcceptedPaymentTokens,
+    PriceBounds,
+    CreatorEarnings,
+    BanAppeals,
+    CollectionLastMint,
+    FtClaimable,
+    TokenProvenance,
+    AuctionTokenIds,
+    CollectionOfferBuyers,
+    CollectionOfferBuyersInner { collection_id_hash: Vec<u8> },
 }
 
 #[near(serializers = [borsh, json])]
@@ -21,8 +21,13 @@ impl Contract {
                 let remaining_allowance = pool.max_user_bytes.saturating_sub(user_used);
                 let can_cover_bytes = remaining_allowance.min(bytes_used);
                 let can_cover_cost = (can_cover_bytes as u128) * storage_byte_cost();
+                let now = crate::time::now_ms();
+                let within_cap = pool
+                    .remaining_spending_cap(now)
+                    .is_none_or(|remaining| can_cover_cost <= remaining);
 
-                if can_cover_cost > 0 && pool.balance.0 >= can_cover_cost {
+                if can_cover_cost > 0 && pool.balance.0 >= can_cover_cost && within_cap {
+                    pool.record_spend(can_cover_cost, now);
                     pool.balance.0 -= can_cover_cost;
                     pool.used_bytes += can_cover_bytes;
                     self.app_pools.insert(app.clone(), pool);
@@ -61,6 +66,28 @@ impl Contract {
         Ok(())
     }
 
+    // Storage invariant: app-tagged purchases never fall through to the platform tier;
+    // the buyer pays unless the app pool opts in to sponsoring buyer storage.
+    pub(crate) fn charge_buyer_storage(
+        &mut self,
+        buyer_id: &AccountId,
+        bytes_used: u64,
+        app_id: Option<&AccountId>,
+    ) -> Result<(), MarketplaceError> {
+        match app_id {
+            Some(app)
+                if bytes_used > 0
+                    && !self
+                        .app_pools
+                        .get(app)
+                        .is_some_and(|pool| pool.sponsor_buyer_storage) =>
+            {
+                self.charge_user_storage(buyer_id, bytes_used)
+            }
+            _ => self.charge_storage_waterfall(buyer_id, bytes_used, app_id),
+        }
+    }
+
     pub(crate) fn release_storage_waterfall(
         &mut self,
         account_id: &AccountId,
@@ -78,6 +105,7 @@ impl Contract {
 
                 let returnable = user_used.min(bytes_freed);
                 let return_cost = (returnable as u128) * storage_byte_cost();
+                pool.reverse_spend(return_cost, crate::time::now_ms());
                 pool.balance.0 += return_cost;
                 pool.used_bytes = pool.used_bytes.saturating_sub(returnable);
                 self.app_user_usage
@@ -2,14 +2,21 @@ pub mod test_utils;
 
 pub mod unit {
     pub mod admin_test;
+    pub mod app_pool_sponsorship_test;
     pub mod app_pool_test;
     pub mod approval_test;
     pub mod auction_settle_test;
+    pub mod collaborative_collection_test;
+    pub mod collection_base_uri_test;
     pub mod collection_manage_test;
+    pub mod collection_merkle_allowlist_test;
+    pub mod collection_mint_limits_test;
     pub mod collection_mint_test;
     pub mod collection_offer_test;
     pub mod collection_purchase_test;
+    pub mod collection_reveal_test;
     pub mod collections_test;
+    pub mod creator_earnings_test;
     pub mod dispatch_test;
     pub mod fee_routing_test;
     pub mod fees_test;
@@ -19,7 +26,10 @@ pub mod unit {
     pub mod metadata_template_test;
     pub mod moderation_test;
     pub mod offer_test;
+    pub mod price_bounds_test;
     pub mod pricing_test;
+    pub mod provenance_test;
+    pub mod royalty_enforcement_test;
     pub mod royalty_test;
     pub mod sale_test;
     pub mod scarce_test;
@@ -34,6 +44,7 @@ pub mod unit {
     pub mod lazy_listing_cleanup_test;
     pub mod lazy_listing_views_test;
     pub mod metadata_platform_test;
+    pub mod mint_refund_test;
     pub mod payout_test;
     pub mod prepaid_balance_test;
     pub mod refund_test;
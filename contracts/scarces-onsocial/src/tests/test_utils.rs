@@ -54,3 +54,68 @@ pub fn make_request(action: crate::Action) -> crate::Request {
         options: None,
     }
 }
+
+/// An open, free collection of 10 with every optional feature off. Override
+/// fields with struct-update syntax.
+#[cfg(test)]
+pub fn collection_config(collection_id: &str) -> CollectionConfig {
+    CollectionConfig {
+        collection_id: collection_id.to_string(),
+        total_supply: 10,
+        metadata_template: r#"{"title":"T"}"#.to_string(),
+        price_near: U128(0),
+        start_time: None,
+        end_time: None,
+        options: scarce::types::ScarceOptions {
+            royalty: None,
+            app_id: None,
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    }
+}
+
+/// `contract` as the first deployment stored it: the current state with every
+/// appended root field cut off.
+#[cfg(test)]
+pub fn first_deployment_state(contract: &Contract) -> Vec<u8> {
+    let full = near_sdk::borsh::to_vec(contract).unwrap();
+    let appended = near_sdk::borsh::to_vec(&(
+        &contract.mint_refund_escrows,
+        &contract.known_marketplaces,
+        &contract.accepted_payment_tokens,
+        &contract.price_bounds,
+        &contract.creator_earnings,
+        &contract.ban_appeals,
+        &contract.collection_last_mint,
+        &contract.ft_claimable,
+        &contract.token_provenance,
+        &contract.auction_token_ids,
+        &contract.collection_offer_buyers,
+    ))
+    .unwrap();
+    full[..full.len() - appended.len()].to_vec()
+}
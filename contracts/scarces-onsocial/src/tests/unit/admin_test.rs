@@ -2,6 +2,33 @@ use crate::tests::test_utils::*;
 use crate::*;
 use near_sdk::testing_env;
 
+#[test]
+fn migrate_upgrades_state_from_first_deployment() {
+    let mut contract = new_contract();
+    contract.next_token_id = 42;
+    near_sdk::env::storage_write(b"STATE", &first_deployment_state(&contract));
+
+    let mut migrated = Contract::migrate();
+    assert_eq!(migrated.owner_id, owner());
+    assert_eq!(migrated.next_token_id, 42);
+    assert!(migrated.ban_appeals.get("col").is_none());
+    migrated.ft_claimable.insert(
+        "usdc.near:alice.near".to_string(),
+        near_sdk::json_types::U128(1),
+    );
+    assert!(migrated.ft_claimable.contains_key("usdc.near:alice.near"));
+}
+
+#[test]
+fn migrate_keeps_current_state() {
+    let mut contract = new_contract();
+    contract.next_token_id = 7;
+    near_sdk::env::storage_write(b"STATE", &near_sdk::borsh::to_vec(&contract).unwrap());
+
+    let migrated = Contract::migrate();
+    assert_eq!(migrated.next_token_id, 7);
+}
+
 #[test]
 fn transfer_ownership_happy() {
     let mut contract = new_contract();
@@ -0,0 +1,190 @@
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::testing_env;
+
+fn app() -> AccountId {
+    "sponsor.near".parse().unwrap()
+}
+
+/// App pool `sponsor.near` backs collection `app_col`; the pool is left holding `pool_balance`.
+fn setup(pool_balance: u128, sponsor_buyer_storage: bool) -> Contract {
+    let mut contract = new_contract();
+    contract.app_pools.insert(
+        app(),
+        AppPool {
+            owner_id: creator(),
+            balance: U128(storage::storage_byte_cost() * 50_000),
+            used_bytes: 0,
+            max_user_bytes: 100_000,
+            moderators: vec![],
+            curated: false,
+            default_royalty: None,
+            primary_sale_bps: 0,
+            metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage,
+        },
+    );
+    let config = CollectionConfig {
+        collection_id: "app_col".to_string(),
+        total_supply: 10,
+        metadata_template: r#"{"title":"Sponsored #{seat_number}"}"#.to_string(),
+        price_near: U128(0),
+        start_time: None,
+        end_time: None,
+        options: scarce::types::ScarceOptions {
+            royalty: None,
+            app_id: Some(app()),
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    };
+    testing_env!(context(creator()).build());
+    contract.create_collection(&creator(), config).unwrap();
+    contract.app_pools.get_mut(&app()).unwrap().balance = U128(pool_balance);
+    contract
+}
+
+fn buyer_pool_usage(contract: &Contract) -> u64 {
+    contract
+        .app_user_usage
+        .get(&format!("{}:{}", buyer(), app()))
+        .copied()
+        .unwrap_or(0)
+}
+
+fn buyer_used_bytes(contract: &Contract) -> u64 {
+    contract
+        .user_storage
+        .get(&buyer())
+        .map(|s| s.used_bytes)
+        .unwrap_or(0)
+}
+
+fn purchase(contract: &mut Contract) -> Result<(), MarketplaceError> {
+    testing_env!(context(buyer()).build());
+    contract
+        .purchase_from_collection(&buyer(), "app_col".to_string(), 1, U128(0), None, 0)
+        .map_err(Into::into)
+}
+
+#[test]
+fn sponsoring_pool_covers_buyer_storage() {
+    let mut contract = setup(storage::storage_byte_cost() * 50_000, true);
+    let pool_before = contract.app_pools.get(&app()).unwrap().clone();
+
+    purchase(&mut contract).unwrap();
+
+    let pool = contract.app_pools.get(&app()).unwrap();
+    let sponsored = buyer_pool_usage(&contract);
+    assert!(sponsored > 0);
+    assert_eq!(pool.used_bytes, pool_before.used_bytes + sponsored);
+    assert_eq!(
+        pool.balance.0,
+        pool_before.balance.0 - sponsored as u128 * storage::storage_byte_cost()
+    );
+    assert_eq!(buyer_used_bytes(&contract), 0);
+}
+
+#[test]
+fn depleted_pool_falls_back_to_buyer_deposit() {
+    let mut contract = setup(0, true);
+
+    let err = purchase(&mut contract).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InsufficientStorage(_)));
+
+    contract.pending_attached_balance = storage::storage_byte_cost() * 50_000;
+    purchase(&mut contract).unwrap();
+    assert_eq!(buyer_pool_usage(&contract), 0);
+    assert!(buyer_used_bytes(&contract) > 0);
+}
+
+#[test]
+fn opted_out_pool_leaves_buyer_paying() {
+    let mut contract = setup(storage::storage_byte_cost() * 50_000, true);
+    testing_env!(context(creator()).build());
+    contract
+        .set_app_config(
+            &creator(),
+            &app(),
+            AppConfig {
+                sponsor_buyer_storage: Some(false),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let pool_before = contract.app_pools.get(&app()).unwrap().clone();
+    assert!(!pool_before.sponsor_buyer_storage);
+
+    let err = purchase(&mut contract).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InsufficientStorage(_)));
+
+    contract.pending_attached_balance = storage::storage_byte_cost() * 50_000;
+    purchase(&mut contract).unwrap();
+    assert_eq!(
+        contract.app_pools.get(&app()).unwrap().used_bytes,
+        pool_before.used_bytes
+    );
+    assert_eq!(buyer_pool_usage(&contract), 0);
+    assert!(buyer_used_bytes(&contract) > 0);
+}
+
+#[test]
+fn registered_pools_sponsor_buyers_by_default() {
+    let mut contract = new_contract();
+    let app_id: AccountId = format!("app.{}", creator()).parse().unwrap();
+    testing_env!(context(creator()).build());
+    contract
+        .register_app(&creator(), &app_id, AppConfig::default(), 0)
+        .unwrap();
+    assert!(
+        contract
+            .app_pools
+            .get(&app_id)
+            .unwrap()
+            .sponsor_buyer_storage
+    );
+}
+
+#[test]
+fn pools_registered_before_spending_caps_still_decode() {
+    let contract = setup(0, false);
+    let bytes = near_sdk::borsh::to_vec(contract.app_pools.get(&app()).unwrap()).unwrap();
+
+    let decoded: AppPool = near_sdk::borsh::from_slice(&bytes).unwrap();
+    assert!(!decoded.sponsor_buyer_storage);
+
+    // Pre-upgrade records end at `metadata`: `None` cap, U128, u64 and bool shorter.
+    let legacy: AppPool = near_sdk::borsh::from_slice(&bytes[..bytes.len() - 26]).unwrap();
+    assert_eq!(legacy.owner_id, creator());
+    assert_eq!(legacy.max_user_bytes, 100_000);
+    assert!(legacy.daily_spending_cap.is_none());
+    assert_eq!(legacy.spent_in_window, U128(0));
+    assert!(legacy.sponsor_buyer_storage);
+}
@@ -17,6 +17,8 @@ fn default_options() -> scarce::types::ScarceOptions {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     }
 }
 
@@ -30,6 +32,8 @@ fn register_app(contract: &mut Contract) {
             primary_sale_bps: Some(500),
             curated: Some(false),
             metadata: Some(r#"{"base_uri":"https://example.com"}"#.to_string()),
+            daily_spending_cap: None,
+            sponsor_buyer_storage: None,
         },
     };
     contract.execute(make_request(action)).unwrap();
@@ -152,6 +156,8 @@ fn get_all_app_ids_pagination() {
                 primary_sale_bps: None,
                 curated: None,
                 metadata: None,
+                daily_spending_cap: None,
+                sponsor_buyer_storage: None,
             },
         }))
         .unwrap();
@@ -197,6 +203,8 @@ fn resolve_base_uri_from_app_metadata() {
             app_id: Some(app_id()),
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -206,6 +214,19 @@ fn resolve_base_uri_from_app_metadata() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
 
@@ -235,6 +256,19 @@ fn resolve_base_uri_none_when_no_uri() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
 
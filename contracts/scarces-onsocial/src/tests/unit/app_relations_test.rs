@@ -22,6 +22,8 @@ fn register_app(contract: &mut Contract, metadata: Option<String>) {
                 primary_sale_bps: None,
                 curated: Some(false),
                 metadata,
+                daily_spending_cap: None,
+                sponsor_buyer_storage: None,
             },
         }))
         .unwrap();
@@ -43,6 +45,8 @@ fn create_collection(contract: &mut Contract, who: &AccountId, col_id: &str, sup
                     app_id: Some(app_id()),
                     transferable: true,
                     burnable: true,
+                    redeem_start: None,
+                    redeem_end: None,
                 },
                 renewable: false,
                 revocation_mode: collections::RevocationMode::None,
@@ -52,6 +56,19 @@ fn create_collection(contract: &mut Contract, who: &AccountId, col_id: &str, sup
                 max_per_wallet: None,
                 start_price: None,
                 allowlist_price: None,
+                creators: None,
+                creators_locked: false,
+                refund_window_ms: None,
+                restocking_fee_bps: 0,
+                restock_refunds: false,
+                base_uri: None,
+                allowlist_merkle_root: None,
+                enforce_royalties: false,
+                track_provenance: false,
+                reveal_at: None,
+                placeholder_metadata: None,
+                mint_cooldown_ms: None,
+                allowlist_exempt_from_limits: false,
             },
         }))
         .unwrap();
@@ -138,6 +155,8 @@ fn app_owner_tracked_on_quick_mint_and_burn() {
                 app_id: Some(app_id()),
                 transferable: true,
                 burnable: true,
+                redeem_start: None,
+                redeem_end: None,
             },
         }))
         .unwrap();
@@ -192,6 +211,8 @@ fn app_owner_reindexed_on_transfer() {
                 app_id: Some(app_id()),
                 transferable: true,
                 burnable: true,
+                redeem_start: None,
+                redeem_end: None,
             },
         }))
         .unwrap();
@@ -275,6 +296,8 @@ fn template_app_placeholders_empty_when_no_app() {
                     app_id: None,
                     transferable: true,
                     burnable: true,
+                    redeem_start: None,
+                    redeem_end: None,
                 },
                 renewable: false,
                 revocation_mode: collections::RevocationMode::None,
@@ -284,6 +307,19 @@ fn template_app_placeholders_empty_when_no_app() {
                 max_per_wallet: None,
                 start_price: None,
                 allowlist_price: None,
+                creators: None,
+                creators_locked: false,
+                refund_window_ms: None,
+                restocking_fee_bps: 0,
+                restock_refunds: false,
+                base_uri: None,
+                allowlist_merkle_root: None,
+                enforce_royalties: false,
+                track_provenance: false,
+                reveal_at: None,
+                placeholder_metadata: None,
+                mint_cooldown_ms: None,
+                allowlist_exempt_from_limits: false,
             },
         }))
         .unwrap();
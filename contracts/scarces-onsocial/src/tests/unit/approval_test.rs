@@ -27,6 +27,8 @@ fn mint_token_via_execute(contract: &mut Contract, token_owner: &AccountId) -> S
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let result = contract
         .execute(make_request(Action::QuickMint { metadata, options }))
@@ -28,6 +28,8 @@ fn make_standalone_token(contract: &mut Contract, owner_account: &AccountId) ->
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let result = contract
         .execute(make_request(Action::QuickMint { metadata, options }))
@@ -47,6 +49,7 @@ fn list_and_setup_auction(contract: &mut Contract, seller: &AccountId) -> String
                 expires_at: None,
                 auction_duration_ns: Some(60_000_000_000),
                 anti_snipe_extension_ns: 0,
+                anti_snipe_window_ns: None,
                 buy_now_price: None,
             },
         }))
@@ -120,6 +123,78 @@ fn settle_auction_no_bids_fails() {
     assert!(matches!(err, MarketplaceError::InvalidState(_)));
 }
 
+fn list_fixed_expiry_auction(contract: &mut Contract, seller: &AccountId, reserve: u128) -> String {
+    let tid = make_standalone_token(contract, seller);
+    testing_env!(context(seller.clone()).build());
+    contract
+        .execute(make_request(Action::ListNativeScarceAuction {
+            token_id: tid.clone(),
+            params: AuctionListing {
+                reserve_price: U128(reserve),
+                min_bid_increment: U128(100),
+                expires_at: Some(1_700_000_000_000_000_000 + 60_000_000_000),
+                auction_duration_ns: None,
+                anti_snipe_extension_ns: 0,
+                anti_snipe_window_ns: None,
+                buy_now_price: None,
+            },
+        }))
+        .unwrap();
+    tid
+}
+
+fn settle_after_expiry(contract: &mut Contract, tid: &str) {
+    testing_env!(
+        context(buyer())
+            .block_timestamp(1_700_000_000_000_000_000 + 120_000_000_000)
+            .build()
+    );
+    contract
+        .execute(make_request(Action::SettleAuction {
+            token_id: tid.to_string(),
+        }))
+        .unwrap();
+}
+
+fn logged(event: &str) -> bool {
+    near_sdk::test_utils::get_logs()
+        .iter()
+        .any(|l| l.contains(&format!("\"operation\":\"{}\"", event)))
+}
+
+#[test]
+fn settle_auction_reserve_met_transfers_to_bidder() {
+    let mut contract = setup_contract();
+    let tid = list_fixed_expiry_auction(&mut contract, &owner(), 1_000);
+
+    testing_env!(context_with_deposit(buyer(), 1_500).build());
+    contract
+        .execute(make_request(Action::PlaceBid {
+            token_id: tid.clone(),
+            amount: U128(1_500),
+        }))
+        .unwrap();
+
+    settle_after_expiry(&mut contract, &tid);
+    assert!(logged("auction_settled"));
+    assert!(!logged("auction_unsold"));
+    assert_eq!(contract.scarces_by_id.get(&tid).unwrap().owner_id, buyer());
+}
+
+#[test]
+fn settle_auction_reserve_unmet_is_unsold() {
+    let mut contract = setup_contract();
+    let tid = list_fixed_expiry_auction(&mut contract, &owner(), 1_000);
+
+    settle_after_expiry(&mut contract, &tid);
+    assert!(logged("auction_unsold"));
+    assert!(!logged("auction_settled"));
+
+    let sale_id = Contract::make_sale_id(&"marketplace.near".parse().unwrap(), &tid);
+    assert!(!contract.sales.contains_key(&sale_id));
+    assert_eq!(contract.scarces_by_id.get(&tid).unwrap().owner_id, owner());
+}
+
 #[test]
 fn delist_scarce_not_found_fails() {
     let contract_id: AccountId = "marketplace.near".parse().unwrap();
@@ -183,3 +258,195 @@ fn delist_scarce_happy() {
     let sale_id = Contract::make_sale_id(&contract_id, &tid);
     assert!(!contract.sales.contains_key(&sale_id));
 }
+
+fn list_auction_ending_at(contract: &mut Contract, seller: &AccountId, expires_at: u64) -> String {
+    let tid = make_standalone_token(contract, seller);
+    testing_env!(context(seller.clone()).build());
+    contract
+        .execute(make_request(Action::ListNativeScarceAuction {
+            token_id: tid.clone(),
+            params: AuctionListing {
+                reserve_price: U128(1_000),
+                min_bid_increment: U128(100),
+                expires_at: Some(expires_at),
+                auction_duration_ns: None,
+                anti_snipe_extension_ns: 0,
+                anti_snipe_window_ns: None,
+                buy_now_price: None,
+            },
+        }))
+        .unwrap();
+    tid
+}
+
+fn run_keeper(contract: &mut Contract, from_index: Option<u32>, limit: u32) -> AuctionSettlement {
+    testing_env!(
+        context(near_sdk::test_utils::accounts(4))
+            .block_timestamp(1_700_000_000_000_000_000 + 120_000_000_000)
+            .build()
+    );
+    contract.settle_ended_auctions(from_index, limit).unwrap()
+}
+
+fn is_listed(contract: &Contract, tid: &str) -> bool {
+    let sale_id = Contract::make_sale_id(&"marketplace.near".parse().unwrap(), tid);
+    contract.sales.contains_key(&sale_id)
+}
+
+#[test]
+fn settle_ended_auctions_only_settles_ended() {
+    let mut contract = setup_contract();
+    let sold = list_fixed_expiry_auction(&mut contract, &owner(), 1_000);
+    let unsold = list_fixed_expiry_auction(&mut contract, &owner(), 1_000);
+    let active = list_auction_ending_at(
+        &mut contract,
+        &owner(),
+        1_700_000_000_000_000_000 + 600_000_000_000,
+    );
+    // Duration auctions have no end time until the first bid.
+    let unstarted = list_and_setup_auction(&mut contract, &owner());
+
+    testing_env!(context_with_deposit(buyer(), 1_500).build());
+    for tid in [&sold, &active] {
+        contract
+            .execute(make_request(Action::PlaceBid {
+                token_id: tid.clone(),
+                amount: U128(1_500),
+            }))
+            .unwrap();
+    }
+
+    let result = run_keeper(&mut contract, None, 10);
+    assert_eq!(result.settled, 2);
+    assert_eq!(result.next_index, None);
+
+    assert!(!is_listed(&contract, &sold));
+    assert!(!is_listed(&contract, &unsold));
+    assert!(is_listed(&contract, &active));
+    assert!(is_listed(&contract, &unstarted));
+    assert_eq!(contract.scarces_by_id.get(&sold).unwrap().owner_id, buyer());
+    assert_eq!(
+        contract.scarces_by_id.get(&unsold).unwrap().owner_id,
+        owner()
+    );
+    assert_eq!(
+        contract.scarces_by_id.get(&active).unwrap().owner_id,
+        owner()
+    );
+}
+
+#[test]
+fn settle_ended_auctions_pages_with_cursor() {
+    let mut contract = setup_contract();
+    for _ in 0..3 {
+        list_fixed_expiry_auction(&mut contract, &owner(), 1_000);
+    }
+
+    let first = run_keeper(&mut contract, None, 2);
+    assert_eq!(first.settled, 2);
+    assert_eq!(first.next_index, Some(2));
+
+    // Settled slots were refilled from the tail, so the cursor runs off the end.
+    let second = run_keeper(&mut contract, first.next_index, 2);
+    assert_eq!(second.settled, 0);
+    assert_eq!(second.next_index, None);
+
+    let sweep = run_keeper(&mut contract, None, 2);
+    assert_eq!(sweep.settled, 1);
+    assert_eq!(sweep.next_index, None);
+    assert!(contract.auction_token_ids.is_empty());
+}
+
+#[test]
+fn settle_ended_auctions_limit_bounds_entries_scanned() {
+    let mut contract = setup_contract();
+    let active = list_auction_ending_at(
+        &mut contract,
+        &owner(),
+        1_700_000_000_000_000_000 + 600_000_000_000,
+    );
+    let ended = list_fixed_expiry_auction(&mut contract, &owner(), 1_000);
+
+    let first = run_keeper(&mut contract, None, 1);
+    assert_eq!(first.settled, 0);
+    assert_eq!(first.next_index, Some(1));
+    assert!(is_listed(&contract, &ended));
+
+    let second = run_keeper(&mut contract, first.next_index, 1);
+    assert_eq!(second.settled, 1);
+    assert_eq!(second.next_index, None);
+    assert!(!is_listed(&contract, &ended));
+    assert!(is_listed(&contract, &active));
+}
+
+#[test]
+fn settle_ended_auctions_noop_when_nothing_ended() {
+    let mut contract = setup_contract();
+    let active = list_auction_ending_at(
+        &mut contract,
+        &owner(),
+        1_700_000_000_000_000_000 + 600_000_000_000,
+    );
+
+    let result = run_keeper(&mut contract, None, 10);
+    assert_eq!(result.settled, 0);
+    assert_eq!(result.next_index, None);
+    assert!(is_listed(&contract, &active));
+
+    let again = run_keeper(&mut contract, None, 10);
+    assert_eq!(again.settled, 0);
+}
+
+#[test]
+fn settle_ended_auctions_drops_failures_from_index() {
+    let mut contract = setup_contract();
+    let blocked = list_fixed_expiry_auction(&mut contract, &owner(), 1_000);
+    let sold = list_fixed_expiry_auction(&mut contract, &owner(), 1_000);
+
+    testing_env!(context_with_deposit(buyer(), 1_500).build());
+    for tid in [&blocked, &sold] {
+        contract
+            .execute(make_request(Action::PlaceBid {
+                token_id: tid.clone(),
+                amount: U128(1_500),
+            }))
+            .unwrap();
+    }
+    contract.scarces_by_id.get_mut(&blocked).unwrap().revoked_at = Some(1);
+
+    let result = run_keeper(&mut contract, None, 10);
+    assert_eq!(result.settled, 1);
+    assert_eq!(result.next_index, None);
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].token_id, blocked);
+
+    // The blocked auction stays listed but leaves the index, so keepers stop rescanning it.
+    assert!(is_listed(&contract, &blocked));
+    assert!(!contract.auction_token_ids.contains(&blocked));
+    assert!(!contract.auction_token_ids.contains(&sold));
+    assert_eq!(contract.scarces_by_id.get(&sold).unwrap().owner_id, buyer());
+
+    let again = run_keeper(&mut contract, None, 10);
+    assert!(again.failed.is_empty());
+}
+
+#[test]
+fn backfill_indexes_auctions_migrated_from_first_deployment() {
+    let mut contract = setup_contract();
+    let tid = list_fixed_expiry_auction(&mut contract, &owner(), 1_000);
+
+    // First-deployment state has no index; drop ours before cutting it off.
+    contract.auction_token_ids.clear();
+    contract.flush_state();
+    near_sdk::env::storage_write(b"STATE", &first_deployment_state(&contract));
+
+    let mut migrated = Contract::migrate();
+    assert!(migrated.auction_token_ids.is_empty());
+
+    assert_eq!(migrated.backfill_auction_index(0, 100), None);
+    assert!(migrated.auction_token_ids.contains(&tid));
+
+    let result = run_keeper(&mut migrated, None, 10);
+    assert_eq!(result.settled, 1);
+    assert!(!is_listed(&migrated, &tid));
+}
@@ -0,0 +1,230 @@
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::testing_env;
+use std::collections::HashMap;
+
+fn co_creator() -> AccountId {
+    "cocreator.near".parse().unwrap()
+}
+
+fn shares(creator_bps: u32, co_creator_bps: u32) -> HashMap<AccountId, u32> {
+    HashMap::from([(creator(), creator_bps), (co_creator(), co_creator_bps)])
+}
+
+fn collab_config(
+    id: &str,
+    creators: Option<HashMap<AccountId, u32>>,
+    locked: bool,
+) -> CollectionConfig {
+    CollectionConfig {
+        collection_id: id.to_string(),
+        total_supply: 10,
+        metadata_template: r#"{"title":"T"}"#.to_string(),
+        price_near: U128(1_000),
+        start_time: None,
+        end_time: None,
+        options: ScarceOptions {
+            royalty: None,
+            app_id: None,
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators,
+        creators_locked: locked,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    }
+}
+
+fn setup_collab(locked: bool) -> Contract {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+    contract
+        .create_collection(
+            &creator(),
+            collab_config("collab", Some(shares(6_000, 4_000)), locked),
+        )
+        .unwrap();
+    contract
+}
+
+#[test]
+fn collab_collection_indexed_under_each_creator() {
+    let contract = setup_collab(false);
+
+    assert_eq!(contract.get_collections_count_by_creator(creator()), 1);
+    assert_eq!(contract.get_collections_count_by_creator(co_creator()), 1);
+    let listed = contract.get_collections_by_creator(co_creator(), None, None);
+    assert_eq!(listed[0].collection_id, "collab");
+}
+
+#[test]
+fn collab_co_creator_can_manage() {
+    let mut contract = setup_collab(false);
+
+    testing_env!(context_with_deposit(co_creator(), 1).build());
+    contract
+        .execute(make_request(Action::UpdateCollectionPrice {
+            collection_id: "collab".to_string(),
+            new_price_near: U128(2_000),
+        }))
+        .unwrap();
+    contract
+        .execute(make_request(Action::PauseCollection {
+            collection_id: "collab".to_string(),
+        }))
+        .unwrap();
+
+    let collection = contract.collections.get("collab").unwrap();
+    assert_eq!(collection.price_near.0, 2_000);
+    assert!(collection.paused);
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::ResumeCollection {
+            collection_id: "collab".to_string(),
+        }))
+        .unwrap();
+    assert!(!contract.collections.get("collab").unwrap().paused);
+}
+
+#[test]
+fn collab_locked_restricts_management_to_primary_creator() {
+    let mut contract = setup_collab(true);
+
+    testing_env!(context_with_deposit(co_creator(), 1).build());
+    let err = contract
+        .execute(make_request(Action::PauseCollection {
+            collection_id: "collab".to_string(),
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::PauseCollection {
+            collection_id: "collab".to_string(),
+        }))
+        .unwrap();
+}
+
+#[test]
+fn collab_non_creator_cannot_manage() {
+    let mut contract = setup_collab(false);
+
+    testing_env!(context_with_deposit(buyer(), 1).build());
+    let err = contract
+        .execute(make_request(Action::UpdateCollectionPrice {
+            collection_id: "collab".to_string(),
+            new_price_near: U128(2_000),
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+}
+
+#[test]
+fn collab_delete_unindexes_all_creators() {
+    let mut contract = setup_collab(false);
+
+    testing_env!(context_with_deposit(co_creator(), 1).build());
+    contract
+        .execute(make_request(Action::DeleteCollection {
+            collection_id: "collab".to_string(),
+        }))
+        .unwrap();
+
+    assert_eq!(contract.get_collections_count_by_creator(creator()), 0);
+    assert_eq!(contract.get_collections_count_by_creator(co_creator()), 0);
+}
+
+#[test]
+fn collab_shares_must_sum_to_basis_points() {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+
+    let err = contract
+        .create_collection(
+            &creator(),
+            collab_config("bad", Some(shares(6_000, 3_000)), false),
+        )
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+}
+
+#[test]
+fn collab_shares_must_include_creator() {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+
+    let creators = HashMap::from([(buyer(), 5_000), (co_creator(), 5_000)]);
+    let err = contract
+        .create_collection(&creator(), collab_config("bad", Some(creators), false))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+}
+
+#[test]
+fn collab_purchase_records_revenue() {
+    let mut contract = setup_collab(false);
+
+    testing_env!(context_with_deposit(buyer(), 100_000).build());
+    contract
+        .execute(make_request(Action::PurchaseFromCollection {
+            collection_id: "collab".to_string(),
+            quantity: 2,
+            max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
+        }))
+        .unwrap();
+
+    let collection = contract.collections.get("collab").unwrap();
+    assert_eq!(collection.minted_count, 2);
+    assert_eq!(collection.total_revenue.0, 2_000);
+}
+
+#[test]
+fn split_creator_payment_follows_shares() {
+    let creators = shares(6_000, 4_000);
+    let splits = fees::routing::split_creator_payment(1_000_000, &creator(), Some(&creators));
+
+    let by_account: HashMap<AccountId, u128> = splits.into_iter().collect();
+    assert_eq!(by_account[&creator()], 600_000);
+    assert_eq!(by_account[&co_creator()], 400_000);
+}
+
+#[test]
+fn split_creator_payment_rounding_dust_goes_to_primary() {
+    let creators = shares(3_333, 6_667);
+    let splits = fees::routing::split_creator_payment(10, &creator(), Some(&creators));
+
+    let by_account: HashMap<AccountId, u128> = splits.into_iter().collect();
+    assert_eq!(by_account[&co_creator()], 6);
+    assert_eq!(by_account[&creator()], 4);
+}
+
+#[test]
+fn split_creator_payment_without_shares_pays_creator() {
+    let splits = fees::routing::split_creator_payment(1_000, &creator(), None);
+    assert_eq!(splits, vec![(creator(), 1_000)]);
+}
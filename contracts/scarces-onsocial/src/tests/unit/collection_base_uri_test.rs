@@ -0,0 +1,145 @@
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::testing_env;
+
+const HASH: &str = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=";
+
+fn base_uri_config(id: &str, base_uri: Option<&str>) -> CollectionConfig {
+    CollectionConfig {
+        collection_id: id.to_string(),
+        total_supply: 10,
+        metadata_template: format!(
+            r#"{{"title":"T #{{seat_number}}","media":"{{seat_number}}.png","media_hash":"{HASH}","reference":"https://cdn.example.com/meta.json","reference_hash":"{HASH}"}}"#
+        ),
+        price_near: U128(1_000),
+        start_time: None,
+        end_time: None,
+        options: ScarceOptions {
+            royalty: None,
+            app_id: None,
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: base_uri.map(str::to_string),
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    }
+}
+
+fn setup_and_buy(base_uri: Option<&str>) -> Contract {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+    contract
+        .create_collection(&creator(), base_uri_config("art", base_uri))
+        .unwrap();
+
+    testing_env!(context_with_deposit(buyer(), 100_000).build());
+    contract
+        .execute(make_request(Action::PurchaseFromCollection {
+            collection_id: "art".to_string(),
+            quantity: 1,
+            max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
+        }))
+        .unwrap();
+    contract
+}
+
+#[test]
+fn relative_media_resolves_against_base_uri() {
+    let contract = setup_and_buy(Some("ipfs://bafyroot/"));
+
+    let metadata = contract
+        .nft_token("art:1".to_string())
+        .unwrap()
+        .metadata
+        .unwrap();
+    assert_eq!(metadata.media.as_deref(), Some("ipfs://bafyroot/1.png"));
+
+    // Stored metadata keeps the relative path.
+    let stored = contract.scarces_by_id.get("art:1").unwrap();
+    assert_eq!(stored.metadata.media.as_deref(), Some("1.png"));
+}
+
+#[test]
+fn absolute_reference_is_left_untouched() {
+    let contract = setup_and_buy(Some("ipfs://bafyroot"));
+
+    let metadata = contract
+        .nft_token("art:1".to_string())
+        .unwrap()
+        .metadata
+        .unwrap();
+    assert_eq!(
+        metadata.reference.as_deref(),
+        Some("https://cdn.example.com/meta.json")
+    );
+    assert_eq!(metadata.media.as_deref(), Some("ipfs://bafyroot/1.png"));
+}
+
+#[test]
+fn enumeration_views_resolve_base_uri() {
+    let contract = setup_and_buy(Some("https://cdn.example.com/art"));
+
+    let owned = contract.nft_tokens_for_owner(buyer(), None, None);
+    assert_eq!(
+        owned[0].metadata.as_ref().unwrap().media.as_deref(),
+        Some("https://cdn.example.com/art/1.png")
+    );
+    let listed = contract.nft_tokens_for_collection("art".to_string(), None, None);
+    assert_eq!(
+        listed[0].metadata.as_ref().unwrap().media.as_deref(),
+        Some("https://cdn.example.com/art/1.png")
+    );
+    assert_eq!(
+        contract.resolve_base_uri("art".to_string()).as_deref(),
+        Some("https://cdn.example.com/art")
+    );
+}
+
+#[test]
+fn without_base_uri_media_is_unchanged() {
+    let contract = setup_and_buy(None);
+
+    let metadata = contract
+        .nft_token("art:1".to_string())
+        .unwrap()
+        .metadata
+        .unwrap();
+    assert_eq!(metadata.media.as_deref(), Some("1.png"));
+}
+
+#[test]
+fn invalid_base_uri_rejected() {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+
+    for bad in ["ftp://host/", "ipfs://", "https://cdn example.com/"] {
+        let err = contract
+            .create_collection(&creator(), base_uri_config("bad", Some(bad)))
+            .unwrap_err();
+        assert!(matches!(err, MarketplaceError::InvalidInput(_)), "{bad}");
+    }
+}
@@ -1,6 +1,7 @@
 use crate::tests::test_utils::*;
 use crate::*;
 use near_sdk::json_types::U128;
+use near_sdk::serde_json::Value;
 use near_sdk::testing_env;
 
 fn app_id() -> AccountId {
@@ -24,6 +25,8 @@ fn minimal_config(id: &str) -> CollectionConfig {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: collections::RevocationMode::None,
@@ -33,6 +36,19 @@ fn minimal_config(id: &str) -> CollectionConfig {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     }
 }
 
@@ -540,3 +556,294 @@ fn set_collection_app_metadata_non_authority_fails() {
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::Unauthorized(_)));
 }
+
+fn purchase_one(contract: &mut Contract, col_id: &str) -> Result<Value, MarketplaceError> {
+    testing_env!(context_with_deposit(buyer(), 2_000_000_000_000_000_000_000_000).build());
+    contract.execute(make_request(Action::PurchaseFromCollection {
+        collection_id: col_id.to_string(),
+        quantity: 1,
+        max_price_per_token: U128(u128::MAX),
+        allowlist_proof: None,
+        refund_if_exhausted: false,
+    }))
+}
+
+#[test]
+fn paused_collection_rejects_purchase_but_allows_metadata_edit() {
+    let mut contract = setup_with_collection("fix");
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::PauseCollection {
+            collection_id: "fix".to_string(),
+        }))
+        .unwrap();
+
+    let err = purchase_one(&mut contract, "fix").unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::SetCollectionMetadata {
+            collection_id: "fix".to_string(),
+            metadata: Some(r#"{"name":"Fixed"}"#.to_string()),
+        }))
+        .unwrap();
+    contract
+        .execute(make_request(Action::ResumeCollection {
+            collection_id: "fix".to_string(),
+        }))
+        .unwrap();
+
+    purchase_one(&mut contract, "fix").unwrap();
+    let col = contract.collections.get("fix").unwrap();
+    assert_eq!(col.metadata.as_deref(), Some(r#"{"name":"Fixed"}"#));
+    assert_eq!(col.minted_count, 1);
+}
+
+#[test]
+fn frozen_collection_rejects_config_edits_but_keeps_selling() {
+    let mut contract = setup_with_collection("lock");
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::FreezeCollection {
+            collection_id: "lock".to_string(),
+        }))
+        .unwrap();
+    assert!(contract.collections.get("lock").unwrap().frozen);
+
+    let err = contract
+        .execute(make_request(Action::SetCollectionMetadata {
+            collection_id: "lock".to_string(),
+            metadata: Some(r#"{"name":"Changed"}"#.to_string()),
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+
+    let err = contract
+        .execute(make_request(Action::UpdateCollectionPrice {
+            collection_id: "lock".to_string(),
+            new_price_near: U128(1),
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+
+    let err = contract
+        .execute(make_request(Action::FreezeCollection {
+            collection_id: "lock".to_string(),
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+
+    purchase_one(&mut contract, "lock").unwrap();
+}
+
+#[test]
+fn freeze_non_creator_fails() {
+    let mut contract = setup_with_collection("lock");
+
+    testing_env!(context_with_deposit(buyer(), 1).build());
+    let err = contract
+        .execute(make_request(Action::FreezeCollection {
+            collection_id: "lock".to_string(),
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+}
+
+#[test]
+fn pause_and_freeze_emit_distinct_events() {
+    let mut contract = setup_with_collection("ev");
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::PauseCollection {
+            collection_id: "ev".to_string(),
+        }))
+        .unwrap();
+    let logs = near_sdk::test_utils::get_logs();
+    assert!(logs.iter().any(|l| l.contains(r#""operation":"pause""#)));
+    assert!(!logs.iter().any(|l| l.contains(r#""operation":"freeze""#)));
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::FreezeCollection {
+            collection_id: "ev".to_string(),
+        }))
+        .unwrap();
+    let logs = near_sdk::test_utils::get_logs();
+    assert!(logs.iter().any(|l| l.contains(r#""operation":"freeze""#)));
+}
+
+// --- BatchUpdateTokenMetadata ---
+
+fn revealed(title: &str) -> TokenMetadata {
+    TokenMetadata {
+        title: Some(title.to_string()),
+        description: Some("revealed".to_string()),
+        media: None,
+        media_hash: None,
+        copies: None,
+        issued_at: None,
+        expires_at: None,
+        starts_at: None,
+        updated_at: None,
+        extra: None,
+        reference: None,
+        reference_hash: None,
+    }
+}
+
+fn setup_with_minted(id: &str, quantity: u32) -> Contract {
+    let mut contract = setup_with_collection(id);
+    contract
+        .execute(make_request(Action::MintFromCollection {
+            collection_id: id.to_string(),
+            quantity,
+            receiver_id: None,
+        }))
+        .unwrap();
+    contract
+}
+
+#[test]
+fn batch_update_token_metadata_updates_each_token() {
+    let mut contract = setup_with_minted("reveal", 3);
+    let issued_at = contract
+        .scarces_by_id
+        .get("reveal:1")
+        .unwrap()
+        .metadata
+        .issued_at;
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    let results = contract
+        .execute(make_request(Action::BatchUpdateTokenMetadata {
+            collection_id: "reveal".to_string(),
+            updates: vec![
+                ("reveal:1".to_string(), revealed("Dragon")),
+                ("reveal:2".to_string(), revealed("Phoenix")),
+            ],
+        }))
+        .unwrap();
+
+    let results = results.as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r["updated"] == Value::Bool(true)));
+
+    let token = contract.scarces_by_id.get("reveal:1").unwrap();
+    assert_eq!(token.metadata.title.as_deref(), Some("Dragon"));
+    assert_eq!(token.metadata.issued_at, issued_at);
+    assert!(token.metadata.updated_at.is_some());
+    assert_eq!(
+        contract
+            .scarces_by_id
+            .get("reveal:2")
+            .unwrap()
+            .metadata
+            .title
+            .as_deref(),
+        Some("Phoenix")
+    );
+    assert_eq!(
+        contract
+            .scarces_by_id
+            .get("reveal:3")
+            .unwrap()
+            .metadata
+            .title
+            .as_deref(),
+        Some("Token #3")
+    );
+}
+
+#[test]
+fn batch_update_token_metadata_reports_out_of_collection_tokens() {
+    let mut contract = setup_with_minted("reveal", 1);
+    testing_env!(context(creator()).build());
+    contract
+        .execute(make_request(Action::CreateCollection {
+            params: minimal_config("other"),
+        }))
+        .unwrap();
+    contract
+        .execute(make_request(Action::MintFromCollection {
+            collection_id: "other".to_string(),
+            quantity: 1,
+            receiver_id: None,
+        }))
+        .unwrap();
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    let results = contract
+        .execute(make_request(Action::BatchUpdateTokenMetadata {
+            collection_id: "reveal".to_string(),
+            updates: vec![
+                ("other:1".to_string(), revealed("Stolen")),
+                ("reveal:9".to_string(), revealed("Missing")),
+                ("reveal:1".to_string(), revealed("Dragon")),
+            ],
+        }))
+        .unwrap();
+
+    let results = results.as_array().unwrap();
+    assert_eq!(results[0]["updated"], Value::Bool(false));
+    assert!(
+        results[0]["error"]
+            .as_str()
+            .unwrap()
+            .contains("does not belong")
+    );
+    assert_eq!(results[1]["updated"], Value::Bool(false));
+    assert_eq!(results[2]["updated"], Value::Bool(true));
+
+    let other = contract.scarces_by_id.get("other:1").unwrap();
+    assert_eq!(other.metadata.title.as_deref(), Some("Token #1"));
+}
+
+#[test]
+fn batch_update_token_metadata_blocked_when_frozen() {
+    let mut contract = setup_with_minted("reveal", 1);
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::FreezeCollection {
+            collection_id: "reveal".to_string(),
+        }))
+        .unwrap();
+
+    let err = contract
+        .execute(make_request(Action::BatchUpdateTokenMetadata {
+            collection_id: "reveal".to_string(),
+            updates: vec![("reveal:1".to_string(), revealed("Dragon"))],
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+}
+
+#[test]
+fn batch_update_token_metadata_rejects_non_creator_and_oversized_batch() {
+    let mut contract = setup_with_minted("reveal", 1);
+
+    testing_env!(context_with_deposit(buyer(), 1).build());
+    let err = contract
+        .execute(make_request(Action::BatchUpdateTokenMetadata {
+            collection_id: "reveal".to_string(),
+            updates: vec![("reveal:1".to_string(), revealed("Dragon"))],
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    let updates = (0..=MAX_BATCH_METADATA_UPDATE)
+        .map(|i| (format!("reveal:{}", i), revealed("x")))
+        .collect();
+    let err = contract
+        .execute(make_request(Action::BatchUpdateTokenMetadata {
+            collection_id: "reveal".to_string(),
+            updates,
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+}
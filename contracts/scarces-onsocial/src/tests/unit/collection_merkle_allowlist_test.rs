@@ -0,0 +1,195 @@
+use crate::collections::allowlist_leaf;
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::testing_env;
+
+const FUTURE: u64 = 2_000_000_000_000_000_000;
+
+fn hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    env::sha256_array([lo, hi].concat())
+}
+
+/// Three-leaf tree: buyer (allocation 2), creator (1), owner (3).
+/// Returns the hex root and buyer's proof.
+fn tree() -> (String, Vec<String>) {
+    let l0 = allowlist_leaf(&buyer(), 2);
+    let l1 = allowlist_leaf(&creator(), 1);
+    let l2 = allowlist_leaf(&owner(), 3);
+    let root = hash_pair(hash_pair(l0, l1), l2);
+    (hex(&root), vec![hex(&l1), hex(&l2)])
+}
+
+fn merkle_config(id: &str, root: Option<String>) -> CollectionConfig {
+    CollectionConfig {
+        collection_id: id.to_string(),
+        total_supply: 100,
+        metadata_template: r#"{"title":"T"}"#.to_string(),
+        price_near: U128(1_000),
+        start_time: Some(FUTURE),
+        end_time: None,
+        options: ScarceOptions {
+            royalty: None,
+            app_id: None,
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: root,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    }
+}
+
+fn setup() -> (Contract, Vec<String>) {
+    let (root, proof) = tree();
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+    contract
+        .create_collection(&creator(), merkle_config("wl", Some(root)))
+        .unwrap();
+    (contract, proof)
+}
+
+fn purchase(
+    contract: &mut Contract,
+    quantity: u32,
+    proof: Option<AllowlistProof>,
+) -> Result<Value, MarketplaceError> {
+    testing_env!(context_with_deposit(buyer(), 100_000).build());
+    contract.execute(make_request(Action::PurchaseFromCollection {
+        collection_id: "wl".to_string(),
+        quantity,
+        max_price_per_token: U128(u128::MAX),
+        allowlist_proof: proof,
+        refund_if_exhausted: false,
+    }))
+}
+
+#[test]
+fn merkle_proof_mints_before_start() {
+    let (mut contract, proof) = setup();
+
+    purchase(
+        &mut contract,
+        2,
+        Some(AllowlistProof {
+            allocation: 2,
+            proof,
+        }),
+    )
+    .unwrap();
+    assert_eq!(contract.collections.get("wl").unwrap().minted_count, 2);
+}
+
+#[test]
+fn merkle_invalid_proof_rejected() {
+    let (mut contract, proof) = setup();
+
+    // Claiming a larger allocation changes the leaf.
+    let err = purchase(
+        &mut contract,
+        1,
+        Some(AllowlistProof {
+            allocation: 5,
+            proof,
+        }),
+    )
+    .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+
+    let err = purchase(&mut contract, 1, None).unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+}
+
+#[test]
+fn merkle_leaf_cap_exhausted_rejected() {
+    let (mut contract, proof) = setup();
+    let claim = AllowlistProof {
+        allocation: 2,
+        proof,
+    };
+
+    purchase(&mut contract, 2, Some(claim.clone())).unwrap();
+    let err = purchase(&mut contract, 1, Some(claim)).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+    assert_eq!(contract.collections.get("wl").unwrap().minted_count, 2);
+}
+
+#[test]
+fn explicit_allowlist_still_works_alongside_root() {
+    let (mut contract, _) = setup();
+    testing_env!(context(creator()).build());
+    contract
+        .set_allowlist(
+            &creator(),
+            "wl",
+            vec![AllowlistEntry {
+                account_id: buyer(),
+                allocation: 1,
+            }],
+        )
+        .unwrap();
+
+    purchase(&mut contract, 1, None).unwrap();
+    assert_eq!(contract.collections.get("wl").unwrap().minted_count, 1);
+}
+
+#[test]
+fn verify_allowlist_proof_view() {
+    let (contract, proof) = setup();
+
+    assert!(contract.verify_allowlist_proof(
+        "wl".to_string(),
+        buyer(),
+        AllowlistProof {
+            allocation: 2,
+            proof: proof.clone(),
+        },
+    ));
+    assert!(!contract.verify_allowlist_proof(
+        "wl".to_string(),
+        creator(),
+        AllowlistProof {
+            allocation: 2,
+            proof,
+        },
+    ));
+}
+
+#[test]
+fn invalid_merkle_root_rejected() {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+
+    for bad in ["abc", &"zz".repeat(32)] {
+        let err = contract
+            .create_collection(&creator(), merkle_config("bad", Some(bad.to_string())))
+            .unwrap_err();
+        assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+    }
+}
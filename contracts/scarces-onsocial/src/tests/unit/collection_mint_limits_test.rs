@@ -0,0 +1,134 @@
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::accounts;
+use near_sdk::testing_env;
+
+const COOLDOWN_MS: u64 = 60_000;
+const BASE_NS: u64 = 1_700_000_000_000_000_000;
+
+fn limited_config(
+    max_per_wallet: Option<u32>,
+    mint_cooldown_ms: Option<u64>,
+    allowlist_exempt_from_limits: bool,
+) -> CollectionConfig {
+    CollectionConfig {
+        total_supply: 100,
+        price_near: U128(1_000),
+        max_per_wallet,
+        mint_cooldown_ms,
+        allowlist_exempt_from_limits,
+        ..collection_config("drop")
+    }
+}
+
+fn setup(config: CollectionConfig) -> Contract {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+    contract.create_collection(&creator(), config).unwrap();
+    contract
+}
+
+fn purchase_at(
+    contract: &mut Contract,
+    buyer_id: AccountId,
+    timestamp_ns: u64,
+) -> Result<Value, MarketplaceError> {
+    testing_env!(
+        context_with_deposit(buyer_id, 100_000)
+            .block_timestamp(timestamp_ns)
+            .build()
+    );
+    contract.execute(make_request(Action::PurchaseFromCollection {
+        collection_id: "drop".to_string(),
+        quantity: 1,
+        max_price_per_token: U128(u128::MAX),
+        allowlist_proof: None,
+        refund_if_exhausted: false,
+    }))
+}
+
+fn ms_to_ns(ms: u64) -> u64 {
+    ms * 1_000_000
+}
+
+#[test]
+fn second_purchase_inside_cooldown_rejected() {
+    let mut contract = setup(limited_config(None, Some(COOLDOWN_MS), false));
+
+    purchase_at(&mut contract, buyer(), BASE_NS).unwrap();
+    let err = purchase_at(&mut contract, buyer(), BASE_NS + ms_to_ns(COOLDOWN_MS - 1)).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(ref m) if m.contains("cooldown")));
+
+    purchase_at(&mut contract, buyer(), BASE_NS + ms_to_ns(COOLDOWN_MS)).unwrap();
+    assert_eq!(contract.collections.get("drop").unwrap().minted_count, 2);
+}
+
+#[test]
+fn cooldown_is_tracked_per_account() {
+    let mut contract = setup(limited_config(None, Some(COOLDOWN_MS), false));
+
+    purchase_at(&mut contract, buyer(), BASE_NS).unwrap();
+    purchase_at(&mut contract, accounts(3), BASE_NS).unwrap();
+    assert_eq!(contract.collections.get("drop").unwrap().minted_count, 2);
+}
+
+#[test]
+fn per_account_cap_blocks_further_mints() {
+    let mut contract = setup(limited_config(Some(2), Some(COOLDOWN_MS), false));
+
+    purchase_at(&mut contract, buyer(), BASE_NS).unwrap();
+    purchase_at(&mut contract, buyer(), BASE_NS + ms_to_ns(COOLDOWN_MS)).unwrap();
+    let err = purchase_at(&mut contract, buyer(), BASE_NS + ms_to_ns(2 * COOLDOWN_MS)).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(ref m) if m.contains("per-wallet")));
+}
+
+#[test]
+fn allowlisted_buyer_exempt_when_enabled() {
+    let mut contract = setup(limited_config(Some(1), Some(COOLDOWN_MS), true));
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::SetAllowlist {
+            collection_id: "drop".to_string(),
+            entries: vec![AllowlistEntry {
+                account_id: buyer(),
+                allocation: 5,
+            }],
+        }))
+        .unwrap();
+
+    for _ in 0..3 {
+        purchase_at(&mut contract, buyer(), BASE_NS).unwrap();
+    }
+
+    purchase_at(&mut contract, accounts(3), BASE_NS).unwrap();
+    assert!(purchase_at(&mut contract, accounts(3), BASE_NS + ms_to_ns(COOLDOWN_MS)).is_err());
+}
+
+#[test]
+fn allowlisted_buyer_limited_without_exemption() {
+    let mut contract = setup(limited_config(None, Some(COOLDOWN_MS), false));
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::SetAllowlist {
+            collection_id: "drop".to_string(),
+            entries: vec![AllowlistEntry {
+                account_id: buyer(),
+                allocation: 5,
+            }],
+        }))
+        .unwrap();
+
+    purchase_at(&mut contract, buyer(), BASE_NS).unwrap();
+    assert!(purchase_at(&mut contract, buyer(), BASE_NS).is_err());
+}
+
+#[test]
+fn zero_cooldown_rejected() {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+    let err = contract
+        .create_collection(&creator(), limited_config(None, Some(0), false))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+}
@@ -16,6 +16,8 @@ fn mint_config(id: &str, supply: u32, mint_mode: MintMode) -> CollectionConfig {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -25,6 +27,19 @@ fn mint_config(id: &str, supply: u32, mint_mode: MintMode) -> CollectionConfig {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     }
 }
 
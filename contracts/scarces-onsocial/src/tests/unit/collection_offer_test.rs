@@ -20,6 +20,8 @@ fn minimal_config(id: &str) -> CollectionConfig {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: collections::RevocationMode::None,
@@ -29,6 +31,19 @@ fn minimal_config(id: &str) -> CollectionConfig {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     }
 }
 
@@ -333,3 +348,209 @@ fn accept_collection_offer_token_not_in_collection_fails() {
         MarketplaceError::Unauthorized(_) | MarketplaceError::InvalidInput(_)
     ));
 }
+
+fn setup_collection(contract: &mut Contract, col_id: &str) {
+    testing_env!(context(creator()).build());
+    contract
+        .execute(make_request(Action::CreateCollection {
+            params: minimal_config(col_id),
+        }))
+        .unwrap();
+}
+
+fn offer(contract: &mut Contract, buyer_id: AccountId, col_id: &str, amount: u128) {
+    testing_env!(context_with_deposit(buyer_id, amount).build());
+    contract
+        .execute(make_request(Action::MakeCollectionOffer {
+            collection_id: col_id.to_string(),
+            amount: U128(amount),
+            expires_at: None,
+        }))
+        .unwrap();
+}
+
+fn mint_and_accept(
+    contract: &mut Contract,
+    col_id: &str,
+    buyer_id: Option<AccountId>,
+) -> near_sdk::serde_json::Value {
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::MintAndAcceptCollectionOffer {
+            collection_id: col_id.to_string(),
+            buyer_id,
+        }))
+        .unwrap()
+}
+
+#[test]
+fn mint_and_accept_collection_offer_happy() {
+    let mut contract = setup_contract();
+    setup_collection(&mut contract, "mac");
+    offer(&mut contract, buyer(), "mac", OFFER_AMOUNT);
+
+    let result = mint_and_accept(&mut contract, "mac", Some(buyer()));
+    assert_eq!(result["token_id"], "mac:1");
+    assert_eq!(result["buyer_id"], buyer().as_str());
+
+    let token = contract.scarces_by_id.get("mac:1").unwrap();
+    assert_eq!(token.owner_id, buyer());
+    assert!(
+        contract
+            .get_collection_offer("mac".to_string(), buyer())
+            .is_none()
+    );
+    assert_eq!(contract.collections.get("mac").unwrap().minted_count, 1);
+}
+
+#[test]
+fn mint_and_accept_picks_highest_offer() {
+    let mut contract = setup_contract();
+    setup_collection(&mut contract, "mach");
+    offer(&mut contract, buyer(), "mach", OFFER_AMOUNT);
+    offer(&mut contract, owner(), "mach", OFFER_AMOUNT * 2);
+
+    let result = mint_and_accept(&mut contract, "mach", None);
+    assert_eq!(result["buyer_id"], owner().as_str());
+    assert_eq!(
+        contract.scarces_by_id.get("mach:1").unwrap().owner_id,
+        owner()
+    );
+    assert!(
+        contract
+            .get_collection_offer("mach".to_string(), buyer())
+            .is_some()
+    );
+}
+
+#[test]
+fn mint_and_accept_cancelled_offer_falls_back_to_mint() {
+    let mut contract = setup_contract();
+    setup_collection(&mut contract, "macc");
+    offer(&mut contract, buyer(), "macc", OFFER_AMOUNT);
+
+    testing_env!(context_with_deposit(buyer(), 1).build());
+    contract
+        .execute(make_request(Action::CancelCollectionOffer {
+            collection_id: "macc".to_string(),
+        }))
+        .unwrap();
+
+    let result = mint_and_accept(&mut contract, "macc", Some(buyer()));
+    assert_eq!(result["token_id"], "macc:1");
+    assert!(result["buyer_id"].is_null());
+    assert_eq!(
+        contract.scarces_by_id.get("macc:1").unwrap().owner_id,
+        creator()
+    );
+}
+
+#[test]
+fn mint_and_accept_requires_collection_authority() {
+    let mut contract = setup_contract();
+    setup_collection(&mut contract, "maca");
+    offer(&mut contract, buyer(), "maca", OFFER_AMOUNT);
+
+    testing_env!(context_with_deposit(owner(), 1).build());
+    let err = contract
+        .execute(make_request(Action::MintAndAcceptCollectionOffer {
+            collection_id: "maca".to_string(),
+            buyer_id: None,
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+    assert_eq!(contract.collections.get("maca").unwrap().minted_count, 0);
+}
+
+#[test]
+fn collection_offer_index_follows_offers() {
+    let mut contract = setup_contract();
+    setup_collection(&mut contract, "idx");
+    setup_collection(&mut contract, "other");
+    offer(&mut contract, buyer(), "idx", OFFER_AMOUNT);
+    offer(&mut contract, owner(), "idx", OFFER_AMOUNT);
+    offer(&mut contract, buyer(), "other", OFFER_AMOUNT);
+    assert_eq!(
+        contract
+            .get_collection_offers("idx".to_string(), None, None)
+            .len(),
+        2
+    );
+
+    testing_env!(context_with_deposit(buyer(), 1).build());
+    contract
+        .execute(make_request(Action::CancelCollectionOffer {
+            collection_id: "idx".to_string(),
+        }))
+        .unwrap();
+    let result = mint_and_accept(&mut contract, "idx", None);
+    assert_eq!(result["buyer_id"], owner().as_str());
+
+    assert!(!contract.collection_offer_buyers.contains_key("idx"));
+    assert_eq!(
+        contract
+            .get_collection_offers("other".to_string(), None, None)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn backfill_indexes_collection_offers_migrated_from_first_deployment() {
+    let mut contract = setup_contract();
+    setup_collection(&mut contract, "mig");
+    offer(&mut contract, buyer(), "mig", OFFER_AMOUNT);
+
+    // First-deployment state has no index; drop ours before cutting it off.
+    let mut buyers = contract.collection_offer_buyers.remove("mig").unwrap();
+    buyers.clear();
+    buyers.flush();
+    contract.flush_state();
+    near_sdk::env::storage_write(b"STATE", &first_deployment_state(&contract));
+
+    let mut migrated = Contract::migrate();
+    assert!(!migrated.collection_offer_buyers.contains_key("mig"));
+
+    assert_eq!(migrated.backfill_collection_offer_index(0, 100), None);
+    let result = mint_and_accept(&mut migrated, "mig", None);
+    assert_eq!(result["buyer_id"], buyer().as_str());
+}
+
+fn bidder(i: u32) -> AccountId {
+    format!("bidder{}.near", i).parse().unwrap()
+}
+
+#[test]
+fn full_collection_offer_book_evicts_weakest_offer() {
+    let mut contract = setup_contract();
+    setup_collection(&mut contract, "full");
+    for i in 0..MAX_COLLECTION_OFFERS {
+        offer(&mut contract, bidder(i), "full", OFFER_AMOUNT + i as u128);
+    }
+
+    testing_env!(context_with_deposit(buyer(), OFFER_AMOUNT).build());
+    let err = contract
+        .execute(make_request(Action::MakeCollectionOffer {
+            collection_id: "full".to_string(),
+            amount: U128(OFFER_AMOUNT),
+            expires_at: None,
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+
+    offer(&mut contract, buyer(), "full", OFFER_AMOUNT * 2);
+    assert!(
+        contract
+            .get_collection_offer("full".to_string(), bidder(0))
+            .is_none()
+    );
+    assert!(
+        contract
+            .get_collection_offer("full".to_string(), buyer())
+            .is_some()
+    );
+    assert_eq!(
+        contract.collection_offer_buyers.get("full").unwrap().len(),
+        MAX_COLLECTION_OFFERS
+    );
+}
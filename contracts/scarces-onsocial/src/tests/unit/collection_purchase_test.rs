@@ -18,6 +18,8 @@ fn setup_contract_with_collection(price: u128) -> (Contract, String) {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -27,6 +29,19 @@ fn setup_contract_with_collection(price: u128) -> (Contract, String) {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
     (contract, "col".to_string())
@@ -42,6 +57,8 @@ fn purchase_quantity_zero_fails() {
             collection_id: col,
             quantity: 0,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::InvalidInput(_)));
@@ -57,6 +74,8 @@ fn purchase_quantity_exceeds_max_batch_fails() {
             collection_id: col,
             quantity: MAX_BATCH_MINT + 1,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::InvalidInput(_)));
@@ -72,6 +91,8 @@ fn purchase_nonexistent_collection_fails() {
             collection_id: "nope".to_string(),
             quantity: 1,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::NotFound(_)));
@@ -93,6 +114,8 @@ fn purchase_creator_only_mode_fails() {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -102,6 +125,19 @@ fn purchase_creator_only_mode_fails() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
     testing_env!(context_with_deposit(buyer(), 100_000).build());
@@ -111,6 +147,8 @@ fn purchase_creator_only_mode_fails() {
             collection_id: "locked".to_string(),
             quantity: 1,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::Unauthorized(_)));
@@ -132,6 +170,8 @@ fn purchase_exceeds_supply_fails() {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -141,6 +181,19 @@ fn purchase_exceeds_supply_fails() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
     testing_env!(context_with_deposit(buyer(), 1_000_000).build());
@@ -150,6 +203,8 @@ fn purchase_exceeds_supply_fails() {
             collection_id: "tiny".to_string(),
             quantity: 3,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::InvalidState(_)));
@@ -171,6 +226,8 @@ fn purchase_exceeds_per_wallet_limit_fails() {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -180,6 +237,19 @@ fn purchase_exceeds_per_wallet_limit_fails() {
         max_per_wallet: Some(2),
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
 
@@ -189,6 +259,8 @@ fn purchase_exceeds_per_wallet_limit_fails() {
             collection_id: "limited".to_string(),
             quantity: 2,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap();
 
@@ -198,6 +270,8 @@ fn purchase_exceeds_per_wallet_limit_fails() {
             collection_id: "limited".to_string(),
             quantity: 1,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::InvalidInput(_)));
@@ -213,6 +287,8 @@ fn purchase_slippage_guard_rejects_high_price() {
             collection_id: col,
             quantity: 1,
             max_price_per_token: U128(5_000),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::InvalidInput(_)));
@@ -228,6 +304,8 @@ fn purchase_insufficient_deposit_fails() {
             collection_id: col,
             quantity: 1,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::InsufficientDeposit(_)));
@@ -243,6 +321,8 @@ fn purchase_single_happy() {
             collection_id: col.clone(),
             quantity: 1,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap();
 
@@ -260,6 +340,8 @@ fn purchase_batch_happy() {
             collection_id: col.clone(),
             quantity: 5,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap();
 
@@ -277,6 +359,8 @@ fn purchase_tracks_revenue() {
             collection_id: col.clone(),
             quantity: 3,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap();
 
@@ -296,6 +380,8 @@ fn purchase_paused_collection_fails() {
             collection_id: col,
             quantity: 1,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::InvalidState(_)));
@@ -318,6 +404,8 @@ fn purchase_before_start_without_allowlist_fails() {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -327,6 +415,19 @@ fn purchase_before_start_without_allowlist_fails() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
 
@@ -336,6 +437,8 @@ fn purchase_before_start_without_allowlist_fails() {
             collection_id: "al".to_string(),
             quantity: 1,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::Unauthorized(_)));
@@ -358,6 +461,8 @@ fn purchase_before_start_with_allowlist_succeeds() {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -367,6 +472,19 @@ fn purchase_before_start_with_allowlist_succeeds() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
 
@@ -383,9 +501,154 @@ fn purchase_before_start_with_allowlist_succeeds() {
             collection_id: "al2".to_string(),
             quantity: 1,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap();
 
     let collection = contract.collections.get("al2").unwrap();
     assert_eq!(collection.minted_count, 1);
 }
+
+fn last_item_config(id: &str, start_time: Option<u64>) -> CollectionConfig {
+    CollectionConfig {
+        collection_id: id.to_string(),
+        total_supply: 1,
+        metadata_template: r#"{"title":"T"}"#.to_string(),
+        price_near: U128(1_000),
+        start_time,
+        end_time: None,
+        options: scarce::types::ScarceOptions {
+            royalty: None,
+            app_id: None,
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    }
+}
+
+fn purchase_one(
+    contract: &mut Contract,
+    buyer_id: AccountId,
+    collection_id: &str,
+    quantity: u32,
+    refund_if_exhausted: bool,
+) -> Result<Value, MarketplaceError> {
+    testing_env!(context_with_deposit(buyer_id, 5_000).build());
+    contract.execute(make_request(Action::PurchaseFromCollection {
+        collection_id: collection_id.to_string(),
+        quantity,
+        max_price_per_token: U128(u128::MAX),
+        allowlist_proof: None,
+        refund_if_exhausted,
+    }))
+}
+
+#[test]
+fn sold_out_purchase_refunded_when_buyer_opts_in() {
+    let mut contract = new_contract();
+    contract
+        .create_collection(&creator(), last_item_config("last", None))
+        .unwrap();
+
+    let first = purchase_one(&mut contract, buyer(), "last", 1, true).unwrap();
+    assert_eq!(first, Value::Null);
+
+    // The second buyer lands after the last item is gone.
+    let refund = purchase_one(&mut contract, owner(), "last", 1, true).unwrap();
+    assert_eq!(refund["minted"], 0);
+    assert_eq!(refund["refunded"], "5000");
+    assert_eq!(refund["reason"], "sold_out");
+    assert!(
+        near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|l| l.contains("mint_failed_refund"))
+    );
+
+    let collection = contract.collections.get("last").unwrap();
+    assert_eq!(collection.minted_count, 1);
+    assert_eq!(collection.total_revenue.0, 1_000);
+    assert_eq!(
+        contract.scarces_by_id.get("last:1").unwrap().owner_id,
+        buyer()
+    );
+}
+
+#[test]
+fn sold_out_purchase_fails_without_refund_opt_in() {
+    let mut contract = new_contract();
+    contract
+        .create_collection(&creator(), last_item_config("last", None))
+        .unwrap();
+    purchase_one(&mut contract, buyer(), "last", 1, false).unwrap();
+
+    let err = purchase_one(&mut contract, owner(), "last", 1, false).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+    assert!(
+        !near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|l| l.contains("mint_failed_refund"))
+    );
+}
+
+#[test]
+fn purchase_beyond_remaining_supply_fails_even_with_refund_opt_in() {
+    let mut contract = new_contract();
+    let mut config = last_item_config("few", None);
+    config.total_supply = 2;
+    contract.create_collection(&creator(), config).unwrap();
+    purchase_one(&mut contract, buyer(), "few", 1, true).unwrap();
+
+    let err = purchase_one(&mut contract, owner(), "few", 2, true).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+    assert_eq!(contract.collections.get("few").unwrap().minted_count, 1);
+}
+
+#[test]
+fn allowlist_exhausted_purchase_refunded_when_buyer_opts_in() {
+    let mut contract = new_contract();
+    let mut config = last_item_config("alx", Some(2_000_000_000_000_000_000));
+    config.total_supply = 10;
+    contract.create_collection(&creator(), config).unwrap();
+
+    testing_env!(context(creator()).build());
+    contract
+        .set_allowlist(
+            &creator(),
+            "alx",
+            vec![AllowlistEntry {
+                account_id: buyer(),
+                allocation: 1,
+            }],
+        )
+        .unwrap();
+
+    purchase_one(&mut contract, buyer(), "alx", 1, true).unwrap();
+    let refund = purchase_one(&mut contract, buyer(), "alx", 1, true).unwrap();
+    assert_eq!(refund["reason"], "allowlist_exhausted");
+    assert_eq!(refund["refunded"], "5000");
+    assert_eq!(contract.collections.get("alx").unwrap().minted_count, 1);
+}
@@ -0,0 +1,189 @@
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::testing_env;
+
+const START_NS: u64 = 1_700_000_000_000_000_000;
+const REVEAL_NS: u64 = START_NS + 3_600_000_000_000;
+
+fn reveal_config(id: &str, reveal_at: Option<u64>, placeholder: Option<&str>) -> CollectionConfig {
+    CollectionConfig {
+        collection_id: id.to_string(),
+        total_supply: 10,
+        metadata_template: r#"{"title":"Token #{seat_number}","description":"Seat {seat_number}"}"#
+            .to_string(),
+        price_near: U128(0),
+        start_time: None,
+        end_time: None,
+        options: scarce::types::ScarceOptions {
+            royalty: None,
+            app_id: None,
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at,
+        placeholder_metadata: placeholder.map(str::to_string),
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    }
+}
+
+const PLACEHOLDER: &str = r#"{"title":"Mystery box","description":"Revealed soon"}"#;
+
+/// Creator holds `col:1` and `col:2`.
+fn setup(reveal_at: Option<u64>) -> Contract {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+    contract
+        .create_collection(
+            &creator(),
+            reveal_config("col", reveal_at, Some(PLACEHOLDER)),
+        )
+        .unwrap();
+    contract
+        .mint_from_collection(&creator(), "col", 2, None)
+        .unwrap();
+    contract
+}
+
+fn title_of(contract: &Contract, token_id: &str) -> Option<String> {
+    contract
+        .nft_token(token_id.to_string())
+        .and_then(|t| t.metadata)
+        .and_then(|m| m.title)
+}
+
+#[test]
+fn unrevealed_tokens_show_placeholder() {
+    let contract = setup(Some(REVEAL_NS));
+
+    let token = contract.nft_token("col:1".to_string()).unwrap();
+    let metadata = token.metadata.unwrap();
+    assert_eq!(metadata.title.as_deref(), Some("Mystery box"));
+    assert_eq!(metadata.description.as_deref(), Some("Revealed soon"));
+    assert!(metadata.issued_at.is_some());
+
+    let status = contract.get_token_status("col:2".to_string()).unwrap();
+    assert_eq!(status.metadata.title.as_deref(), Some("Mystery box"));
+
+    // Display-only: the stored token already holds its real metadata.
+    let stored = &contract.scarces_by_id.get("col:1").unwrap().metadata;
+    assert_eq!(stored.title.as_deref(), Some("Token #1"));
+}
+
+#[test]
+fn tokens_reveal_once_reveal_at_passes() {
+    let contract = setup(Some(REVEAL_NS));
+
+    testing_env!(context(buyer()).block_timestamp(REVEAL_NS - 1).build());
+    assert_eq!(title_of(&contract, "col:1").as_deref(), Some("Mystery box"));
+
+    testing_env!(context(buyer()).block_timestamp(REVEAL_NS).build());
+    assert_eq!(title_of(&contract, "col:1").as_deref(), Some("Token #1"));
+    assert_eq!(title_of(&contract, "col:2").as_deref(), Some("Token #2"));
+}
+
+#[test]
+fn manual_reveal_shows_real_metadata_for_minted_tokens() {
+    let mut contract = setup(None);
+    assert_eq!(title_of(&contract, "col:2").as_deref(), Some("Mystery box"));
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::RevealCollection {
+            collection_id: "col".to_string(),
+        }))
+        .unwrap();
+    let logs = near_sdk::test_utils::get_logs();
+    assert!(logs.iter().any(|l| l.contains(r#""operation":"reveal""#)));
+
+    let metadata = contract
+        .nft_token("col:2".to_string())
+        .and_then(|t| t.metadata)
+        .unwrap();
+    assert_eq!(metadata.title.as_deref(), Some("Token #2"));
+    assert_eq!(metadata.description.as_deref(), Some("Seat 2"));
+    assert!(contract.get_collection("col".to_string()).unwrap().revealed);
+
+    contract
+        .mint_from_collection(&creator(), "col", 1, None)
+        .unwrap();
+    assert_ne!(title_of(&contract, "col:3").as_deref(), Some("Mystery box"));
+}
+
+#[test]
+fn reveal_requires_creator_and_placeholder() {
+    let mut contract = setup(None);
+
+    testing_env!(context_with_deposit(buyer(), 1).build());
+    let err = contract
+        .execute(make_request(Action::RevealCollection {
+            collection_id: "col".to_string(),
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract.reveal_collection(&creator(), "col").unwrap();
+    let err = contract.reveal_collection(&creator(), "col").unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+
+    contract
+        .create_collection(&creator(), reveal_config("plain", None, None))
+        .unwrap();
+    let err = contract.reveal_collection(&creator(), "plain").unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+}
+
+#[test]
+fn frozen_collection_can_still_reveal() {
+    let mut contract = setup(None);
+    testing_env!(context(creator()).build());
+    contract.freeze_collection(&creator(), "col").unwrap();
+
+    contract.reveal_collection(&creator(), "col").unwrap();
+    assert_eq!(title_of(&contract, "col:1").as_deref(), Some("Token #1"));
+}
+
+#[test]
+fn create_collection_validates_reveal_config() {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+
+    let err = contract
+        .create_collection(&creator(), reveal_config("a", Some(REVEAL_NS), None))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+
+    let err = contract
+        .create_collection(&creator(), reveal_config("b", None, Some("not json")))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+    testing_env!(context(creator()).block_timestamp(REVEAL_NS).build());
+    let err = contract
+        .create_collection(
+            &creator(),
+            reveal_config("c", Some(REVEAL_NS), Some(PLACEHOLDER)),
+        )
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+}
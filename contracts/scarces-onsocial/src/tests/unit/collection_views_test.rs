@@ -13,6 +13,8 @@ fn default_options() -> scarce::types::ScarceOptions {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     }
 }
 
@@ -33,6 +35,19 @@ fn minimal_config(id: &str) -> CollectionConfig {
         max_per_wallet: Some(3),
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     }
 }
 
@@ -264,6 +279,8 @@ fn get_wallet_mint_remaining_tracks() {
         collection_id: "vcw2".into(),
         quantity: 1,
         max_price_per_token: U128(u128::MAX),
+        allowlist_proof: None,
+        refund_if_exhausted: false,
     };
     contract.execute(make_request(action)).unwrap();
 
@@ -353,3 +370,64 @@ fn calculate_purchase_price_not_found() {
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::NotFound(_)));
 }
+
+#[test]
+fn get_collection_overview_none_for_missing() {
+    let contract = setup_contract();
+    testing_env!(context(owner()).build());
+    assert!(contract.get_collection_overview("nope".into()).is_none());
+}
+
+#[test]
+fn get_collection_overview_floor_tracks_cheapest_listing() {
+    let mut contract = setup_contract();
+    create_collection(&mut contract, "ov");
+    contract
+        .mint_from_collection(&creator(), "ov", 2, None)
+        .unwrap();
+
+    let list = |contract: &mut Contract, token_id: &str, price: u128| {
+        testing_env!(context(creator()).build());
+        contract
+            .execute(make_request(Action::ListNativeScarce {
+                token_id: token_id.to_string(),
+                price: U128(price),
+                expires_at: None,
+            }))
+            .unwrap();
+    };
+
+    list(&mut contract, "ov:1", 5_000);
+    let overview = contract.get_collection_overview("ov".into()).unwrap();
+    assert_eq!(overview["minted_count"], 2);
+    assert_eq!(overview["total_supply"], 10);
+    assert_eq!(overview["floor_price"], "5000");
+    assert_eq!(overview["active_listings"], 1);
+    assert_eq!(overview["paused"], false);
+    assert_eq!(overview["in_mint_window"], true);
+
+    list(&mut contract, "ov:2", 2_000);
+    let overview = contract.get_collection_overview("ov".into()).unwrap();
+    assert_eq!(overview["floor_price"], "2000");
+    assert_eq!(overview["active_listings"], 2);
+
+    let offer = 10_000_000_000_000_000_000_000;
+    testing_env!(context_with_deposit(buyer(), offer).build());
+    contract
+        .execute(make_request(Action::MakeCollectionOffer {
+            collection_id: "ov".to_string(),
+            amount: U128(offer),
+            expires_at: None,
+        }))
+        .unwrap();
+    let overview = contract.get_collection_overview("ov".into()).unwrap();
+    assert_eq!(overview["collection_offer_count"], 1);
+    assert_eq!(
+        overview["highest_collection_offer"]["amount"],
+        offer.to_string()
+    );
+    assert_eq!(
+        overview["highest_collection_offer"]["buyer_id"],
+        buyer().as_str()
+    );
+}
@@ -15,6 +15,8 @@ fn minimal_config(id: &str) -> CollectionConfig {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: collections::RevocationMode::None,
@@ -24,6 +26,19 @@ fn minimal_config(id: &str) -> CollectionConfig {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     }
 }
 
@@ -42,6 +57,32 @@ fn create_collection_happy_path() {
     assert_eq!(col.minted_count, 0);
 }
 
+#[test]
+fn collections_created_before_appended_fields_still_decode() {
+    let mut contract = new_contract();
+    contract
+        .create_collection(&creator(), minimal_config("legacy"))
+        .unwrap();
+    let mut col = contract.collections.get("legacy").unwrap().clone();
+    let bytes = near_sdk::borsh::to_vec(&col).unwrap();
+
+    // Pre-upgrade records end at `app_metadata`; the appended fields take 40 bytes at their defaults.
+    let legacy: LazyCollection = near_sdk::borsh::from_slice(&bytes[..bytes.len() - 40]).unwrap();
+    assert_eq!(legacy.creator_id, creator());
+    assert_eq!(legacy.total_supply, 10);
+    assert!(!legacy.frozen);
+    assert!(legacy.creators.is_none());
+    assert_eq!(legacy.held_revenue, U128(0));
+    assert_eq!(legacy.mint_refunded_count, 0);
+
+    col.frozen = true;
+    col.mint_refunded_count = 3;
+    let decoded: LazyCollection =
+        near_sdk::borsh::from_slice(&near_sdk::borsh::to_vec(&col).unwrap()).unwrap();
+    assert!(decoded.frozen);
+    assert_eq!(decoded.mint_refunded_count, 3);
+}
+
 #[test]
 fn create_collection_duplicate_id_fails() {
     let mut contract = new_contract();
@@ -0,0 +1,140 @@
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::testing_env;
+
+const PRICE: u128 = 1_000_000;
+const ROYALTY_BPS: u32 = 1_000;
+
+/// Creator's "col" sells at PRICE and pays the creator a 10% royalty.
+fn setup_collection() -> Contract {
+    let mut contract = new_contract();
+    let config = CollectionConfig {
+        collection_id: "col".to_string(),
+        total_supply: 10,
+        metadata_template: r#"{"title":"T"}"#.to_string(),
+        price_near: U128(PRICE),
+        start_time: None,
+        end_time: None,
+        options: scarce::types::ScarceOptions {
+            royalty: Some(std::collections::HashMap::from([(creator(), ROYALTY_BPS)])),
+            app_id: None,
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    };
+    testing_env!(context(creator()).build());
+    contract.create_collection(&creator(), config).unwrap();
+    contract
+}
+
+fn transfers_to(account: &AccountId) -> u128 {
+    near_sdk::test_utils::get_created_receipts()
+        .into_iter()
+        .filter(|r| &r.receiver_id == account)
+        .flat_map(|r| r.actions)
+        // MockAction is not re-exported; read transfers through its serde form.
+        .filter_map(|a| {
+            let action = near_sdk::serde_json::to_value(a).unwrap();
+            action["Transfer"]["deposit"].as_str()?.parse::<u128>().ok()
+        })
+        .sum()
+}
+
+fn amount(value: &Value) -> u128 {
+    value.as_str().unwrap().parse().unwrap()
+}
+
+fn buy_from_collection(contract: &mut Contract) {
+    testing_env!(context_with_deposit(buyer(), PRICE * 2).build());
+    contract
+        .execute(make_request(Action::PurchaseFromCollection {
+            collection_id: "col".to_string(),
+            quantity: 1,
+            max_price_per_token: U128(PRICE),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
+        }))
+        .unwrap();
+}
+
+#[test]
+fn primary_sale_credits_net_creator_payment() {
+    let mut contract = setup_collection();
+    buy_from_collection(&mut contract);
+    let paid = transfers_to(&creator());
+    assert!(paid > 0 && paid < PRICE, "creator paid {paid}");
+
+    let earnings = contract.get_creator_earnings(creator());
+    assert_eq!(amount(&earnings["primary"]), paid);
+    assert_eq!(amount(&earnings["royalties"]), 0);
+    assert_eq!(amount(&earnings["total"]), paid);
+    assert_eq!(amount(&earnings["by_collection"]["col"]["primary"]), paid);
+}
+
+#[test]
+fn secondary_sale_credits_royalty() {
+    let mut contract = setup_collection();
+    buy_from_collection(&mut contract);
+    let primary = transfers_to(&creator());
+
+    let resale = 2 * PRICE;
+    testing_env!(context(buyer()).build());
+    contract
+        .list_native_scarce(&buyer(), "col:1", U128(resale), None)
+        .unwrap();
+    testing_env!(context_with_deposit(owner(), resale).build());
+    contract
+        .execute(make_request(Action::PurchaseNativeScarce {
+            token_id: "col:1".to_string(),
+        }))
+        .unwrap();
+
+    let (total_fee, _, _, _) = contract.calculate_fee_split(resale, None);
+    let royalty = (resale - total_fee) * ROYALTY_BPS as u128 / 10_000;
+    assert_eq!(transfers_to(&creator()), royalty);
+
+    let earnings = contract.get_creator_earnings(creator());
+    assert_eq!(amount(&earnings["primary"]), primary);
+    assert_eq!(amount(&earnings["royalties"]), royalty);
+    assert_eq!(amount(&earnings["total"]), primary + royalty);
+    assert_eq!(
+        amount(&earnings["by_collection"]["col"]["royalties"]),
+        royalty
+    );
+
+    // The seller's proceeds are not royalties.
+    let seller = contract.get_creator_earnings(buyer());
+    assert_eq!(amount(&seller["total"]), 0);
+}
+
+#[test]
+fn unknown_account_has_zero_earnings() {
+    let contract = new_contract();
+    let earnings = contract.get_creator_earnings(buyer());
+    assert_eq!(amount(&earnings["total"]), 0);
+    assert!(earnings["by_collection"].as_object().unwrap().is_empty());
+}
@@ -31,6 +31,8 @@ fn dispatch_quick_mint_returns_token_id() {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let action = Action::QuickMint { metadata, options };
     let result = contract.dispatch_action(action, &buyer()).unwrap();
@@ -55,6 +57,8 @@ fn dispatch_create_collection() {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -64,6 +68,19 @@ fn dispatch_create_collection() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     let action = Action::CreateCollection { params };
     let result = contract.dispatch_action(action, &creator()).unwrap();
@@ -95,6 +112,8 @@ fn dispatch_list_native_scarce() {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let tid = contract.quick_mint(&buyer(), metadata, options).unwrap();
 
@@ -134,6 +153,8 @@ fn dispatch_delist_native_scarce() {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let tid = contract.quick_mint(&buyer(), metadata, options).unwrap();
     contract
@@ -173,6 +194,8 @@ fn dispatch_transfer_scarce() {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let tid = contract.quick_mint(&buyer(), metadata, options).unwrap();
 
@@ -204,6 +227,8 @@ fn dispatch_burn_scarce() {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -213,6 +238,19 @@ fn dispatch_burn_scarce() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), params).unwrap();
     contract
@@ -250,13 +288,19 @@ fn dispatch_create_lazy_listing_returns_id() {
             reference_hash: None,
         },
         price: U128(1_000),
+        payment_token: None,
         options: scarce::types::ScarceOptions {
             royalty: None,
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         expires_at: None,
+        start_price: None,
+        decay_start: None,
+        decay_end: None,
     };
     let action = Action::CreateLazyListing { params };
     let result = contract.dispatch_action(action, &creator()).unwrap();
@@ -281,6 +325,8 @@ fn dispatch_pause_and_resume_collection() {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -290,6 +336,19 @@ fn dispatch_pause_and_resume_collection() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), params).unwrap();
 
@@ -376,6 +435,8 @@ fn dispatch_burn_standalone_token() {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let tid = contract.quick_mint(&buyer(), metadata, options).unwrap();
     assert!(tid.starts_with("s:"));
@@ -413,6 +474,8 @@ fn dispatch_update_price() {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let tid = contract.quick_mint(&buyer(), metadata, options).unwrap();
     contract
@@ -458,6 +521,8 @@ fn execute_purchase_native_scarce_happy() {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let tid = contract.quick_mint(&buyer(), metadata, options).unwrap();
     contract
@@ -498,13 +563,19 @@ fn execute_purchase_lazy_listing_happy() {
             reference_hash: None,
         },
         price: U128(3_000),
+        payment_token: None,
         options: scarce::types::ScarceOptions {
             royalty: None,
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         expires_at: None,
+        start_price: None,
+        decay_start: None,
+        decay_end: None,
     };
     let listing_id = contract.create_lazy_listing(&creator(), params).unwrap();
 
@@ -546,6 +617,8 @@ fn execute_place_bid_happy() {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let tid = contract.quick_mint(&buyer(), metadata, options).unwrap();
     let auction_params = AuctionListing {
@@ -554,6 +627,7 @@ fn execute_place_bid_happy() {
         expires_at: Some(2_000_000_000_000_000_000),
         auction_duration_ns: None,
         anti_snipe_extension_ns: 0,
+        anti_snipe_window_ns: None,
         buy_now_price: None,
     };
     contract
@@ -591,6 +665,8 @@ fn execute_make_collection_offer_happy() {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -600,6 +676,19 @@ fn execute_make_collection_offer_happy() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), params).unwrap();
 
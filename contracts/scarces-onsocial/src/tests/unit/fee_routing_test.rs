@@ -30,6 +30,10 @@ fn fee_split_with_app_pool() {
             moderators: vec![],
             curated: false,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
 
@@ -96,6 +100,10 @@ fn route_fee_with_app_funds_pool() {
             moderators: vec![],
             curated: false,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
 
@@ -290,6 +298,10 @@ fn app_commission_pool_zero_bps() {
             moderators: vec![],
             curated: false,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
     assert_eq!(contract.calculate_app_commission(1_000_000, Some(&app)), 0);
@@ -311,6 +323,10 @@ fn app_commission_computed() {
             moderators: vec![],
             curated: false,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
 
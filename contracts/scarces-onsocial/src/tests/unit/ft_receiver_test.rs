@@ -173,6 +173,8 @@ fn wnear_deposit_enables_prepaid_purchase() {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let token_id = contract.quick_mint(&creator(), metadata, options).unwrap();
     contract
@@ -240,3 +242,64 @@ fn wnear_deposit_credits_different_account_via_msg() {
     let stored = contract.user_storage.get(&alice).unwrap();
     assert_eq!(stored.balance, U128(amount));
 }
+
+fn usdc() -> AccountId {
+    "usdc.near".parse().unwrap()
+}
+
+fn payout_callback(results: Vec<near_sdk::PromiseResult>) {
+    testing_env!(
+        context("marketplace.near".parse::<AccountId>().unwrap()).build(),
+        near_sdk::test_vm_config(),
+        near_sdk::RuntimeFeesConfig::test(),
+        Default::default(),
+        results,
+    );
+}
+
+#[test]
+fn ft_payout_success_leaves_nothing_claimable() {
+    let mut contract = new_contract();
+    payout_callback(vec![near_sdk::PromiseResult::Successful(vec![])]);
+    contract.on_ft_payout_resolved(usdc(), creator(), U128(500));
+
+    assert_eq!(contract.get_ft_claimable(usdc(), creator()), U128(0));
+}
+
+#[test]
+fn ft_payout_failure_credits_claimable_balance() {
+    let mut contract = new_contract();
+    payout_callback(vec![near_sdk::PromiseResult::Failed]);
+    contract.on_ft_payout_resolved(usdc(), creator(), U128(500));
+    contract.on_ft_payout_resolved(usdc(), creator(), U128(250));
+
+    assert_eq!(contract.get_ft_claimable(usdc(), creator()), U128(750));
+    assert_eq!(contract.get_ft_claimable(wnear(), creator()), U128(0));
+    assert!(
+        near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|l| l.contains("ft_payout_failed") && l.contains("\"claimable\":\"750\""))
+    );
+}
+
+#[test]
+fn claim_ft_payout_retries_and_clears_balance() {
+    let mut contract = new_contract();
+    payout_callback(vec![near_sdk::PromiseResult::Failed]);
+    contract.on_ft_payout_resolved(usdc(), creator(), U128(500));
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .execute(make_request(Action::ClaimFtPayout {
+            ft_contract_id: usdc(),
+        }))
+        .unwrap();
+    assert_eq!(contract.get_ft_claimable(usdc(), creator()), U128(0));
+
+    let err = contract
+        .execute(make_request(Action::ClaimFtPayout {
+            ft_contract_id: usdc(),
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::NotFound(_)));
+}
@@ -25,13 +25,19 @@ fn create_listing_with_expiry(contract: &mut Contract, expires_at: Option<u64>)
             reference_hash: None,
         },
         price: U128(1_000),
+        payment_token: None,
         options: scarce::types::ScarceOptions {
             royalty: None,
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         expires_at,
+        start_price: None,
+        decay_start: None,
+        decay_end: None,
     };
     let action = Action::CreateLazyListing { params };
     contract
@@ -48,8 +54,9 @@ fn cleanup_no_expired_returns_zero() {
     create_listing_with_expiry(&mut contract, None);
 
     testing_env!(context(owner()).build());
-    let cleaned = contract.cleanup_expired_lazy_listings(None);
-    assert_eq!(cleaned, 0);
+    let result = contract.cleanup_expired_lazy_listings(None, None);
+    assert_eq!(result.cleaned, 0);
+    assert_eq!(result.next_index, None);
 }
 
 #[test]
@@ -63,9 +70,14 @@ fn cleanup_removes_expired() {
     testing_env!(ctx.build());
     assert!(contract.get_lazy_listing(id.clone()).is_some());
 
-    let cleaned = contract.cleanup_expired_lazy_listings(None);
+    let cleaned = contract.cleanup_expired_lazy_listings(None, None).cleaned;
     assert_eq!(cleaned, 1);
-    assert!(contract.get_lazy_listing(id).is_none());
+    assert!(contract.get_lazy_listing(id.clone()).is_none());
+    assert!(
+        near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|l| l.contains(r#""operation":"expired_cleaned""#) && l.contains(&id))
+    );
 }
 
 #[test]
@@ -81,8 +93,9 @@ fn cleanup_respects_limit() {
     testing_env!(ctx.build());
     assert_eq!(contract.get_lazy_listings_count(), 5);
 
-    let cleaned = contract.cleanup_expired_lazy_listings(Some(2));
-    assert_eq!(cleaned, 2);
+    let result = contract.cleanup_expired_lazy_listings(None, Some(2));
+    assert_eq!(result.cleaned, 2);
+    assert_eq!(result.next_index, Some(0));
     assert_eq!(contract.get_lazy_listings_count(), 3);
 }
 
@@ -98,8 +111,72 @@ fn cleanup_skips_non_expired() {
     let mut ctx = context(owner());
     ctx.block_timestamp(1_700_000_010_000_000_000);
     testing_env!(ctx.build());
-    let cleaned = contract.cleanup_expired_lazy_listings(None);
+    let cleaned = contract.cleanup_expired_lazy_listings(None, None).cleaned;
     assert_eq!(cleaned, 1);
     assert!(contract.get_lazy_listing(non_expired_id).is_some());
     assert_eq!(contract.get_lazy_listings_count(), 2);
 }
+
+#[test]
+fn cleanup_refunds_creator_storage() {
+    let mut contract = setup_contract();
+    let soon = 1_700_000_001_000_000_000;
+    let id = create_listing_with_expiry(&mut contract, Some(soon));
+    let used_with_listing = contract
+        .user_storage
+        .get(&creator())
+        .unwrap()
+        .tier2_used_bytes;
+    assert!(used_with_listing > 0);
+
+    testing_env!(
+        context(owner())
+            .block_timestamp(1_700_000_010_000_000_000)
+            .build()
+    );
+    let balance_before = contract.platform_storage_balance;
+    let result = contract.cleanup_expired_lazy_listings(None, None);
+
+    assert_eq!(result.cleaned, 1);
+    assert!(contract.get_lazy_listing(id).is_none());
+    // Listing storage was drawn from the platform pool on the creator's behalf.
+    assert_eq!(
+        contract
+            .user_storage
+            .get(&creator())
+            .unwrap()
+            .tier2_used_bytes,
+        0
+    );
+    assert!(contract.platform_storage_balance > balance_before);
+}
+
+#[test]
+fn cleanup_cursor_resumes_past_active_listings() {
+    let mut contract = setup_contract();
+    let soon = 1_700_000_001_000_000_000;
+    let far_future = 1_800_000_000_000_000_000;
+    let active: Vec<String> = (0..3)
+        .map(|_| create_listing_with_expiry(&mut contract, Some(far_future)))
+        .collect();
+    for _ in 0..2 {
+        create_listing_with_expiry(&mut contract, Some(soon));
+    }
+
+    testing_env!(
+        context(owner())
+            .block_timestamp(1_700_000_010_000_000_000)
+            .build()
+    );
+    let first = contract.cleanup_expired_lazy_listings(None, Some(3));
+    assert_eq!(first.cleaned, 0);
+    assert_eq!(first.next_index, Some(3));
+
+    let second = contract.cleanup_expired_lazy_listings(first.next_index, Some(3));
+    assert_eq!(second.cleaned, 2);
+    assert_eq!(second.next_index, None);
+    assert_eq!(contract.get_lazy_listings_count(), 3);
+    for id in active {
+        assert!(contract.get_lazy_listing(id).is_some());
+    }
+}
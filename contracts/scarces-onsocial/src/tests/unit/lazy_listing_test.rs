@@ -20,13 +20,19 @@ fn make_lazy_listing_params(price: u128) -> LazyListing {
             reference_hash: None,
         },
         price: U128(price),
+        payment_token: None,
         options: scarce::types::ScarceOptions {
             royalty: None,
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         expires_at: None,
+        start_price: None,
+        decay_start: None,
+        decay_end: None,
     }
 }
 
@@ -219,3 +225,230 @@ fn update_price_nonexistent_fails() {
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::NotFound(_)));
 }
+
+fn ft_token() -> AccountId {
+    "social.token.near".parse().unwrap()
+}
+
+/// Accepts `ft_token()` and lists an item priced in it.
+fn setup_ft_listing(price: u128) -> (Contract, String) {
+    let mut contract = setup_contract();
+    testing_env!(context_with_deposit(owner(), 1).build());
+    contract.add_accepted_payment_token(ft_token()).unwrap();
+
+    testing_env!(context(creator()).build());
+    let mut params = make_lazy_listing_params(price);
+    params.payment_token = Some(ft_token());
+    let id = contract.create_lazy_listing(&creator(), params).unwrap();
+    (contract, id)
+}
+
+fn ft_purchase_msg(listing_id: &str) -> String {
+    format!(r#"{{"type":"purchase_lazy_listing","listing_id":"{listing_id}"}}"#)
+}
+
+#[test]
+fn create_lazy_listing_unaccepted_payment_token_fails() {
+    let mut contract = setup_contract();
+    testing_env!(context(creator()).build());
+
+    let mut params = make_lazy_listing_params(1_000);
+    params.payment_token = Some(ft_token());
+    let err = contract
+        .create_lazy_listing(&creator(), params)
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+}
+
+#[test]
+fn ft_purchase_mints_and_returns_overpayment() {
+    let (mut contract, id) = setup_ft_listing(1_000);
+
+    testing_env!(context(ft_token()).build());
+    let unused = match contract.ft_on_transfer(buyer(), U128(1_500), ft_purchase_msg(&id)) {
+        near_sdk::PromiseOrValue::Value(v) => v.0,
+        _ => panic!("expected value"),
+    };
+    assert_eq!(unused, 500);
+    assert!(!contract.lazy_listings.contains_key(&id));
+    let token_id = format!("s:{}", contract.next_token_id - 1);
+    assert_eq!(
+        contract.scarces_by_id.get(&token_id).unwrap().owner_id,
+        buyer()
+    );
+}
+
+#[test]
+#[should_panic(expected = "Insufficient payment")]
+fn ft_purchase_underpayment_panics() {
+    let (mut contract, id) = setup_ft_listing(1_000);
+
+    testing_env!(context(ft_token()).build());
+    let _ = contract.ft_on_transfer(buyer(), U128(999), ft_purchase_msg(&id));
+}
+
+#[test]
+#[should_panic(expected = "Only wNEAR or accepted payment tokens")]
+fn ft_purchase_from_unaccepted_token_panics() {
+    let (mut contract, id) = setup_ft_listing(1_000);
+
+    testing_env!(context("other.token.near".parse().unwrap()).build());
+    let _ = contract.ft_on_transfer(buyer(), U128(1_000), ft_purchase_msg(&id));
+}
+
+#[test]
+fn near_purchase_of_ft_listing_rejected() {
+    let (mut contract, id) = setup_ft_listing(1_000);
+
+    testing_env!(context_with_deposit(buyer(), 1_000).build());
+    let err = contract
+        .purchase_lazy_listing(&buyer(), id.clone(), 1_000)
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+    assert!(contract.lazy_listings.contains_key(&id));
+    assert_eq!(contract.pending_attached_balance, 1_000);
+}
+
+// --- Declining price ---
+
+const DECAY_START: u64 = 1_700_000_000_000_000_000;
+const DECAY_END: u64 = DECAY_START + 1_000_000_000_000;
+
+fn setup_declining_listing(start_price: u128, end_price: u128) -> (Contract, String) {
+    let mut contract = setup_contract();
+    testing_env!(context(creator()).build());
+    let mut params = make_lazy_listing_params(end_price);
+    params.start_price = Some(U128(start_price));
+    params.decay_start = Some(DECAY_START);
+    params.decay_end = Some(DECAY_END);
+    let id = contract.create_lazy_listing(&creator(), params).unwrap();
+    (contract, id)
+}
+
+fn price_at(contract: &Contract, id: &str, timestamp: u64) -> u128 {
+    testing_env!(context(buyer()).block_timestamp(timestamp).build());
+    contract.get_lazy_listing_price(id.to_string()).unwrap().0
+}
+
+#[test]
+fn declining_price_at_start_midpoint_and_end() {
+    let (contract, id) = setup_declining_listing(10_000, 2_000);
+
+    assert_eq!(price_at(&contract, &id, DECAY_START), 10_000);
+    assert_eq!(
+        price_at(&contract, &id, DECAY_START + (DECAY_END - DECAY_START) / 2),
+        6_000
+    );
+    assert_eq!(price_at(&contract, &id, DECAY_END), 2_000);
+    assert_eq!(price_at(&contract, &id, DECAY_END + 1), 2_000);
+}
+
+#[test]
+fn declining_purchase_charges_current_price() {
+    let (mut contract, id) = setup_declining_listing(10_000, 2_000);
+    let midpoint = DECAY_START + (DECAY_END - DECAY_START) / 2;
+
+    testing_env!(
+        context_with_deposit(buyer(), 10_000)
+            .block_timestamp(midpoint)
+            .build()
+    );
+    let token_id = contract
+        .purchase_lazy_listing(&buyer(), id.clone(), 10_000)
+        .unwrap();
+
+    assert_eq!(
+        contract.scarces_by_id.get(&token_id).unwrap().paid_price.0,
+        6_000
+    );
+    assert_eq!(contract.pending_attached_balance, 4_000);
+    assert!(!contract.lazy_listings.contains_key(&id));
+}
+
+#[test]
+fn declining_purchase_below_current_price_fails() {
+    let (mut contract, id) = setup_declining_listing(10_000, 2_000);
+    let midpoint = DECAY_START + (DECAY_END - DECAY_START) / 2;
+
+    testing_env!(
+        context_with_deposit(buyer(), 5_999)
+            .block_timestamp(midpoint)
+            .build()
+    );
+    let err = contract
+        .purchase_lazy_listing(&buyer(), id.clone(), 5_999)
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InsufficientDeposit(_)));
+    assert!(contract.lazy_listings.contains_key(&id));
+}
+
+#[test]
+fn equal_start_and_end_price_stays_fixed() {
+    let (contract, id) = setup_declining_listing(3_000, 3_000);
+
+    assert_eq!(price_at(&contract, &id, DECAY_START), 3_000);
+    assert_eq!(price_at(&contract, &id, DECAY_END - 1), 3_000);
+}
+
+#[test]
+fn listings_created_before_ft_and_declining_prices_still_decode() {
+    let (contract, id) = setup_declining_listing(10_000, 2_000);
+    let bytes = near_sdk::borsh::to_vec(contract.lazy_listings.get(&id).unwrap()).unwrap();
+
+    let decoded: LazyListingRecord = near_sdk::borsh::from_slice(&bytes).unwrap();
+    assert_eq!(decoded.start_price, Some(U128(10_000)));
+
+    // Pre-upgrade records end at `created_at`: `None` token, `Some(U128)`, two `Some(u64)`.
+    let legacy: LazyListingRecord =
+        near_sdk::borsh::from_slice(&bytes[..bytes.len() - 36]).unwrap();
+    assert_eq!(legacy.creator_id, creator());
+    assert_eq!(legacy.price, U128(2_000));
+    assert!(legacy.payment_token.is_none());
+    assert!(legacy.start_price.is_none() && legacy.decay_end.is_none());
+    assert_eq!(legacy.current_price(), 2_000);
+}
+
+#[test]
+fn declining_listing_validates_window() {
+    let mut contract = setup_contract();
+    testing_env!(context(creator()).build());
+
+    let mut params = make_lazy_listing_params(2_000);
+    params.start_price = Some(U128(1_000));
+    params.decay_start = Some(DECAY_START);
+    params.decay_end = Some(DECAY_END);
+    let err = contract
+        .create_lazy_listing(&creator(), params)
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+
+    let mut params = make_lazy_listing_params(2_000);
+    params.start_price = Some(U128(5_000));
+    params.decay_start = Some(DECAY_END);
+    params.decay_end = Some(DECAY_START);
+    let err = contract
+        .create_lazy_listing(&creator(), params)
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+
+    let mut params = make_lazy_listing_params(2_000);
+    params.decay_end = Some(DECAY_END);
+    let err = contract
+        .create_lazy_listing(&creator(), params)
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+}
+
+#[test]
+fn update_price_cannot_exceed_start_price() {
+    let (mut contract, id) = setup_declining_listing(10_000, 2_000);
+
+    let err = contract
+        .update_lazy_listing_price(&creator(), &id, 10_001)
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+    contract
+        .update_lazy_listing_price(&creator(), &id, 4_000)
+        .unwrap();
+    assert_eq!(price_at(&contract, &id, DECAY_END), 4_000);
+}
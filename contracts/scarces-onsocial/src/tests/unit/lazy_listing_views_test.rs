@@ -28,13 +28,19 @@ fn make_lazy_listing_params(price: u128) -> LazyListing {
             reference_hash: None,
         },
         price: U128(price),
+        payment_token: None,
         options: scarce::types::ScarceOptions {
             royalty: None,
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         expires_at: None,
+        start_price: None,
+        decay_start: None,
+        decay_end: None,
     }
 }
 
@@ -69,13 +75,19 @@ fn create_listing_with_app(contract: &mut Contract) -> String {
             reference_hash: None,
         },
         price: U128(3_000),
+        payment_token: None,
         options: scarce::types::ScarceOptions {
             royalty: None,
             app_id: Some(app_id()),
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         expires_at: None,
+        start_price: None,
+        decay_start: None,
+        decay_end: None,
     };
     let action = Action::CreateLazyListing { params };
     contract
@@ -141,6 +153,8 @@ fn get_lazy_listings_by_app_filters() {
             primary_sale_bps: None,
             curated: None,
             metadata: None,
+            daily_spending_cap: None,
+            sponsor_buyer_storage: None,
         },
     };
     contract.execute(make_request(register_action)).unwrap();
@@ -8,6 +8,24 @@ fn setup_with_token(
     revocation_mode: RevocationMode,
     burnable: bool,
     max_redeems: Option<u32>,
+) -> (Contract, String) {
+    setup_with_redeem_window(
+        renewable,
+        revocation_mode,
+        burnable,
+        max_redeems,
+        None,
+        None,
+    )
+}
+
+fn setup_with_redeem_window(
+    renewable: bool,
+    revocation_mode: RevocationMode,
+    burnable: bool,
+    max_redeems: Option<u32>,
+    redeem_start: Option<u64>,
+    redeem_end: Option<u64>,
 ) -> (Contract, String) {
     let mut contract = new_contract();
 
@@ -23,6 +41,8 @@ fn setup_with_token(
             app_id: None,
             transferable: true,
             burnable,
+            redeem_start,
+            redeem_end,
         },
         renewable,
         revocation_mode,
@@ -32,6 +52,19 @@ fn setup_with_token(
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
 
@@ -207,6 +240,98 @@ fn redeem_increments_collection_counters() {
     assert_eq!(col.fully_redeemed_count, 1, "1 of 1 max → fully redeemed");
 }
 
+// Test context block time is 1_700_000_000_000_000_000 ns.
+const NOW_NS: u64 = 1_700_000_000_000_000_000;
+const HOUR_NS: u64 = 3_600_000_000_000;
+
+#[test]
+fn redeem_before_window_fails() {
+    let (mut contract, tid) = setup_with_redeem_window(
+        false,
+        RevocationMode::None,
+        true,
+        Some(1),
+        Some(NOW_NS + HOUR_NS),
+        Some(NOW_NS + 2 * HOUR_NS),
+    );
+    testing_env!(context(creator()).build());
+
+    let err = contract.redeem_token(&creator(), &tid, "col").unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(ref m) if m.contains("not opened")));
+    assert_eq!(contract.scarces_by_id.get(&tid).unwrap().redeem_count, 0);
+}
+
+#[test]
+fn redeem_inside_window_succeeds() {
+    let (mut contract, tid) = setup_with_redeem_window(
+        false,
+        RevocationMode::None,
+        true,
+        Some(1),
+        Some(NOW_NS - HOUR_NS),
+        Some(NOW_NS + HOUR_NS),
+    );
+    testing_env!(context(creator()).build());
+
+    contract.redeem_token(&creator(), &tid, "col").unwrap();
+    assert_eq!(contract.scarces_by_id.get(&tid).unwrap().redeem_count, 1);
+
+    let status = contract.get_token_status(tid).unwrap();
+    assert_eq!(status.redeem_start, Some(NOW_NS - HOUR_NS));
+    assert_eq!(status.redeem_end, Some(NOW_NS + HOUR_NS));
+}
+
+#[test]
+fn redeem_after_window_fails() {
+    let (mut contract, tid) = setup_with_redeem_window(
+        false,
+        RevocationMode::None,
+        true,
+        Some(1),
+        None,
+        Some(NOW_NS + HOUR_NS),
+    );
+    testing_env!(
+        context(creator())
+            .block_timestamp(NOW_NS + 2 * HOUR_NS)
+            .build()
+    );
+
+    let err = contract.redeem_token(&creator(), &tid, "col").unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(ref m) if m.contains("closed")));
+}
+
+#[test]
+fn redeem_without_window_succeeds_anytime() {
+    let (mut contract, tid) = setup_with_token(false, RevocationMode::None, true, Some(1));
+    testing_env!(context(creator()).block_timestamp(NOW_NS * 2).build());
+
+    contract.redeem_token(&creator(), &tid, "col").unwrap();
+    assert!(contract.get_token_status(tid).unwrap().redeem_end.is_none());
+}
+
+#[test]
+fn tokens_minted_before_redeem_windows_still_decode() {
+    let (contract, tid) = setup_with_redeem_window(
+        false,
+        RevocationMode::None,
+        true,
+        Some(1),
+        Some(NOW_NS),
+        Some(NOW_NS + HOUR_NS),
+    );
+    let bytes = near_sdk::borsh::to_vec(contract.scarces_by_id.get(&tid).unwrap()).unwrap();
+
+    let decoded: Scarce = near_sdk::borsh::from_slice(&bytes).unwrap();
+    assert_eq!(decoded.redeem_end, Some(NOW_NS + HOUR_NS));
+
+    // Pre-upgrade records end at `app_id`: two `Some(u64)` fields shorter.
+    let legacy: Scarce = near_sdk::borsh::from_slice(&bytes[..bytes.len() - 18]).unwrap();
+    assert_eq!(legacy.owner_id, buyer());
+    assert_eq!(legacy.redeem_start, None);
+    assert_eq!(legacy.redeem_end, None);
+}
+
 #[test]
 fn burn_happy_path() {
     let (mut contract, tid) = setup_with_token(false, RevocationMode::None, true, None);
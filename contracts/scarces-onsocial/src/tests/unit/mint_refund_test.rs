@@ -0,0 +1,258 @@
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::get_logs;
+use near_sdk::testing_env;
+
+const PRICE: u128 = 1_000_000;
+const WINDOW_MS: u64 = 60_000;
+const BASE_NS: u64 = 1_700_000_000_000_000_000;
+
+fn refundable_config(restock: bool) -> CollectionConfig {
+    CollectionConfig {
+        collection_id: "guarded".to_string(),
+        total_supply: 10,
+        metadata_template: r#"{"title":"G"}"#.to_string(),
+        price_near: U128(PRICE),
+        start_time: None,
+        end_time: None,
+        options: ScarceOptions {
+            royalty: None,
+            app_id: None,
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: Some(WINDOW_MS),
+        restocking_fee_bps: 1_000,
+        restock_refunds: restock,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    }
+}
+
+fn setup(restock: bool) -> (Contract, String) {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+    contract
+        .create_collection(&creator(), refundable_config(restock))
+        .unwrap();
+
+    testing_env!(context_with_deposit(buyer(), PRICE).build());
+    contract
+        .execute(make_request(Action::PurchaseFromCollection {
+            collection_id: "guarded".to_string(),
+            quantity: 1,
+            max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
+        }))
+        .unwrap();
+    (contract, "guarded:1".to_string())
+}
+
+fn at_ms_after_mint(account: AccountId, elapsed_ms: u64) {
+    testing_env!(
+        context_with_deposit(account, 1)
+            .block_timestamp(BASE_NS + elapsed_ms * 1_000_000)
+            .build()
+    );
+}
+
+fn claim(contract: &mut Contract, token_id: &str) -> Result<Value, MarketplaceError> {
+    contract.execute(make_request(Action::ClaimRefund {
+        token_id: token_id.to_string(),
+        collection_id: "guarded".to_string(),
+    }))
+}
+
+#[test]
+fn purchase_holds_full_price_in_escrow() {
+    let (contract, token_id) = setup(true);
+
+    let escrow = contract.get_mint_refund_escrow(token_id).unwrap();
+    assert_eq!(escrow.buyer_id, buyer());
+    assert_eq!(escrow.amount.0, PRICE);
+    let stats = contract.get_collection_stats("guarded".into()).unwrap();
+    assert_eq!(stats.held_revenue, escrow.amount);
+}
+
+#[test]
+fn in_window_refund_returns_token_and_funds() {
+    let (mut contract, token_id) = setup(true);
+    let held = contract
+        .get_mint_refund_escrow(token_id.clone())
+        .unwrap()
+        .amount
+        .0;
+
+    at_ms_after_mint(buyer(), WINDOW_MS / 2);
+    claim(&mut contract, &token_id).unwrap();
+
+    assert!(contract.scarces_by_id.get(&token_id).is_none());
+    assert!(contract.get_mint_refund_escrow(token_id).is_none());
+    let col = contract.collections.get("guarded").unwrap();
+    assert_eq!(col.held_revenue.0, 0);
+    assert_eq!(col.mint_refunded_count, 1);
+    assert_eq!(col.refunded_count, 0);
+    // Restocked: one unit returns to available supply.
+    assert_eq!(col.total_supply, 11);
+    assert_eq!(contract.get_collection_availability("guarded".into()), 10);
+
+    assert_eq!(held, PRICE);
+    let restocking_fee = PRICE / 10;
+    let expected = format!("\"refund_amount\":\"{}\"", PRICE - restocking_fee);
+    assert!(
+        get_logs()
+            .iter()
+            .any(|l| l.contains("mint_refund_claimed") && l.contains(&expected))
+    );
+}
+
+#[test]
+fn in_window_refund_burns_without_restock() {
+    let (mut contract, token_id) = setup(false);
+
+    at_ms_after_mint(buyer(), 1);
+    claim(&mut contract, &token_id).unwrap();
+
+    let col = contract.collections.get("guarded").unwrap();
+    assert_eq!(col.total_supply, 10);
+    assert_eq!(contract.get_collection_availability("guarded".into()), 9);
+}
+
+#[test]
+fn post_window_refund_rejected() {
+    let (mut contract, token_id) = setup(true);
+
+    at_ms_after_mint(buyer(), WINDOW_MS + 1);
+    let err = claim(&mut contract, &token_id).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+    assert!(contract.scarces_by_id.get(&token_id).is_some());
+}
+
+#[test]
+fn refund_rejected_for_non_buyer_holder() {
+    let (mut contract, token_id) = setup(true);
+
+    at_ms_after_mint(buyer(), 1);
+    contract
+        .execute(make_request(Action::TransferScarce {
+            receiver_id: owner(),
+            token_id: token_id.clone(),
+            memo: None,
+        }))
+        .unwrap();
+
+    at_ms_after_mint(owner(), 2);
+    let err = claim(&mut contract, &token_id).unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+}
+
+#[test]
+fn release_escrow_after_window_closes() {
+    let (mut contract, token_id) = setup(true);
+    let release = Action::ReleaseRefundEscrow {
+        collection_id: "guarded".to_string(),
+        token_ids: vec![token_id.clone()],
+    };
+
+    at_ms_after_mint(creator(), WINDOW_MS);
+    let err = contract.execute(make_request(release.clone())).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+
+    let platform_before = contract.platform_storage_balance;
+    at_ms_after_mint(creator(), WINDOW_MS + 1);
+    contract.execute(make_request(release)).unwrap();
+
+    assert!(contract.get_mint_refund_escrow(token_id.clone()).is_none());
+    let col = contract.collections.get("guarded").unwrap();
+    assert_eq!(col.held_revenue.0, 0);
+    assert!(contract.scarces_by_id.get(&token_id).is_some());
+    // Fees are only taken once the sale can no longer be refunded.
+    assert!(contract.platform_storage_balance > platform_before);
+}
+
+#[test]
+fn release_escrow_requires_collection_authority() {
+    let (mut contract, token_id) = setup(true);
+
+    at_ms_after_mint(buyer(), WINDOW_MS + 1);
+    let err = contract
+        .execute(make_request(Action::ReleaseRefundEscrow {
+            collection_id: "guarded".to_string(),
+            token_ids: vec![token_id.clone()],
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+    assert!(contract.get_mint_refund_escrow(token_id).is_some());
+}
+
+#[test]
+fn release_escrow_rejects_duplicate_tokens() {
+    let (mut contract, token_id) = setup(true);
+
+    at_ms_after_mint(creator(), WINDOW_MS + 1);
+    let err = contract
+        .execute(make_request(Action::ReleaseRefundEscrow {
+            collection_id: "guarded".to_string(),
+            token_ids: vec![token_id.clone(), token_id.clone()],
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+    assert_eq!(
+        contract.collections.get("guarded").unwrap().held_revenue.0,
+        PRICE
+    );
+    assert!(contract.get_mint_refund_escrow(token_id).is_some());
+}
+
+#[test]
+fn cancellation_excludes_mint_refunded_tokens() {
+    let (mut contract, token_id) = setup(false);
+    at_ms_after_mint(buyer(), 1);
+    claim(&mut contract, &token_id).unwrap();
+
+    // One minted token was refunded and burned, so none are left to cover.
+    testing_env!(context_with_deposit(creator(), 0).build());
+    contract
+        .execute(make_request(Action::CancelCollection {
+            collection_id: "guarded".to_string(),
+            refund_per_token: U128(PRICE),
+            refund_deadline_ns: None,
+        }))
+        .unwrap();
+    assert!(
+        get_logs()
+            .iter()
+            .any(|l| l.contains("\"refundable_count\":0"))
+    );
+}
+
+#[test]
+fn restocking_fee_above_max_rejected() {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+
+    let mut config = refundable_config(false);
+    config.restocking_fee_bps = MAX_RESTOCKING_FEE_BPS + 1;
+    let err = contract.create_collection(&creator(), config).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+}
@@ -42,6 +42,8 @@ fn setup_with_app_collection(col_id: &str) -> Contract {
             app_id: Some(app_id()),
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: collections::RevocationMode::None,
@@ -51,6 +53,19 @@ fn setup_with_app_collection(col_id: &str) -> Contract {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract
         .execute(make_request(Action::CreateCollection { params: cfg }))
@@ -211,3 +226,282 @@ fn unban_collection_non_authority_fails() {
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::Unauthorized(_)));
 }
+
+fn other_app() -> AccountId {
+    "app.otherowner.near".parse().unwrap()
+}
+
+fn other_app_owner() -> AccountId {
+    "otherowner.near".parse().unwrap()
+}
+
+fn add_other_app_collection(contract: &mut Contract, col_id: &str) {
+    testing_env!(
+        context_with_deposit(other_app_owner(), 1_000_000_000_000_000_000_000_000).build()
+    );
+    contract
+        .execute(make_request(Action::RegisterApp {
+            app_id: other_app(),
+            params: AppConfig::default(),
+        }))
+        .unwrap();
+
+    testing_env!(context(creator()).build());
+    let cfg = CollectionConfig {
+        collection_id: col_id.to_string(),
+        total_supply: 10,
+        metadata_template: r#"{"title":"T"}"#.to_string(),
+        price_near: U128(0),
+        start_time: None,
+        end_time: None,
+        options: ScarceOptions {
+            royalty: None,
+            app_id: Some(other_app()),
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: collections::RevocationMode::None,
+        max_redeems: None,
+        mint_mode: collections::MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    };
+    contract
+        .execute(make_request(Action::CreateCollection { params: cfg }))
+        .unwrap();
+}
+
+#[test]
+fn scoped_moderator_bans_in_scope_collection() {
+    let mut contract = setup_with_app_collection("inscope");
+    add_other_app_collection(&mut contract, "outscope");
+    testing_env!(context_with_deposit(moderator(), 1).build());
+
+    contract
+        .execute(make_request(Action::BanCollection {
+            app_id: app_id(),
+            collection_id: "inscope".to_string(),
+            reason: None,
+        }))
+        .unwrap();
+
+    assert!(contract.collections.get("inscope").unwrap().banned);
+}
+
+#[test]
+fn scoped_moderator_cannot_ban_out_of_scope_collection() {
+    let mut contract = setup_with_app_collection("inscope2");
+    add_other_app_collection(&mut contract, "outscope2");
+    testing_env!(context_with_deposit(moderator(), 1).build());
+
+    let err = contract
+        .execute(make_request(Action::BanCollection {
+            app_id: other_app(),
+            collection_id: "outscope2".to_string(),
+            reason: None,
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+
+    let err = contract
+        .execute(make_request(Action::BanCollection {
+            app_id: app_id(),
+            collection_id: "outscope2".to_string(),
+            reason: None,
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+    assert!(!contract.collections.get("outscope2").unwrap().banned);
+}
+
+#[test]
+fn platform_owner_moderates_any_app() {
+    let mut contract = setup_with_app_collection("global");
+    add_other_app_collection(&mut contract, "global2");
+    testing_env!(context_with_deposit(owner(), 1).build());
+
+    contract
+        .execute(make_request(Action::BanCollection {
+            app_id: other_app(),
+            collection_id: "global2".to_string(),
+            reason: Some("policy".to_string()),
+        }))
+        .unwrap();
+    assert!(contract.collections.get("global2").unwrap().banned);
+
+    contract
+        .execute(make_request(Action::UnbanCollection {
+            app_id: other_app(),
+            collection_id: "global2".to_string(),
+        }))
+        .unwrap();
+    assert!(!contract.collections.get("global2").unwrap().banned);
+}
+
+// --- Ban appeals ---
+
+fn setup_banned_collection(col_id: &str) -> Contract {
+    let mut contract = setup_with_app_collection(col_id);
+    testing_env!(context_with_deposit(moderator(), 1).build());
+    contract
+        .execute(make_request(Action::BanCollection {
+            app_id: app_id(),
+            collection_id: col_id.to_string(),
+            reason: Some("spam".to_string()),
+        }))
+        .unwrap();
+    contract
+}
+
+fn submit_appeal(
+    contract: &mut Contract,
+    col_id: &str,
+) -> Result<near_sdk::serde_json::Value, MarketplaceError> {
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract.execute(make_request(Action::SubmitBanAppeal {
+        collection_id: col_id.to_string(),
+        reason: "Original artwork, not spam".to_string(),
+    }))
+}
+
+fn resolve_appeal(
+    contract: &mut Contract,
+    actor: AccountId,
+    col_id: &str,
+    uphold: bool,
+) -> Result<near_sdk::serde_json::Value, MarketplaceError> {
+    testing_env!(context_with_deposit(actor, 1).build());
+    contract.execute(make_request(Action::ResolveBanAppeal {
+        collection_id: col_id.to_string(),
+        uphold,
+        note: Some("reviewed".to_string()),
+    }))
+}
+
+#[test]
+fn submit_ban_appeal_records_open_appeal() {
+    let mut contract = setup_banned_collection("appeal1");
+
+    submit_appeal(&mut contract, "appeal1").unwrap();
+
+    let appeal = contract.get_ban_appeal("appeal1".to_string()).unwrap();
+    assert_eq!(appeal.status, AppealStatus::Open);
+    assert_eq!(appeal.creator_id, creator());
+    assert_eq!(appeal.app_id, app_id());
+    assert!(
+        near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|l| l.contains(r#""operation":"ban_appeal_submitted""#))
+    );
+
+    let err = submit_appeal(&mut contract, "appeal1").unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+}
+
+#[test]
+fn submit_ban_appeal_requires_creator_and_ban() {
+    let mut contract = setup_with_app_collection("appeal2");
+    let err = submit_appeal(&mut contract, "appeal2").unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+
+    testing_env!(context_with_deposit(moderator(), 1).build());
+    contract
+        .execute(make_request(Action::BanCollection {
+            app_id: app_id(),
+            collection_id: "appeal2".to_string(),
+            reason: None,
+        }))
+        .unwrap();
+    testing_env!(context_with_deposit(buyer(), 1).build());
+    let err = contract
+        .execute(make_request(Action::SubmitBanAppeal {
+            collection_id: "appeal2".to_string(),
+            reason: "not mine".to_string(),
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+}
+
+#[test]
+fn upheld_appeal_keeps_ban() {
+    let mut contract = setup_banned_collection("appeal3");
+    submit_appeal(&mut contract, "appeal3").unwrap();
+
+    resolve_appeal(&mut contract, moderator(), "appeal3", true).unwrap();
+
+    assert!(contract.collections.get("appeal3").unwrap().banned);
+    let appeal = contract.get_ban_appeal("appeal3".to_string()).unwrap();
+    assert_eq!(appeal.status, AppealStatus::Upheld);
+    assert_eq!(appeal.resolved_by, Some(moderator()));
+    assert_eq!(appeal.note.as_deref(), Some("reviewed"));
+    let logs = near_sdk::test_utils::get_logs();
+    assert!(
+        logs.iter()
+            .any(|l| l.contains(r#""operation":"ban_appeal_resolved""#))
+    );
+    assert!(!logs.iter().any(|l| l.contains(r#""operation":"unban""#)));
+
+    let err = resolve_appeal(&mut contract, moderator(), "appeal3", false).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+
+    // A resolved appeal does not block a fresh one.
+    submit_appeal(&mut contract, "appeal3").unwrap();
+    assert_eq!(
+        contract
+            .get_ban_appeal("appeal3".to_string())
+            .unwrap()
+            .status,
+        AppealStatus::Open
+    );
+}
+
+#[test]
+fn overturned_appeal_unbans_collection() {
+    let mut contract = setup_banned_collection("appeal4");
+    submit_appeal(&mut contract, "appeal4").unwrap();
+
+    resolve_appeal(&mut contract, owner(), "appeal4", false).unwrap();
+
+    assert!(!contract.collections.get("appeal4").unwrap().banned);
+    assert_eq!(
+        contract
+            .get_ban_appeal("appeal4".to_string())
+            .unwrap()
+            .status,
+        AppealStatus::Overturned
+    );
+    let logs = near_sdk::test_utils::get_logs();
+    assert!(logs.iter().any(|l| l.contains(r#""operation":"unban""#)));
+    assert!(
+        logs.iter()
+            .any(|l| l.contains(r#""operation":"ban_appeal_resolved""#))
+    );
+}
+
+#[test]
+fn resolve_ban_appeal_requires_moderation_authority() {
+    let mut contract = setup_banned_collection("appeal5");
+    submit_appeal(&mut contract, "appeal5").unwrap();
+
+    let err = resolve_appeal(&mut contract, buyer(), "appeal5", false).unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+    assert!(contract.collections.get("appeal5").unwrap().banned);
+}
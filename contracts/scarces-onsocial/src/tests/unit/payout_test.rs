@@ -34,6 +34,8 @@ fn quick_mint(contract: &mut Contract, minter: &AccountId) -> String {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
     };
     contract
@@ -57,6 +59,8 @@ fn quick_mint_with_royalty(
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
     };
     contract
@@ -25,6 +25,8 @@ fn setup_listed_scarce(contract: &mut Contract) -> (String, u128) {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let tid = contract.quick_mint(&buyer(), metadata, options).unwrap();
     contract
@@ -170,6 +172,8 @@ fn uses_prepaid_balance_purchase_actions() {
             collection_id: "c:1".into(),
             quantity: 1,
             max_price_per_token: U128(u128::MAX),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }
         .uses_prepaid_balance()
     );
@@ -0,0 +1,153 @@
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::testing_env;
+
+fn marketplace() -> AccountId {
+    "marketplace.near".parse().unwrap()
+}
+
+fn external_nft() -> AccountId {
+    "nft.near".parse().unwrap()
+}
+
+fn set_bounds(contract: &mut Contract, nft_contract_id: AccountId, min: u128, max: u128) {
+    testing_env!(context_with_deposit(owner(), 1).build());
+    contract
+        .set_price_bounds(nft_contract_id, Some(U128(min)), Some(U128(max)))
+        .unwrap();
+}
+
+fn mint(contract: &mut Contract) -> String {
+    testing_env!(context(buyer()).build());
+    let metadata = scarce::types::TokenMetadata {
+        title: Some("Bounded".into()),
+        description: None,
+        media: None,
+        media_hash: None,
+        copies: None,
+        issued_at: None,
+        expires_at: None,
+        starts_at: None,
+        updated_at: None,
+        extra: None,
+        reference: None,
+        reference_hash: None,
+    };
+    let options = scarce::types::ScarceOptions {
+        royalty: None,
+        app_id: None,
+        transferable: true,
+        burnable: true,
+        redeem_start: None,
+        redeem_end: None,
+    };
+    contract.quick_mint(&buyer(), metadata, options).unwrap()
+}
+
+#[test]
+fn native_listing_outside_bounds_rejected() {
+    let mut contract = new_contract();
+    set_bounds(&mut contract, marketplace(), 1_000, 10_000);
+    let tid = mint(&mut contract);
+
+    testing_env!(context(buyer()).build());
+    for price in [999, 10_001] {
+        let err = contract
+            .list_native_scarce(&buyer(), &tid, U128(price), None)
+            .unwrap_err();
+        assert!(matches!(err, MarketplaceError::InvalidInput(_)), "{price}");
+    }
+    contract
+        .list_native_scarce(&buyer(), &tid, U128(5_000), None)
+        .unwrap();
+}
+
+#[test]
+fn update_price_respects_bounds() {
+    let mut contract = new_contract();
+    let tid = mint(&mut contract);
+    testing_env!(context(buyer()).build());
+    contract
+        .list_native_scarce(&buyer(), &tid, U128(5_000), None)
+        .unwrap();
+
+    set_bounds(&mut contract, marketplace(), 1_000, 10_000);
+    testing_env!(context(buyer()).build());
+    let err = contract
+        .update_price(&buyer(), &marketplace(), &tid, U128(50_000))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+    contract
+        .update_price(&buyer(), &marketplace(), &tid, U128(9_000))
+        .unwrap();
+}
+
+#[test]
+fn external_approval_listing_outside_bounds_rejected() {
+    let mut contract = new_contract();
+    testing_env!(context_with_deposit(owner(), 1).build());
+    contract.add_approved_nft_contract(external_nft()).unwrap();
+    set_bounds(&mut contract, external_nft(), 1_000, 10_000);
+
+    testing_env!(context(external_nft()).signer_account_id(buyer()).build());
+    let result = contract.nft_on_approve(
+        "low".into(),
+        buyer(),
+        1,
+        r#"{"sale_conditions":"10"}"#.into(),
+    );
+    assert!(matches!(result, Err(MarketplaceError::InvalidInput(_))));
+
+    assert!(
+        contract
+            .nft_on_approve(
+                "ok".into(),
+                buyer(),
+                1,
+                r#"{"sale_conditions":"2000"}"#.into(),
+            )
+            .is_ok()
+    );
+    assert!(contract.get_sale(external_nft(), "ok".into()).is_some());
+}
+
+#[test]
+fn unbounded_contracts_and_clear_restore_default() {
+    let mut contract = new_contract();
+    set_bounds(&mut contract, external_nft(), 1_000, 10_000);
+    let tid = mint(&mut contract);
+
+    // Bounds on another contract do not apply to native listings.
+    testing_env!(context(buyer()).build());
+    contract
+        .list_native_scarce(&buyer(), &tid, U128(1), None)
+        .unwrap();
+
+    testing_env!(context_with_deposit(owner(), 1).build());
+    contract.clear_price_bounds(external_nft()).unwrap();
+    assert!(contract.get_price_bounds(external_nft()).is_none());
+}
+
+#[test]
+fn set_price_bounds_validates_input_and_owner() {
+    let mut contract = new_contract();
+
+    testing_env!(context_with_deposit(owner(), 1).build());
+    assert!(
+        contract
+            .set_price_bounds(external_nft(), Some(U128(10)), Some(U128(5)))
+            .is_err()
+    );
+    assert!(
+        contract
+            .set_price_bounds(external_nft(), None, None)
+            .is_err()
+    );
+
+    testing_env!(context_with_deposit(buyer(), 1).build());
+    let err = contract
+        .set_price_bounds(external_nft(), Some(U128(1)), None)
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+}
@@ -29,6 +29,7 @@ fn dutch_collection(floor: u128, start_price: u128, start: u64, end: u64) -> Laz
         max_per_wallet: None,
         transferable: true,
         paused: false,
+        frozen: false,
         cancelled: false,
         refund_pool: U128(0),
         refund_per_token: U128(0),
@@ -39,6 +40,24 @@ fn dutch_collection(floor: u128, start_price: u128, start: u64, end: u64) -> Laz
         banned: false,
         metadata: None,
         app_metadata: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        held_revenue: U128(0),
+        mint_refunded_count: 0,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        redeem_start: None,
+        redeem_end: None,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        revealed: false,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     }
 }
 
@@ -66,6 +85,7 @@ fn fixed_collection(price: u128) -> LazyCollection {
         max_per_wallet: None,
         transferable: true,
         paused: false,
+        frozen: false,
         cancelled: false,
         refund_pool: U128(0),
         refund_per_token: U128(0),
@@ -76,6 +96,24 @@ fn fixed_collection(price: u128) -> LazyCollection {
         banned: false,
         metadata: None,
         app_metadata: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        held_revenue: U128(0),
+        mint_refunded_count: 0,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        redeem_start: None,
+        redeem_end: None,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        revealed: false,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     }
 }
 
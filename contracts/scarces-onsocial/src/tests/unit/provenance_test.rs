@@ -0,0 +1,133 @@
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::testing_env;
+
+const START_NS: u64 = 1_700_000_000_000_000_000;
+
+fn provenance_config(id: &str, track_provenance: bool) -> CollectionConfig {
+    CollectionConfig {
+        collection_id: id.to_string(),
+        total_supply: 10,
+        metadata_template: r#"{"title":"Token #{seat_number}"}"#.to_string(),
+        price_near: U128(0),
+        start_time: None,
+        end_time: None,
+        options: scarce::types::ScarceOptions {
+            royalty: None,
+            app_id: None,
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    }
+}
+
+/// Creator holds `col:1`.
+fn setup(track_provenance: bool) -> Contract {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+    contract
+        .create_collection(&creator(), provenance_config("col", track_provenance))
+        .unwrap();
+    contract
+        .mint_from_collection(&creator(), "col", 1, None)
+        .unwrap();
+    contract
+}
+
+fn transfer_at(contract: &mut Contract, from: AccountId, to: &AccountId, timestamp: u64) {
+    testing_env!(context(from.clone()).block_timestamp(timestamp).build());
+    contract.transfer(&from, to, "col:1", None, None).unwrap();
+}
+
+#[test]
+fn mint_and_transfers_record_ordered_provenance() {
+    let mut contract = setup(true);
+    transfer_at(&mut contract, creator(), &buyer(), START_NS + 1);
+    transfer_at(&mut contract, buyer(), &owner(), START_NS + 2);
+
+    assert_eq!(
+        contract.get_token_provenance("col:1".to_string()),
+        vec![
+            (creator(), START_NS),
+            (buyer(), START_NS + 1),
+            (owner(), START_NS + 2),
+        ]
+    );
+}
+
+#[test]
+fn provenance_cap_evicts_oldest_entry() {
+    let mut contract = setup(true);
+    let accounts = [creator(), buyer()];
+    for i in 0..MAX_PROVENANCE_ENTRIES as u64 {
+        let from = accounts[(i % 2) as usize].clone();
+        let to = accounts[((i + 1) % 2) as usize].clone();
+        transfer_at(&mut contract, from, &to, START_NS + i + 1);
+    }
+
+    let provenance = contract.get_token_provenance("col:1".to_string());
+    assert_eq!(provenance.len(), MAX_PROVENANCE_ENTRIES);
+    // The mint entry at START_NS was evicted; the oldest kept is the first transfer.
+    assert_eq!(provenance[0], (buyer(), START_NS + 1));
+    assert_eq!(
+        provenance.last().unwrap().1,
+        START_NS + MAX_PROVENANCE_ENTRIES as u64
+    );
+}
+
+#[test]
+fn untracked_collection_stores_no_provenance() {
+    let mut contract = setup(false);
+    transfer_at(&mut contract, creator(), &buyer(), START_NS + 1);
+
+    assert!(
+        contract
+            .get_token_provenance("col:1".to_string())
+            .is_empty()
+    );
+    assert!(!contract.token_provenance.contains_key("col:1"));
+}
+
+#[test]
+fn burn_clears_provenance() {
+    let mut contract = setup(true);
+    testing_env!(context(creator()).build());
+    contract.burn_scarce(&creator(), "col:1", "col").unwrap();
+
+    assert!(
+        contract
+            .get_token_provenance("col:1".to_string())
+            .is_empty()
+    );
+    assert!(!contract.token_provenance.contains_key("col:1"));
+}
+
+#[test]
+fn provenance_of_unknown_token_is_empty() {
+    let contract = new_contract();
+    assert!(contract.get_token_provenance("nope".to_string()).is_empty());
+}
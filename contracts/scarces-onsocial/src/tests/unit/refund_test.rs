@@ -13,6 +13,8 @@ fn default_options() -> scarce::types::ScarceOptions {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     }
 }
 
@@ -34,6 +36,19 @@ fn create_refundable_collection(contract: &mut Contract) {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
 }
@@ -44,6 +59,8 @@ fn mint_and_get_token(contract: &mut Contract) -> String {
         collection_id: "refcol".into(),
         quantity: 1,
         max_price_per_token: U128(u128::MAX),
+        allowlist_proof: None,
+        refund_if_exhausted: false,
     };
     contract.execute(make_request(action)).unwrap();
     let col = contract.collections.get("refcol").unwrap();
@@ -0,0 +1,186 @@
+use crate::tests::test_utils::*;
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::testing_env;
+use std::collections::HashMap;
+
+fn market() -> AccountId {
+    "rogue-market.near".parse().unwrap()
+}
+
+fn enforced_config(id: &str, enforce_royalties: bool) -> CollectionConfig {
+    let mut royalty = HashMap::new();
+    royalty.insert(creator(), 1_000u32);
+    CollectionConfig {
+        collection_id: id.to_string(),
+        total_supply: 10,
+        metadata_template: r#"{"title":"Token #{seat_number}"}"#.to_string(),
+        price_near: U128(0),
+        start_time: None,
+        end_time: None,
+        options: scarce::types::ScarceOptions {
+            royalty: Some(royalty),
+            app_id: None,
+            transferable: true,
+            burnable: true,
+            redeem_start: None,
+            redeem_end: None,
+        },
+        renewable: false,
+        revocation_mode: RevocationMode::None,
+        max_redeems: None,
+        mint_mode: MintMode::Open,
+        metadata: None,
+        max_per_wallet: None,
+        start_price: None,
+        allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
+    }
+}
+
+/// Creator holds `col:1`; `market()` is registered as skipping royalties.
+fn setup(enforce_royalties: bool) -> Contract {
+    let mut contract = new_contract();
+    testing_env!(context(creator()).build());
+    contract
+        .create_collection(&creator(), enforced_config("col", enforce_royalties))
+        .unwrap();
+    contract
+        .mint_from_collection(&creator(), "col", 1, None)
+        .unwrap();
+
+    testing_env!(context_with_deposit(owner(), 1).build());
+    contract.set_known_marketplace(market(), false).unwrap();
+    contract
+}
+
+fn approve_market(contract: &mut Contract) {
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .nft_approve("col:1".to_string(), market(), None)
+        .unwrap();
+}
+
+#[test]
+fn wallet_to_wallet_transfer_allowed() {
+    let mut contract = setup(true);
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .nft_transfer(buyer(), "col:1".to_string(), None, None)
+        .unwrap();
+    assert_eq!(
+        contract.scarces_by_id.get("col:1").unwrap().owner_id,
+        buyer()
+    );
+}
+
+#[test]
+fn transfer_to_non_royalty_marketplace_blocked() {
+    let mut contract = setup(true);
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    let err = contract
+        .nft_transfer(market(), "col:1".to_string(), None, None)
+        .unwrap_err();
+    let MarketplaceError::Unauthorized(reason) = err else {
+        panic!("expected Unauthorized");
+    };
+    assert!(reason.contains("col:1"));
+    assert!(reason.contains(market().as_str()));
+    assert!(reason.contains("does not honour payouts"));
+    assert_eq!(
+        contract.scarces_by_id.get("col:1").unwrap().owner_id,
+        creator()
+    );
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    let err = contract
+        .execute(make_request(Action::TransferScarce {
+            receiver_id: market(),
+            token_id: "col:1".to_string(),
+            memo: None,
+        }))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+}
+
+#[test]
+fn approved_non_royalty_marketplace_cannot_move_token() {
+    let mut contract = setup(true);
+    approve_market(&mut contract);
+
+    testing_env!(context_with_deposit(market(), 1).build());
+    let err = contract
+        .nft_transfer(buyer(), "col:1".to_string(), None, None)
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+}
+
+#[test]
+fn payout_transfer_bypasses_enforcement() {
+    let mut contract = setup(true);
+    approve_market(&mut contract);
+
+    testing_env!(context_with_deposit(market(), 1).build());
+    let payout = contract
+        .nft_transfer_payout(buyer(), "col:1".to_string(), None, None, U128(10_000), None)
+        .unwrap();
+    assert_eq!(payout.payout[&creator()].0, 10_000);
+    assert_eq!(
+        contract.scarces_by_id.get("col:1").unwrap().owner_id,
+        buyer()
+    );
+}
+
+#[test]
+fn royalty_respecting_marketplace_allowed() {
+    let mut contract = setup(true);
+    testing_env!(context_with_deposit(owner(), 1).build());
+    contract.set_known_marketplace(market(), true).unwrap();
+
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .nft_transfer(market(), "col:1".to_string(), None, None)
+        .unwrap();
+}
+
+#[test]
+fn unenforced_collection_unaffected() {
+    let mut contract = setup(false);
+    testing_env!(context_with_deposit(creator(), 1).build());
+    contract
+        .nft_transfer(market(), "col:1".to_string(), None, None)
+        .unwrap();
+}
+
+#[test]
+fn known_marketplace_admin_requires_owner() {
+    let mut contract = new_contract();
+
+    testing_env!(context_with_deposit(buyer(), 1).build());
+    assert!(matches!(
+        contract.set_known_marketplace(market(), false),
+        Err(MarketplaceError::Unauthorized(_))
+    ));
+
+    testing_env!(context_with_deposit(owner(), 1).build());
+    contract.set_known_marketplace(market(), false).unwrap();
+    assert_eq!(contract.get_known_marketplaces(), vec![(market(), false)]);
+
+    testing_env!(context_with_deposit(owner(), 1).build());
+    contract.remove_known_marketplace(market()).unwrap();
+    assert!(contract.get_known_marketplaces().is_empty());
+}
@@ -29,6 +29,10 @@ fn merge_app_only() {
             moderators: vec![],
             curated: false,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
 
@@ -81,6 +85,10 @@ fn merge_disjoint_accounts() {
             moderators: vec![],
             curated: false,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
 
@@ -127,6 +135,10 @@ fn merge_shared_account_summed() {
             moderators: vec![],
             curated: false,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
 
@@ -161,6 +173,10 @@ fn merge_exceeds_max_royalty_bps_fails() {
             moderators: vec![],
             curated: false,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
 
@@ -193,6 +209,10 @@ fn merge_exactly_max_royalty_bps_ok() {
             moderators: vec![],
             curated: false,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
 
@@ -237,6 +257,8 @@ fn make_token(royalty: Option<HashMap<AccountId, u32>>) -> Scarce {
         transferable: None,
         burnable: None,
         app_id: None,
+        redeem_start: None,
+        redeem_end: None,
     }
 }
 
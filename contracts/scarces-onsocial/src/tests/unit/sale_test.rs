@@ -24,6 +24,8 @@ fn make_standalone_token(contract: &mut Contract, owner_account: &AccountId) ->
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     contract
         .quick_mint(owner_account, metadata, options)
@@ -107,6 +109,74 @@ fn list_native_scarce_past_expiry_fails() {
     assert!(matches!(err, MarketplaceError::InvalidInput(_)));
 }
 
+fn listing_item(token_id: &str, price: u128) -> NativeListingItem {
+    NativeListingItem {
+        token_id: token_id.to_string(),
+        price: U128(price),
+        expires_at: None,
+    }
+}
+
+#[test]
+fn batch_list_native_scarce_happy() {
+    let mut contract = new_contract();
+    let t1 = make_standalone_token(&mut contract, &buyer());
+    let t2 = make_standalone_token(&mut contract, &buyer());
+    testing_env!(context(buyer()).build());
+
+    let result = contract
+        .execute(make_request(Action::BatchListNativeScarce {
+            listings: vec![listing_item(&t1, 1_000), listing_item(&t2, 2_000)],
+        }))
+        .unwrap();
+
+    let contract_id: AccountId = "marketplace.near".parse().unwrap();
+    let sale_ids: Vec<String> = near_sdk::serde_json::from_value(result).unwrap();
+    assert_eq!(
+        sale_ids,
+        vec![
+            Contract::make_sale_id(&contract_id, &t1),
+            Contract::make_sale_id(&contract_id, &t2),
+        ]
+    );
+    assert_eq!(
+        contract.sales.get(&sale_ids[1]).unwrap().sale_conditions.0,
+        2_000
+    );
+    let listed = near_sdk::test_utils::get_logs()
+        .iter()
+        .filter(|l| l.contains("\"list_native\""))
+        .count();
+    assert_eq!(listed, 2);
+}
+
+#[test]
+fn batch_list_native_scarce_partial_ownership_rejects_all() {
+    let mut contract = new_contract();
+    let mine = make_standalone_token(&mut contract, &buyer());
+    let theirs = make_standalone_token(&mut contract, &creator());
+    testing_env!(context(buyer()).build());
+
+    let err = contract
+        .batch_list_native_scarce(
+            &buyer(),
+            vec![listing_item(&mine, 1_000), listing_item(&theirs, 1_000)],
+        )
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+    assert_eq!(contract.get_supply_sales(), 0);
+}
+
+#[test]
+fn batch_list_native_scarce_empty_fails() {
+    let mut contract = new_contract();
+    testing_env!(context(buyer()).build());
+    let err = contract
+        .batch_list_native_scarce(&buyer(), vec![])
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+}
+
 #[test]
 fn delist_native_scarce_happy() {
     let mut contract = new_contract();
@@ -167,6 +237,34 @@ fn update_price_happy() {
     );
 }
 
+#[test]
+fn update_price_emits_old_and_new_price() {
+    let mut contract = new_contract();
+    let tid = make_standalone_token(&mut contract, &buyer());
+    testing_env!(context(buyer()).build());
+    contract
+        .list_native_scarce(&buyer(), &tid, U128(1_000), None)
+        .unwrap();
+    let mkt: AccountId = "marketplace.near".parse().unwrap();
+    contract
+        .update_price(&buyer(), &mkt, &tid, U128(2_500))
+        .unwrap();
+
+    let log = near_sdk::test_utils::get_logs()
+        .into_iter()
+        .find(|l| l.contains("\"operation\":\"update_price\""))
+        .expect("update_price event");
+    let event: near_sdk::serde_json::Value =
+        near_sdk::serde_json::from_str(log.strip_prefix("EVENT_JSON:").unwrap()).unwrap();
+    assert_eq!(event["standard"], "onsocial");
+    let data = &event["data"][0];
+    assert_eq!(data["sale_id"], Contract::make_sale_id(&mkt, &tid));
+    assert_eq!(data["scarce_contract_id"], mkt.as_str());
+    assert_eq!(data["token_id"], tid.as_str());
+    assert_eq!(data["old_price"], "1000");
+    assert_eq!(data["new_price"], "2500");
+}
+
 #[test]
 fn update_price_zero_fails() {
     let mut contract = new_contract();
@@ -211,6 +309,7 @@ fn auction_list_happy() {
         expires_at: Some(2_000_000_000_000_000_000),
         auction_duration_ns: None,
         anti_snipe_extension_ns: 0,
+        anti_snipe_window_ns: None,
         buy_now_price: None,
     };
     contract
@@ -234,6 +333,7 @@ fn auction_list_zero_increment_fails() {
         expires_at: Some(2_000_000_000_000_000_000),
         auction_duration_ns: None,
         anti_snipe_extension_ns: 0,
+        anti_snipe_window_ns: None,
         buy_now_price: None,
     };
     let err = contract
@@ -254,6 +354,7 @@ fn auction_list_no_expiry_no_duration_fails() {
         expires_at: None,
         auction_duration_ns: None,
         anti_snipe_extension_ns: 0,
+        anti_snipe_window_ns: None,
         buy_now_price: None,
     };
     let err = contract
@@ -274,6 +375,7 @@ fn auction_list_buy_now_below_reserve_fails() {
         expires_at: Some(2_000_000_000_000_000_000),
         auction_duration_ns: None,
         anti_snipe_extension_ns: 0,
+        anti_snipe_window_ns: None,
         buy_now_price: Some(U128(500)),
     };
     let err = contract
@@ -294,6 +396,7 @@ fn cancel_auction_no_bids_happy() {
         expires_at: Some(2_000_000_000_000_000_000),
         auction_duration_ns: None,
         anti_snipe_extension_ns: 0,
+        anti_snipe_window_ns: None,
         buy_now_price: None,
     };
     contract
@@ -317,6 +420,7 @@ fn cancel_auction_wrong_owner_fails() {
         expires_at: Some(2_000_000_000_000_000_000),
         auction_duration_ns: None,
         anti_snipe_extension_ns: 0,
+        anti_snipe_window_ns: None,
         buy_now_price: None,
     };
     contract
@@ -326,6 +430,131 @@ fn cancel_auction_wrong_owner_fails() {
     assert!(matches!(err, MarketplaceError::Unauthorized(_)));
 }
 
+const T0: u64 = 1_700_000_000_000_000_000;
+const SEC: u64 = 1_000_000_000;
+
+/// Ends at T0 + 60s; bids in the last 10s add 30s.
+fn list_anti_snipe_auction(contract: &mut Contract) -> String {
+    let tid = make_standalone_token(contract, &buyer());
+    testing_env!(context(buyer()).build());
+    let params = AuctionListing {
+        reserve_price: U128(1_000),
+        min_bid_increment: U128(100),
+        expires_at: Some(T0 + 60 * SEC),
+        auction_duration_ns: None,
+        anti_snipe_extension_ns: 30 * SEC,
+        anti_snipe_window_ns: Some(10 * SEC),
+        buy_now_price: None,
+    };
+    contract
+        .list_native_scarce_auction(&buyer(), &tid, params)
+        .unwrap();
+    tid
+}
+
+fn bid_at(contract: &mut Contract, tid: &str, amount: u128, at: u64) -> Option<u64> {
+    testing_env!(context(creator()).block_timestamp(at).build());
+    contract
+        .place_bid(&creator(), tid.to_string(), amount)
+        .unwrap();
+    let sale_id = Contract::make_sale_id(&"marketplace.near".parse().unwrap(), tid);
+    contract.sales.get(&sale_id).unwrap().expires_at
+}
+
+#[test]
+fn anti_snipe_bid_outside_window_does_not_extend() {
+    let mut contract = new_contract();
+    let tid = list_anti_snipe_auction(&mut contract);
+
+    assert_eq!(
+        bid_at(&mut contract, &tid, 1_000, T0 + 40 * SEC),
+        Some(T0 + 60 * SEC)
+    );
+    assert_eq!(contract.get_auction(tid).unwrap().anti_snipe_extensions, 0);
+}
+
+#[test]
+fn anti_snipe_bid_inside_window_extends() {
+    let mut contract = new_contract();
+    let tid = list_anti_snipe_auction(&mut contract);
+
+    assert_eq!(
+        bid_at(&mut contract, &tid, 1_000, T0 + 55 * SEC),
+        Some(T0 + 90 * SEC)
+    );
+    let view = contract.get_auction(tid).unwrap();
+    assert_eq!(view.expires_at, Some(T0 + 90 * SEC));
+    assert_eq!(view.anti_snipe_extensions, 1);
+    assert!(
+        near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|l| l.contains("auction_extended"))
+    );
+}
+
+#[test]
+fn anti_snipe_extensions_capped() {
+    let mut contract = new_contract();
+    let tid = list_anti_snipe_auction(&mut contract);
+
+    let mut expires = T0 + 60 * SEC;
+    let mut amount = 1_000;
+    for _ in 0..MAX_ANTI_SNIPE_EXTENSIONS {
+        expires = bid_at(&mut contract, &tid, amount, expires - SEC).unwrap();
+        amount += 100;
+    }
+    assert_eq!(
+        expires,
+        T0 + 60 * SEC + MAX_ANTI_SNIPE_EXTENSIONS as u64 * 30 * SEC
+    );
+
+    assert_eq!(
+        bid_at(&mut contract, &tid, amount, expires - SEC),
+        Some(expires)
+    );
+}
+
+#[test]
+fn auctions_listed_before_anti_snipe_windows_still_decode() {
+    let mut contract = new_contract();
+    let tid = list_anti_snipe_auction(&mut contract);
+    bid_at(&mut contract, &tid, 1_000, T0 + 55 * SEC);
+    let sale_id = Contract::make_sale_id(&"marketplace.near".parse().unwrap(), &tid);
+    let bytes = near_sdk::borsh::to_vec(contract.sales.get(&sale_id).unwrap()).unwrap();
+
+    let decoded: Sale = near_sdk::borsh::from_slice(&bytes).unwrap();
+    assert_eq!(decoded.auction.unwrap().anti_snipe_extensions, 1);
+
+    // Pre-upgrade records end at `buy_now_price`: a u64 and a u32 shorter.
+    let legacy: Sale = near_sdk::borsh::from_slice(&bytes[..bytes.len() - 12]).unwrap();
+    let auction = legacy.auction.unwrap();
+    assert_eq!(legacy.expires_at, Some(T0 + 90 * SEC));
+    assert_eq!(auction.highest_bid, U128(1_000));
+    assert_eq!(auction.anti_snipe_window_ns, 30 * SEC);
+    assert_eq!(auction.anti_snipe_extensions, 0);
+}
+
+#[test]
+fn anti_snipe_window_requires_extension() {
+    let mut contract = new_contract();
+    let tid = make_standalone_token(&mut contract, &buyer());
+    testing_env!(context(buyer()).build());
+
+    let params = AuctionListing {
+        reserve_price: U128(1_000),
+        min_bid_increment: U128(100),
+        expires_at: Some(T0 + 60 * SEC),
+        auction_duration_ns: None,
+        anti_snipe_extension_ns: 0,
+        anti_snipe_window_ns: Some(10 * SEC),
+        buy_now_price: None,
+    };
+    let err = contract
+        .list_native_scarce_auction(&buyer(), &tid, params)
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+}
+
 #[test]
 fn make_sale_id_format() {
     let contract_id: AccountId = "nft.near".parse().unwrap();
@@ -369,6 +598,8 @@ fn list_soulbound_token_fails() {
             app_id: None,
             transferable: false,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -378,6 +609,19 @@ fn list_soulbound_token_fails() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
     testing_env!(context(creator()).build());
@@ -408,6 +652,8 @@ fn list_revoked_token_fails() {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::Invalidate,
@@ -417,6 +663,19 @@ fn list_revoked_token_fails() {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     contract.create_collection(&creator(), config).unwrap();
     testing_env!(context(creator()).build());
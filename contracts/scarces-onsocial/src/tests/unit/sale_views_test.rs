@@ -13,6 +13,8 @@ fn default_options() -> scarce::types::ScarceOptions {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     }
 }
 
@@ -108,7 +110,7 @@ fn get_supply_by_owner_tracks() {
 fn get_sales_by_owner_empty() {
     let contract = setup_contract();
     testing_env!(context(owner()).build());
-    let sales = contract.get_sales_by_owner_id(buyer(), None, None);
+    let sales = contract.get_sales_by_owner_id(buyer(), None, None, None);
     assert!(sales.is_empty());
 }
 
@@ -119,7 +121,7 @@ fn get_sales_by_owner_returns_correct() {
     quick_mint_and_list(&mut contract, &buyer(), 2_000);
 
     testing_env!(context(owner()).build());
-    let sales = contract.get_sales_by_owner_id(buyer(), None, None);
+    let sales = contract.get_sales_by_owner_id(buyer(), None, None, None);
     assert_eq!(sales.len(), 2);
 }
 
@@ -131,10 +133,10 @@ fn get_sales_pagination() {
     }
     testing_env!(context(owner()).build());
 
-    let page1 = contract.get_sales(None, Some(2));
+    let page1 = contract.get_sales(None, Some(2), None);
     assert_eq!(page1.len(), 2);
 
-    let page2 = contract.get_sales(Some(2), Some(10));
+    let page2 = contract.get_sales(Some(2), Some(10), None);
     assert_eq!(page2.len(), 3);
 }
 
@@ -214,6 +216,7 @@ fn get_auction_returns_auction_data() {
         expires_at: Some(2_000_000_000_000_000_000),
         auction_duration_ns: None,
         anti_snipe_extension_ns: 0,
+        anti_snipe_window_ns: None,
         buy_now_price: None,
     };
     let list_action = Action::ListNativeScarceAuction {
@@ -236,3 +239,306 @@ fn get_auctions_empty() {
     let auctions = contract.get_auctions(None, None);
     assert!(auctions.is_empty());
 }
+
+fn mint_and_auction(contract: &mut Contract, seller: &AccountId, duration_ns: u64) -> String {
+    let token_id = quick_mint_and_list(contract, seller, 1);
+    testing_env!(context_with_deposit(seller.clone(), 1).build());
+    contract
+        .execute(make_request(Action::DelistNativeScarce {
+            token_id: token_id.clone(),
+        }))
+        .unwrap();
+    testing_env!(context(seller.clone()).build());
+    contract
+        .execute(make_request(Action::ListNativeScarceAuction {
+            token_id: token_id.clone(),
+            params: AuctionListing {
+                reserve_price: U128(1_000),
+                min_bid_increment: U128(100),
+                expires_at: None,
+                auction_duration_ns: Some(duration_ns),
+                anti_snipe_extension_ns: 0,
+                anti_snipe_window_ns: None,
+                buy_now_price: None,
+            },
+        }))
+        .unwrap();
+    token_id
+}
+
+fn bid(contract: &mut Contract, token_id: &str, amount: u128) {
+    testing_env!(context_with_deposit(creator(), amount).build());
+    contract
+        .execute(make_request(Action::PlaceBid {
+            token_id: token_id.to_string(),
+            amount: U128(amount),
+        }))
+        .unwrap();
+}
+
+#[test]
+fn get_active_auctions_reports_min_bid_and_time_remaining() {
+    let mut contract = setup_contract();
+    let unstarted = mint_and_auction(&mut contract, &buyer(), 60_000_000_000);
+    let running = mint_and_auction(&mut contract, &buyer(), 60_000_000_000);
+    bid(&mut contract, &running, 1_000);
+
+    testing_env!(
+        context(owner())
+            .block_timestamp(1_700_000_000_000_000_000 + 20_000_000_000)
+            .build()
+    );
+    let auctions = contract.get_active_auctions(None, None);
+    assert_eq!(auctions.len(), 2);
+
+    let view = auctions.iter().find(|a| a.token_id == unstarted).unwrap();
+    assert_eq!(view.min_next_bid.0, 1_000);
+    assert_eq!(view.time_remaining_ns, None);
+
+    let view = auctions.iter().find(|a| a.token_id == running).unwrap();
+    assert_eq!(view.min_next_bid.0, 1_100);
+    assert_eq!(view.highest_bidder, Some(creator()));
+    assert_eq!(view.time_remaining_ns, Some(40_000_000_000));
+}
+
+#[test]
+fn get_active_auctions_skips_ended_and_fixed_price() {
+    let mut contract = setup_contract();
+    quick_mint_and_list(&mut contract, &buyer(), 5_000);
+    let ended = mint_and_auction(&mut contract, &buyer(), 60_000_000_000);
+    bid(&mut contract, &ended, 1_000);
+    let open = mint_and_auction(&mut contract, &buyer(), 600_000_000_000);
+
+    testing_env!(
+        context(owner())
+            .block_timestamp(1_700_000_000_000_000_000 + 120_000_000_000)
+            .build()
+    );
+    let auctions = contract.get_active_auctions(None, None);
+    assert_eq!(auctions.len(), 1);
+    assert_eq!(auctions[0].token_id, open);
+
+    assert!(contract.get_active_auctions(None, Some(0)).is_empty());
+    assert!(contract.get_active_auctions(Some(3), None).is_empty());
+}
+
+// --- simulate_purchase ---
+
+fn artist() -> AccountId {
+    "artist.near".parse().unwrap()
+}
+
+fn mint_royalty_and_list(contract: &mut Contract, price: u128) -> String {
+    testing_env!(context(creator()).build());
+    let metadata = scarce::types::TokenMetadata {
+        title: Some("Royalty sale".into()),
+        description: None,
+        media: None,
+        media_hash: None,
+        copies: None,
+        issued_at: None,
+        expires_at: None,
+        starts_at: None,
+        updated_at: None,
+        extra: None,
+        reference: None,
+        reference_hash: None,
+    };
+    let options = scarce::types::ScarceOptions {
+        royalty: Some(std::collections::HashMap::from([(artist(), 1_000)])),
+        ..default_options()
+    };
+    let token_id = contract.quick_mint(&creator(), metadata, options).unwrap();
+    contract
+        .list_native_scarce(&creator(), &token_id, U128(price), None)
+        .unwrap();
+    token_id
+}
+
+fn transfers_to(account: &AccountId) -> u128 {
+    near_sdk::test_utils::get_created_receipts()
+        .into_iter()
+        .filter(|r| &r.receiver_id == account)
+        .flat_map(|r| r.actions)
+        // MockAction is not re-exported; read transfers through its serde form.
+        .filter_map(|a| {
+            let action = near_sdk::serde_json::to_value(a).unwrap();
+            action["Transfer"]["deposit"].as_str()?.parse::<u128>().ok()
+        })
+        .sum()
+}
+
+fn amount(value: &Value) -> u128 {
+    value.as_str().unwrap().parse().unwrap()
+}
+
+#[test]
+fn simulate_purchase_matches_real_purchase() {
+    let mut contract = setup_contract();
+    let token_id = mint_royalty_and_list(&mut contract, 10_000_000);
+
+    testing_env!(context(buyer()).build());
+    let sim = contract.simulate_purchase(token_id.clone(), U128(12_000_000));
+    assert!(sim.get("error").is_none(), "{sim}");
+    assert_eq!(amount(&sim["refund"]), 2_000_000);
+
+    let payout_to = |account: &AccountId| {
+        sim["payouts"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|p| p["account_id"] == account.as_str())
+            .map(|p| amount(&p["amount"]))
+            .unwrap_or(0)
+    };
+    assert_eq!(amount(&sim["seller_proceeds"]), payout_to(&creator()));
+    assert_eq!(
+        payout_to(&artist()) + payout_to(&creator()) + amount(&sim["total_fee"]),
+        10_000_000
+    );
+
+    testing_env!(context_with_deposit(buyer(), 12_000_000).build());
+    contract
+        .purchase_native_scarce(&buyer(), token_id, 12_000_000)
+        .unwrap();
+
+    assert_eq!(transfers_to(&artist()), payout_to(&artist()));
+    assert_eq!(transfers_to(&creator()), payout_to(&creator()));
+    assert_eq!(contract.pending_attached_balance, amount(&sim["refund"]));
+    let event = near_sdk::test_utils::get_logs()
+        .into_iter()
+        .find(|l| l.contains("\"operation\":\"purchase\""))
+        .unwrap();
+    let event: Value =
+        near_sdk::serde_json::from_str(event.trim_start_matches("EVENT_JSON:")).unwrap();
+    assert_eq!(event["data"][0]["marketplace_fee"], sim["marketplace_fee"]);
+    assert_eq!(event["data"][0]["app_pool_amount"], sim["app_pool_amount"]);
+}
+
+#[test]
+fn simulate_purchase_reports_errors() {
+    let mut contract = setup_contract();
+    let token_id = quick_mint_and_list(&mut contract, &buyer(), 5_000);
+
+    testing_env!(context(owner()).build());
+    assert_eq!(
+        contract.simulate_purchase("missing".into(), U128(5_000))["error"],
+        "not_listed"
+    );
+    let sim = contract.simulate_purchase(token_id, U128(4_999));
+    assert_eq!(sim["error"], "offer_below_price");
+    assert_eq!(amount(&sim["price"]), 5_000);
+}
+
+// --- Expiry-aware enumeration and cleanup ---
+
+const NOW: u64 = 1_700_000_000_000_000_000;
+
+fn mint_and_list_expiring(contract: &mut Contract, expires_at: Option<u64>) -> String {
+    testing_env!(context(buyer()).build());
+    let metadata = scarce::types::TokenMetadata {
+        title: Some("Expiring".into()),
+        description: None,
+        media: None,
+        media_hash: None,
+        copies: None,
+        issued_at: None,
+        expires_at: None,
+        starts_at: None,
+        updated_at: None,
+        extra: None,
+        reference: None,
+        reference_hash: None,
+    };
+    let token_id = contract
+        .quick_mint(&buyer(), metadata, default_options())
+        .unwrap();
+    contract
+        .list_native_scarce(&buyer(), &token_id, U128(1_000), expires_at)
+        .unwrap();
+    token_id
+}
+
+#[test]
+fn sale_views_hide_expired_by_default() {
+    let mut contract = setup_contract();
+    mint_and_list_expiring(&mut contract, Some(NOW + 1_000));
+    let live = mint_and_list_expiring(&mut contract, None);
+
+    testing_env!(context(owner()).block_timestamp(NOW + 2_000).build());
+    let contract_id: AccountId = "marketplace.near".parse().unwrap();
+
+    let visible = contract.get_sales(None, None, None);
+    assert_eq!(visible.len(), 1);
+    assert!(matches!(
+        &visible[0].sale_type,
+        SaleType::NativeScarce { token_id } if token_id == &live
+    ));
+    assert_eq!(contract.get_sales(None, None, Some(true)).len(), 2);
+    assert_eq!(
+        contract
+            .get_sales_by_owner_id(buyer(), None, None, None)
+            .len(),
+        1
+    );
+    assert_eq!(
+        contract
+            .get_sales_by_owner_id(buyer(), None, None, Some(true))
+            .len(),
+        2
+    );
+    assert_eq!(
+        contract
+            .get_sales_by_scarce_contract_id(contract_id.clone(), None, None, None)
+            .len(),
+        1
+    );
+    assert_eq!(
+        contract
+            .get_sales_by_scarce_contract_id(contract_id, Some(1), None, Some(true))
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn clear_expired_sales_removes_and_refunds_storage() {
+    let mut contract = setup_contract();
+    let expired = mint_and_list_expiring(&mut contract, Some(NOW + 1_000));
+    mint_and_list_expiring(&mut contract, None);
+
+    testing_env!(context(creator()).block_timestamp(NOW + 2_000).build());
+    let balance_before_clear = contract.platform_storage_balance;
+    assert_eq!(contract.clear_expired_sales(10), 1);
+
+    assert_eq!(contract.get_sales(None, None, Some(true)).len(), 1);
+    assert_eq!(contract.get_supply_by_owner_id(buyer()), 1);
+    assert_eq!(
+        contract.get_supply_by_scarce_contract_id("marketplace.near".parse().unwrap()),
+        1
+    );
+    // Listing storage was drawn from the platform pool; freed bytes go back to it.
+    assert!(contract.platform_storage_balance > balance_before_clear);
+    assert!(
+        near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|l| l.contains("\"sale_expired\"") && l.contains(&expired))
+    );
+
+    // Nothing left to sweep.
+    assert_eq!(contract.clear_expired_sales(10), 0);
+}
+
+#[test]
+fn clear_expired_sales_respects_limit() {
+    let mut contract = setup_contract();
+    for _ in 0..3 {
+        mint_and_list_expiring(&mut contract, Some(NOW + 1_000));
+    }
+
+    testing_env!(context(creator()).block_timestamp(NOW + 2_000).build());
+    assert_eq!(contract.clear_expired_sales(2), 2);
+    assert_eq!(contract.get_supply_sales(), 1);
+    assert_eq!(contract.clear_expired_sales(2), 1);
+    assert_eq!(contract.get_supply_sales(), 0);
+}
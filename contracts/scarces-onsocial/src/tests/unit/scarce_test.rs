@@ -1,5 +1,6 @@
 use crate::tests::test_utils::*;
 use crate::*;
+use near_sdk::testing_env;
 
 fn mint_token(contract: &mut Contract, owner: &AccountId, token_id: &str) {
     let ctx = MintContext {
@@ -283,6 +284,8 @@ fn quick_mint_increments_counter() {
                 app_id: None,
                 transferable: true,
                 burnable: true,
+                redeem_start: None,
+                redeem_end: None,
             },
         )
         .unwrap();
@@ -310,3 +313,98 @@ fn nft_token_nonexistent_returns_none() {
     let contract = new_contract();
     assert!(contract.nft_token("nope".to_string()).is_none());
 }
+
+#[test]
+fn batch_burn_owned_tokens() {
+    let mut contract = new_contract();
+    mint_token(&mut contract, &owner(), "t1");
+    mint_token(&mut contract, &owner(), "t2");
+    testing_env!(context_with_deposit(owner(), 1).build());
+
+    contract
+        .execute(make_request(Action::BatchBurn {
+            token_ids: vec!["t1".into(), "t2".into()],
+        }))
+        .unwrap();
+
+    for id in ["t1", "t2"] {
+        assert!(!contract.scarces_by_id.contains_key(id));
+    }
+    assert!(
+        contract
+            .scarces_per_owner
+            .get(&owner())
+            .is_none_or(|tokens| tokens.is_empty())
+    );
+    let burned = near_sdk::test_utils::get_logs()
+        .iter()
+        .filter(|l| l.contains("SCARCE_UPDATE") && l.contains("\"burn\""))
+        .count();
+    assert_eq!(burned, 2);
+}
+
+#[test]
+fn batch_burn_rejects_transfer_approval() {
+    let mut contract = new_contract();
+    mint_token(&mut contract, &owner(), "t1");
+    mint_token(&mut contract, &creator(), "t2");
+    contract.approve(&creator(), "t2", &owner(), None).unwrap();
+
+    let err = contract
+        .batch_burn(&owner(), vec!["t1".into(), "t2".into()])
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+    assert!(contract.scarces_by_id.contains_key("t1"));
+    assert!(contract.scarces_by_id.contains_key("t2"));
+}
+
+#[test]
+fn batch_burn_partial_ownership_rejects_all() {
+    let mut contract = new_contract();
+    mint_token(&mut contract, &owner(), "t1");
+    mint_token(&mut contract, &creator(), "t2");
+
+    let err = contract
+        .batch_burn(&owner(), vec!["t1".into(), "t2".into()])
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::Unauthorized(_)));
+    assert!(contract.scarces_by_id.contains_key("t1"));
+    assert!(contract.scarces_by_id.contains_key("t2"));
+}
+
+#[test]
+fn batch_burn_rejects_bad_batch_sizes_and_duplicates() {
+    let mut contract = new_contract();
+    mint_token(&mut contract, &owner(), "t1");
+
+    let err = contract.batch_burn(&owner(), vec![]).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+
+    let too_many = (0..=MAX_BATCH_BURN).map(|i| format!("t{}", i)).collect();
+    let err = contract.batch_burn(&owner(), too_many).unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+
+    let err = contract
+        .batch_burn(&owner(), vec!["t1".into(), "t1".into()])
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidInput(_)));
+    assert!(contract.scarces_by_id.contains_key("t1"));
+}
+
+#[test]
+fn batch_burn_max_batch_fits_gas_budget() {
+    let mut contract = new_contract();
+    let token_ids: Vec<String> = (0..MAX_BATCH_BURN).map(|i| format!("t{}", i)).collect();
+    for id in &token_ids {
+        mint_token(&mut contract, &owner(), id);
+    }
+
+    let before = env::used_gas();
+    contract.batch_burn(&owner(), token_ids).unwrap();
+    let used = env::used_gas().saturating_sub(before);
+    assert!(
+        used < Gas::from_tgas(50),
+        "max batch burn used {} TGas",
+        used.as_tgas()
+    );
+}
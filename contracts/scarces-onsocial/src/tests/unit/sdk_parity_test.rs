@@ -15,6 +15,7 @@ const ALL_ACTION_TYPES: &[&str] = &[
     "revoke_scarce",
     "revoke_all_scarce",
     "burn_scarce",
+    "batch_burn",
     "renew_token",
     "revoke_token",
     "redeem_token",
@@ -27,12 +28,17 @@ const ALL_ACTION_TYPES: &[&str] = &[
     "delete_collection",
     "pause_collection",
     "resume_collection",
+    "freeze_collection",
+    "reveal_collection",
     "set_allowlist",
     "remove_from_allowlist",
     "set_collection_metadata",
     "set_collection_app_metadata",
+    "batch_update_token_metadata",
     "withdraw_unclaimed_refunds",
+    "release_refund_escrow",
     "list_native_scarce",
+    "batch_list_native_scarce",
     "delist_native_scarce",
     "list_native_scarce_auction",
     "settle_auction",
@@ -43,6 +49,7 @@ const ALL_ACTION_TYPES: &[&str] = &[
     "cancel_offer",
     "accept_collection_offer",
     "cancel_collection_offer",
+    "mint_and_accept_collection_offer",
     "create_lazy_listing",
     "cancel_lazy_listing",
     "update_lazy_listing_price",
@@ -59,6 +66,7 @@ const ALL_ACTION_TYPES: &[&str] = &[
     "register_app",
     "set_spending_cap",
     "storage_withdraw",
+    "claim_ft_payout",
     "withdraw_app_pool",
     "withdraw_platform_storage",
     "set_app_config",
@@ -67,6 +75,8 @@ const ALL_ACTION_TYPES: &[&str] = &[
     "remove_moderator",
     "ban_collection",
     "unban_collection",
+    "submit_ban_appeal",
+    "resolve_ban_appeal",
 ];
 
 #[derive(Deserialize)]
@@ -24,6 +24,8 @@ fn setup_listed_scarce(contract: &mut Contract, price: u128) -> String {
         app_id: None,
         transferable: true,
         burnable: true,
+        redeem_start: None,
+        redeem_end: None,
     };
     let tid = contract.quick_mint(&buyer(), metadata, options).unwrap();
     contract
@@ -51,13 +53,19 @@ fn setup_lazy_listing(contract: &mut Contract, price: u128) -> String {
             reference_hash: None,
         },
         price: U128(price),
+        payment_token: None,
         options: scarce::types::ScarceOptions {
             royalty: None,
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         expires_at: None,
+        start_price: None,
+        decay_start: None,
+        decay_end: None,
     };
     contract.create_lazy_listing(&creator(), params).unwrap()
 }
@@ -75,6 +83,8 @@ fn setup_collection(contract: &mut Contract, price: u128) -> String {
             app_id: None,
             transferable: true,
             burnable: true,
+            redeem_start: None,
+            redeem_end: None,
         },
         renewable: false,
         revocation_mode: RevocationMode::None,
@@ -84,6 +94,19 @@ fn setup_collection(contract: &mut Contract, price: u128) -> String {
         max_per_wallet: None,
         start_price: None,
         allowlist_price: None,
+        creators: None,
+        creators_locked: false,
+        refund_window_ms: None,
+        restocking_fee_bps: 0,
+        restock_refunds: false,
+        base_uri: None,
+        allowlist_merkle_root: None,
+        enforce_royalties: false,
+        track_provenance: false,
+        reveal_at: None,
+        placeholder_metadata: None,
+        mint_cooldown_ms: None,
+        allowlist_exempt_from_limits: false,
     };
     testing_env!(context(creator()).build());
     contract.create_collection(&creator(), config).unwrap();
@@ -102,6 +125,8 @@ fn max_price_collection_allows_at_price() {
             collection_id: col.clone(),
             quantity: 1,
             max_price_per_token: U128(price),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap();
 
@@ -121,6 +146,8 @@ fn max_price_collection_rejects_over_price() {
             collection_id: col,
             quantity: 1,
             max_price_per_token: U128(price - 1),
+            allowlist_proof: None,
+            refund_if_exhausted: false,
         }))
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::InvalidInput(_)));
@@ -357,3 +384,129 @@ fn set_spending_cap_requires_confirmation_for_direct_auth() {
         .unwrap_err();
     assert!(matches!(err, MarketplaceError::InsufficientDeposit(_)));
 }
+
+// --- App pool daily spending cap ---
+
+const DAY_NS: u64 = 86_400_000_000_000;
+
+fn capped_pool_app(contract: &mut Contract, balance: u128, cap: u128) -> AccountId {
+    let app: AccountId = "capped.near".parse().unwrap();
+    contract.app_pools.insert(
+        app.clone(),
+        AppPool {
+            owner_id: creator(),
+            balance: U128(balance),
+            used_bytes: 0,
+            max_user_bytes: 10_000,
+            moderators: vec![],
+            curated: false,
+            default_royalty: None,
+            primary_sale_bps: 0,
+            metadata: None,
+            daily_spending_cap: Some(U128(cap)),
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
+        },
+    );
+    app
+}
+
+#[test]
+fn app_pool_withdrawal_rejected_once_cap_exhausted() {
+    let mut contract = new_contract();
+    let app = capped_pool_app(&mut contract, 10_000, 1_000);
+    testing_env!(context(creator()).build());
+
+    contract
+        .withdraw_app_pool(&creator(), &app, U128(600))
+        .unwrap();
+    assert_eq!(
+        contract.get_remaining_spending_cap(app.clone()),
+        Some(U128(400))
+    );
+
+    let err = contract
+        .withdraw_app_pool(&creator(), &app, U128(500))
+        .unwrap_err();
+    assert!(matches!(err, MarketplaceError::InvalidState(_)));
+    let pool = contract.app_pools.get(&app).unwrap();
+    assert_eq!(pool.balance.0, 9_400);
+}
+
+#[test]
+fn app_pool_spending_window_resets_after_24h() {
+    let mut contract = new_contract();
+    let app = capped_pool_app(&mut contract, 10_000, 1_000);
+    testing_env!(context(creator()).build());
+    contract
+        .withdraw_app_pool(&creator(), &app, U128(1_000))
+        .unwrap();
+    assert_eq!(
+        contract.get_remaining_spending_cap(app.clone()),
+        Some(U128(0))
+    );
+
+    testing_env!(
+        context(creator())
+            .block_timestamp(1_700_000_000_000_000_000 + DAY_NS)
+            .build()
+    );
+    assert_eq!(
+        contract.get_remaining_spending_cap(app.clone()),
+        Some(U128(1_000))
+    );
+    contract
+        .withdraw_app_pool(&creator(), &app, U128(1_000))
+        .unwrap();
+    assert_eq!(contract.app_pools.get(&app).unwrap().balance.0, 8_000);
+}
+
+#[test]
+fn app_pool_storage_charges_count_against_cap_and_reverse_on_release() {
+    let mut contract = new_contract();
+    let byte_cost = storage::storage_byte_cost();
+    let app = capped_pool_app(&mut contract, byte_cost * 1_000, byte_cost * 50);
+    testing_env!(context(buyer()).build());
+
+    contract
+        .charge_storage_waterfall(&buyer(), 40, Some(&app))
+        .unwrap();
+    assert_eq!(
+        contract.get_remaining_spending_cap(app.clone()),
+        Some(U128(byte_cost * 10))
+    );
+
+    // Over the remaining allowance: the pool is skipped and the user pays.
+    contract.pending_attached_balance = byte_cost * 20;
+    contract
+        .charge_storage_waterfall(&buyer(), 20, Some(&app))
+        .unwrap();
+    let pool = contract.app_pools.get(&app).unwrap();
+    assert_eq!(pool.used_bytes, 40);
+    assert_eq!(pool.balance.0, byte_cost * 960);
+
+    contract.release_storage_waterfall(&buyer(), 40, Some(&app));
+    assert_eq!(
+        contract.get_remaining_spending_cap(app.clone()),
+        Some(U128(byte_cost * 50))
+    );
+}
+
+#[test]
+fn app_pool_without_cap_reports_none() {
+    let mut contract = new_contract();
+    let app = capped_pool_app(&mut contract, 10_000, 1_000);
+    testing_env!(context(creator()).build());
+    contract
+        .set_app_config(
+            &creator(),
+            &app,
+            AppConfig {
+                daily_spending_cap: Some(U128(0)),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(contract.get_remaining_spending_cap(app), None);
+}
@@ -93,6 +93,10 @@ fn tier1_app_pool_covers_storage() {
             default_royalty: None,
             primary_sale_bps: 0,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
 
@@ -129,6 +133,10 @@ fn tier1_per_user_cap_falls_to_tier3() {
             default_royalty: None,
             primary_sale_bps: 0,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
 
@@ -185,6 +193,10 @@ fn release_tier1_credits_app_pool() {
             default_royalty: None,
             primary_sale_bps: 0,
             metadata: None,
+            daily_spending_cap: None,
+            spent_in_window: U128(0),
+            spending_window_start_ms: 0,
+            sponsor_buyer_storage: true,
         },
     );
     let usage_key = format!("{}:{}", buyer(), app);
@@ -1,7 +1,94 @@
 use crate::constants::GAS_MIGRATE_TGAS;
 use crate::*;
+use near_sdk::borsh::BorshDeserialize;
 use near_sdk::json_types::Base58CryptoHash;
 
+/// State layout of the first deployment, before any field was appended.
+#[near(serializers = [borsh])]
+struct ContractV1 {
+    version: String,
+    owner_id: AccountId,
+    fee_recipient: AccountId,
+    sales: IterableMap<String, Sale>,
+    by_owner_id: LookupMap<AccountId, IterableSet<String>>,
+    by_scarce_contract_id: LookupMap<AccountId, IterableSet<String>>,
+    scarces_per_owner: LookupMap<AccountId, IterableSet<String>>,
+    scarces_by_id: IterableMap<String, Scarce>,
+    next_approval_id: u64,
+    next_token_id: u64,
+    collections: IterableMap<String, LazyCollection>,
+    collections_by_creator: LookupMap<AccountId, IterableSet<String>>,
+    fee_config: FeeConfig,
+    app_pools: LookupMap<AccountId, AppPool>,
+    app_pool_ids: IterableSet<AccountId>,
+    app_user_usage: LookupMap<String, u64>,
+    app_creators: LookupMap<AccountId, IterableSet<AccountId>>,
+    app_creator_collection_counts: LookupMap<String, u32>,
+    app_owners: LookupMap<AccountId, IterableSet<AccountId>>,
+    app_owner_token_counts: LookupMap<String, u32>,
+    platform_storage_balance: u128,
+    user_storage: LookupMap<AccountId, UserStorageBalance>,
+    collection_mint_counts: LookupMap<String, u32>,
+    collection_allowlist: LookupMap<String, u32>,
+    offers: IterableMap<String, Offer>,
+    collection_offers: IterableMap<String, CollectionOffer>,
+    lazy_listings: IterableMap<String, LazyListingRecord>,
+    contract_metadata: external::ScarceContractMetadata,
+    approved_nft_contracts: IterableSet<AccountId>,
+    wnear_account_id: Option<AccountId>,
+}
+
+impl From<ContractV1> for Contract {
+    fn from(old: ContractV1) -> Self {
+        Self {
+            version: old.version,
+            owner_id: old.owner_id,
+            fee_recipient: old.fee_recipient,
+            sales: old.sales,
+            by_owner_id: old.by_owner_id,
+            by_scarce_contract_id: old.by_scarce_contract_id,
+            scarces_per_owner: old.scarces_per_owner,
+            scarces_by_id: old.scarces_by_id,
+            next_approval_id: old.next_approval_id,
+            next_token_id: old.next_token_id,
+            collections: old.collections,
+            collections_by_creator: old.collections_by_creator,
+            fee_config: old.fee_config,
+            app_pools: old.app_pools,
+            app_pool_ids: old.app_pool_ids,
+            app_user_usage: old.app_user_usage,
+            app_creators: old.app_creators,
+            app_creator_collection_counts: old.app_creator_collection_counts,
+            app_owners: old.app_owners,
+            app_owner_token_counts: old.app_owner_token_counts,
+            platform_storage_balance: old.platform_storage_balance,
+            user_storage: old.user_storage,
+            collection_mint_counts: old.collection_mint_counts,
+            collection_allowlist: old.collection_allowlist,
+            offers: old.offers,
+            collection_offers: old.collection_offers,
+            lazy_listings: old.lazy_listings,
+            contract_metadata: old.contract_metadata,
+            approved_nft_contracts: old.approved_nft_contracts,
+            wnear_account_id: old.wnear_account_id,
+            mint_refund_escrows: LookupMap::new(StorageKey::MintRefundEscrows),
+            known_marketplaces: IterableMap::new(StorageKey::KnownMarketplaces),
+            accepted_payment_tokens: IterableSet::new(StorageKey::AcceptedPaymentTokens),
+            price_bounds: LookupMap::new(StorageKey::PriceBounds),
+            creator_earnings: LookupMap::new(StorageKey::CreatorEarnings),
+            ban_appeals: LookupMap::new(StorageKey::BanAppeals),
+            collection_last_mint: LookupMap::new(StorageKey::CollectionLastMint),
+            ft_claimable: LookupMap::new(StorageKey::FtClaimable),
+            token_provenance: LookupMap::new(StorageKey::TokenProvenance),
+            // Filled by `backfill_auction_index`; migrate gas must not grow with `sales`.
+            auction_token_ids: IterableSet::new(StorageKey::AuctionTokenIds),
+            // Filled by `backfill_collection_offer_index`, for the same reason.
+            collection_offer_buyers: LookupMap::new(StorageKey::CollectionOfferBuyers),
+            pending_attached_balance: 0,
+        }
+    }
+}
+
 #[near]
 impl Contract {
     #[handle_result]
@@ -39,7 +126,11 @@ impl Contract {
     #[private]
     #[init(ignore_state)]
     pub fn migrate() -> Self {
-        let mut contract: Self = env::state_read().expect("State read failed");
+        let raw = env::storage_read(b"STATE").expect("State read failed");
+        // Upgrade invariant: V1 state is a strict prefix of the current layout, so it never decodes as `Self`.
+        let mut contract: Self = Self::try_from_slice(&raw)
+            .or_else(|_| ContractV1::try_from_slice(&raw).map(Self::from))
+            .expect("State read failed");
         let old_version = contract.version.clone();
         contract.version = env!("CARGO_PKG_VERSION").to_string();
         contract.contract_metadata.spec = NFT_METADATA_SPEC.to_string();
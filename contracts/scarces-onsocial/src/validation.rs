@@ -28,6 +28,99 @@ pub(crate) fn validate_royalty(royalty: &HashMap<AccountId, u32>) -> Result<(),
     Ok(())
 }
 
+pub(crate) fn validate_creator_shares(
+    creator_id: &AccountId,
+    creators: &HashMap<AccountId, u32>,
+) -> Result<(), MarketplaceError> {
+    if creators.len() < 2 || creators.len() > MAX_COLLECTION_CREATORS {
+        return Err(MarketplaceError::InvalidInput(format!(
+            "Collaborative collections require 2-{} creators",
+            MAX_COLLECTION_CREATORS
+        )));
+    }
+    if !creators.contains_key(creator_id) {
+        return Err(MarketplaceError::InvalidInput(
+            "Creator shares must include the collection creator".into(),
+        ));
+    }
+    if creators.values().any(|bps| *bps == 0) {
+        return Err(MarketplaceError::InvalidInput(
+            "Each creator share must be > 0 bps".into(),
+        ));
+    }
+    let total: u32 = creators.values().sum();
+    if total != BASIS_POINTS as u32 {
+        return Err(MarketplaceError::InvalidInput(format!(
+            "Creator shares must sum to {} bps, got {}",
+            BASIS_POINTS, total
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_refund_terms(
+    refund_window_ms: Option<u64>,
+    restocking_fee_bps: u32,
+) -> Result<(), MarketplaceError> {
+    if refund_window_ms.is_some_and(|window| window == 0 || window > MAX_REFUND_WINDOW_MS) {
+        return Err(MarketplaceError::InvalidInput(format!(
+            "refund_window_ms must be 1-{}",
+            MAX_REFUND_WINDOW_MS
+        )));
+    }
+    if restocking_fee_bps > MAX_RESTOCKING_FEE_BPS {
+        return Err(MarketplaceError::InvalidInput(format!(
+            "Restocking fee cannot exceed {} bps",
+            MAX_RESTOCKING_FEE_BPS
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_redeem_window(
+    redeem_start: Option<u64>,
+    redeem_end: Option<u64>,
+) -> Result<(), MarketplaceError> {
+    if redeem_start
+        .zip(redeem_end)
+        .is_some_and(|(start, end)| end <= start)
+    {
+        return Err(MarketplaceError::InvalidInput(
+            "redeem_end must be after redeem_start".into(),
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_base_uri(base_uri: &str) -> Result<(), MarketplaceError> {
+    if base_uri.len() > MAX_BASE_URI_LEN {
+        return Err(MarketplaceError::InvalidInput(format!(
+            "base_uri exceeds max length of {}",
+            MAX_BASE_URI_LEN
+        )));
+    }
+    let Some(scheme) = BASE_URI_SCHEMES.iter().find(|s| base_uri.starts_with(**s)) else {
+        return Err(MarketplaceError::InvalidInput(
+            "base_uri must start with https://, http://, ipfs://, or ar://".into(),
+        ));
+    };
+    if base_uri.len() == scheme.len() || base_uri.chars().any(char::is_whitespace) {
+        return Err(MarketplaceError::InvalidInput(
+            "base_uri must have a host and no whitespace".into(),
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_merkle_root(root: &str) -> Result<(), MarketplaceError> {
+    if crate::collections::parse_merkle_hash(root).is_none() {
+        return Err(MarketplaceError::InvalidInput(
+            "allowlist_merkle_root must be 32 bytes of hex".into(),
+        ));
+    }
+    Ok(())
+}
+
 pub(crate) fn normalize_contract_metadata(
     mut metadata: external::ScarceContractMetadata,
 ) -> Result<external::ScarceContractMetadata, MarketplaceError> {
@@ -5,8 +5,9 @@
 //! Reward formula: (user_stake_seconds / total_stake_seconds) × total_released - claimed
 
 use near_sdk::{
-    AccountId, BorshStorageKey, Gas, NearToken, PanicOnDefault, Promise, PromiseError, env,
-    json_types::U128, near, serde_json, store::LookupMap,
+    AccountId, BorshStorageKey, Gas, NearToken, PanicOnDefault, Promise, PromiseError,
+    PromiseOrValue, borsh::BorshDeserialize, env, json_types::U128, near, serde_json,
+    store::LookupMap,
 };
 use near_sdk_macros::NearSchema;
 use primitive_types::U256;
@@ -16,14 +17,17 @@ use primitive_types::U256;
 const MONTH_NS: u64 = 30 * 24 * 60 * 60 * 1_000_000_000;
 const WEEK_NS: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
 const NS_PER_SEC: u64 = 1_000_000_000;
+const NS_PER_MS: u64 = 1_000_000;
 const WEEKLY_RATE_BPS: u128 = 20; // 0.2% per week
 const GAS_FT_TRANSFER: Gas = Gas::from_tgas(15);
 const GAS_CALLBACK: Gas = Gas::from_tgas(15);
 const GAS_MIGRATE: Gas = Gas::from_tgas(200);
 const STORAGE_DEPOSIT: u128 = 5_000_000_000_000_000_000_000;
-const CONTRACT_VERSION: u32 = 1;
+const CONTRACT_VERSION: u32 = 2;
 const VALID_LOCK_PERIODS: [u64; 5] = [1, 6, 12, 24, 48];
 const MIN_STAKE: u128 = 10_000_000_000_000_000;
+const MAX_UNBONDING_PERIOD_MS: u64 = 90 * 24 * 60 * 60 * 1_000;
+const MAX_PENDING_WITHDRAWALS: usize = 20;
 const EVENT_STANDARD: &str = "onsocial";
 const EVENT_VERSION: &str = "1.0.0";
 
@@ -58,6 +62,7 @@ enum StorageKey {
     Accounts,
     StoragePaid,
     PendingUnlocks,
+    PendingWithdrawals,
 }
 
 // --- Types ---
@@ -104,6 +109,53 @@ pub struct OnsocialStaking {
     total_rewards_released: u128,
     last_release_time: u64,
     scheduled_pool: u128,
+    unbonding_period_ms: u64,
+    /// Queued unlocks per account as `(amount, unlock_time_ns)`.
+    pending_withdrawals: LookupMap<AccountId, Vec<(u128, u64)>>,
+    total_unbonding: u128,
+}
+
+/// State layout before the unbonding queue (contract version 1).
+#[near(serializers = [borsh])]
+struct OnsocialStakingV1 {
+    version: u32,
+    token_id: AccountId,
+    owner_id: AccountId,
+    accounts: LookupMap<AccountId, Account>,
+    storage_paid: LookupMap<AccountId, bool>,
+    pending_unlocks: LookupMap<AccountId, PendingUnlock>,
+    total_locked: u128,
+    total_effective_stake: u128,
+    infra_pool: u128,
+    total_stake_seconds: u128,
+    last_global_update: u64,
+    total_rewards_released: u128,
+    last_release_time: u64,
+    scheduled_pool: u128,
+}
+
+impl From<OnsocialStakingV1> for OnsocialStaking {
+    fn from(old: OnsocialStakingV1) -> Self {
+        Self {
+            version: old.version,
+            token_id: old.token_id,
+            owner_id: old.owner_id,
+            accounts: old.accounts,
+            storage_paid: old.storage_paid,
+            pending_unlocks: old.pending_unlocks,
+            total_locked: old.total_locked,
+            total_effective_stake: old.total_effective_stake,
+            infra_pool: old.infra_pool,
+            total_stake_seconds: old.total_stake_seconds,
+            last_global_update: old.last_global_update,
+            total_rewards_released: old.total_rewards_released,
+            last_release_time: old.last_release_time,
+            scheduled_pool: old.scheduled_pool,
+            unbonding_period_ms: 0,
+            pending_withdrawals: LookupMap::new(StorageKey::PendingWithdrawals),
+            total_unbonding: 0,
+        }
+    }
 }
 
 #[near]
@@ -126,6 +178,9 @@ impl OnsocialStaking {
             total_rewards_released: 0,
             last_release_time: now,
             scheduled_pool: 0,
+            unbonding_period_ms: 0,
+            pending_withdrawals: LookupMap::new(StorageKey::PendingWithdrawals),
+            total_unbonding: 0,
         }
     }
 
@@ -476,8 +531,10 @@ impl OnsocialStaking {
         self.extend_lock(account.lock_months)
     }
 
+    /// Pays out immediately when no unbonding period is set; otherwise queues
+    /// the amount for `claim_unstaked` once the period has elapsed.
     #[handle_result]
-    pub fn unlock(&mut self) -> Result<Promise, StakingError> {
+    pub fn unlock(&mut self) -> Result<PromiseOrValue<()>, StakingError> {
         let account_id = env::predecessor_account_id();
         self.sync_account(&account_id);
 
@@ -497,9 +554,44 @@ impl OnsocialStaking {
         let effective = account.tracked_effective_stake;
         let old_account = account.clone();
 
+        let mut queue = self
+            .pending_withdrawals
+            .get(&account_id)
+            .cloned()
+            .unwrap_or_default();
+        if self.unbonding_period_ms > 0 && queue.len() >= MAX_PENDING_WITHDRAWALS {
+            return Err(StakingError::InvalidInput(
+                "Too many pending withdrawals: claim_unstaked first".into(),
+            ));
+        }
+
         self.total_locked = self.total_locked.saturating_sub(amount);
         self.total_effective_stake = self.total_effective_stake.saturating_sub(effective);
 
+        let mut account = account;
+        account.locked_amount = 0;
+        account.unlock_at = 0;
+        account.lock_months = 0;
+        account.tracked_effective_stake = 0;
+
+        if self.unbonding_period_ms > 0 {
+            let unlock_time = env::block_timestamp()
+                .saturating_add(self.unbonding_period_ms.saturating_mul(NS_PER_MS));
+            queue.push((amount, unlock_time));
+            self.pending_withdrawals.insert(account_id.clone(), queue);
+            self.total_unbonding = self.total_unbonding.saturating_add(amount);
+            self.accounts.insert(account_id.clone(), account);
+            self.emit_event(
+                "UNLOCK_QUEUED",
+                &account_id,
+                serde_json::json!({
+                    "amount": amount.to_string(),
+                    "unlock_time": unlock_time
+                }),
+            );
+            return Ok(PromiseOrValue::Value(()));
+        }
+
         self.pending_unlocks.insert(
             account_id.clone(),
             PendingUnlock {
@@ -510,22 +602,95 @@ impl OnsocialStaking {
                 old_lock_months: old_account.lock_months,
             },
         );
-
-        let mut account = account;
-        account.locked_amount = 0;
-        account.unlock_at = 0;
-        account.lock_months = 0;
-        account.tracked_effective_stake = 0;
         self.accounts.insert(account_id.clone(), account);
 
-        Ok(self.ft_transfer_with_callback(
+        Ok(PromiseOrValue::Promise(self.ft_transfer_with_callback(
             account_id.clone(),
             amount,
             "on_unlock_callback",
             serde_json::json!({ "account_id": account_id }),
+        )))
+    }
+
+    /// Transfers every queued unlock whose unbonding period has elapsed.
+    #[handle_result]
+    pub fn claim_unstaked(&mut self) -> Result<Promise, StakingError> {
+        let account_id = env::predecessor_account_id();
+        let queue = self
+            .pending_withdrawals
+            .get(&account_id)
+            .cloned()
+            .unwrap_or_default();
+
+        let now = env::block_timestamp();
+        let (matured, waiting): (Vec<_>, Vec<_>) = queue
+            .into_iter()
+            .partition(|(_, unlock_time)| *unlock_time <= now);
+        let total = matured
+            .iter()
+            .try_fold(0u128, |sum, (amount, _)| sum.checked_add(*amount))
+            .ok_or_else(|| StakingError::InvalidInput("Withdrawal total overflow".into()))?;
+        if total == 0 {
+            return Err(StakingError::NothingToClaim);
+        }
+
+        if waiting.is_empty() {
+            self.pending_withdrawals.remove(&account_id);
+        } else {
+            self.pending_withdrawals.insert(account_id.clone(), waiting);
+        }
+        self.total_unbonding = self.total_unbonding.saturating_sub(total);
+
+        Ok(self.ft_transfer_with_callback(
+            account_id.clone(),
+            total,
+            "on_claim_unstaked_callback",
+            serde_json::json!({
+                "account_id": account_id,
+                "entries": matured
+                    .iter()
+                    .map(|(amount, unlock_time)| (U128(*amount), *unlock_time))
+                    .collect::<Vec<_>>()
+            }),
         ))
     }
 
+    #[private]
+    pub fn on_claim_unstaked_callback(
+        &mut self,
+        #[callback_result] call_result: Result<(), PromiseError>,
+        account_id: AccountId,
+        entries: Vec<(U128, u64)>,
+    ) {
+        let total: u128 = entries.iter().map(|(amount, _)| amount.0).sum();
+        if call_result.is_ok() {
+            self.emit_event(
+                "UNLOCK_CLAIMED",
+                &account_id,
+                serde_json::json!({
+                    "amount": total.to_string(),
+                    "entries": entries.len()
+                }),
+            );
+        } else {
+            let mut queue = self
+                .pending_withdrawals
+                .get(&account_id)
+                .cloned()
+                .unwrap_or_default();
+            queue.extend(entries.iter().map(|(amount, t)| (amount.0, *t)));
+            self.pending_withdrawals.insert(account_id.clone(), queue);
+            self.total_unbonding = self.total_unbonding.saturating_add(total);
+            self.emit_event(
+                "UNLOCK_CLAIM_FAILED",
+                &account_id,
+                serde_json::json!({
+                    "amount": total.to_string()
+                }),
+            );
+        }
+    }
+
     #[private]
     pub fn on_unlock_callback(
         &mut self,
@@ -795,6 +960,31 @@ impl OnsocialStaking {
         Ok(())
     }
 
+    #[payable]
+    #[handle_result]
+    pub fn set_unbonding_period(&mut self, unbonding_period_ms: u64) -> Result<(), StakingError> {
+        if env::attached_deposit().as_yoctonear() != 1 {
+            return Err(StakingError::InvalidInput("Attach 1 yoctoNEAR".into()));
+        }
+        self.assert_owner()?;
+        if unbonding_period_ms > MAX_UNBONDING_PERIOD_MS {
+            return Err(StakingError::InvalidInput(format!(
+                "Unbonding period cannot exceed {} ms",
+                MAX_UNBONDING_PERIOD_MS
+            )));
+        }
+        let old = self.unbonding_period_ms;
+        self.unbonding_period_ms = unbonding_period_ms;
+        self.emit_event(
+            "UNBONDING_PERIOD_UPDATED",
+            &self.owner_id.clone(),
+            serde_json::json!({
+                "old_period_ms": old, "new_period_ms": unbonding_period_ms
+            }),
+        );
+        Ok(())
+    }
+
     #[handle_result]
     pub fn update_contract(&self) -> Result<Promise, StakingError> {
         self.assert_owner()?;
@@ -813,8 +1003,16 @@ impl OnsocialStaking {
     #[private]
     #[init(ignore_state)]
     pub fn migrate() -> Self {
-        let mut contract: Self = env::state_read().expect("State read failed");
-        let old = contract.version;
+        let raw = env::storage_read(b"STATE").expect("State read failed");
+        // `version` is the first borsh field in every layout.
+        let old = u32::try_from_slice(&raw[..4]).expect("State read failed");
+        let mut contract: Self = if old < 2 {
+            OnsocialStakingV1::try_from_slice(&raw)
+                .expect("State read failed")
+                .into()
+        } else {
+            Self::try_from_slice(&raw).expect("State read failed")
+        };
         contract.version = CONTRACT_VERSION;
         contract.emit_event(
             "CONTRACT_UPGRADE",
@@ -859,6 +1057,8 @@ impl OnsocialStaking {
             scheduled_pool: U128(projected_pool),
             infra_pool: U128(self.infra_pool),
             last_release_time: self.last_release_time,
+            unbonding_period_ms: self.unbonding_period_ms,
+            total_unbonding: U128(self.total_unbonding),
         }
     }
 
@@ -915,6 +1115,23 @@ impl OnsocialStaking {
         }
     }
 
+    pub fn get_pending_withdrawals(&self, account_id: AccountId) -> Vec<PendingWithdrawalView> {
+        let now = env::block_timestamp();
+        self.pending_withdrawals
+            .get(&account_id)
+            .map(|queue| {
+                queue
+                    .iter()
+                    .map(|(amount, unlock_time)| PendingWithdrawalView {
+                        amount: U128(*amount),
+                        unlock_time: *unlock_time,
+                        claimable: *unlock_time <= now,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// How many new users the contract can still auto-register for free.
     /// Returns 0 when the subsidy is exhausted (users must call storage_deposit).
     pub fn get_storage_subsidy_available(&self) -> u32 {
@@ -1115,6 +1332,16 @@ pub struct ContractStats {
     pub scheduled_pool: U128,
     pub infra_pool: U128,
     pub last_release_time: u64,
+    pub unbonding_period_ms: u64,
+    pub total_unbonding: U128,
+}
+
+#[near(serializers = [json])]
+pub struct PendingWithdrawalView {
+    pub amount: U128,
+    /// Nanoseconds, like `unlock_at`.
+    pub unlock_time: u64,
+    pub claimable: bool,
 }
 
 #[near(serializers = [json])]
@@ -127,7 +127,7 @@ fn purchase_credits(contract: &mut OnsocialStaking, sender: &str, amount: u128)
 fn test_init() {
     let contract = setup_contract();
 
-    assert_eq!(contract.version, 1);
+    assert_eq!(contract.version, 2);
     assert_eq!(contract.token_id.as_str(), "social.token.near");
     assert_eq!(contract.owner_id.as_str(), "owner.near");
     assert_eq!(contract.total_locked, 0);
@@ -143,7 +143,7 @@ fn test_get_stats_initial() {
     let contract = setup_contract();
     let stats = contract.get_stats();
 
-    assert_eq!(stats.version, 1);
+    assert_eq!(stats.version, 2);
     assert_eq!(stats.total_locked.0, 0);
     assert_eq!(stats.total_effective_stake.0, 0);
     assert_eq!(stats.scheduled_pool.0, 0);
@@ -1000,7 +1000,7 @@ fn test_contract_stats_complete() {
 
     let stats = contract.get_stats();
 
-    assert_eq!(stats.version, 1);
+    assert_eq!(stats.version, 2);
     assert_eq!(stats.total_locked.0, ONE_SOCIAL);
     assert_eq!(stats.total_effective_stake.0, ONE_SOCIAL * 110 / 100);
     assert_eq!(stats.scheduled_pool.0, 100 * ONE_SOCIAL + 20 * ONE_SOCIAL); // 100 funded + 40% of 50
@@ -3378,11 +3378,11 @@ fn test_storage_deposit_for_another() {
 fn test_version_tracking() {
     let contract = setup_contract();
 
-    // Initial version should be 1
-    assert_eq!(contract.version, 1);
+    // Initial version should be 2
+    assert_eq!(contract.version, 2);
 
     let stats = contract.get_stats();
-    assert_eq!(stats.version, 1);
+    assert_eq!(stats.version, 2);
 }
 
 // =============================================================================
@@ -3857,3 +3857,181 @@ fn test_claim_blocked_while_unlock_pending() {
         "Guard should block claim_rewards when unlock is pending"
     );
 }
+
+// =============================================================================
+// Unbonding Queue Tests
+// =============================================================================
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1_000;
+
+fn set_unbonding_period(contract: &mut OnsocialStaking, period_ms: u64) {
+    let mut context = get_context("owner.near");
+    context.attached_deposit(NearToken::from_yoctonear(1));
+    testing_env!(context.build());
+    contract.set_unbonding_period(period_ms).unwrap();
+}
+
+/// Locks 10 SOCIAL for 1 month and unlocks it right after expiry.
+/// Returns the unlock timestamp.
+fn lock_and_unlock(contract: &mut OnsocialStaking, account: &str) -> u64 {
+    let start_time = 1_000_000_000_000_000_000u64;
+    setup_with_storage(contract, account);
+    lock_tokens_at(contract, account, 10 * ONE_SOCIAL, 1, start_time);
+
+    let unlock_time = start_time + MONTH_NS + NS_PER_SEC;
+    let mut context = get_context(account);
+    context.block_timestamp(unlock_time);
+    testing_env!(context.build());
+    let _ = contract.unlock().unwrap();
+    unlock_time
+}
+
+#[test]
+fn test_set_unbonding_period() {
+    let mut contract = setup_contract();
+    assert_eq!(contract.get_stats().unbonding_period_ms, 0);
+
+    set_unbonding_period(&mut contract, 7 * DAY_MS);
+    assert_eq!(contract.get_stats().unbonding_period_ms, 7 * DAY_MS);
+
+    let mut context = get_context("owner.near");
+    context.attached_deposit(NearToken::from_yoctonear(1));
+    testing_env!(context.build());
+    assert!(
+        contract
+            .set_unbonding_period(MAX_UNBONDING_PERIOD_MS + 1)
+            .is_err()
+    );
+
+    let mut context = get_context("alice.near");
+    context.attached_deposit(NearToken::from_yoctonear(1));
+    testing_env!(context.build());
+    assert!(contract.set_unbonding_period(0).is_err());
+}
+
+#[test]
+fn test_unlock_without_unbonding_period_pays_immediately() {
+    let mut contract = setup_contract();
+    lock_and_unlock(&mut contract, "alice.near");
+
+    let alice: AccountId = "alice.near".parse().unwrap();
+    assert!(contract.pending_unlocks.contains_key(&alice));
+    assert!(contract.get_pending_withdrawals(alice).is_empty());
+    assert_eq!(contract.total_unbonding, 0);
+}
+
+#[test]
+fn test_unlock_with_unbonding_period_queues_withdrawal() {
+    let mut contract = setup_contract();
+    set_unbonding_period(&mut contract, 7 * DAY_MS);
+    let unlock_time = lock_and_unlock(&mut contract, "alice.near");
+
+    let alice: AccountId = "alice.near".parse().unwrap();
+    assert!(!contract.pending_unlocks.contains_key(&alice));
+    assert_eq!(contract.total_locked, 0);
+    assert_eq!(contract.total_effective_stake, 0);
+    assert_eq!(contract.total_unbonding, 10 * ONE_SOCIAL);
+    assert_eq!(contract.get_account(alice.clone()).locked_amount.0, 0);
+
+    let pending = contract.get_pending_withdrawals(alice);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].amount.0, 10 * ONE_SOCIAL);
+    assert_eq!(pending[0].unlock_time, unlock_time + 7 * DAY_MS * NS_PER_MS);
+    assert!(!pending[0].claimable);
+}
+
+#[test]
+fn test_claim_unstaked_before_maturity_fails() {
+    let mut contract = setup_contract();
+    set_unbonding_period(&mut contract, 7 * DAY_MS);
+    let unlock_time = lock_and_unlock(&mut contract, "alice.near");
+
+    let mut context = get_context("alice.near");
+    context.block_timestamp(unlock_time + 7 * DAY_MS * NS_PER_MS - 1);
+    testing_env!(context.build());
+    assert!(matches!(
+        contract.claim_unstaked(),
+        Err(StakingError::NothingToClaim)
+    ));
+    assert_eq!(contract.total_unbonding, 10 * ONE_SOCIAL);
+}
+
+#[test]
+fn test_claim_unstaked_takes_only_matured_entries() {
+    let mut contract = setup_contract();
+    set_unbonding_period(&mut contract, 7 * DAY_MS);
+    let alice: AccountId = "alice.near".parse().unwrap();
+    let first = lock_and_unlock(&mut contract, "alice.near");
+
+    // Second entry queued three days later.
+    let second = first + 3 * DAY_MS * NS_PER_MS;
+    contract
+        .pending_withdrawals
+        .get_mut(&alice)
+        .unwrap()
+        .push((5 * ONE_SOCIAL, second + 7 * DAY_MS * NS_PER_MS));
+    contract.total_unbonding += 5 * ONE_SOCIAL;
+
+    let mut context = get_context("alice.near");
+    context.block_timestamp(first + 7 * DAY_MS * NS_PER_MS);
+    testing_env!(context.build());
+    let _ = contract.claim_unstaked().unwrap();
+
+    let pending = contract.get_pending_withdrawals(alice);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].amount.0, 5 * ONE_SOCIAL);
+    assert_eq!(contract.total_unbonding, 5 * ONE_SOCIAL);
+}
+
+#[test]
+fn test_claim_unstaked_callback_failure_requeues() {
+    let mut contract = setup_contract();
+    set_unbonding_period(&mut contract, 7 * DAY_MS);
+    let alice: AccountId = "alice.near".parse().unwrap();
+    let unlock_time = lock_and_unlock(&mut contract, "alice.near");
+    let matures_at = unlock_time + 7 * DAY_MS * NS_PER_MS;
+
+    let mut context = get_context("alice.near");
+    context.block_timestamp(matures_at);
+    testing_env!(context.build());
+    let _ = contract.claim_unstaked().unwrap();
+    assert!(contract.get_pending_withdrawals(alice.clone()).is_empty());
+    assert_eq!(contract.total_unbonding, 0);
+
+    let mut context = get_context("staking.near");
+    context.current_account_id("staking.near".parse().unwrap());
+    context.block_timestamp(matures_at);
+    testing_env!(context.build());
+    contract.on_claim_unstaked_callback(
+        Err(near_sdk::PromiseError::Failed),
+        alice.clone(),
+        vec![(U128(10 * ONE_SOCIAL), matures_at)],
+    );
+
+    let pending = contract.get_pending_withdrawals(alice);
+    assert_eq!(pending.len(), 1);
+    assert!(pending[0].claimable);
+    assert_eq!(contract.total_unbonding, 10 * ONE_SOCIAL);
+}
+
+#[test]
+fn test_unlock_queue_capped() {
+    let mut contract = setup_contract();
+    set_unbonding_period(&mut contract, 7 * DAY_MS);
+    let alice: AccountId = "alice.near".parse().unwrap();
+    setup_with_storage(&mut contract, "alice.near");
+    contract
+        .pending_withdrawals
+        .insert(alice, vec![(ONE_SOCIAL, u64::MAX); MAX_PENDING_WITHDRAWALS]);
+
+    let start_time = 1_000_000_000_000_000_000u64;
+    lock_tokens_at(&mut contract, "alice.near", 10 * ONE_SOCIAL, 1, start_time);
+    let mut context = get_context("alice.near");
+    context.block_timestamp(start_time + MONTH_NS + NS_PER_SEC);
+    testing_env!(context.build());
+    assert!(matches!(
+        contract.unlock(),
+        Err(StakingError::InvalidInput(_))
+    ));
+    assert_eq!(contract.total_locked, 10 * ONE_SOCIAL);
+}
@@ -4,6 +4,7 @@ use near_contract_standards::fungible_token::FungibleToken;
 use near_contract_standards::fungible_token::metadata::{
     FT_METADATA_SPEC, FungibleTokenMetadata, FungibleTokenMetadataProvider,
 };
+use near_sdk::borsh::BorshDeserialize;
 use near_sdk::{
     AccountId, BorshStorageKey, NearToken, PanicOnDefault, PromiseOrValue, env, json_types::U128,
     near, require,
@@ -11,6 +12,7 @@ use near_sdk::{
 
 const VERSION: &str = "1.0.0";
 const DECIMALS: u8 = 18;
+const MAX_BATCH_TRANSFERS: usize = 100;
 
 #[derive(BorshStorageKey)]
 #[near]
@@ -24,11 +26,32 @@ pub struct Contract {
     token: FungibleToken,
     owner_id: AccountId,
     metadata: FungibleTokenMetadata,
+    // Upgrade invariant: fields below were appended after the first deployment; see `ContractV1`.
+    max_supply: Option<U128>,
+}
+
+/// State layout of the first deployment, read only by `migrate`.
+#[near(serializers = [borsh])]
+struct ContractV1 {
+    token: FungibleToken,
+    owner_id: AccountId,
+    metadata: FungibleTokenMetadata,
+}
+
+impl From<ContractV1> for Contract {
+    fn from(v1: ContractV1) -> Self {
+        Self {
+            token: v1.token,
+            owner_id: v1.owner_id,
+            metadata: v1.metadata,
+            max_supply: None,
+        }
+    }
 }
 
 #[near]
 impl Contract {
-    /// Mints `total_supply` to `owner_id`.
+    /// Mints `total_supply` to `owner_id`. `max_supply` caps later owner mints; without it the supply is fixed.
     #[init]
     pub fn new(
         owner_id: AccountId,
@@ -36,11 +59,18 @@ impl Contract {
         symbol: String,
         total_supply: U128,
         icon: String,
+        max_supply: Option<U128>,
     ) -> Self {
         require!(!name.is_empty(), "Token name cannot be empty");
         require!(!symbol.is_empty(), "Token symbol cannot be empty");
         require!(total_supply.0 > 0, "Total supply must be greater than 0");
         require!(!icon.is_empty(), "Token icon cannot be empty");
+        if let Some(max) = max_supply {
+            require!(
+                total_supply.0 <= max.0,
+                "Total supply cannot exceed max supply"
+            );
+        }
 
         let metadata = FungibleTokenMetadata {
             spec: FT_METADATA_SPEC.to_string(),
@@ -56,6 +86,7 @@ impl Contract {
             token: FungibleToken::new(StorageKey::FungibleToken),
             owner_id: owner_id.clone(),
             metadata,
+            max_supply,
         };
 
         this.token.internal_register_account(&owner_id);
@@ -71,6 +102,16 @@ impl Contract {
         this
     }
 
+    /// Re-reads state after a code upgrade; first-deployment state keeps a fixed supply.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let raw = env::storage_read(b"STATE").expect("State read failed");
+        Self::try_from_slice(&raw)
+            .or_else(|_| ContractV1::try_from_slice(&raw).map(Self::from))
+            .expect("State read failed")
+    }
+
     /// Updates token icon (data URL). Owner only. New icon required.
     pub fn set_icon(&mut self, icon: String) {
         self.assert_owner();
@@ -136,6 +177,81 @@ impl Contract {
         .emit();
     }
 
+    /// Transfers to up to 100 registered receivers in one call (1 yocto).
+    /// All entries are validated first, so the batch applies fully or not at all.
+    #[payable]
+    pub fn ft_transfer_batch(&mut self, transfers: Vec<(AccountId, U128)>, memo: Option<String>) {
+        near_sdk::assert_one_yocto();
+        require!(!transfers.is_empty(), "Batch cannot be empty");
+        require!(
+            transfers.len() <= MAX_BATCH_TRANSFERS,
+            "Batch exceeds 100 transfers"
+        );
+
+        let sender_id = env::predecessor_account_id();
+        let mut total: u128 = 0;
+        for (receiver_id, amount) in &transfers {
+            require!(amount.0 > 0, "The amount should be a positive number");
+            require!(
+                receiver_id != &sender_id,
+                "Sender and receiver should be different"
+            );
+            require!(
+                self.token.accounts.contains_key(receiver_id),
+                format!("The account {} is not registered", receiver_id)
+            );
+            total = total
+                .checked_add(amount.0)
+                .unwrap_or_else(|| env::panic_str("Batch total overflow"));
+        }
+        require!(
+            self.token.internal_unwrap_balance_of(&sender_id) >= total,
+            "The account doesn't have enough balance"
+        );
+
+        for (receiver_id, amount) in transfers {
+            self.token
+                .internal_transfer(&sender_id, &receiver_id, amount.0, memo.clone());
+        }
+    }
+
+    /// Mints `amount` to a registered account. Owner only; requires and is bounded by `max_supply`.
+    #[payable]
+    pub fn mint(&mut self, account_id: AccountId, amount: U128) {
+        require!(
+            env::attached_deposit() >= NearToken::from_yoctonear(1),
+            "Requires attached deposit of at least 1 yoctoNEAR"
+        );
+        self.assert_owner();
+        require!(amount.0 > 0, "Mint amount must be greater than 0");
+        require!(
+            self.token.accounts.contains_key(&account_id),
+            "Account is not registered"
+        );
+        let max = self
+            .max_supply
+            .unwrap_or_else(|| env::panic_str("Supply is fixed: no max supply set"));
+        let new_supply = self
+            .token
+            .total_supply
+            .checked_add(amount.0)
+            .unwrap_or_else(|| env::panic_str("Total supply overflow"));
+        require!(new_supply <= max.0, "Mint would exceed max supply");
+
+        self.token.internal_deposit(&account_id, amount.0);
+
+        near_contract_standards::fungible_token::events::FtMint {
+            owner_id: &account_id,
+            amount,
+            memo: Some("Owner mint"),
+        }
+        .emit();
+    }
+
+    pub fn get_max_supply(&self) -> Option<U128> {
+        self.max_supply
+    }
+
     fn assert_owner(&self) {
         require!(
             env::predecessor_account_id() == self.owner_id,
@@ -3,6 +3,7 @@
 use super::*;
 use near_contract_standards::fungible_token::core::FungibleTokenCore;
 use near_contract_standards::fungible_token::metadata::FungibleTokenMetadataProvider;
+use near_contract_standards::storage_management::StorageManagement;
 use near_sdk::test_utils::{VMContextBuilder, accounts};
 use near_sdk::testing_env;
 
@@ -28,6 +29,7 @@ fn setup_contract() -> Contract {
         "SOCIAL".to_string(),
         U128(TEST_TOTAL_SUPPLY),
         TEST_ICON.to_string(),
+        None,
     )
 }
 
@@ -66,6 +68,7 @@ fn test_custom_token_parameters() {
         "MTK".to_string(),
         U128(custom_supply),
         "data:image/svg+xml;base64,test".to_string(),
+        None,
     );
 
     assert_eq!(contract.ft_total_supply().0, custom_supply);
@@ -91,6 +94,7 @@ fn test_new_empty_name_fails() {
         "SOCIAL".to_string(),
         U128(TEST_TOTAL_SUPPLY),
         TEST_ICON.to_string(),
+        None,
     );
 }
 
@@ -107,6 +111,7 @@ fn test_new_empty_symbol_fails() {
         "".to_string(),
         U128(TEST_TOTAL_SUPPLY),
         TEST_ICON.to_string(),
+        None,
     );
 }
 
@@ -123,6 +128,7 @@ fn test_new_zero_supply_fails() {
         "SOCIAL".to_string(),
         U128(0),
         TEST_ICON.to_string(),
+        None,
     );
 }
 
@@ -218,6 +224,215 @@ fn test_renounce_owner_then_set_icon_fails() {
     contract.set_icon("icon".to_string());
 }
 
+// --- Mint Tests ---
+
+fn setup_capped_contract(max_supply: u128) -> Contract {
+    let owner = accounts(0);
+    testing_env!(get_context(owner.clone()).build());
+    Contract::new(
+        owner,
+        "OnSocial".to_string(),
+        "SOCIAL".to_string(),
+        U128(TEST_TOTAL_SUPPLY),
+        TEST_ICON.to_string(),
+        Some(U128(max_supply)),
+    )
+}
+
+fn owner_context_with_yocto() {
+    let mut context = get_context(accounts(0));
+    context.attached_deposit(NearToken::from_yoctonear(1));
+    testing_env!(context.build());
+}
+
+#[test]
+fn test_mint_up_to_max_supply() {
+    let mut contract = setup_capped_contract(TEST_TOTAL_SUPPLY + 1_000);
+    owner_context_with_yocto();
+
+    contract.mint(accounts(0), U128(1_000));
+
+    assert_eq!(contract.ft_total_supply().0, TEST_TOTAL_SUPPLY + 1_000);
+    assert_eq!(
+        contract.ft_balance_of(accounts(0)).0,
+        TEST_TOTAL_SUPPLY + 1_000
+    );
+    assert_eq!(
+        contract.get_max_supply(),
+        Some(U128(TEST_TOTAL_SUPPLY + 1_000))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mint would exceed max supply")]
+fn test_mint_over_max_supply_fails() {
+    let mut contract = setup_capped_contract(TEST_TOTAL_SUPPLY + 1_000);
+    owner_context_with_yocto();
+
+    contract.mint(accounts(0), U128(1_001));
+}
+
+#[test]
+#[should_panic(expected = "Supply is fixed: no max supply set")]
+fn test_mint_without_max_supply_fails() {
+    let mut contract = setup_contract();
+    owner_context_with_yocto();
+
+    contract.mint(accounts(0), U128(1));
+}
+
+#[test]
+#[should_panic(expected = "Only owner can call this method")]
+fn test_mint_non_owner_fails() {
+    let mut contract = setup_contract();
+    let mut context = get_context(accounts(1));
+    context.attached_deposit(NearToken::from_yoctonear(1));
+    testing_env!(context.build());
+
+    contract.mint(accounts(1), U128(1_000));
+}
+
+#[test]
+#[should_panic(expected = "Account is not registered")]
+fn test_mint_to_unregistered_account_fails() {
+    let mut contract = setup_contract();
+    owner_context_with_yocto();
+
+    contract.mint(accounts(1), U128(1_000));
+}
+
+#[test]
+#[should_panic(expected = "Total supply cannot exceed max supply")]
+fn test_new_total_supply_above_max_supply_fails() {
+    setup_capped_contract(TEST_TOTAL_SUPPLY - 1);
+}
+
+#[test]
+fn test_migrate_from_first_deployment_keeps_fixed_supply() {
+    let contract = setup_contract();
+    let v1_state =
+        near_sdk::borsh::to_vec(&(&contract.token, &contract.owner_id, &contract.metadata))
+            .unwrap();
+    env::storage_write(b"STATE", &v1_state);
+
+    let migrated = Contract::migrate();
+
+    assert_eq!(migrated.get_max_supply(), None);
+    assert_eq!(migrated.ft_total_supply().0, TEST_TOTAL_SUPPLY);
+    assert_eq!(migrated.ft_balance_of(accounts(0)).0, TEST_TOTAL_SUPPLY);
+}
+
+#[test]
+#[should_panic(expected = "Supply is fixed: no max supply set")]
+fn test_migrated_first_deployment_cannot_mint() {
+    let contract = setup_contract();
+    let v1_state =
+        near_sdk::borsh::to_vec(&(&contract.token, &contract.owner_id, &contract.metadata))
+            .unwrap();
+    env::storage_write(b"STATE", &v1_state);
+
+    let mut migrated = Contract::migrate();
+    owner_context_with_yocto();
+    migrated.mint(accounts(0), U128(1));
+}
+
+#[test]
+fn test_migrate_keeps_current_max_supply() {
+    let contract = setup_capped_contract(TEST_TOTAL_SUPPLY + 1_000);
+    env::storage_write(b"STATE", &near_sdk::borsh::to_vec(&contract).unwrap());
+
+    let migrated = Contract::migrate();
+
+    assert_eq!(
+        migrated.get_max_supply(),
+        Some(U128(TEST_TOTAL_SUPPLY + 1_000))
+    );
+}
+
+// --- Batch Transfer Tests ---
+
+fn register(contract: &mut Contract, account_id: AccountId) {
+    let mut context = get_context(account_id.clone());
+    context.attached_deposit(NearToken::from_millinear(125));
+    testing_env!(context.build());
+    contract.storage_deposit(Some(account_id), None);
+}
+
+#[test]
+fn test_ft_transfer_batch() {
+    let mut contract = setup_contract();
+    register(&mut contract, accounts(1));
+    register(&mut contract, accounts(2));
+    owner_context_with_yocto();
+
+    contract.ft_transfer_batch(
+        vec![(accounts(1), U128(100)), (accounts(2), U128(250))],
+        Some("airdrop".to_string()),
+    );
+
+    assert_eq!(contract.ft_balance_of(accounts(1)).0, 100);
+    assert_eq!(contract.ft_balance_of(accounts(2)).0, 250);
+    assert_eq!(
+        contract.ft_balance_of(accounts(0)).0,
+        TEST_TOTAL_SUPPLY - 350
+    );
+    let transfer_events = near_sdk::test_utils::get_logs()
+        .iter()
+        .filter(|l| l.contains("\"event\":\"ft_transfer\""))
+        .count();
+    assert_eq!(transfer_events, 2);
+}
+
+#[test]
+#[should_panic(expected = "is not registered")]
+fn test_ft_transfer_batch_unregistered_receiver_fails() {
+    let mut contract = setup_contract();
+    register(&mut contract, accounts(1));
+    owner_context_with_yocto();
+
+    contract.ft_transfer_batch(
+        vec![(accounts(1), U128(100)), (accounts(2), U128(100))],
+        None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Batch exceeds 100 transfers")]
+fn test_ft_transfer_batch_over_cap_fails() {
+    let mut contract = setup_contract();
+    register(&mut contract, accounts(1));
+    owner_context_with_yocto();
+
+    contract.ft_transfer_batch(vec![(accounts(1), U128(1)); 101], None);
+}
+
+#[test]
+#[should_panic(expected = "The account doesn't have enough balance")]
+fn test_ft_transfer_batch_insufficient_total_fails() {
+    let mut contract = setup_contract();
+    register(&mut contract, accounts(1));
+    register(&mut contract, accounts(2));
+    owner_context_with_yocto();
+
+    contract.ft_transfer_batch(
+        vec![
+            (accounts(1), U128(TEST_TOTAL_SUPPLY)),
+            (accounts(2), U128(1)),
+        ],
+        None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Requires attached deposit of exactly 1 yoctoNEAR")]
+fn test_ft_transfer_batch_requires_one_yocto() {
+    let mut contract = setup_contract();
+    register(&mut contract, accounts(1));
+    testing_env!(get_context(accounts(0)).build());
+
+    contract.ft_transfer_batch(vec![(accounts(1), U128(1))], None);
+}
+
 // --- Burn Tests ---
 
 #[test]
@@ -292,6 +507,7 @@ fn test_metadata_with_icon() {
         "SOCIAL".to_string(),
         U128(TEST_TOTAL_SUPPLY),
         icon.clone(),
+        None,
     );
 
     assert_eq!(contract.ft_metadata().icon, Some(icon));
@@ -310,6 +526,7 @@ fn test_new_empty_icon_fails() {
         "SOCIAL".to_string(),
         U128(TEST_TOTAL_SUPPLY),
         "".to_string(),
+        None,
     );
 }
 
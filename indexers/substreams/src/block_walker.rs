@@ -1,8 +1,10 @@
 //! Shared EVENT_JSON block walkers.
 
+use std::collections::HashSet;
 use substreams_near::pb::sf::near::r#type::v1::Block;
 
 const EVENT_JSON_PREFIX: &str = "EVENT_JSON:";
+const EVENT_BORSH_PREFIX: &str = "EVENT_BORSH:";
 
 pub struct BlockContext {
     pub block_height: u64,
@@ -12,11 +14,13 @@ pub struct BlockContext {
 
 pub struct EventLog<'a> {
     pub receipt_id: String,
+    /// JSON payload, or the full `EVENT_BORSH:` log for borsh-encoded events.
     pub json_data: &'a str,
     pub log_index: usize,
 }
 
-/// Extracts the `contract_id` filter from params.
+/// Extracts the `contract_id` filter from params. The value may list several
+/// contracts separated by commas.
 pub fn parse_contract_filter(params: &str) -> Option<String> {
     params.split('=').nth(1).map(|s| s.trim().to_string())
 }
@@ -55,11 +59,38 @@ pub fn block_context(block: &Block) -> BlockContext {
     }
 }
 
-/// Iterates EVENT_JSON logs for one optional contract filter.
+/// Yields `(log_index, payload)` for the event logs of one receipt.
+///
+/// JSON payloads have their prefix stripped. `EVENT_BORSH:` logs keep theirs
+/// so decoders can tell the encodings apart, and are skipped when the receipt
+/// also logged JSON: deployments emitting both write every event twice.
+fn event_payloads(logs: &[String]) -> impl Iterator<Item = (usize, &str)> {
+    let has_json = logs.iter().any(|log| log.starts_with(EVENT_JSON_PREFIX));
+    logs.iter().enumerate().filter_map(move |(log_index, log)| {
+        if let Some(json_data) = log.strip_prefix(EVENT_JSON_PREFIX) {
+            Some((log_index, json_data))
+        } else if !has_json && log.starts_with(EVENT_BORSH_PREFIX) {
+            Some((log_index, log.as_str()))
+        } else {
+            None
+        }
+    })
+}
+
+/// Iterates EVENT_JSON logs for an optional comma-separated contract filter.
 pub fn for_each_event_log<F>(block: &Block, contract_filter: Option<&str>, mut callback: F)
 where
     F: FnMut(EventLog<'_>),
 {
+    let contract_set: Option<HashSet<String>> = contract_filter.map(|filter| {
+        filter
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect()
+    });
+
     for shard in &block.shards {
         for receipt_execution in &shard.receipt_execution_outcomes {
             let receipt = match &receipt_execution.receipt {
@@ -77,7 +108,10 @@ where
 
             let receiver_id = &receipt.receiver_id;
 
-            if contract_filter.is_some_and(|filter| receiver_id != filter) {
+            if contract_set
+                .as_ref()
+                .is_some_and(|set| !set.contains(receiver_id))
+            {
                 continue;
             }
 
@@ -87,13 +121,7 @@ where
                 .map(|id| bs58::encode(&id.bytes).into_string())
                 .unwrap_or_default();
 
-            for (log_index, log) in outcome.logs.iter().enumerate() {
-                if !log.starts_with(EVENT_JSON_PREFIX) {
-                    continue;
-                }
-
-                let json_data = &log[EVENT_JSON_PREFIX.len()..];
-
+            for (log_index, json_data) in event_payloads(&outcome.logs) {
                 callback(EventLog {
                     receipt_id: receipt_id.clone(),
                     json_data,
@@ -152,13 +180,7 @@ pub fn for_each_event_log_multi<'a, F>(
                 .map(|id| bs58::encode(&id.bytes).into_string())
                 .unwrap_or_default();
 
-            for (log_index, log) in outcome.logs.iter().enumerate() {
-                if !log.starts_with(EVENT_JSON_PREFIX) {
-                    continue;
-                }
-
-                let json_data = &log[EVENT_JSON_PREFIX.len()..];
-
+            for (log_index, json_data) in event_payloads(&outcome.logs) {
                 callback(LabeledEventLog {
                     label,
                     receipt_id: receipt_id.clone(),
@@ -1,9 +1,16 @@
-//! NEP-297 JSON decoder for core-onsocial contract events.
+//! NEP-297 decoder for core-onsocial contract events.
 //!
-//! Format: `EVENT_JSON:{"standard":"onsocial","version":"1.0.0","event":"...","data":[...]}`
+//! Formats:
+//! - `EVENT_JSON:{"standard":"onsocial","version":"1.0.0","event":"...","data":[...]}`
+//! - `EVENT_BORSH:{base64(borsh(BorshEvent))}` for deployments that opted in;
+//!   the block walker passes these with their prefix attached.
 
+use base64::{Engine, engine::general_purpose::STANDARD};
+use borsh::BorshDeserialize;
 use serde::Deserialize;
-use serde_json::Value;
+use serde_json::{Map, Number, Value};
+
+pub const EVENT_BORSH_PREFIX: &str = "EVENT_BORSH:";
 
 /// NEP-297 event structure (matches Event in contract types.rs)
 #[derive(Deserialize, Debug, Clone)]
@@ -32,20 +39,117 @@ pub struct EventData {
     pub extra: serde_json::Map<String, Value>,
 }
 
-/// Decode a NEP-297 JSON event from log string
+/// Versioned borsh schema (matches BorshEvent in contract events/borsh.rs)
+#[derive(BorshDeserialize, Debug)]
+enum BorshEvent {
+    V1(BorshEventV1),
+}
+
+#[derive(BorshDeserialize, Debug)]
+struct BorshEventV1 {
+    standard: String,
+    version: String,
+    event: String,
+    data: Vec<BorshEventData>,
+}
+
+#[derive(BorshDeserialize, Debug)]
+struct BorshEventData {
+    operation: String,
+    author: String,
+    partition_id: Option<u16>,
+    extra: Vec<(String, BorshValue)>,
+}
+
+#[derive(BorshDeserialize, Debug)]
+enum BorshValue {
+    Null,
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Array(Vec<BorshValue>),
+    Object(Vec<(String, BorshValue)>),
+}
+
+impl From<BorshValue> for Value {
+    fn from(value: BorshValue) -> Self {
+        match value {
+            BorshValue::Null => Value::Null,
+            BorshValue::Bool(b) => Value::Bool(b),
+            BorshValue::U64(u) => Value::from(u),
+            BorshValue::I64(i) => Value::from(i),
+            BorshValue::F64(f) => Number::from_f64(f).map_or(Value::Null, Value::Number),
+            BorshValue::String(s) => Value::String(s),
+            BorshValue::Array(items) => Value::Array(items.into_iter().map(Value::from).collect()),
+            BorshValue::Object(fields) => Value::Object(fields_to_map(fields)),
+        }
+    }
+}
+
+fn fields_to_map(fields: Vec<(String, BorshValue)>) -> Map<String, Value> {
+    fields
+        .into_iter()
+        .map(|(k, v)| (k, Value::from(v)))
+        .collect()
+}
+
+/// Decode a NEP-297 event from a JSON payload or a prefixed borsh log
 pub fn decode_onsocial_event(json_data: &str) -> Result<OnSocialEvent, DecodeError> {
-    serde_json::from_str(json_data).map_err(|e| DecodeError::Json(e.to_string()))
+    match json_data.strip_prefix(EVENT_BORSH_PREFIX) {
+        Some(encoded) => decode_borsh_event(encoded),
+        None => serde_json::from_str(json_data).map_err(|e| DecodeError::Json(e.to_string())),
+    }
+}
+
+/// Like [`decode_onsocial_event`], but a JSON payload may also be a top-level
+/// array of event objects (batched logs).
+pub fn decode_onsocial_events(json_data: &str) -> Result<Vec<OnSocialEvent>, DecodeError> {
+    if json_data.trim_start().starts_with('[') {
+        serde_json::from_str(json_data).map_err(|e| DecodeError::Json(e.to_string()))
+    } else {
+        decode_onsocial_event(json_data).map(|event| vec![event])
+    }
+}
+
+fn decode_borsh_event(encoded: &str) -> Result<OnSocialEvent, DecodeError> {
+    let bytes = STANDARD
+        .decode(encoded)
+        .map_err(|e| DecodeError::Base64(e.to_string()))?;
+    let BorshEvent::V1(event) =
+        borsh::from_slice(&bytes).map_err(|e| DecodeError::Borsh(e.to_string()))?;
+
+    Ok(OnSocialEvent {
+        standard: event.standard,
+        version: event.version,
+        event: event.event,
+        data: event
+            .data
+            .into_iter()
+            .map(|d| EventData {
+                operation: d.operation,
+                author: d.author,
+                partition_id: d.partition_id,
+                extra: fields_to_map(d.extra),
+            })
+            .collect(),
+    })
 }
 
 #[derive(Debug)]
 pub enum DecodeError {
     Json(String),
+    Base64(String),
+    Borsh(String),
 }
 
 impl std::fmt::Display for DecodeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             DecodeError::Json(e) => write!(f, "JSON decode error: {}", e),
+            DecodeError::Base64(e) => write!(f, "base64 decode error: {}", e),
+            DecodeError::Borsh(e) => write!(f, "borsh decode error: {}", e),
         }
     }
 }
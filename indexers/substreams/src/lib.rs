@@ -27,7 +27,7 @@ use block_walker::{
     parse_multi_contract_filter,
 };
 use boost_decoder::decode_boost_event;
-use core_decoder::decode_onsocial_event;
+use core_decoder::decode_onsocial_events;
 use pb::boost::v1::BoostOutput;
 use pb::combined::v1::CombinedOutput;
 use pb::core_onsocial::v1::{
@@ -38,7 +38,7 @@ use pb::scarces::v1::ScarcesOutput;
 use pb::social_spend::v1::SocialSpendOutput;
 use pb::token::v1::TokenOutput;
 use rewards_decoder::decode_rewards_event;
-use scarces_decoder::decode_scarces_event;
+use scarces_decoder::{decode_auction_updates, decode_scarces_event};
 use serde_json::Value;
 use social_spend_decoder::decode_social_spend_event;
 use substreams_near::pb::sf::near::r#type::v1::Block;
@@ -58,15 +58,49 @@ fn process_core_log(
     contract_updates: &mut Vec<ContractUpdate>,
     permission_updates: &mut Vec<PermissionUpdate>,
 ) {
-    let event = match decode_onsocial_event(json_data) {
+    let events = match decode_onsocial_events(json_data) {
         Ok(e) => e,
         Err(_) => return,
     };
 
-    if event.standard != "onsocial" || !event.version.starts_with("1.") {
-        return;
+    // Batched logs are flattened; data indexes keep counting across events
+    // so ids stay unique within the log.
+    let mut base_index = 0u32;
+    for event in events {
+        let data_count = event.data.len() as u32;
+        if event.standard == "onsocial" && event.version.starts_with("1.") {
+            process_core_event(
+                &event,
+                receipt_id,
+                log_index,
+                base_index,
+                block_height,
+                block_timestamp,
+                data_updates,
+                storage_updates,
+                group_updates,
+                contract_updates,
+                permission_updates,
+            );
+        }
+        base_index += data_count;
     }
+}
 
+#[allow(clippy::too_many_arguments)]
+fn process_core_event(
+    event: &core_decoder::OnSocialEvent,
+    receipt_id: &str,
+    log_index: usize,
+    base_index: u32,
+    block_height: u64,
+    block_timestamp: u64,
+    data_updates: &mut Vec<DataUpdate>,
+    storage_updates: &mut Vec<StorageUpdate>,
+    group_updates: &mut Vec<GroupUpdate>,
+    contract_updates: &mut Vec<ContractUpdate>,
+    permission_updates: &mut Vec<PermissionUpdate>,
+) {
     match event.event.as_str() {
         "DATA_UPDATE" => {
             for (i, data) in event.data.iter().enumerate() {
@@ -74,7 +108,7 @@ fn process_core_log(
                     data,
                     receipt_id,
                     log_index as u32,
-                    i as u32,
+                    base_index + i as u32,
                     block_height,
                     block_timestamp,
                 ) {
@@ -88,7 +122,7 @@ fn process_core_log(
                     data,
                     receipt_id,
                     log_index as u32,
-                    i as u32,
+                    base_index + i as u32,
                     block_height,
                     block_timestamp,
                 ) {
@@ -102,7 +136,7 @@ fn process_core_log(
                     data,
                     receipt_id,
                     log_index as u32,
-                    i as u32,
+                    base_index + i as u32,
                     block_height,
                     block_timestamp,
                 ) {
@@ -112,7 +146,7 @@ fn process_core_log(
                     data,
                     receipt_id,
                     log_index as u32,
-                    i as u32,
+                    base_index + i as u32,
                     block_height,
                     block_timestamp,
                 ) {
@@ -126,7 +160,7 @@ fn process_core_log(
                     data,
                     receipt_id,
                     log_index as u32,
-                    i as u32,
+                    base_index + i as u32,
                     block_height,
                     block_timestamp,
                 ) {
@@ -140,7 +174,7 @@ fn process_core_log(
                     data,
                     receipt_id,
                     log_index as u32,
-                    i as u32,
+                    base_index + i as u32,
                     block_height,
                     block_timestamp,
                 ) {
@@ -283,6 +317,7 @@ fn map_scarces_output(
     let filter = parse_contract_filter(&params);
     let ctx = block_context(&block);
     let mut events = Vec::new();
+    let mut auction_updates = Vec::new();
 
     for_each_event_log(&block, filter.as_deref(), |log| {
         if let Some(event) = decode_scarces_event(
@@ -294,10 +329,18 @@ fn map_scarces_output(
         ) {
             events.push(event);
         }
+        auction_updates.extend(decode_auction_updates(
+            log.json_data,
+            &log.receipt_id,
+            ctx.block_height,
+            ctx.block_timestamp,
+            log.log_index,
+        ));
     });
 
     Ok(ScarcesOutput {
         events,
+        auction_updates,
         block_height: ctx.block_height,
         block_timestamp: ctx.block_timestamp,
         block_hash: ctx.block_hash,
@@ -354,6 +397,7 @@ fn map_combined_output(
     let mut rewards_events = Vec::new();
     let mut token_events = Vec::new();
     let mut scarces_events = Vec::new();
+    let mut scarces_auction_updates = Vec::new();
     let mut social_spend_events = Vec::new();
 
     for_each_event_log_multi(&block, &contracts, |log| match log.label {
@@ -412,6 +456,13 @@ fn map_combined_output(
             ) {
                 scarces_events.push(event);
             }
+            scarces_auction_updates.extend(decode_auction_updates(
+                log.json_data,
+                &log.receipt_id,
+                ctx.block_height,
+                ctx.block_timestamp,
+                log.log_index,
+            ));
         }
         "social_spend" => {
             if let Some(event) = decode_social_spend_event(
@@ -458,6 +509,7 @@ fn map_combined_output(
         }),
         scarces: Some(ScarcesOutput {
             events: scarces_events,
+            auction_updates: scarces_auction_updates,
             block_height: ctx.block_height,
             block_timestamp: ctx.block_timestamp,
             block_hash: ctx.block_hash.clone(),
@@ -763,6 +815,14 @@ fn extract_storage_update(
     block_timestamp: u64,
 ) -> Option<StorageUpdate> {
     let id = format!("{}-{}-{}-storage", receipt_id, log_index, data_index);
+    let mut numeric_parse_failures = 0;
+    let amount_u128 = get_u128_string(&data.extra, "amount", &mut numeric_parse_failures);
+    let previous_balance_u128 =
+        get_u128_string(&data.extra, "previous_balance", &mut numeric_parse_failures);
+    let new_balance_u128 = get_u128_string(&data.extra, "new_balance", &mut numeric_parse_failures);
+    let bytes_u64 = get_u64_checked(&data.extra, "bytes", &mut numeric_parse_failures);
+    let used_bytes_u64 = get_u64_checked(&data.extra, "used_bytes", &mut numeric_parse_failures);
+    let max_bytes_u64 = get_u64_checked(&data.extra, "max_bytes", &mut numeric_parse_failures);
 
     Some(StorageUpdate {
         id,
@@ -797,6 +857,13 @@ fn extract_storage_update(
         used_bytes: get_string(&data.extra, "used_bytes").unwrap_or_default(),
         // Capture all fields as JSON so nothing is ever lost
         extra_data: serde_json::to_string(&data.extra).unwrap_or_default(),
+        amount_u128,
+        previous_balance_u128,
+        new_balance_u128,
+        bytes_u64,
+        used_bytes_u64,
+        max_bytes_u64,
+        numeric_parse_failures,
     })
 }
 
@@ -949,7 +1016,8 @@ fn extract_contract_update(
     let id = format!("{}-{}-{}-contract", receipt_id, log_index, data_index);
 
     // Capture event-specific fields emitted by core CONTRACT_UPDATE operations
-    // (`update_config`, `update_manager`, `contract_upgrade`, status changes
+    // (`update_config`, `update_manager`, the two-step `propose_manager`/
+    // `accept_manager`/`cancel_manager_transfer`, `contract_upgrade`, status changes
     // `enter_read_only`/`resume_live`/`activate_contract`, and `wnear_account_set`)
     // as a JSON blob. `set` (meta_tx) emits `actor_id`/`payer_id` which are already
     // promoted to first-class columns above.
@@ -958,6 +1026,7 @@ fn extract_contract_update(
         "new_config",
         "old_manager",
         "new_manager",
+        "pending_manager",
         "old_version",
         "new_version",
         "previous",
@@ -1045,6 +1114,37 @@ fn get_u64(extra: &serde_json::Map<String, Value>, key: &str) -> Option<u64> {
     extra.get(key).and_then(|v| v.as_u64())
 }
 
+/// Canonical decimal form of a u128 field; missing or malformed values are
+/// "0", and malformed ones bump `failures`.
+fn get_u128_string(
+    extra: &serde_json::Map<String, Value>,
+    key: &str,
+    failures: &mut u32,
+) -> String {
+    let Some(raw) = get_string(extra, key) else {
+        return "0".to_string();
+    };
+    match raw.parse::<u128>() {
+        Ok(n) => n.to_string(),
+        Err(_) => {
+            *failures += 1;
+            "0".to_string()
+        }
+    }
+}
+
+/// Like [`get_u64`] but also accepts numeric strings; missing or malformed
+/// values are 0, and malformed ones bump `failures`.
+fn get_u64_checked(extra: &serde_json::Map<String, Value>, key: &str, failures: &mut u32) -> u64 {
+    let Some(raw) = get_string(extra, key) else {
+        return 0;
+    };
+    raw.parse().unwrap_or_else(|_| {
+        *failures += 1;
+        0
+    })
+}
+
 fn get_i32(extra: &serde_json::Map<String, Value>, key: &str) -> Option<i32> {
     extra.get(key).and_then(|v| v.as_i64()).map(|n| n as i32)
 }
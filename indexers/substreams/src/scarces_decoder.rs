@@ -136,6 +136,96 @@ pub fn decode_scarces_event(
     })
 }
 
+/// Auction lifecycle rows for every `auction_*` entry in a SCARCE_UPDATE log.
+pub fn decode_auction_updates(
+    json_data: &str,
+    receipt_id: &str,
+    block_height: u64,
+    block_timestamp: u64,
+    log_index: usize,
+) -> Vec<AuctionUpdate> {
+    let Ok(parsed) = serde_json::from_str::<Value>(json_data) else {
+        return Vec::new();
+    };
+    if parsed.get("standard").and_then(Value::as_str) != Some("onsocial")
+        || parsed.get("event").and_then(Value::as_str) != Some("SCARCE_UPDATE")
+    {
+        return Vec::new();
+    }
+    let Some(data_arr) = parsed.get("data").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    data_arr
+        .iter()
+        .enumerate()
+        .filter_map(|(data_index, data)| {
+            extract_auction_update(
+                data,
+                receipt_id,
+                block_height,
+                block_timestamp,
+                log_index,
+                data_index as u32,
+            )
+        })
+        .collect()
+}
+
+fn extract_auction_update(
+    data: &Value,
+    receipt_id: &str,
+    block_height: u64,
+    block_timestamp: u64,
+    log_index: usize,
+    data_index: u32,
+) -> Option<AuctionUpdate> {
+    let operation = str_field(data, "operation");
+    let status = match operation.as_str() {
+        "auction_created" | "auction_bid" | "auction_extended" => "active",
+        "auction_settled" => "settled",
+        "auction_unsold" => "unsold",
+        "auction_cancelled" => "cancelled",
+        _ => return None,
+    };
+    let id = format!("{}-{}-{}-auction", receipt_id, log_index, data_index);
+    let token_id = str_field(data, "token_id");
+
+    // Closed auctions end at the block that closed them.
+    let end_time = if status == "active" {
+        match u64_field(data, "new_expires_at") {
+            0 => u64_field(data, "expires_at"),
+            t => t,
+        }
+    } else {
+        block_timestamp
+    };
+
+    Some(AuctionUpdate {
+        id,
+        block_height,
+        block_timestamp,
+        receipt_id: receipt_id.to_string(),
+        auction_id: token_id.clone(),
+        token_id,
+        seller: str_field_any(data, &["owner_id", "seller_id"]),
+        highest_bidder: str_field_any(data, &["bidder", "winner_id"]),
+        highest_bid: str_field_any(data, &["bid_amount", "winning_bid", "highest_bid"]),
+        reserve_price: str_field(data, "reserve_price"),
+        buy_now_price: str_field(data, "buy_now_price"),
+        bid_count: u32_field(data, "bid_count"),
+        start_time: if operation == "auction_created" {
+            block_timestamp
+        } else {
+            0
+        },
+        end_time,
+        status: status.to_string(),
+        reason: str_field(data, "reason"),
+        operation,
+    })
+}
+
 fn str_field(data: &Value, key: &str) -> String {
     data.get(key)
         .and_then(|v| match v {
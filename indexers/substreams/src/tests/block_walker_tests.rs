@@ -19,6 +19,12 @@ fn parse_contract_filter_extracts_value() {
     assert_eq!(filter.unwrap(), "core.onsocial.near");
 }
 
+#[test]
+fn parse_contract_filter_keeps_contract_list() {
+    let filter = parse_contract_filter("contracts=core.onsocial.near,scarces.onsocial.near");
+    assert_eq!(filter.unwrap(), "core.onsocial.near,scarces.onsocial.near");
+}
+
 #[test]
 fn parse_contract_filter_empty_string_returns_none() {
     assert!(parse_contract_filter("").is_none());
@@ -130,6 +136,25 @@ fn event_log_filters_by_contract_id() {
     assert_eq!(count, 0, "Should filter out non-matching contract");
 }
 
+#[test]
+fn event_log_filters_by_contract_list() {
+    let core = r#"{"standard":"onsocial","event":"A","data":[]}"#;
+    let scarces = r#"{"standard":"onsocial","event":"B","data":[]}"#;
+    let boost = r#"{"standard":"onsocial","event":"C","data":[]}"#;
+    let block = MockBlockBuilder::new(100, 1000)
+        .add_receipt("core.onsocial.near", &[10], vec![core])
+        .add_receipt("scarces.onsocial.near", &[20], vec![scarces])
+        .add_receipt("boost.onsocial.near", &[30], vec![boost])
+        .build();
+
+    let filter = parse_contract_filter("contracts=core.onsocial.near, scarces.onsocial.near");
+    let mut events = Vec::new();
+    for_each_event_log(&block, filter.as_deref(), |log| {
+        events.push(log.json_data.to_string());
+    });
+    assert_eq!(events, vec![core.to_string(), scarces.to_string()]);
+}
+
 #[test]
 fn event_log_no_filter_returns_all() {
     let json1 = r#"{"standard":"onsocial","event":"A","data":[]}"#;
@@ -167,6 +192,37 @@ fn event_log_skips_non_event_json_lines() {
     assert_eq!(count, 1, "Only EVENT_JSON: lines should be processed");
 }
 
+#[test]
+fn event_log_passes_borsh_logs_with_prefix() {
+    let block = MockBlockBuilder::new(100, 1000)
+        .add_receipt_raw_logs(
+            "core.onsocial.near",
+            &[10],
+            vec!["EVENT_BORSH:AAAA", "some debug line"],
+        )
+        .build();
+
+    let mut payloads = Vec::new();
+    for_each_event_log(&block, Some("core.onsocial.near"), |log| {
+        payloads.push(log.json_data.to_string());
+    });
+    assert_eq!(payloads, vec!["EVENT_BORSH:AAAA"]);
+}
+
+#[test]
+fn event_log_skips_borsh_duplicates_of_json() {
+    let json = r#"EVENT_JSON:{"standard":"onsocial","event":"A","data":[]}"#;
+    let block = MockBlockBuilder::new(100, 1000)
+        .add_receipt_raw_logs("core.onsocial.near", &[10], vec![json, "EVENT_BORSH:AAAA"])
+        .build();
+
+    let mut indices = Vec::new();
+    for_each_event_log(&block, Some("core.onsocial.near"), |log| {
+        indices.push(log.log_index);
+    });
+    assert_eq!(indices, vec![0], "JSON wins when both encodings are logged");
+}
+
 #[test]
 fn event_log_multiple_logs_per_receipt() {
     let json1 = r#"{"standard":"onsocial","event":"A","data":[]}"#;
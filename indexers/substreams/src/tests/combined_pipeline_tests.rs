@@ -15,7 +15,7 @@ use crate::pb::social_spend::v1::SocialSpendOutput;
 use crate::pb::token::v1::TokenOutput;
 use crate::process_core_log;
 use crate::rewards_decoder::decode_rewards_event;
-use crate::scarces_decoder::decode_scarces_event;
+use crate::scarces_decoder::{decode_auction_updates, decode_scarces_event};
 use crate::social_spend_decoder::decode_social_spend_event;
 use crate::tests::mock_block::MockBlockBuilder;
 use crate::token_decoder::decode_token_events;
@@ -49,6 +49,7 @@ fn run_combined_pipeline(
     let mut rewards_events = Vec::new();
     let mut token_events = Vec::new();
     let mut scarces_events = Vec::new();
+    let mut scarces_auction_updates = Vec::new();
     let mut social_spend_events = Vec::new();
 
     for_each_event_log_multi(block, &contracts, |log| match log.label {
@@ -107,6 +108,13 @@ fn run_combined_pipeline(
             ) {
                 scarces_events.push(event);
             }
+            scarces_auction_updates.extend(decode_auction_updates(
+                log.json_data,
+                &log.receipt_id,
+                ctx.block_height,
+                ctx.block_timestamp,
+                log.log_index,
+            ));
         }
         "social_spend" => {
             if let Some(event) = decode_social_spend_event(
@@ -153,6 +161,7 @@ fn run_combined_pipeline(
         }),
         scarces: Some(ScarcesOutput {
             events: scarces_events,
+            auction_updates: scarces_auction_updates,
             block_height: ctx.block_height,
             block_timestamp: ctx.block_timestamp,
             block_hash: ctx.block_hash.clone(),
@@ -1,4 +1,4 @@
-use crate::core_decoder::decode_onsocial_event;
+use crate::core_decoder::{decode_onsocial_event, decode_onsocial_events};
 
 #[test]
 fn test_decode_nep297_event() {
@@ -76,6 +76,46 @@ fn test_decode_invalid_json() {
     assert!(result.is_err());
 }
 
+/// GROUP_UPDATE add_member encoded by the contract's `EventEncoding::Borsh` path.
+const BORSH_ADD_MEMBER: &str = "EVENT_BORSH:AAgAAABvbnNvY2lhbAUAAAAxLjAuMAwAAABHUk9VUF9VUERBVEUBAAAACgAAAGFkZF9tZW1iZXIKAAAAYWxpY2UubmVhcgEqAAMAAAAIAAAAZ3JvdXBfaWQFCAAAAG15X2dyb3VwBgAAAG1lbWJlcgUIAAAAYm9iLm5lYXIFAAAAbGV2ZWwCAwAAAAAAAAA=";
+
+#[test]
+fn test_decode_borsh_event_matches_json() {
+    let json = r#"{
+        "standard": "onsocial",
+        "version": "1.0.0",
+        "event": "GROUP_UPDATE",
+        "data": [{
+            "operation": "add_member",
+            "author": "alice.near",
+            "partition_id": 42,
+            "group_id": "my_group",
+            "member": "bob.near",
+            "level": 3
+        }]
+    }"#;
+
+    let from_json = decode_onsocial_event(json).unwrap();
+    let from_borsh = decode_onsocial_event(BORSH_ADD_MEMBER).unwrap();
+
+    assert_eq!(from_borsh.standard, from_json.standard);
+    assert_eq!(from_borsh.version, from_json.version);
+    assert_eq!(from_borsh.event, from_json.event);
+    assert_eq!(from_borsh.data.len(), 1);
+
+    let (b, j) = (&from_borsh.data[0], &from_json.data[0]);
+    assert_eq!(b.operation, j.operation);
+    assert_eq!(b.author, j.author);
+    assert_eq!(b.partition_id, j.partition_id);
+    assert_eq!(b.extra, j.extra);
+}
+
+#[test]
+fn test_decode_invalid_borsh() {
+    assert!(decode_onsocial_event("EVENT_BORSH:not base64!").is_err());
+    assert!(decode_onsocial_event("EVENT_BORSH:AQID").is_err());
+}
+
 /// Generate test vectors for documentation
 #[test]
 fn generate_test_vectors() {
@@ -86,3 +126,26 @@ fn generate_test_vectors() {
     println!("\nThis is now standard JSON - parseable by any language!");
     println!("===========================================\n");
 }
+
+#[test]
+fn test_decode_batched_event_array() {
+    let json = r#"[
+        {"standard":"onsocial","version":"1.0.0","event":"DATA_UPDATE","data":[{"operation":"set","author":"alice.near","path":"alice.near/post/a"}]},
+        {"standard":"onsocial","version":"1.0.0","event":"DATA_UPDATE","data":[{"operation":"set","author":"bob.near","path":"bob.near/post/b"}]}
+    ]"#;
+
+    let events = decode_onsocial_events(json).unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].data[0].author, "alice.near");
+    assert_eq!(events[1].data[0].author, "bob.near");
+}
+
+#[test]
+fn test_decode_events_single_object() {
+    let json = r#"{"standard":"onsocial","version":"1.0.0","event":"DATA_UPDATE","data":[]}"#;
+    let events = decode_onsocial_events(json).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, "DATA_UPDATE");
+
+    assert!(decode_onsocial_events("[not json").is_err());
+}
@@ -75,6 +75,21 @@ fn core_data_update_post() {
     assert!(du.id.ends_with("-data"));
 }
 
+#[test]
+fn core_batched_log_keeps_data_ids_unique() {
+    let json = r#"[{"standard":"onsocial","version":"1.0.0","event":"DATA_UPDATE","data":[{"operation":"set","author":"alice.near","path":"alice.near/post/a"}]},{"standard":"onsocial","version":"1.0.0","event":"DATA_UPDATE","data":[{"operation":"set","author":"bob.near","path":"bob.near/post/b"}]}]"#;
+    let block = MockBlockBuilder::new(100, 1000)
+        .add_receipt(CONTRACT, &[1], vec![json])
+        .build();
+
+    let output = run_core_pipeline(&block);
+    assert_eq!(output.data_updates.len(), 2);
+    assert_eq!(output.data_updates[0].account_id, "alice.near");
+    assert_eq!(output.data_updates[1].account_id, "bob.near");
+    assert!(output.data_updates[0].id.ends_with("-0-0-data"));
+    assert!(output.data_updates[1].id.ends_with("-0-1-data"));
+}
+
 #[test]
 fn core_data_update_post_extracts_channel_and_kind() {
     let json = r#"{"standard":"onsocial","version":"1.0.0","event":"DATA_UPDATE","data":[{"operation":"set","author":"alice.near","path":"alice.near/post/main","value":"{\"text\":\"Hello world\",\"channel\":\"engineering\",\"kind\":\"announcement\"}"}]}"#;
@@ -258,6 +273,32 @@ fn core_storage_update_deposit() {
     assert_eq!(su.previous_balance, "0");
     assert_eq!(su.new_balance, "5000000000000000000000");
     assert!(su.id.ends_with("-storage"));
+
+    assert_eq!(su.amount_u128, "5000000000000000000000");
+    assert_eq!(su.previous_balance_u128, "0");
+    assert_eq!(su.new_balance_u128, "5000000000000000000000");
+    assert_eq!(su.numeric_parse_failures, 0);
+}
+
+#[test]
+fn core_storage_update_typed_numeric_fields() {
+    let json = r#"{"standard":"onsocial","version":"1.0.0","event":"STORAGE_UPDATE","data":[{"operation":"share_storage","author":"alice.near","amount":"12abc","bytes":2048,"used_bytes":"512","max_bytes":"-1"}]}"#;
+    let block = MockBlockBuilder::new(100, 1000)
+        .add_receipt(CONTRACT, &[1], vec![json])
+        .build();
+
+    let output = run_core_pipeline(&block);
+    let su = &output.storage_updates[0];
+    // String fields keep the raw values.
+    assert_eq!(su.amount, "12abc");
+    assert_eq!(su.bytes, "2048");
+
+    assert_eq!(su.amount_u128, "0");
+    assert_eq!(su.new_balance_u128, "0");
+    assert_eq!(su.bytes_u64, 2048);
+    assert_eq!(su.used_bytes_u64, 512);
+    assert_eq!(su.max_bytes_u64, 0);
+    assert_eq!(su.numeric_parse_failures, 2);
 }
 
 #[test]
@@ -143,6 +143,7 @@ fn run_scarces(block: &substreams_near::pb::sf::near::r#type::v1::Block) -> Scar
 
     ScarcesOutput {
         events,
+        auction_updates: Vec::new(),
         block_height: ctx.block_height,
         block_timestamp: ctx.block_timestamp,
         block_hash: ctx.block_hash,
@@ -1,4 +1,4 @@
-use crate::scarces_decoder::decode_scarces_event;
+use crate::scarces_decoder::{decode_auction_updates, decode_scarces_event};
 
 // ─── Event Type Filtering ───────────────────────────────────────────
 
@@ -268,3 +268,65 @@ fn test_numeric_string_coercion() {
     let event = decode_scarces_event(json, "r", 1, 1, 0).unwrap();
     assert_eq!(event.total_supply, 50);
 }
+
+// ─── Auction Updates ──────────────────────────────────────────────
+
+#[test]
+fn test_auction_created_update() {
+    let json = r#"{"standard":"onsocial","version":"1.0.0","event":"SCARCE_UPDATE","data":[{"operation":"auction_created","author":"alice.near","owner_id":"alice.near","token_id":"t1","reserve_price":"1000","buy_now_price":"5000","expires_at":9000}]}"#;
+    let updates = decode_auction_updates(json, "rcpt", 10, 1000, 2);
+    assert_eq!(updates.len(), 1);
+    let u = &updates[0];
+    assert_eq!(u.id, "rcpt-2-0-auction");
+    assert_eq!(u.auction_id, "t1");
+    assert_eq!(u.seller, "alice.near");
+    assert_eq!(u.reserve_price, "1000");
+    assert_eq!(u.buy_now_price, "5000");
+    assert_eq!(u.start_time, 1000);
+    assert_eq!(u.end_time, 9000);
+    assert_eq!(u.status, "active");
+}
+
+#[test]
+fn test_auction_bid_update_uses_extended_expiry() {
+    let json = r#"{"standard":"onsocial","version":"1.0.0","event":"SCARCE_UPDATE","data":[{"operation":"auction_bid","author":"bob.near","bidder":"bob.near","token_id":"t1","bid_amount":"1500","bid_count":3,"new_expires_at":12000}]}"#;
+    let u = &decode_auction_updates(json, "r", 1, 1000, 0)[0];
+    assert_eq!(u.highest_bidder, "bob.near");
+    assert_eq!(u.highest_bid, "1500");
+    assert_eq!(u.bid_count, 3);
+    assert_eq!(u.start_time, 0);
+    assert_eq!(u.end_time, 12000);
+    assert_eq!(u.status, "active");
+}
+
+#[test]
+fn test_auction_terminal_statuses() {
+    let settled = r#"{"standard":"onsocial","version":"1.0.0","event":"SCARCE_UPDATE","data":[{"operation":"auction_settled","author":"bob.near","winner_id":"bob.near","seller_id":"alice.near","token_id":"t1","winning_bid":"2000"}]}"#;
+    let u = &decode_auction_updates(settled, "r", 1, 7000, 0)[0];
+    assert_eq!(u.status, "settled");
+    assert_eq!(u.seller, "alice.near");
+    assert_eq!(u.highest_bidder, "bob.near");
+    assert_eq!(u.highest_bid, "2000");
+    assert_eq!(u.end_time, 7000);
+
+    let unsold = r#"{"standard":"onsocial","version":"1.0.0","event":"SCARCE_UPDATE","data":[{"operation":"auction_unsold","author":"alice.near","seller_id":"alice.near","token_id":"t1","reserve_price":"1000","highest_bid":"500"}]}"#;
+    let u = &decode_auction_updates(unsold, "r", 1, 7000, 0)[0];
+    assert_eq!(u.status, "unsold");
+    assert_eq!(u.highest_bid, "500");
+
+    let cancelled = r#"{"standard":"onsocial","version":"1.0.0","event":"SCARCE_UPDATE","data":[{"operation":"auction_cancelled","author":"alice.near","actor_id":"alice.near","token_id":"t1","reason":"owner_cancelled"}]}"#;
+    let u = &decode_auction_updates(cancelled, "r", 1, 7000, 0)[0];
+    assert_eq!(u.status, "cancelled");
+    assert_eq!(u.reason, "owner_cancelled");
+}
+
+#[test]
+fn test_auction_updates_skip_non_auction_events() {
+    let list = r#"{"standard":"onsocial","version":"1.0.0","event":"SCARCE_UPDATE","data":[{"operation":"list","author":"a"}]}"#;
+    assert!(decode_auction_updates(list, "r", 1, 1, 0).is_empty());
+
+    let offer = r#"{"standard":"onsocial","version":"1.0.0","event":"OFFER_UPDATE","data":[{"operation":"auction_bid","author":"a"}]}"#;
+    assert!(decode_auction_updates(offer, "r", 1, 1, 0).is_empty());
+
+    assert!(decode_auction_updates("not json", "r", 1, 1, 0).is_empty());
+}
@@ -4,7 +4,7 @@
 
 use crate::block_walker::{block_context, for_each_event_log};
 use crate::pb::scarces::v1::ScarcesOutput;
-use crate::scarces_decoder::decode_scarces_event;
+use crate::scarces_decoder::{decode_auction_updates, decode_scarces_event};
 use crate::tests::mock_block::MockBlockBuilder;
 
 const CONTRACT: &str = "scarces.onsocial.near";
@@ -12,6 +12,7 @@ const CONTRACT: &str = "scarces.onsocial.near";
 fn run_scarces_pipeline(block: &substreams_near::pb::sf::near::r#type::v1::Block) -> ScarcesOutput {
     let ctx = block_context(block);
     let mut events = Vec::new();
+    let mut auction_updates = Vec::new();
 
     for_each_event_log(block, Some(CONTRACT), |log| {
         if let Some(event) = decode_scarces_event(
@@ -23,10 +24,18 @@ fn run_scarces_pipeline(block: &substreams_near::pb::sf::near::r#type::v1::Block
         ) {
             events.push(event);
         }
+        auction_updates.extend(decode_auction_updates(
+            log.json_data,
+            &log.receipt_id,
+            ctx.block_height,
+            ctx.block_timestamp,
+            log.log_index,
+        ));
     });
 
     ScarcesOutput {
         events,
+        auction_updates,
         block_height: ctx.block_height,
         block_timestamp: ctx.block_timestamp,
         block_hash: ctx.block_hash,
@@ -84,6 +93,23 @@ fn scarces_auction_created_full_pipeline() {
     assert_eq!(e.anti_snipe_extension_ns, 300_000_000_000);
 }
 
+#[test]
+fn scarces_auction_lifecycle_emits_auction_updates() {
+    let created = r#"{"standard":"onsocial","version":"1.0.0","event":"SCARCE_UPDATE","data":[{"operation":"auction_created","author":"alice.near","owner_id":"alice.near","token_id":"t1","reserve_price":"1000","expires_at":5000}]}"#;
+    let bid = r#"{"standard":"onsocial","version":"1.0.0","event":"SCARCE_UPDATE","data":[{"operation":"auction_bid","author":"bob.near","bidder":"bob.near","token_id":"t1","bid_amount":"1500","bid_count":1}]}"#;
+    let listed = r#"{"standard":"onsocial","version":"1.0.0","event":"SCARCE_UPDATE","data":[{"operation":"list","author":"alice.near","owner_id":"alice.near"}]}"#;
+    let block = MockBlockBuilder::new(100, 1000)
+        .add_receipt(CONTRACT, &[1], vec![created, bid, listed])
+        .build();
+
+    let output = run_scarces_pipeline(&block);
+    assert_eq!(output.events.len(), 3);
+    assert_eq!(output.auction_updates.len(), 2);
+    assert_eq!(output.auction_updates[0].operation, "auction_created");
+    assert_eq!(output.auction_updates[1].highest_bidder, "bob.near");
+    assert_eq!(output.auction_updates[1].highest_bid, "1500");
+}
+
 #[test]
 fn scarces_collection_purchase_full_pipeline() {
     let json = r#"{"standard":"onsocial","version":"1.0.0","event":"COLLECTION_UPDATE","data":[{"operation":"purchase","author":"buyer.near","buyer_id":"buyer.near","creator_id":"creator.near","collection_id":"col-1","quantity":2,"total_price":"2000","marketplace_fee":"100","app_pool_amount":"50","app_commission":"25","token_ids":["t1","t2"]}]}"#;
@@ -64,40 +64,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let listener = tokio::net::TcpListener::bind(&bind_address).await?;
 
     axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
+        .with_graceful_shutdown(shutdown_signal(state.clone()))
         .await?;
 
     // --- Graceful shutdown: drain TXs, persist local delegate keys ---
     info!("HTTP server stopped, draining in-flight transactions...");
-
-    let drain_deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(30);
-    loop {
-        let in_flight = state.key_pool.delegate_total_in_flight();
-        if in_flight == 0 {
-            info!("All in-flight transactions drained");
-            break;
-        }
-        if tokio::time::Instant::now() >= drain_deadline {
-            warn!(
-                remaining = in_flight,
-                "Drain timeout — some TXs may be lost"
-            );
-            break;
-        }
-        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-    }
-
-    if let Err(e) = state.key_pool.persist_keys_public() {
-        error!(error = %e, "Failed to persist key store on shutdown");
-    } else {
-        info!("Key store persisted to disk");
-    }
+    state.shutdown().await;
 
     info!("Relayer shut down gracefully");
     Ok(())
 }
 
-async fn shutdown_signal() {
+/// Resolves on SIGINT/SIGTERM. Lanes start draining immediately so requests
+/// still reaching the server fail fast instead of taking a nonce.
+async fn shutdown_signal(state: Arc<AppState>) {
     let ctrl_c = async {
         tokio::signal::ctrl_c()
             .await
@@ -119,4 +99,5 @@ async fn shutdown_signal() {
         _ = ctrl_c => info!("Received SIGINT, shutting down..."),
         _ = terminate => info!("Received SIGTERM, shutting down..."),
     }
+    state.key_pool.begin_drain();
 }
@@ -1,6 +1,7 @@
 //! Relayer configuration.
 
 use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
 
 /// Signing backend.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Default)]
@@ -19,6 +20,11 @@ pub struct Config {
     #[serde(default = "defaults::fallback_rpc_url")]
     pub fallback_rpc_url: String,
 
+    /// Preferred RPC endpoint per receiver account, tried before the
+    /// primary/fallback pool (e.g. a node with a hot contract's state warm).
+    #[serde(default)]
+    pub receiver_rpc_urls: HashMap<String, String>,
+
     #[serde(default = "defaults::relayer_account_id")]
     pub relayer_account_id: String,
 
@@ -46,6 +52,15 @@ pub struct Config {
     #[serde(default = "defaults::delegate_pool_size")]
     pub delegate_pool_size: u32,
 
+    /// `/ready` reports 503 while average in-flight TXs per active delegate
+    /// lane exceeds this.
+    #[serde(default = "defaults::ready_max_per_key_load")]
+    pub ready_max_per_key_load: f32,
+
+    /// How long shutdown waits for in-flight TXs before persisting keys.
+    #[serde(default = "defaults::drain_timeout_secs")]
+    pub drain_timeout_secs: u64,
+
     #[serde(default)]
     pub signer_mode: SignerMode,
 
@@ -61,8 +76,14 @@ pub struct Config {
     #[serde(default = "defaults::gcp_kms_admin_key")]
     pub gcp_kms_admin_key: String,
 
-    #[serde(default = "defaults::allowed_methods")]
-    pub allowed_methods: Vec<String>,
+    /// Inner FunctionCall methods accepted on delegates, with per-call caps.
+    /// Entries are bare names (default caps) or `{ name, max_gas_tgas,
+    /// max_deposit_yocto }` tables.
+    #[serde(
+        default = "defaults::allowed_methods",
+        deserialize_with = "deserialize_allowed_methods"
+    )]
+    pub allowed_methods: Vec<MethodPolicy>,
 
     /// Rewards contract used by the private service relay endpoint.
     #[serde(default = "defaults::rewards_contract_id")]
@@ -85,6 +106,7 @@ impl Default for Config {
         Self {
             rpc_url: defaults::rpc_url(),
             fallback_rpc_url: defaults::fallback_rpc_url(),
+            receiver_rpc_urls: HashMap::new(),
             relayer_account_id: defaults::relayer_account_id(),
             instance_name: defaults::instance_name(),
             keys_path: defaults::keys_path(),
@@ -93,6 +115,8 @@ impl Default for Config {
             admin_key_path: defaults::admin_key_path(),
             delegate_store_path: defaults::delegate_store_path(),
             delegate_pool_size: defaults::delegate_pool_size(),
+            ready_max_per_key_load: defaults::ready_max_per_key_load(),
+            drain_timeout_secs: defaults::drain_timeout_secs(),
             signer_mode: SignerMode::default(),
             gcp_kms_project: defaults::gcp_kms_project(),
             gcp_kms_location: defaults::gcp_kms_location(),
@@ -106,6 +130,95 @@ impl Default for Config {
     }
 }
 
+/// Gas cap for methods configured by name only (protocol max per receipt).
+pub const DEFAULT_METHOD_MAX_GAS_TGAS: u64 = 300;
+/// Deposit cap for methods configured by name only: the 1-yocto confirmation.
+pub const DEFAULT_METHOD_MAX_DEPOSIT_YOCTO: u128 = 1;
+
+/// An allowed inner FunctionCall method and the most it may request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodPolicy {
+    pub name: String,
+    pub max_gas_tgas: u64,
+    pub max_deposit_yocto: u128,
+}
+
+impl From<&str> for MethodPolicy {
+    fn from(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            max_gas_tgas: DEFAULT_METHOD_MAX_GAS_TGAS,
+            max_deposit_yocto: DEFAULT_METHOD_MAX_DEPOSIT_YOCTO,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MethodPolicyInput {
+    Name(String),
+    Detailed {
+        name: String,
+        #[serde(default)]
+        max_gas_tgas: Option<u64>,
+        /// String because TOML integers cannot hold a u128.
+        #[serde(default)]
+        max_deposit_yocto: Option<String>,
+    },
+}
+
+impl MethodPolicyInput {
+    fn into_policy<E: serde::de::Error>(self) -> Result<MethodPolicy, E> {
+        match self {
+            MethodPolicyInput::Name(name) => Ok(MethodPolicy::from(name.trim())),
+            MethodPolicyInput::Detailed {
+                name,
+                max_gas_tgas,
+                max_deposit_yocto,
+            } => {
+                let mut policy = MethodPolicy::from(name.trim());
+                if let Some(max_gas_tgas) = max_gas_tgas {
+                    policy.max_gas_tgas = max_gas_tgas;
+                }
+                if let Some(deposit) = max_deposit_yocto {
+                    policy.max_deposit_yocto = deposit.trim().parse().map_err(|_| {
+                        E::custom(format!(
+                            "max_deposit_yocto for {} must be a decimal u128 string",
+                            policy.name
+                        ))
+                    })?;
+                }
+                Ok(policy)
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AllowedMethodsInput {
+    List(Vec<MethodPolicyInput>),
+    String(String),
+}
+
+fn deserialize_allowed_methods<'de, D>(deserializer: D) -> Result<Vec<MethodPolicy>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match AllowedMethodsInput::deserialize(deserializer)? {
+        AllowedMethodsInput::List(methods) => methods
+            .into_iter()
+            .map(MethodPolicyInput::into_policy)
+            .collect(),
+        AllowedMethodsInput::String(methods) => Ok(methods
+            .split(',')
+            .map(str::trim)
+            .filter(|method| !method.is_empty())
+            .map(MethodPolicy::from)
+            .collect()),
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(untagged)]
 enum AllowedContractsInput {
@@ -241,6 +354,20 @@ mod defaults {
             .max(1)
     }
 
+    pub fn ready_max_per_key_load() -> f32 {
+        std::env::var("RELAYER_READY_MAX_PER_KEY_LOAD")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(8.0)
+    }
+
+    pub fn drain_timeout_secs() -> u64 {
+        std::env::var("RELAYER_DRAIN_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(30)
+    }
+
     pub fn gcp_kms_project() -> String {
         std::env::var("GCP_KMS_PROJECT").unwrap_or_default()
     }
@@ -257,7 +384,7 @@ mod defaults {
         std::env::var("GCP_KMS_ADMIN_KEY").unwrap_or_else(|_| "admin-key".into())
     }
 
-    pub fn allowed_methods() -> Vec<String> {
+    pub fn allowed_methods() -> Vec<super::MethodPolicy> {
         vec!["execute".into(), "execute_admin".into()]
     }
 
@@ -454,4 +581,45 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn config_parses_allowed_methods_with_and_without_caps() {
+        let config: super::Config = serde_json::from_value(serde_json::json!({
+            "allowed_methods": [
+                "execute",
+                { "name": "execute_admin", "max_gas_tgas": 50, "max_deposit_yocto": "0" }
+            ]
+        }))
+        .expect("config should deserialize");
+
+        assert_eq!(
+            config.allowed_methods,
+            vec![
+                super::MethodPolicy::from("execute"),
+                super::MethodPolicy {
+                    name: "execute_admin".to_string(),
+                    max_gas_tgas: 50,
+                    max_deposit_yocto: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn config_parses_receiver_rpc_urls() {
+        let config: super::Config = serde_json::from_value(serde_json::json!({
+            "receiver_rpc_urls": {
+                "scarces.onsocial.near": "https://scarces-rpc.example.com"
+            }
+        }))
+        .expect("config should deserialize");
+
+        assert_eq!(
+            config
+                .receiver_rpc_urls
+                .get("scarces.onsocial.near")
+                .map(String::as_str),
+            Some("https://scarces-rpc.example.com")
+        );
+    }
 }
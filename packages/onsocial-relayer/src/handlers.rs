@@ -1,9 +1,13 @@
 //! HTTP request handlers.
 
+use crate::config::MethodPolicy;
 use crate::key_pool::FullAccessTxOutcome;
 use crate::metrics::METRICS;
 use crate::middleware::RequestId;
-use crate::response::{ExecuteResponse, HealthResponse, KeyPoolStats, TxStatusResponse};
+use crate::response::{
+    ExecuteResponse, HealthResponse, KeyPoolStats, SimulateResponse, TxStatusResponse,
+};
+use crate::rpc::ChainView;
 use crate::state::AppState;
 use crate::Error;
 use axum::extract::{FromRequest, Path, Query, State};
@@ -12,10 +16,12 @@ use axum::response::IntoResponse;
 use axum::Json;
 use base64::{engine::general_purpose::STANDARD as B64, Engine as _};
 use near_gas::NearGas;
+use near_primitives::action::delegate::SignedDelegateAction;
+use near_primitives::borsh::BorshDeserialize;
 use near_primitives::hash::CryptoHash;
 use near_primitives::transaction::{Action, FunctionCallAction, TransferAction};
 use near_primitives::types::AccountId;
-use near_primitives::views::FinalExecutionStatus;
+use near_primitives::views::{AccessKeyPermissionView, FinalExecutionStatus};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::atomic::Ordering;
@@ -30,31 +36,96 @@ pub struct ExecuteParams {
     pub wait: bool,
 }
 
-const MAX_DELEGATE_INNER_DEPOSIT_YOCTO: u128 = 1;
-
 fn validate_delegate_inner_action(
     action: &Action,
-    allowed_methods: &[String],
+    allowed_methods: &[MethodPolicy],
 ) -> Result<(), String> {
     let fc = match action {
         Action::FunctionCall(fc) => fc.as_ref(),
         _ => return Err("Only FunctionCall inner actions are allowed".to_string()),
     };
 
-    if !allowed_methods.iter().any(|m| m == &fc.method_name) {
+    let Some(policy) = allowed_methods.iter().find(|m| m.name == fc.method_name) else {
         return Err(format!("Inner method not allowed: {}", fc.method_name));
+    };
+
+    let max_gas = NearGas::from_tgas(policy.max_gas_tgas).as_gas();
+    if fc.gas > max_gas {
+        return Err(format!(
+            "Inner gas {} exceeds max {max_gas} for {}",
+            fc.gas, policy.name
+        ));
     }
 
-    if fc.deposit > MAX_DELEGATE_INNER_DEPOSIT_YOCTO {
+    if fc.deposit > policy.max_deposit_yocto {
         return Err(format!(
-            "Inner action deposit exceeds max {MAX_DELEGATE_INNER_DEPOSIT_YOCTO} yoctoNEAR"
+            "Inner action deposit exceeds max {} yoctoNEAR",
+            policy.max_deposit_yocto
         ));
     }
 
     Ok(())
 }
 
-/// Readiness probe. 200 once pool has active keys.
+/// Decodes a base64 `SignedDelegateAction` and applies the relay policy:
+/// valid user signature, allowlisted receiver, allowlisted FunctionCalls.
+fn check_signed_delegate(
+    encoded: &str,
+    allowed_contracts: &[AccountId],
+    allowed_methods: &[MethodPolicy],
+) -> Result<SignedDelegateAction, (StatusCode, String)> {
+    let bytes = B64.decode(encoded.as_bytes()).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            "signed_delegate is not valid base64".to_string(),
+        )
+    })?;
+
+    let signed_delegate = SignedDelegateAction::try_from_slice(&bytes).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            "signed_delegate is not a valid borsh SignedDelegateAction".to_string(),
+        )
+    })?;
+
+    // Verify user signature locally so we don't waste a relayer nonce on a
+    // doomed tx (the protocol re-verifies on-chain).
+    if !signed_delegate.verify() {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            "Invalid signature on SignedDelegateAction".to_string(),
+        ));
+    }
+
+    let inner_receiver = &signed_delegate.delegate_action.receiver_id;
+    if !allowed_contracts.contains(inner_receiver) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Inner receiver not allowed: {inner_receiver}"),
+        ));
+    }
+
+    // Sessions only submit allowlisted FunctionCalls. Permit the standard
+    // 1-yocto confirmation deposit, but reject value-bearing deposits so a
+    // stolen session key cannot be coerced into spending user funds through
+    // the relayer.
+    if signed_delegate.delegate_action.actions.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Delegate must contain at least one action".to_string(),
+        ));
+    }
+    for nda in &signed_delegate.delegate_action.actions {
+        let action: Action = nda.clone().into();
+        validate_delegate_inner_action(&action, allowed_methods)
+            .map_err(|message| (StatusCode::BAD_REQUEST, message))?;
+    }
+
+    Ok(signed_delegate)
+}
+
+/// Readiness probe. 200 once the pool is bootstrapped, 503 again while it
+/// is saturated so load balancers route elsewhere.
 pub async fn ready(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     if !state.ready.load(Ordering::Relaxed)
         && state.key_pool.active_delegate_count() >= state.config.delegate_pool_size.max(1) as usize
@@ -62,7 +133,11 @@ pub async fn ready(State(state): State<Arc<AppState>>) -> impl IntoResponse {
         state.ready.store(true, Ordering::Relaxed);
     }
 
-    if state.ready.load(Ordering::Relaxed) {
+    if state.ready.load(Ordering::Relaxed)
+        && !state
+            .key_pool
+            .is_saturated(state.config.ready_max_per_key_load)
+    {
         StatusCode::OK
     } else {
         StatusCode::SERVICE_UNAVAILABLE
@@ -71,10 +146,13 @@ pub async fn ready(State(state): State<Arc<AppState>>) -> impl IntoResponse {
 
 /// Prometheus metrics.
 pub async fn metrics(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let pool = &state.key_pool;
     let body = METRICS.render(
-        state.key_pool.active_delegate_count(),
+        pool.active_delegate_count(),
         0,
-        state.key_pool.delegate_total_in_flight(),
+        pool.draining_delegate_count(),
+        pool.delegate_total_in_flight(),
+        pool.delegate_per_key_load(),
     );
     (
         [(
@@ -124,10 +202,11 @@ pub async fn health(State(state): State<Arc<AppState>>) -> impl IntoResponse {
         active_rpc: state.rpc.active_url().to_string(),
         failovers: state.rpc.failover_count(),
         rpc_status,
+        rpc_endpoints: state.rpc.endpoint_health(),
         key_pool: KeyPoolStats {
             active_keys: pool.active_delegate_count(),
             warm_keys: 0,
-            draining_keys: 0,
+            draining_keys: pool.draining_delegate_count(),
             total_in_flight: pool.delegate_total_in_flight(),
             per_key_load: pool.delegate_per_key_load(),
             per_contract: std::collections::HashMap::new(),
@@ -196,10 +275,6 @@ pub async fn execute_delegate(
     Query(params): Query<ExecuteParams>,
     request_parts: axum::extract::Request,
 ) -> (StatusCode, Json<ExecuteResponse>) {
-    use base64::{engine::general_purpose::STANDARD as B64, Engine as _};
-    use near_primitives::action::delegate::SignedDelegateAction;
-    use near_primitives::borsh::BorshDeserialize;
-
     let start = std::time::Instant::now();
     METRICS.tx_total.fetch_add(1, Ordering::Relaxed);
     state.request_count.fetch_add(1, Ordering::Relaxed);
@@ -226,102 +301,22 @@ pub async fn execute_delegate(
             }
         };
 
-    let bytes = match B64.decode(body.signed_delegate.as_bytes()) {
-        Ok(b) => b,
-        Err(e) => {
-            METRICS.tx_error.fetch_add(1, Ordering::Relaxed);
-            warn!(req_id = %req_id, error = %e, "signed_delegate base64 decode failed");
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ExecuteResponse::err(
-                    "signed_delegate is not valid base64",
-                    None,
-                )),
-            );
-        }
-    };
-
-    let signed_delegate: SignedDelegateAction = match SignedDelegateAction::try_from_slice(&bytes) {
+    let signed_delegate = match check_signed_delegate(
+        &body.signed_delegate,
+        &state.allowed_contracts,
+        &state.allowed_methods,
+    ) {
         Ok(sd) => sd,
-        Err(e) => {
+        Err((status, message)) => {
             METRICS.tx_error.fetch_add(1, Ordering::Relaxed);
-            warn!(req_id = %req_id, error = %e, "signed_delegate borsh decode failed");
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ExecuteResponse::err(
-                    "signed_delegate is not a valid borsh SignedDelegateAction",
-                    None,
-                )),
-            );
+            warn!(req_id = %req_id, error = %message, "delegate rejected");
+            return (status, Json(ExecuteResponse::err(message, None)));
         }
     };
 
-    // Verify user signature locally so we don't waste a relayer nonce on a
-    // doomed tx (the protocol re-verifies on-chain).
-    if !signed_delegate.verify() {
-        METRICS.tx_error.fetch_add(1, Ordering::Relaxed);
-        warn!(req_id = %req_id, "delegate signature verification failed");
-        return (
-            StatusCode::UNAUTHORIZED,
-            Json(ExecuteResponse::err(
-                "Invalid signature on SignedDelegateAction",
-                None,
-            )),
-        );
-    }
-
     let inner_receiver = signed_delegate.delegate_action.receiver_id.clone();
     let inner_sender = signed_delegate.delegate_action.sender_id.clone();
 
-    if !state.allowed_contracts.contains(&inner_receiver) {
-        METRICS.tx_error.fetch_add(1, Ordering::Relaxed);
-        warn!(
-            req_id = %req_id,
-            receiver = %inner_receiver,
-            "delegate inner receiver not in allowlist"
-        );
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ExecuteResponse::err(
-                format!("Inner receiver not allowed: {inner_receiver}"),
-                None,
-            )),
-        );
-    }
-
-    // ── Inner-action shape check ────────────────────────────────────────
-    // Sessions only submit allowlisted FunctionCalls. Permit the standard
-    // 1-yocto confirmation deposit, but reject value-bearing deposits so a
-    // stolen session key cannot be coerced into spending user funds through
-    // the relayer.
-    if signed_delegate.delegate_action.actions.is_empty() {
-        METRICS.tx_error.fetch_add(1, Ordering::Relaxed);
-        warn!(req_id = %req_id, "delegate has no inner actions");
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ExecuteResponse::err(
-                "Delegate must contain at least one action",
-                None,
-            )),
-        );
-    }
-    for nda in &signed_delegate.delegate_action.actions {
-        let action: Action = nda.clone().into();
-        if let Err(message) = validate_delegate_inner_action(&action, &state.allowed_methods) {
-            METRICS.tx_error.fetch_add(1, Ordering::Relaxed);
-            warn!(
-                req_id = %req_id,
-                error = %message,
-                allowed = ?state.allowed_methods,
-                "delegate inner action rejected"
-            );
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ExecuteResponse::err(message, None)),
-            );
-        }
-    }
-
     info!(
         req_id = %req_id,
         sender = %inner_sender,
@@ -361,6 +356,135 @@ pub async fn execute_delegate(
     full_access_tx_response(&req_id, "delegate", submitted)
 }
 
+// ---------------------------------------------------------------------------
+// /simulate — dry-run of /execute_delegate.
+//
+// Body: { "signed_delegate": "<base64 borsh SignedDelegateAction>" }
+//
+// Applies the same relay policy as /execute_delegate, then checks the
+// delegate against the chain: expiry, the user's access key, its nonce and
+// function-call permission. Nothing is signed or broadcast and no signer
+// lane is acquired. `estimated_gas` is the gas attached by the inner calls,
+// i.e. the most the user's call can burn.
+// ---------------------------------------------------------------------------
+pub async fn simulate(
+    State(state): State<Arc<AppState>>,
+    request_parts: axum::extract::Request,
+) -> (StatusCode, Json<SimulateResponse>) {
+    state.request_count.fetch_add(1, Ordering::Relaxed);
+
+    let req_id = request_parts
+        .extensions()
+        .get::<RequestId>()
+        .map(|r| r.0.clone())
+        .unwrap_or_default();
+
+    let body: ExecuteDelegateBody =
+        match axum::Json::<ExecuteDelegateBody>::from_request(request_parts, &state).await {
+            Ok(axum::Json(v)) => v,
+            Err(e) => {
+                warn!(req_id = %req_id, error = %e, "Invalid simulate body");
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(SimulateResponse::err(
+                        "Body must be { signed_delegate: <base64> }",
+                    )),
+                );
+            }
+        };
+
+    let signed_delegate = match check_signed_delegate(
+        &body.signed_delegate,
+        &state.allowed_contracts,
+        &state.allowed_methods,
+    ) {
+        Ok(sd) => sd,
+        Err((status, message)) => {
+            warn!(req_id = %req_id, error = %message, "simulated delegate rejected");
+            return (status, Json(SimulateResponse::err(message)));
+        }
+    };
+
+    match simulate_delegate(&state.rpc, &signed_delegate).await {
+        Ok(report) => (StatusCode::OK, Json(report)),
+        Err(e) => {
+            error!(req_id = %req_id, error = %e, "Simulation RPC error");
+            (
+                StatusCode::BAD_GATEWAY,
+                Json(SimulateResponse::err("RPC temporarily unavailable")),
+            )
+        }
+    }
+}
+
+/// Predicts the runtime's delegate checks from the chain head and the
+/// sender's access key. `Err` only when the chain could not be read.
+async fn simulate_delegate<R: ChainView>(
+    rpc: &R,
+    signed_delegate: &SignedDelegateAction,
+) -> Result<SimulateResponse, Error> {
+    let delegate = &signed_delegate.delegate_action;
+    let calls: Vec<Box<FunctionCallAction>> = delegate
+        .actions
+        .iter()
+        .filter_map(|nda| match Action::from(nda.clone()) {
+            Action::FunctionCall(fc) => Some(fc),
+            _ => None,
+        })
+        .collect();
+    let estimated_gas = calls.iter().map(|fc| fc.gas).sum();
+    let fail = |error: String| Ok(SimulateResponse::predicted_failure(estimated_gas, error));
+
+    let (_, height) = rpc.latest_block().await?;
+    if delegate.max_block_height < height {
+        return fail(format!(
+            "Delegate expired at block {} (current {height})",
+            delegate.max_block_height
+        ));
+    }
+
+    let Some(access_key) = rpc
+        .find_access_key(&delegate.sender_id, &delegate.public_key)
+        .await?
+    else {
+        return fail(format!(
+            "Access key {} not found on {}",
+            delegate.public_key, delegate.sender_id
+        ));
+    };
+
+    if delegate.nonce <= access_key.nonce {
+        return fail(format!(
+            "Nonce {} already used (access key nonce {})",
+            delegate.nonce, access_key.nonce
+        ));
+    }
+
+    if let AccessKeyPermissionView::FunctionCall {
+        receiver_id,
+        method_names,
+        ..
+    } = &access_key.permission
+    {
+        if receiver_id != delegate.receiver_id.as_str() {
+            return fail(format!("Access key only allows calls to {receiver_id}"));
+        }
+        for fc in &calls {
+            if fc.deposit > 0 {
+                return fail("Function-call access keys cannot attach a deposit".to_string());
+            }
+            if !method_names.is_empty() && !method_names.contains(&fc.method_name) {
+                return fail(format!(
+                    "Access key does not allow method {}",
+                    fc.method_name
+                ));
+            }
+        }
+    }
+
+    Ok(SimulateResponse::ok(estimated_gas))
+}
+
 // ---------------------------------------------------------------------------
 // /execute_rewards — private service relay for rewards contract actions.
 //
@@ -926,6 +1050,171 @@ pub async fn latest_block(State(state): State<Arc<AppState>>) -> impl IntoRespon
 #[cfg(test)]
 mod tests {
     use super::*;
+    use near_crypto::{InMemorySigner, KeyType, PublicKey, Signer};
+    use near_primitives::action::delegate::{DelegateAction, NonDelegateAction};
+    use near_primitives::views::AccessKeyView;
+
+    const CALL_GAS: u64 = 30_000_000_000_000;
+
+    struct MockChain {
+        height: u64,
+        access_key: Option<AccessKeyView>,
+        reachable: bool,
+    }
+
+    impl ChainView for MockChain {
+        async fn latest_block(&self) -> Result<(CryptoHash, u64), Error> {
+            if !self.reachable {
+                return Err(Error::Rpc("connection refused".into()));
+            }
+            Ok((CryptoHash::default(), self.height))
+        }
+
+        async fn find_access_key(
+            &self,
+            _account_id: &AccountId,
+            _public_key: &PublicKey,
+        ) -> Result<Option<AccessKeyView>, Error> {
+            Ok(self.access_key.clone())
+        }
+    }
+
+    fn session_key(nonce: u64) -> Option<AccessKeyView> {
+        Some(AccessKeyView {
+            nonce,
+            permission: AccessKeyPermissionView::FunctionCall {
+                allowance: None,
+                receiver_id: "core.onsocial.testnet".to_string(),
+                method_names: vec!["execute".to_string()],
+            },
+        })
+    }
+
+    fn signed_delegate(nonce: u64, max_block_height: u64) -> SignedDelegateAction {
+        let signer: Signer =
+            InMemorySigner::from_seed("alice.testnet".parse().unwrap(), KeyType::ED25519, "alice");
+        let call = Action::FunctionCall(Box::new(FunctionCallAction {
+            method_name: "execute".to_string(),
+            args: vec![],
+            gas: CALL_GAS,
+            deposit: 0,
+        }));
+        let Ok(call) = NonDelegateAction::try_from(call) else {
+            panic!("FunctionCall is a non-delegate action");
+        };
+        let delegate_action = DelegateAction {
+            sender_id: "alice.testnet".parse().unwrap(),
+            receiver_id: "core.onsocial.testnet".parse().unwrap(),
+            actions: vec![call],
+            nonce,
+            max_block_height,
+            public_key: signer.public_key(),
+        };
+        let signature = signer.sign(delegate_action.get_nep461_hash().as_ref());
+        SignedDelegateAction {
+            delegate_action,
+            signature,
+        }
+    }
+
+    fn encode(signed_delegate: &SignedDelegateAction) -> String {
+        B64.encode(near_primitives::borsh::to_vec(signed_delegate).unwrap())
+    }
+
+    #[test]
+    fn signed_delegate_check_accepts_valid_signature() {
+        let allowed_contracts = ["core.onsocial.testnet".parse().unwrap()];
+
+        let checked = check_signed_delegate(
+            &encode(&signed_delegate(6, 200)),
+            &allowed_contracts,
+            &["execute".into()],
+        )
+        .unwrap();
+
+        assert_eq!(checked.delegate_action.nonce, 6);
+    }
+
+    #[test]
+    fn signed_delegate_check_rejects_tampered_payload() {
+        let allowed_contracts = ["core.onsocial.testnet".parse().unwrap()];
+        let mut tampered = signed_delegate(6, 200);
+        tampered.delegate_action.max_block_height = 10_000;
+
+        let (status, message) =
+            check_signed_delegate(&encode(&tampered), &allowed_contracts, &["execute".into()])
+                .unwrap_err();
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(message.contains("Invalid signature"), "{message}");
+    }
+
+    #[tokio::test]
+    async fn simulate_reports_gas_for_valid_delegate() {
+        let chain = MockChain {
+            height: 100,
+            access_key: session_key(5),
+            reachable: true,
+        };
+
+        let report = simulate_delegate(&chain, &signed_delegate(6, 200))
+            .await
+            .unwrap();
+
+        assert!(report.success);
+        assert_eq!(report.estimated_gas, Some(CALL_GAS));
+        assert!(report.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn simulate_predicts_used_nonce_and_expiry() {
+        let chain = MockChain {
+            height: 100,
+            access_key: session_key(5),
+            reachable: true,
+        };
+
+        let reused = simulate_delegate(&chain, &signed_delegate(5, 200))
+            .await
+            .unwrap();
+        assert!(!reused.success);
+        assert_eq!(reused.estimated_gas, Some(CALL_GAS));
+        assert_eq!(
+            reused.error.as_deref(),
+            Some("Nonce 5 already used (access key nonce 5)")
+        );
+
+        let expired = simulate_delegate(&chain, &signed_delegate(6, 99))
+            .await
+            .unwrap();
+        assert_eq!(
+            expired.error.as_deref(),
+            Some("Delegate expired at block 99 (current 100)")
+        );
+    }
+
+    #[tokio::test]
+    async fn simulate_predicts_missing_key_and_surfaces_rpc_errors() {
+        let missing = MockChain {
+            height: 100,
+            access_key: None,
+            reachable: true,
+        };
+        let report = simulate_delegate(&missing, &signed_delegate(1, 200))
+            .await
+            .unwrap();
+        assert!(report.error.unwrap().starts_with("Access key ed25519:"));
+
+        let down = MockChain {
+            height: 100,
+            access_key: session_key(0),
+            reachable: false,
+        };
+        assert!(matches!(
+            simulate_delegate(&down, &signed_delegate(1, 200)).await,
+            Err(Error::Rpc(_))
+        ));
+    }
 
     #[test]
     fn rewards_action_validation_rejects_bad_credit_amount() {
@@ -1008,7 +1297,7 @@ mod tests {
             deposit: 1,
         }));
 
-        assert!(validate_delegate_inner_action(&action, &["execute".to_string()]).is_ok());
+        assert!(validate_delegate_inner_action(&action, &["execute".into()]).is_ok());
     }
 
     #[test]
@@ -1021,11 +1310,34 @@ mod tests {
         }));
 
         assert_eq!(
-            validate_delegate_inner_action(&action, &["execute".to_string()]),
+            validate_delegate_inner_action(&action, &["execute".into()]),
             Err("Inner action deposit exceeds max 1 yoctoNEAR".to_string())
         );
     }
 
+    #[test]
+    fn delegate_validation_enforces_per_method_gas_cap() {
+        let policies = [MethodPolicy {
+            name: "execute".to_string(),
+            max_gas_tgas: 50,
+            max_deposit_yocto: 1,
+        }];
+        let call = |tgas: u64| {
+            Action::FunctionCall(Box::new(FunctionCallAction {
+                method_name: "execute".to_string(),
+                args: vec![],
+                gas: NearGas::from_tgas(tgas).as_gas(),
+                deposit: 0,
+            }))
+        };
+
+        assert!(validate_delegate_inner_action(&call(50), &policies).is_ok());
+        assert_eq!(
+            validate_delegate_inner_action(&call(51), &policies),
+            Err("Inner gas 51000000000000 exceeds max 50000000000000 for execute".to_string())
+        );
+    }
+
     #[test]
     fn delegate_validation_rejects_non_allowlisted_methods() {
         let action = Action::FunctionCall(Box::new(FunctionCallAction {
@@ -1036,7 +1348,7 @@ mod tests {
         }));
 
         assert_eq!(
-            validate_delegate_inner_action(&action, &["execute".to_string()]),
+            validate_delegate_inner_action(&action, &["execute".into()]),
             Err("Inner method not allowed: danger".to_string())
         );
     }
@@ -0,0 +1,80 @@
+//! Graceful shutdown: stop handing out lanes, let in-flight TXs finish.
+
+use super::slot::{ACTIVE, DRAINING};
+use super::KeyPool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tracing::{info, warn};
+
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+impl KeyPool {
+    /// Move every active lane to `DRAINING` so `acquire_delegate` rejects
+    /// new work while existing guards keep their nonces.
+    pub fn begin_drain(&self) {
+        for slot in self.read_delegate_slots().iter() {
+            let _ =
+                slot.state
+                    .compare_exchange(ACTIVE, DRAINING, Ordering::Relaxed, Ordering::Relaxed);
+        }
+    }
+
+    /// Drain the pool and wait for in-flight TXs to finish. Returns `false`
+    /// if `timeout` elapsed first.
+    pub async fn drain(&self, timeout: Duration) -> bool {
+        self.begin_drain();
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let in_flight = self.delegate_total_in_flight();
+            if in_flight == 0 {
+                info!("All in-flight transactions drained");
+                return true;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                warn!(
+                    remaining = in_flight,
+                    "Drain timeout — some TXs may be lost"
+                );
+                return false;
+            }
+            tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::make_test_pool_with_delegate_keys;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_drain_rejects_new_work_and_waits_for_in_flight() {
+        let pool = Arc::new(make_test_pool_with_delegate_keys(2));
+        let guard = pool.acquire_delegate().unwrap();
+
+        pool.begin_drain();
+        assert!(pool.acquire_delegate().is_err());
+        assert_eq!(pool.draining_delegate_count(), 2);
+        assert_eq!(pool.delegate_total_in_flight(), 1);
+
+        let drain = tokio::spawn({
+            let pool = Arc::clone(&pool);
+            async move { pool.drain(Duration::from_secs(5)).await }
+        });
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert!(!drain.is_finished());
+
+        drop(guard);
+        assert!(drain.await.unwrap());
+        assert_eq!(pool.delegate_total_in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_drain_times_out_with_stuck_guard() {
+        let pool = make_test_pool_with_delegate_keys(1);
+        let _guard = pool.acquire_delegate().unwrap();
+
+        assert!(!pool.drain(Duration::from_millis(150)).await);
+    }
+}
@@ -1,6 +1,7 @@
 //! FullAccess signer lanes for NEP-366 delegate and rewards relay.
 
 mod bootstrap;
+mod drain;
 mod scaling;
 mod slot;
 
@@ -8,12 +9,14 @@ pub use bootstrap::bootstrap_pool_from_chain;
 pub use slot::{KeyGuard, KeySlot};
 
 use crate::key_store::KeyStore;
+use crate::metrics::METRICS;
 use crate::signer::RelayerSigner;
 use near_crypto::Signer;
 use near_primitives::hash::CryptoHash;
 use near_primitives::transaction::{Action, SignedTransaction};
 use near_primitives::types::AccountId;
 use near_primitives::views::{AccessKeyPermissionView, FinalExecutionOutcomeView};
+use std::future::Future;
 #[cfg(feature = "gcp")]
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -136,10 +139,10 @@ impl KeyPool {
             .count()
     }
 
+    /// In-flight TXs on every lane, including lanes that are draining.
     pub fn delegate_total_in_flight(&self) -> u32 {
         self.read_delegate_slots()
             .iter()
-            .filter(|slot| slot.is_active())
             .map(|slot| slot.in_flight.load(Ordering::Relaxed))
             .sum()
     }
@@ -152,6 +155,18 @@ impl KeyPool {
         self.delegate_total_in_flight() as f32 / active as f32
     }
 
+    pub fn draining_delegate_count(&self) -> usize {
+        self.read_delegate_slots()
+            .iter()
+            .filter(|slot| slot.is_draining())
+            .count()
+    }
+
+    /// No active lanes, or average in-flight per lane above `max_per_key_load`.
+    pub fn is_saturated(&self, max_per_key_load: f32) -> bool {
+        self.active_delegate_count() == 0 || self.delegate_per_key_load() > max_per_key_load
+    }
+
     pub fn relayer_account(&self) -> &AccountId {
         &self.account_id
     }
@@ -167,41 +182,28 @@ impl KeyPool {
         let key_guard = self.acquire_delegate()?;
         let _submit_guard = key_guard.lock_submit().await;
 
-        let signed_tx = self
-            .sign_delegate_tx(
-                &key_guard,
-                rpc,
-                receiver_id,
-                actions.clone(),
-                key_guard.nonce,
-            )
-            .await?;
-
-        match Self::submit_signed_delegate_tx(rpc, signed_tx, wait).await {
-            Ok(outcome) => Ok(outcome),
-            Err(error) if Self::is_nonce_error(&error) => {
-                tracing::warn!(
-                    key = %key_guard.public_key(),
-                    error = %error,
-                    "Delegate signer nonce drift detected; resyncing and retrying once"
-                );
-                let public_key = key_guard.public_key();
-                let access_key = rpc.query_access_key(&self.account_id, &public_key).await?;
-                if !matches!(&access_key.permission, AccessKeyPermissionView::FullAccess) {
-                    return Err(crate::Error::Config(
-                        "delegate signer key is no longer FullAccess".into(),
-                    ));
-                }
-
-                let retry_nonce = access_key.nonce + 1;
-                key_guard.slot.nonce.store(retry_nonce, Ordering::SeqCst);
-                let retry_tx = self
-                    .sign_delegate_tx(&key_guard, rpc, receiver_id, actions, retry_nonce)
+        let guard = &key_guard;
+        let submit = move |nonce: u64| {
+            let actions = actions.clone();
+            async move {
+                let signed_tx = self
+                    .sign_delegate_tx(guard, rpc, receiver_id, actions, nonce)
                     .await?;
-                Self::submit_signed_delegate_tx(rpc, retry_tx, wait).await
+                Self::submit_signed_delegate_tx(rpc, signed_tx, wait).await
             }
-            Err(error) => Err(error),
-        }
+        };
+        let chain_nonce = move || async move {
+            let public_key = guard.public_key();
+            let access_key = rpc.query_access_key(&self.account_id, &public_key).await?;
+            if !matches!(&access_key.permission, AccessKeyPermissionView::FullAccess) {
+                return Err(crate::Error::Config(
+                    "delegate signer key is no longer FullAccess".into(),
+                ));
+            }
+            Ok(access_key.nonce)
+        };
+
+        submit_with_nonce_resync(guard, submit, chain_nonce).await
     }
 
     async fn sign_delegate_tx(
@@ -237,11 +239,6 @@ impl KeyPool {
         }
     }
 
-    fn is_nonce_error(error: &crate::Error) -> bool {
-        let message = error.to_string();
-        message.contains("InvalidNonce") || message.contains("nonce")
-    }
-
     /// Persist local delegate keys to the configured store. Skipped for KMS keys.
     pub(crate) fn persist_keys(&self) -> Result<(), crate::Error> {
         let admin_public_key = self.admin_signer.public_key().to_string();
@@ -251,7 +248,7 @@ impl KeyPool {
         for slot in self.read_delegate_slots().iter() {
             if let Some(Signer::InMemory(ims)) = slot.signer.as_local_signer() {
                 let public_key = ims.public_key.to_string();
-                if slot.is_active()
+                if (slot.is_active() || slot.is_draining())
                     && public_key != admin_public_key
                     && seen.insert(public_key.clone())
                 {
@@ -268,6 +265,69 @@ impl KeyPool {
     }
 }
 
+/// Minimum gap between chain nonce resyncs on one lane. A lane that keeps
+/// failing inside the window returns the error instead of hammering RPC.
+const NONCE_RESYNC_COOLDOWN_MS: u64 = 1_000;
+
+fn is_nonce_error(error: &crate::Error) -> bool {
+    let message = error.to_string();
+    message.contains("InvalidNonce") || message.contains("nonce")
+}
+
+/// Submit with the guard's nonce. On a nonce error, adopt the chain nonce
+/// and retry once, unless this lane already resynced within the cooldown.
+async fn submit_with_nonce_resync<T, S, SFut, C, CFut>(
+    key_guard: &KeyGuard,
+    mut submit: S,
+    chain_nonce: C,
+) -> Result<T, crate::Error>
+where
+    S: FnMut(u64) -> SFut,
+    SFut: Future<Output = Result<T, crate::Error>>,
+    C: FnOnce() -> CFut,
+    CFut: Future<Output = Result<u64, crate::Error>>,
+{
+    let error = match submit(key_guard.nonce).await {
+        Err(error) if is_nonce_error(&error) => error,
+        result => return result,
+    };
+
+    let slot = &key_guard.slot;
+    let now = now_ms();
+    let last = slot.last_nonce_resync_ms.load(Ordering::Relaxed);
+    if now.saturating_sub(last) < NONCE_RESYNC_COOLDOWN_MS
+        || slot
+            .last_nonce_resync_ms
+            .compare_exchange(last, now, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+    {
+        tracing::warn!(
+            key = %key_guard.public_key(),
+            error = %error,
+            "Delegate signer nonce drift within resync cooldown; not retrying"
+        );
+        return Err(error);
+    }
+
+    tracing::warn!(
+        key = %key_guard.public_key(),
+        error = %error,
+        "Delegate signer nonce drift detected; resyncing and retrying once"
+    );
+    METRICS.nonce_retries.fetch_add(1, Ordering::Relaxed);
+
+    let retry_nonce = chain_nonce().await? + 1;
+    slot.nonce.store(retry_nonce, Ordering::SeqCst);
+    submit(retry_nonce).await
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
@@ -396,6 +456,76 @@ pub(crate) mod tests {
         assert_eq!(pool.delegate_per_key_load(), f32::MAX);
     }
 
+    #[test]
+    fn test_saturation_flips_at_high_water_mark() {
+        let pool = make_test_pool_with_delegate_keys(2);
+        let mut guards = Vec::new();
+        for _ in 0..4 {
+            guards.push(pool.acquire_delegate().unwrap());
+        }
+        assert!(!pool.is_saturated(2.0));
+
+        guards.push(pool.acquire_delegate().unwrap());
+        assert!(pool.is_saturated(2.0));
+
+        guards.pop();
+        assert!(!pool.is_saturated(2.0));
+    }
+
+    #[test]
+    fn test_empty_pool_is_saturated() {
+        let pool = make_test_pool_with_delegate_keys(1);
+        pool.read_delegate_slots()[0]
+            .state
+            .store(slot::DRAINING, Ordering::Relaxed);
+        assert_eq!(pool.draining_delegate_count(), 1);
+        assert!(pool.is_saturated(f32::MAX));
+    }
+
+    #[tokio::test]
+    async fn test_stale_nonce_resyncs_from_chain_and_retries() {
+        let pool = make_test_pool_with_delegate_keys(1);
+        let guard = pool.acquire_delegate().unwrap();
+        let chain_nonce = 5_000;
+        let resyncs_before = METRICS.nonce_retries.load(Ordering::Relaxed);
+        let attempts = std::sync::Mutex::new(Vec::new());
+
+        let result = submit_with_nonce_resync(
+            &guard,
+            |nonce| {
+                attempts.lock().unwrap().push(nonce);
+                async move {
+                    if nonce <= chain_nonce {
+                        Err(crate::Error::Rpc("InvalidNonce".into()))
+                    } else {
+                        Ok(nonce)
+                    }
+                }
+            },
+            move || async move { Ok(chain_nonce) },
+        )
+        .await;
+
+        assert_eq!(result.unwrap(), chain_nonce + 1);
+        assert_eq!(
+            *attempts.lock().unwrap(),
+            vec![guard.nonce, chain_nonce + 1]
+        );
+        assert_eq!(guard.slot.nonce.load(Ordering::SeqCst), chain_nonce + 1);
+        assert!(METRICS.nonce_retries.load(Ordering::Relaxed) > resyncs_before);
+        drop(guard);
+
+        // A second drift on the same lane inside the cooldown is not retried.
+        let guard = pool.acquire_delegate().unwrap();
+        let result: Result<u64, _> = submit_with_nonce_resync(
+            &guard,
+            |_| async { Err(crate::Error::Rpc("InvalidNonce".into())) },
+            || async { Err(crate::Error::Rpc("chain queried".into())) },
+        )
+        .await;
+        assert_eq!(result.unwrap_err().to_string(), "rpc error: InvalidNonce");
+    }
+
     #[test]
     fn test_relayer_account() {
         let pool = make_empty_test_pool();
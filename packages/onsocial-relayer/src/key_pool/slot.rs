@@ -7,6 +7,8 @@ use std::sync::Arc;
 use tokio::sync::Mutex as AsyncMutex;
 
 pub(crate) const ACTIVE: u8 = 1;
+/// Finishing in-flight work; `acquire_delegate` skips it.
+pub(crate) const DRAINING: u8 = 2;
 
 pub struct KeySlot {
     pub(crate) signer: RelayerSigner,
@@ -16,6 +18,8 @@ pub struct KeySlot {
     pub(crate) nonce: AtomicU64,
     /// Serializes RPC submissions per key to preserve nonce ordering.
     pub submit_lock: AsyncMutex<()>,
+    /// Unix ms of the last chain nonce resync; rate-limits resync retries.
+    pub(crate) last_nonce_resync_ms: AtomicU64,
 }
 
 impl KeySlot {
@@ -26,12 +30,17 @@ impl KeySlot {
             in_flight: AtomicU32::new(0),
             nonce: AtomicU64::new(nonce),
             submit_lock: AsyncMutex::new(()),
+            last_nonce_resync_ms: AtomicU64::new(0),
         }
     }
 
     pub(crate) fn is_active(&self) -> bool {
         self.state.load(Ordering::Relaxed) == ACTIVE
     }
+
+    pub(crate) fn is_draining(&self) -> bool {
+        self.state.load(Ordering::Relaxed) == DRAINING
+    }
 }
 
 /// RAII guard. Decrements `in_flight` on drop.
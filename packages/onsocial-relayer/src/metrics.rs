@@ -68,7 +68,14 @@ impl Metrics {
         self.kms_sign_total.fetch_add(1, Ordering::Relaxed);
     }
 
-    pub fn render(&self, pool_active: usize, pool_warm: usize, pool_in_flight: u32) -> String {
+    pub fn render(
+        &self,
+        pool_active: usize,
+        pool_warm: usize,
+        pool_draining: usize,
+        pool_in_flight: u32,
+        pool_per_key_load: f32,
+    ) -> String {
         let tx_total = self.tx_total.load(Ordering::Relaxed);
         let tx_success = self.tx_success.load(Ordering::Relaxed);
         let tx_error = self.tx_error.load(Ordering::Relaxed);
@@ -85,6 +92,12 @@ impl Metrics {
         let tx_dur_sum_s = tx_dur_sum as f64 / 1_000_000.0;
         let tx_dur_max_s = tx_dur_max as f64 / 1_000_000.0;
         let kms_dur_sum_s = kms_dur_sum as f64 / 1_000_000.0;
+        // An empty pool reports f32::MAX load; Prometheus spells that +Inf.
+        let per_key_load = if pool_per_key_load < f32::MAX {
+            format!("{pool_per_key_load:.3}")
+        } else {
+            "+Inf".to_string()
+        };
 
         format!(
             "\
@@ -127,9 +140,15 @@ relayer_key_pool_active {pool_active}\n\
 # HELP relayer_key_pool_warm Pre-warmed spare delegate keys.\n\
 # TYPE relayer_key_pool_warm gauge\n\
 relayer_key_pool_warm {pool_warm}\n\
+# HELP relayer_key_pool_draining Delegate keys finishing in-flight TXs before removal.\n\
+# TYPE relayer_key_pool_draining gauge\n\
+relayer_key_pool_draining {pool_draining}\n\
 # HELP relayer_key_pool_in_flight In-flight delegate TXs across all keys.\n\
 # TYPE relayer_key_pool_in_flight gauge\n\
-relayer_key_pool_in_flight {pool_in_flight}\n"
+relayer_key_pool_in_flight {pool_in_flight}\n\
+# HELP relayer_key_pool_per_key_load Average in-flight delegate TXs per active key.\n\
+# TYPE relayer_key_pool_per_key_load gauge\n\
+relayer_key_pool_per_key_load {per_key_load}\n"
         )
     }
 }
@@ -59,6 +59,43 @@ impl ExecuteResponse {
     }
 }
 
+/// `/simulate` result. `success: false` with `estimated_gas` set is a
+/// predicted on-chain failure; without it the request was rejected outright.
+#[derive(Serialize)]
+pub struct SimulateResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_gas: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl SimulateResponse {
+    pub fn ok(estimated_gas: u64) -> Self {
+        Self {
+            success: true,
+            estimated_gas: Some(estimated_gas),
+            error: None,
+        }
+    }
+
+    pub fn predicted_failure(estimated_gas: u64, error: String) -> Self {
+        Self {
+            success: false,
+            estimated_gas: Some(estimated_gas),
+            error: Some(error),
+        }
+    }
+
+    pub fn err(error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            estimated_gas: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
 #[derive(Serialize)]
 pub struct TxStatusResponse {
     pub tx_hash: String,
@@ -129,5 +166,6 @@ pub struct HealthResponse {
     pub active_rpc: String,
     pub failovers: u64,
     pub rpc_status: &'static str,
+    pub rpc_endpoints: Vec<crate::rpc::EndpointHealth>,
     pub key_pool: KeyPoolStats,
 }
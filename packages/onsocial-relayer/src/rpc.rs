@@ -1,15 +1,22 @@
 //! RPC client with primary → fallback failover and circuit breaker.
+//!
+//! Requests go to the primary until it fails `failure_threshold` times in a
+//! row, then to the fallback. While failed over, the primary is probed again
+//! once per `probe_interval`; a successful probe fails back.
 
 use near_crypto::PublicKey;
 use near_jsonrpc_client::methods;
 use near_jsonrpc_client::JsonRpcClient;
+use near_jsonrpc_primitives::types::query::RpcQueryError;
 use near_primitives::hash::CryptoHash;
-use near_primitives::transaction::SignedTransaction;
+use near_primitives::transaction::{Action, SignedTransaction};
 use near_primitives::types::{AccountId, BlockReference, Finality};
 use near_primitives::views::{AccessKeyView, FinalExecutionOutcomeView};
+use serde::Serialize;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Mutex;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use tracing::{info, warn};
 
@@ -19,10 +26,70 @@ const BLOCK_HASH_TTL_SECS: u64 = 30;
 
 const CIRCUIT_BREAKER_THRESHOLD: u64 = 5;
 const CIRCUIT_BREAKER_WINDOW_MS: u64 = 30_000;
-struct CircuitState {
-    failures: u64,
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Endpoint {
+    Primary,
+    Fallback,
+}
+
+impl Endpoint {
+    fn other(self) -> Self {
+        match self {
+            Self::Primary => Self::Fallback,
+            Self::Fallback => Self::Primary,
+        }
+    }
+
+    fn role(self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Fallback => "fallback",
+        }
+    }
+}
+
+#[derive(Default)]
+struct EndpointStats {
+    consecutive_failures: u64,
+    last_success_ms: u64,
     last_failure_ms: u64,
+}
+
+struct CircuitState {
+    primary: EndpointStats,
+    fallback: EndpointStats,
+    /// Routing to the fallback.
     open: bool,
+    /// The next primary call is a fail-back probe.
+    probing: bool,
+}
+
+impl CircuitState {
+    fn stats_mut(&mut self, endpoint: Endpoint) -> &mut EndpointStats {
+        match endpoint {
+            Endpoint::Primary => &mut self.primary,
+            Endpoint::Fallback => &mut self.fallback,
+        }
+    }
+}
+
+/// Health of one RPC endpoint, as reported by `/health`.
+#[derive(Clone, Debug, Serialize)]
+pub struct EndpointHealth {
+    pub role: &'static str,
+    pub url: String,
+    /// Requests are currently routed here first.
+    pub active: bool,
+    pub consecutive_failures: u64,
+    /// Unix millis of the last successful call.
+    pub last_success_ms: Option<u64>,
+}
+
+/// Dedicated endpoint for submissions targeting a specific receiver.
+struct ReceiverRoute {
+    url: String,
+    client: JsonRpcClient,
 }
 
 pub struct RpcClient {
@@ -31,9 +98,12 @@ pub struct RpcClient {
     primary_url: String,
     fallback_url: String,
     circuit: Mutex<CircuitState>,
+    failure_threshold: u64,
+    probe_interval_ms: u64,
     total_failovers: AtomicU64,
     cached_block_hash: RwLock<Option<(CryptoHash, Instant)>>,
     block_hash_stale: std::sync::atomic::AtomicBool,
+    receiver_routes: HashMap<AccountId, ReceiverRoute>,
 }
 
 impl RpcClient {
@@ -49,14 +119,58 @@ impl RpcClient {
             primary_url: primary_url.to_string(),
             fallback_url: fallback_url.to_string(),
             circuit: Mutex::new(CircuitState {
-                failures: 0,
-                last_failure_ms: 0,
+                primary: EndpointStats::default(),
+                fallback: EndpointStats::default(),
                 open: false,
+                probing: false,
             }),
+            failure_threshold: CIRCUIT_BREAKER_THRESHOLD,
+            probe_interval_ms: CIRCUIT_BREAKER_WINDOW_MS,
             total_failovers: AtomicU64::new(0),
             cached_block_hash: RwLock::new(None),
             block_hash_stale: std::sync::atomic::AtomicBool::new(false),
+            receiver_routes: HashMap::new(),
+        }
+    }
+
+    /// Fail over after `failure_threshold` consecutive primary failures and
+    /// probe the primary again every `probe_interval` while failed over.
+    pub fn with_failover_policy(
+        mut self,
+        failure_threshold: u64,
+        probe_interval: Duration,
+    ) -> Self {
+        self.failure_threshold = failure_threshold.max(1);
+        self.probe_interval_ms = probe_interval.as_millis() as u64;
+        self
+    }
+
+    /// Route submissions for specific receivers to a preferred endpoint.
+    /// Invalid account IDs and empty URLs are skipped with a warning.
+    pub fn with_receiver_routes(mut self, routes: &HashMap<String, String>) -> Self {
+        for (receiver, url) in routes {
+            let receiver: AccountId = match receiver.parse() {
+                Ok(account_id) => account_id,
+                Err(e) => {
+                    warn!(receiver = %receiver, error = %e, "Ignoring invalid receiver RPC route");
+                    continue;
+                }
+            };
+            let url = url.trim();
+            if url.is_empty() {
+                warn!(receiver = %receiver, "Ignoring receiver RPC route with empty URL");
+                continue;
+            }
+            info!(receiver = %receiver, rpc = url, "Receiver RPC route configured");
+            self.receiver_routes.insert(
+                receiver,
+                ReceiverRoute {
+                    url: url.to_string(),
+                    client: JsonRpcClient::connect(url),
+                },
+            );
         }
+        self
     }
 
     pub fn primary_url(&self) -> &str {
@@ -67,6 +181,14 @@ impl RpcClient {
         &self.fallback_url
     }
 
+    /// Endpoint a submission to `receiver_id` is sent to first.
+    pub fn endpoint_for(&self, receiver_id: &AccountId) -> &str {
+        self.receiver_routes
+            .get(receiver_id)
+            .map(|route| route.url.as_str())
+            .unwrap_or_else(|| self.active_url())
+    }
+
     // --- TX submission ---
 
     /// Recent block hash, cached for <30s.
@@ -81,32 +203,11 @@ impl RpcClient {
             }
         }
         // Slow path: RPC with failover
-        let block = match self
-            .primary
-            .call(methods::block::RpcBlockRequest {
+        let block = self
+            .call_with_failover("block query", || methods::block::RpcBlockRequest {
                 block_reference: BlockReference::Finality(Finality::Final),
             })
-            .await
-        {
-            Ok(b) => {
-                self.record_success();
-                b
-            }
-            Err(e) => {
-                self.record_failure();
-                warn!(error = %e, "Primary RPC block query failed, trying fallback");
-                self.fallback
-                    .call(methods::block::RpcBlockRequest {
-                        block_reference: BlockReference::Finality(Finality::Final),
-                    })
-                    .await
-                    .map_err(|e2| {
-                        crate::Error::Rpc(format!(
-                            "block query failed on both RPCs: primary={e}, fallback={e2}"
-                        ))
-                    })?
-            }
-        };
+            .await?;
         let hash = block.header.hash;
         {
             let mut cache = self.cached_block_hash.write().await;
@@ -119,22 +220,11 @@ impl RpcClient {
     /// Latest finalized block hash + height. Not cached (used only by
     /// NEP-366 delegate flows; client typically caches the result).
     pub async fn latest_block(&self) -> Result<(CryptoHash, u64), crate::Error> {
-        let req = || methods::block::RpcBlockRequest {
-            block_reference: BlockReference::Finality(Finality::Final),
-        };
-        let block = match self.active().call(req()).await {
-            Ok(b) => {
-                self.record_success();
-                b
-            }
-            Err(e) => {
-                self.record_failure();
-                warn!(error = %e, "Primary RPC latest_block failed, trying fallback");
-                self.fallback.call(req()).await.map_err(|e2| {
-                    crate::Error::Rpc(format!("latest_block failed: primary={e}, fallback={e2}"))
-                })?
-            }
-        };
+        let block = self
+            .call_with_failover("latest_block", || methods::block::RpcBlockRequest {
+                block_reference: BlockReference::Finality(Finality::Final),
+            })
+            .await?;
         Ok((block.header.hash, block.header.height))
     }
 
@@ -144,6 +234,31 @@ impl RpcClient {
         account_id: &AccountId,
         public_key: &PublicKey,
     ) -> Result<AccessKeyView, crate::Error> {
+        let resp = self
+            .call_with_failover("access_key query", || methods::query::RpcQueryRequest {
+                block_reference: BlockReference::Finality(Finality::Final),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: account_id.clone(),
+                    public_key: public_key.clone(),
+                },
+            })
+            .await?;
+
+        match resp.kind {
+            near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(ak) => Ok(ak),
+            other => Err(crate::Error::Rpc(format!(
+                "unexpected query response: {other:?}"
+            ))),
+        }
+    }
+
+    /// Like [`Self::query_access_key`], but a key missing on chain is `None`
+    /// rather than an error.
+    pub async fn find_access_key(
+        &self,
+        account_id: &AccountId,
+        public_key: &PublicKey,
+    ) -> Result<Option<AccessKeyView>, crate::Error> {
         let make_request = || methods::query::RpcQueryRequest {
             block_reference: BlockReference::Finality(Finality::Final),
             request: near_primitives::views::QueryRequest::ViewAccessKey {
@@ -151,25 +266,50 @@ impl RpcClient {
                 public_key: public_key.clone(),
             },
         };
+        let is_unknown_key = |e: &near_jsonrpc_client::errors::JsonRpcError<RpcQueryError>| {
+            matches!(
+                e.handler_error(),
+                Some(RpcQueryError::UnknownAccessKey { .. })
+            )
+        };
 
-        let resp = match self.active().call(make_request()).await {
+        let first = self.route();
+        let resp = match self.client(first).call(make_request()).await {
             Ok(r) => {
-                self.record_success();
+                self.record_success(first);
                 r
             }
+            Err(e) if is_unknown_key(&e) => {
+                self.record_success(first);
+                return Ok(None);
+            }
             Err(e) => {
-                self.record_failure();
-                warn!(error = %e, "RPC access_key query failed, trying fallback");
-                self.fallback.call(make_request()).await.map_err(|e2| {
-                    crate::Error::Rpc(format!(
-                        "access_key query failed: primary={e}, fallback={e2}"
-                    ))
-                })?
+                self.record_failure(first);
+                let second = first.other();
+                warn!(error = %e, "RPC access_key lookup failed on {}, trying {}", first.role(), second.role());
+                match self.client(second).call(make_request()).await {
+                    Ok(r) => {
+                        self.record_success(second);
+                        r
+                    }
+                    Err(e2) if is_unknown_key(&e2) => {
+                        self.record_success(second);
+                        return Ok(None);
+                    }
+                    Err(e2) => {
+                        self.record_failure(second);
+                        return Err(crate::Error::Rpc(format!(
+                            "access_key lookup failed: {}={e}, {}={e2}",
+                            first.role(),
+                            second.role()
+                        )));
+                    }
+                }
             }
         };
 
         match resp.kind {
-            near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(ak) => Ok(ak),
+            near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(ak) => Ok(Some(ak)),
             other => Err(crate::Error::Rpc(format!(
                 "unexpected query response: {other:?}"
             ))),
@@ -181,34 +321,27 @@ impl RpcClient {
         &self,
         signed_tx: SignedTransaction,
     ) -> Result<FinalExecutionOutcomeView, crate::Error> {
-        let client = self.active();
-        match client
-            .call(methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
-                signed_transaction: signed_tx.clone(),
-            })
-            .await
-        {
-            Ok(outcome) => {
-                self.record_success();
-                Ok(outcome)
-            }
-            Err(e) => {
-                self.record_failure();
-                warn!(error = %e, "Primary broadcast_tx_commit failed, trying fallback");
-                let outcome = self
-                    .fallback
-                    .call(methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
-                        signed_transaction: signed_tx,
-                    })
-                    .await
-                    .map_err(|e2| {
-                        crate::Error::Rpc(format!(
-                            "broadcast_tx_commit failed: primary={e}, fallback={e2}"
-                        ))
-                    })?;
-                Ok(outcome)
+        if let Some(route) = self.route_for(&signed_tx) {
+            match route
+                .client
+                .call(methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+                    signed_transaction: signed_tx.clone(),
+                })
+                .await
+            {
+                Ok(outcome) => return Ok(outcome),
+                Err(e) => {
+                    METRICS.rpc_errors.fetch_add(1, Ordering::Relaxed);
+                    warn!(error = %e, rpc = %route.url, "Routed broadcast_tx_commit failed, using default pool");
+                }
             }
         }
+        self.call_with_failover("broadcast_tx_commit", || {
+            methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+                signed_transaction: signed_tx.clone(),
+            }
+        })
+        .await
     }
 
     /// `send_tx_async`. Returns hash immediately.
@@ -216,34 +349,27 @@ impl RpcClient {
         &self,
         signed_tx: SignedTransaction,
     ) -> Result<CryptoHash, crate::Error> {
-        match self
-            .active()
-            .call(methods::broadcast_tx_async::RpcBroadcastTxAsyncRequest {
-                signed_transaction: signed_tx.clone(),
-            })
-            .await
-        {
-            Ok(hash) => {
-                self.record_success();
-                Ok(hash)
-            }
-            Err(e) => {
-                self.record_failure();
-                warn!(error = %e, "Primary send_tx_async failed, trying fallback");
-                let hash = self
-                    .fallback
-                    .call(methods::broadcast_tx_async::RpcBroadcastTxAsyncRequest {
-                        signed_transaction: signed_tx,
-                    })
-                    .await
-                    .map_err(|e2| {
-                        crate::Error::Rpc(format!(
-                            "broadcast_tx_async failed: primary={e}, fallback={e2}"
-                        ))
-                    })?;
-                Ok(hash)
+        if let Some(route) = self.route_for(&signed_tx) {
+            match route
+                .client
+                .call(methods::broadcast_tx_async::RpcBroadcastTxAsyncRequest {
+                    signed_transaction: signed_tx.clone(),
+                })
+                .await
+            {
+                Ok(hash) => return Ok(hash),
+                Err(e) => {
+                    METRICS.rpc_errors.fetch_add(1, Ordering::Relaxed);
+                    warn!(error = %e, rpc = %route.url, "Routed send_tx_async failed, using default pool");
+                }
             }
         }
+        self.call_with_failover("broadcast_tx_async", || {
+            methods::broadcast_tx_async::RpcBroadcastTxAsyncRequest {
+                signed_transaction: signed_tx.clone(),
+            }
+        })
+        .await
     }
 
     pub async fn tx_status(
@@ -251,103 +377,173 @@ impl RpcClient {
         tx_hash: CryptoHash,
         sender_id: &AccountId,
     ) -> Result<FinalExecutionOutcomeView, crate::Error> {
-        let make_request = || methods::tx::RpcTransactionStatusRequest {
-            transaction_info: methods::tx::TransactionInfo::TransactionId {
-                tx_hash,
-                sender_account_id: sender_id.clone(),
-            },
-            wait_until: near_primitives::views::TxExecutionStatus::Final,
-        };
-
-        let resp = match self.active().call(make_request()).await {
-            Ok(r) => {
-                self.record_success();
-                r
-            }
-            Err(e) => {
-                self.record_failure();
-                self.fallback.call(make_request()).await.map_err(|e2| {
-                    crate::Error::Rpc(format!("tx_status failed: primary={e}, fallback={e2}"))
-                })?
-            }
-        };
+        let resp = self
+            .call_with_failover("tx_status", || methods::tx::RpcTransactionStatusRequest {
+                transaction_info: methods::tx::TransactionInfo::TransactionId {
+                    tx_hash,
+                    sender_account_id: sender_id.clone(),
+                },
+                wait_until: near_primitives::views::TxExecutionStatus::Final,
+            })
+            .await?;
 
         resp.final_execution_outcome
             .map(|e| e.into_outcome())
             .ok_or_else(|| crate::Error::Rpc("TX not finalized yet".into()))
     }
 
+    /// Probes both endpoints directly. Results feed endpoint health, so a
+    /// healthy primary seen here also fails back.
     pub async fn health_check(&self) -> Result<&'static str, crate::Error> {
-        match self
-            .primary
-            .call(methods::block::RpcBlockRequest {
-                block_reference: BlockReference::Finality(Finality::Final),
-            })
-            .await
-        {
-            Ok(_) => Ok("ok"),
+        let probe = || methods::block::RpcBlockRequest {
+            block_reference: BlockReference::Finality(Finality::Final),
+        };
+        match self.primary.call(probe()).await {
+            Ok(_) => {
+                self.record_success(Endpoint::Primary);
+                Ok("ok")
+            }
             Err(_) => {
-                match self
-                    .fallback
-                    .call(methods::block::RpcBlockRequest {
-                        block_reference: BlockReference::Finality(Finality::Final),
-                    })
-                    .await
-                {
-                    Ok(_) => Ok("degraded"),
-                    Err(e) => Err(crate::Error::Rpc(format!("Both RPCs unreachable: {e}"))),
+                self.record_failure(Endpoint::Primary);
+                match self.fallback.call(probe()).await {
+                    Ok(_) => {
+                        self.record_success(Endpoint::Fallback);
+                        Ok("degraded")
+                    }
+                    Err(e) => {
+                        self.record_failure(Endpoint::Fallback);
+                        Err(crate::Error::Rpc(format!("Both RPCs unreachable: {e}")))
+                    }
                 }
             }
         }
     }
 
+    // --- Receiver routing ---
+
+    fn route_for(&self, signed_tx: &SignedTransaction) -> Option<&ReceiverRoute> {
+        if self.receiver_routes.is_empty() {
+            return None;
+        }
+        self.receiver_routes.get(&submission_receiver(signed_tx))
+    }
+
     // --- Failover / circuit breaker ---
 
-    fn active(&self) -> &JsonRpcClient {
+    /// Sends to the routed endpoint, then once to the other if that fails.
+    async fn call_with_failover<M>(
+        &self,
+        what: &str,
+        make_request: impl Fn() -> M,
+    ) -> Result<M::Response, crate::Error>
+    where
+        M: methods::RpcMethod,
+        near_jsonrpc_client::errors::JsonRpcError<M::Error>: std::fmt::Display,
+    {
+        let first = self.route();
+        let e = match self.client(first).call(make_request()).await {
+            Ok(resp) => {
+                self.record_success(first);
+                return Ok(resp);
+            }
+            Err(e) => e,
+        };
+        self.record_failure(first);
+        let second = first.other();
+        warn!(error = %e, "RPC {what} failed on {}, trying {}", first.role(), second.role());
+        match self.client(second).call(make_request()).await {
+            Ok(resp) => {
+                self.record_success(second);
+                Ok(resp)
+            }
+            Err(e2) => {
+                self.record_failure(second);
+                Err(crate::Error::Rpc(format!(
+                    "{what} failed: {}={e}, {}={e2}",
+                    first.role(),
+                    second.role()
+                )))
+            }
+        }
+    }
+
+    fn route(&self) -> Endpoint {
         if self.is_circuit_open() {
-            &self.fallback
+            Endpoint::Fallback
         } else {
-            &self.primary
+            Endpoint::Primary
+        }
+    }
+
+    fn client(&self, endpoint: Endpoint) -> &JsonRpcClient {
+        match endpoint {
+            Endpoint::Primary => &self.primary,
+            Endpoint::Fallback => &self.fallback,
         }
     }
 
-    fn record_success(&self) {
+    fn url(&self, endpoint: Endpoint) -> &str {
+        match endpoint {
+            Endpoint::Primary => &self.primary_url,
+            Endpoint::Fallback => &self.fallback_url,
+        }
+    }
+
+    fn record_success(&self, endpoint: Endpoint) {
         let mut circuit = self.circuit.lock().unwrap_or_else(|e| e.into_inner());
-        if circuit.failures > 0 {
-            info!(primary = %self.primary_url, "Primary RPC recovered");
-            circuit.failures = 0;
-            circuit.open = false;
+        let stats = circuit.stats_mut(endpoint);
+        let was_failing = stats.consecutive_failures > 0;
+        stats.consecutive_failures = 0;
+        stats.last_success_ms = now_ms();
+        if endpoint == Endpoint::Primary {
+            circuit.probing = false;
+            if circuit.open || was_failing {
+                info!(primary = %self.primary_url, "Primary RPC recovered");
+                if circuit.open {
+                    circuit.open = false;
+                    self.block_hash_stale.store(true, Ordering::Relaxed);
+                }
+            }
         }
     }
 
-    fn record_failure(&self) {
+    fn record_failure(&self, endpoint: Endpoint) {
         METRICS.rpc_errors.fetch_add(1, Ordering::Relaxed);
         let mut circuit = self.circuit.lock().unwrap_or_else(|e| e.into_inner());
-        circuit.failures += 1;
-        circuit.last_failure_ms = now_ms();
-        if circuit.failures >= CIRCUIT_BREAKER_THRESHOLD && !circuit.open {
-            circuit.open = true;
-            self.total_failovers.fetch_add(1, Ordering::Relaxed);
-            METRICS.rpc_failovers.fetch_add(1, Ordering::Relaxed);
-            // Invalidate block hash cache — fallback may have different chain head
-            self.block_hash_stale.store(true, Ordering::Relaxed);
-            warn!(
-                failures = circuit.failures,
-                fallback = %self.fallback_url,
-                "Circuit breaker opened — routing to fallback"
-            );
+        let stats = circuit.stats_mut(endpoint);
+        stats.consecutive_failures += 1;
+        stats.last_failure_ms = now_ms();
+        let failures = stats.consecutive_failures;
+        if endpoint != Endpoint::Primary || failures < self.failure_threshold || circuit.open {
+            return;
+        }
+        circuit.open = true;
+        if std::mem::take(&mut circuit.probing) {
+            warn!(primary = %self.primary_url, "Primary RPC probe failed, staying on fallback");
+            return;
         }
+        self.total_failovers.fetch_add(1, Ordering::Relaxed);
+        METRICS.rpc_failovers.fetch_add(1, Ordering::Relaxed);
+        // Invalidate block hash cache — fallback may have different chain head
+        self.block_hash_stale.store(true, Ordering::Relaxed);
+        warn!(
+            failures,
+            fallback = %self.fallback_url,
+            "Circuit breaker opened — routing to fallback"
+        );
     }
 
+    /// True while failed over. Once `probe_interval` has passed since the
+    /// primary's last failure, this returns false so the next call probes it.
     pub fn is_circuit_open(&self) -> bool {
         let mut circuit = self.circuit.lock().unwrap_or_else(|e| e.into_inner());
         if !circuit.open {
             return false;
         }
-        if now_ms() - circuit.last_failure_ms > CIRCUIT_BREAKER_WINDOW_MS {
+        if now_ms().saturating_sub(circuit.primary.last_failure_ms) >= self.probe_interval_ms {
             circuit.open = false;
-            circuit.failures = 0;
-            info!(primary = %self.primary_url, "Circuit breaker half-open, retrying primary");
+            circuit.probing = true;
+            info!(primary = %self.primary_url, "Circuit breaker half-open, probing primary");
             return false;
         }
         true
@@ -358,11 +554,60 @@ impl RpcClient {
     }
 
     pub fn active_url(&self) -> &str {
-        if self.is_circuit_open() {
-            &self.fallback_url
-        } else {
-            &self.primary_url
-        }
+        self.url(self.route())
+    }
+
+    /// Per-endpoint health, primary first.
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        let active = self.route();
+        let circuit = self.circuit.lock().unwrap_or_else(|e| e.into_inner());
+        [
+            (Endpoint::Primary, &circuit.primary),
+            (Endpoint::Fallback, &circuit.fallback),
+        ]
+        .into_iter()
+        .map(|(endpoint, stats)| EndpointHealth {
+            role: endpoint.role(),
+            url: self.url(endpoint).to_string(),
+            active: endpoint == active,
+            consecutive_failures: stats.consecutive_failures,
+            last_success_ms: (stats.last_success_ms > 0).then_some(stats.last_success_ms),
+        })
+        .collect()
+    }
+}
+
+/// Chain reads `/simulate` needs, so handlers can be tested without a node.
+pub(crate) trait ChainView {
+    async fn latest_block(&self) -> Result<(CryptoHash, u64), crate::Error>;
+    async fn find_access_key(
+        &self,
+        account_id: &AccountId,
+        public_key: &PublicKey,
+    ) -> Result<Option<AccessKeyView>, crate::Error>;
+}
+
+impl ChainView for RpcClient {
+    async fn latest_block(&self) -> Result<(CryptoHash, u64), crate::Error> {
+        RpcClient::latest_block(self).await
+    }
+
+    async fn find_access_key(
+        &self,
+        account_id: &AccountId,
+        public_key: &PublicKey,
+    ) -> Result<Option<AccessKeyView>, crate::Error> {
+        RpcClient::find_access_key(self, account_id, public_key).await
+    }
+}
+
+/// Contract a submission ultimately targets. For NEP-366 outer transactions
+/// the outer receiver is the user, so the inner delegate receiver is used.
+fn submission_receiver(signed_tx: &SignedTransaction) -> AccountId {
+    let tx = &signed_tx.transaction;
+    match tx.actions() {
+        [Action::Delegate(signed_delegate)] => signed_delegate.delegate_action.receiver_id.clone(),
+        _ => tx.receiver_id().clone(),
     }
 }
 
@@ -372,3 +617,178 @@ fn now_ms() -> u64 {
         .unwrap_or_default()
         .as_millis() as u64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_crypto::{InMemorySigner, KeyType, Signer};
+    use near_primitives::transaction::{FunctionCallAction, Transaction, TransactionV0};
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+
+    const PRIMARY: &str = "https://primary.example.com";
+    const FALLBACK: &str = "https://fallback.example.com";
+    const SCARCES_RPC: &str = "https://scarces-rpc.example.com";
+
+    fn routed_client() -> RpcClient {
+        let routes =
+            HashMap::from([("scarces.onsocial.near".to_string(), SCARCES_RPC.to_string())]);
+        RpcClient::new(PRIMARY, FALLBACK).with_receiver_routes(&routes)
+    }
+
+    fn function_call_tx(receiver: &str) -> SignedTransaction {
+        let signer: Signer = InMemorySigner::from_seed(
+            "relayer.onsocial.near".parse().unwrap(),
+            KeyType::ED25519,
+            "seed",
+        );
+        let tx = Transaction::V0(TransactionV0 {
+            signer_id: "relayer.onsocial.near".parse().unwrap(),
+            public_key: signer.public_key(),
+            nonce: 1,
+            receiver_id: receiver.parse().unwrap(),
+            block_hash: CryptoHash::default(),
+            actions: vec![Action::FunctionCall(Box::new(FunctionCallAction {
+                method_name: "execute".to_string(),
+                args: vec![],
+                gas: 100_000_000_000_000,
+                deposit: 0,
+            }))],
+        });
+        let signature = signer.sign(tx.get_hash_and_size().0.as_ref());
+        SignedTransaction::new(signature, tx)
+    }
+
+    #[test]
+    fn mapped_receiver_uses_mapped_endpoint() {
+        let rpc = routed_client();
+
+        assert_eq!(
+            rpc.endpoint_for(&"scarces.onsocial.near".parse().unwrap()),
+            SCARCES_RPC
+        );
+        let route = rpc
+            .route_for(&function_call_tx("scarces.onsocial.near"))
+            .expect("mapped receiver should be routed");
+        assert_eq!(route.url, SCARCES_RPC);
+    }
+
+    #[test]
+    fn unmapped_receiver_uses_default_pool() {
+        let rpc = routed_client();
+
+        assert_eq!(
+            rpc.endpoint_for(&"core.onsocial.near".parse().unwrap()),
+            PRIMARY
+        );
+        assert!(rpc
+            .route_for(&function_call_tx("core.onsocial.near"))
+            .is_none());
+    }
+
+    #[test]
+    fn invalid_receiver_routes_are_ignored() {
+        let routes = HashMap::from([
+            ("Not A Valid Account".to_string(), SCARCES_RPC.to_string()),
+            ("core.onsocial.near".to_string(), "  ".to_string()),
+        ]);
+        let rpc = RpcClient::new(PRIMARY, FALLBACK).with_receiver_routes(&routes);
+
+        assert!(rpc.receiver_routes.is_empty());
+    }
+
+    /// JSON-RPC stub answering `broadcast_tx_async` while `healthy` is set
+    /// and HTTP 500 otherwise. Returns its URL and a hit counter.
+    async fn mock_rpc(healthy: Arc<AtomicBool>) -> (String, Arc<AtomicU64>) {
+        use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
+
+        type MockState = (Arc<AtomicBool>, Arc<AtomicU64>);
+        async fn handle(
+            State((healthy, hits)): State<MockState>,
+            Json(req): Json<serde_json::Value>,
+        ) -> Result<Json<serde_json::Value>, StatusCode> {
+            hits.fetch_add(1, Ordering::SeqCst);
+            if !healthy.load(Ordering::SeqCst) {
+                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+            Ok(Json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": req["id"],
+                "result": CryptoHash::default().to_string(),
+            })))
+        }
+
+        let hits = Arc::new(AtomicU64::new(0));
+        let app = Router::new()
+            .route("/", post(handle))
+            .with_state((healthy, hits.clone()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (format!("http://{addr}"), hits)
+    }
+
+    #[tokio::test]
+    async fn fails_over_to_fallback_and_back_when_primary_heals() {
+        let primary_up = Arc::new(AtomicBool::new(false));
+        let (primary_url, primary_hits) = mock_rpc(primary_up.clone()).await;
+        let (fallback_url, fallback_hits) = mock_rpc(Arc::new(AtomicBool::new(true))).await;
+        let rpc = RpcClient::new(&primary_url, &fallback_url)
+            .with_failover_policy(2, Duration::from_millis(200));
+        let tx = function_call_tx("core.onsocial.near");
+
+        // Primary errors are absorbed by the fallback until the threshold.
+        for _ in 0..2 {
+            rpc.send_tx_async(tx.clone()).await.unwrap();
+        }
+        assert_eq!(primary_hits.load(Ordering::SeqCst), 2);
+        assert_eq!(rpc.active_url(), fallback_url);
+        assert_eq!(rpc.failover_count(), 1);
+
+        // Failed over: the primary is no longer tried.
+        rpc.send_tx_async(tx.clone()).await.unwrap();
+        assert_eq!(primary_hits.load(Ordering::SeqCst), 2);
+        assert_eq!(fallback_hits.load(Ordering::SeqCst), 3);
+
+        let health = rpc.endpoint_health();
+        assert_eq!(health[0].consecutive_failures, 2);
+        assert!(health[0].last_success_ms.is_none());
+        assert!(health[1].active);
+        assert!(health[1].last_success_ms.is_some());
+
+        // A probe against a still-broken primary stays on the fallback.
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        rpc.send_tx_async(tx.clone()).await.unwrap();
+        assert_eq!(primary_hits.load(Ordering::SeqCst), 3);
+        assert_eq!(rpc.active_url(), fallback_url);
+        assert_eq!(rpc.failover_count(), 1);
+
+        // Once the primary heals, the next probe fails back.
+        primary_up.store(true, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        rpc.send_tx_async(tx.clone()).await.unwrap();
+        assert_eq!(rpc.active_url(), primary_url);
+        let health = rpc.endpoint_health();
+        assert!(health[0].active);
+        assert_eq!(health[0].consecutive_failures, 0);
+        assert!(health[0].last_success_ms.is_some());
+    }
+
+    #[tokio::test]
+    async fn reports_error_when_both_endpoints_fail() {
+        let (primary_url, _) = mock_rpc(Arc::new(AtomicBool::new(false))).await;
+        let (fallback_url, _) = mock_rpc(Arc::new(AtomicBool::new(false))).await;
+        let rpc = RpcClient::new(&primary_url, &fallback_url);
+
+        let err = rpc
+            .send_tx_async(function_call_tx("core.onsocial.near"))
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("primary="), "{err}");
+        let health = rpc.endpoint_health();
+        assert_eq!(health[0].consecutive_failures, 1);
+        assert_eq!(health[1].consecutive_failures, 1);
+        assert!(health[0].active);
+    }
+}
@@ -9,7 +9,7 @@ use near_crypto::{SecretKey, Signer};
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use std::time::Instant;
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 
 /// Shared application state.
 pub struct AppState {
@@ -18,7 +18,7 @@ pub struct AppState {
     pub key_pool: Arc<KeyPool>,
     pub allowed_contracts: Vec<near_primitives::types::AccountId>,
     /// Inner FunctionCall methods accepted on `/execute_delegate` delegates.
-    pub allowed_methods: Vec<String>,
+    pub allowed_methods: Vec<crate::config::MethodPolicy>,
     pub start_time: Instant,
     pub request_count: AtomicU64,
     /// `/ready` returns 503 until the delegate signer pool reaches its target size.
@@ -29,7 +29,8 @@ pub struct AppState {
 
 impl AppState {
     pub async fn new(config: Config) -> Result<Self, crate::Error> {
-        let rpc = RpcClient::new(&config.rpc_url, &config.fallback_rpc_url);
+        let rpc = RpcClient::new(&config.rpc_url, &config.fallback_rpc_url)
+            .with_receiver_routes(&config.receiver_rpc_urls);
 
         let allowed_contracts: Vec<near_primitives::types::AccountId> = config
             .allowed_contracts
@@ -53,11 +54,11 @@ impl AppState {
 
         info!(contracts = ?allowed_contracts, "Allowed contracts");
 
-        let allowed_methods: Vec<String> = config
+        let allowed_methods: Vec<crate::config::MethodPolicy> = config
             .allowed_methods
             .iter()
-            .map(|method| method.trim().to_string())
-            .filter(|method| !method.is_empty())
+            .filter(|method| !method.name.is_empty())
+            .cloned()
             .collect();
         if allowed_methods.is_empty() {
             return Err(crate::Error::Config(
@@ -144,6 +145,21 @@ impl AppState {
     }
 }
 
+impl AppState {
+    /// Shutdown hook: drain signer lanes, then persist local delegate keys.
+    /// Nonces are not stored; bootstrap re-reads them from chain.
+    pub async fn shutdown(&self) {
+        let timeout = std::time::Duration::from_secs(self.config.drain_timeout_secs);
+        self.key_pool.drain(timeout).await;
+
+        if let Err(e) = self.key_pool.persist_keys_public() {
+            error!(error = %e, "Failed to persist key store on shutdown");
+        } else {
+            info!("Key store persisted to disk");
+        }
+    }
+}
+
 async fn bootstrap_local_pool(
     config: &Config,
     rpc: &RpcClient,
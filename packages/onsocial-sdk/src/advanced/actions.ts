@@ -60,6 +60,8 @@ import {
 
 export type CoreAction =
   | { type: 'set'; data: Record<string, unknown> }
+  | { type: 'set_atomic'; data: Record<string, unknown> }
+  | { type: 'delete_subtree'; path: string }
   | { type: 'create_group'; group_id: string; config: Record<string, unknown> }
   | { type: 'join_group'; group_id: string }
   | { type: 'leave_group'; group_id: string }
@@ -98,6 +100,13 @@ export type CoreAction =
     }
   | { type: 'cancel_proposal'; group_id: string; proposal_id: string }
   | { type: 'expire_proposal'; group_id: string; proposal_id: string }
+  | {
+      type: 'finalize_expired_proposal';
+      group_id: string;
+      proposal_id: string;
+    }
+  | { type: 'delegate_vote'; group_id: string; delegate: string }
+  | { type: 'undelegate_vote'; group_id: string }
   | {
       type: 'set_permission';
       grantee: string;
@@ -105,6 +114,12 @@ export type CoreAction =
       level: number;
       expires_at?: string;
     }
+  | {
+      type: 'sweep_expired_permissions';
+      owner: string;
+      limit: number;
+      from_key?: string;
+    }
   | {
       type: 'set_key_permission';
       public_key: string;
@@ -162,6 +177,7 @@ export type ScarcesAction =
   | { type: 'revoke_all_scarce'; token_id: string }
   // Lifecycle
   | { type: 'burn_scarce'; token_id: string; collection_id?: string }
+  | { type: 'batch_burn'; token_ids: string[] }
   | {
       type: 'renew_token';
       token_id: string;
@@ -194,9 +210,18 @@ export type ScarcesAction =
       burnable?: boolean;
       revocation_mode?: string;
       max_redeems?: number;
+      redeem_start?: number;
+      redeem_end?: number;
       metadata?: string;
       start_price?: string;
       allowlist_price?: string;
+      allowlist_merkle_root?: string;
+      enforce_royalties?: boolean;
+      track_provenance?: boolean;
+      reveal_at?: number;
+      placeholder_metadata?: string;
+      mint_cooldown_ms?: number;
+      allowlist_exempt_from_limits?: boolean;
     }
   | {
       type: 'update_collection_price';
@@ -212,6 +237,8 @@ export type ScarcesAction =
   | { type: 'delete_collection'; collection_id: string }
   | { type: 'pause_collection'; collection_id: string }
   | { type: 'resume_collection'; collection_id: string }
+  | { type: 'freeze_collection'; collection_id: string }
+  | { type: 'reveal_collection'; collection_id: string }
   | { type: 'set_allowlist'; collection_id: string; entries: AllowlistEntry[] }
   | { type: 'remove_from_allowlist'; collection_id: string; accounts: string[] }
   | {
@@ -225,7 +252,17 @@ export type ScarcesAction =
       collection_id: string;
       metadata?: string;
     }
+  | {
+      type: 'batch_update_token_metadata';
+      collection_id: string;
+      updates: Array<[string, TokenMetadata]>;
+    }
   | { type: 'withdraw_unclaimed_refunds'; collection_id: string }
+  | {
+      type: 'release_refund_escrow';
+      collection_id: string;
+      token_ids: string[];
+    }
   | {
       type: 'cancel_collection';
       collection_id: string;
@@ -239,6 +276,10 @@ export type ScarcesAction =
       price: string;
       expires_at?: number;
     }
+  | {
+      type: 'batch_list_native_scarce';
+      listings: Array<{ token_id: string; price: string; expires_at?: number }>;
+    }
   | { type: 'delist_native_scarce'; token_id: string }
   | {
       type: 'list_native_scarce_auction';
@@ -248,6 +289,7 @@ export type ScarcesAction =
       expires_at?: number;
       auction_duration_ns?: number;
       anti_snipe_extension_ns?: number;
+      anti_snipe_window_ns?: number;
       buy_now_price?: string;
     }
   | { type: 'settle_auction'; token_id: string }
@@ -264,6 +306,8 @@ export type ScarcesAction =
       collection_id: string;
       quantity: number;
       max_price_per_token: string;
+      allowlist_proof?: AllowlistProof;
+      refund_if_exhausted?: boolean;
     }
   | { type: 'purchase_lazy_listing'; listing_id: string }
   | { type: 'purchase_native_scarce'; token_id: string }
@@ -290,16 +334,27 @@ export type ScarcesAction =
       token_id: string;
       buyer_id: string;
     }
+  | {
+      type: 'mint_and_accept_collection_offer';
+      collection_id: string;
+      buyer_id?: string;
+    }
   // Lazy listings
   | {
       type: 'create_lazy_listing';
       metadata: TokenMetadata;
       price: string;
+      /** FT contract the price is denominated in; buy via `ft_transfer_call`. */
+      payment_token?: string;
       royalty?: Record<string, number>;
       app_id?: string;
       transferable?: boolean;
       burnable?: boolean;
       expires_at?: number;
+      /** Declining price: starts here and falls linearly to `price` at `decay_end`. */
+      start_price?: string;
+      decay_start?: number;
+      decay_end?: number;
     }
   | { type: 'cancel_lazy_listing'; listing_id: string }
   | { type: 'update_lazy_listing_price'; listing_id: string; new_price: string }
@@ -319,9 +374,13 @@ export type ScarcesAction =
       primary_sale_bps?: number;
       curated?: boolean;
       metadata?: string;
+      daily_spending_cap?: string;
+      /** Draw buyers' primary-sale storage from the pool (default true). */
+      sponsor_buyer_storage?: boolean;
     }
   | { type: 'set_spending_cap'; cap?: string }
   | { type: 'storage_withdraw' }
+  | { type: 'claim_ft_payout'; ft_contract_id: string }
   | { type: 'withdraw_app_pool'; app_id: string; amount: string }
   | { type: 'withdraw_platform_storage'; amount: string }
   | {
@@ -332,6 +391,9 @@ export type ScarcesAction =
       primary_sale_bps?: number;
       curated?: boolean;
       metadata?: string;
+      daily_spending_cap?: string;
+      /** Draw buyers' primary-sale storage from the pool (default true). */
+      sponsor_buyer_storage?: boolean;
     }
   | { type: 'transfer_app_ownership'; app_id: string; new_owner: string }
   | { type: 'add_moderator'; app_id: string; account_id: string }
@@ -342,7 +404,14 @@ export type ScarcesAction =
       collection_id: string;
       reason?: string;
     }
-  | { type: 'unban_collection'; app_id: string; collection_id: string };
+  | { type: 'unban_collection'; app_id: string; collection_id: string }
+  | { type: 'submit_ban_appeal'; collection_id: string; reason: string }
+  | {
+      type: 'resolve_ban_appeal';
+      collection_id: string;
+      uphold: boolean;
+      note?: string;
+    };
 
 export interface TokenMetadata {
   title: string;
@@ -360,6 +429,12 @@ export interface AllowlistEntry {
   allocation: number;
 }
 
+/** Merkle proof for `sha256("{account_id}:{allocation}")`; hex sibling hashes. */
+export interface AllowlistProof {
+  allocation: number;
+  proof: string[];
+}
+
 // ── Rewards Actions (rewards-onsocial) ──────────────────────────────────────
 
 export type RewardsAction =
@@ -391,6 +466,17 @@ export interface PreparedActionRequest<T extends Action = Action> {
 export interface RequestOptions {
   /** Refund unused deposit to payer instead of crediting actor's storage. */
   refund_unused_deposit?: boolean;
+  /** Reject execution after this block timestamp (ms, U64 string). */
+  deadline_ms?: string;
+  /**
+   * Compare-and-swap guard for `Set`: data key → hex sha256 of its current
+   * canonical JSON, or null if the key must not exist yet.
+   */
+  expected_value_hashes?: Record<string, string | null>;
+  /** App whose pool covers the new bytes first; it must allow the target. */
+  storage_sponsor?: string;
+  /** Record the writes in their `parent`'s reverse index. */
+  index_references?: boolean;
 }
 
 /** Mirrors `Request` envelope from contracts/core-onsocial. */
@@ -405,6 +491,16 @@ export function buildOptions(opts: RequestOptions): RequestOptions {
   if (opts.refund_unused_deposit !== undefined) {
     out.refund_unused_deposit = opts.refund_unused_deposit;
   }
+  if (opts.deadline_ms !== undefined) out.deadline_ms = opts.deadline_ms;
+  if (opts.expected_value_hashes !== undefined) {
+    out.expected_value_hashes = opts.expected_value_hashes;
+  }
+  if (opts.storage_sponsor !== undefined) {
+    out.storage_sponsor = opts.storage_sponsor;
+  }
+  if (opts.index_references !== undefined) {
+    out.index_references = opts.index_references;
+  }
   return out;
 }
 
@@ -428,6 +524,24 @@ export function buildCoreSetAction(data: Record<string, unknown>): CoreAction {
   return { type: 'set', data };
 }
 
+/**
+ * Write several data paths all-or-nothing. The contract reports every failing
+ * path at once and writes nothing if any fails.
+ */
+export function buildSetAtomicAction(
+  data: Record<string, unknown>
+): CoreAction {
+  return { type: 'set_atomic', data };
+}
+
+/**
+ * Soft-delete every entry under `path` (`post/*`, or a subtree root such as
+ * `post/drafts`). Returns `{ deleted, has_more }`; repeat while `has_more`.
+ */
+export function buildDeleteSubtreeAction(path: string): CoreAction {
+  return { type: 'delete_subtree', path };
+}
+
 export function buildProfileAction(profile: ProfileData): CoreAction {
   return buildCoreSetAction(buildProfileSetData(profile));
 }
@@ -792,6 +906,39 @@ export function buildExpireProposalAction(
   };
 }
 
+/**
+ * Settle an active proposal whose voting period has passed. Anyone may submit
+ * this. Returns the final status, e.g. `rejected_quorum` or `executed`.
+ */
+export function buildFinalizeExpiredProposalAction(
+  groupId: string,
+  proposalId: string
+): CoreAction {
+  return {
+    type: 'finalize_expired_proposal',
+    group_id: groupId,
+    proposal_id: proposalId,
+  };
+}
+
+export function buildDelegateVoteAction(
+  groupId: string,
+  delegate: string
+): CoreAction {
+  return {
+    type: 'delegate_vote',
+    group_id: groupId,
+    delegate,
+  };
+}
+
+export function buildUndelegateVoteAction(groupId: string): CoreAction {
+  return {
+    type: 'undelegate_vote',
+    group_id: groupId,
+  };
+}
+
 // ── Permission action builders ──────────────────────────────────────────────
 
 /**
@@ -828,6 +975,26 @@ export function buildSetPermissionAction(
   return action;
 }
 
+/**
+ * Remove `owner`'s expired account permissions. Anyone may submit this.
+ * Returns `{ swept, next_key }`; pass `next_key` back as `fromKey` until null.
+ */
+export function buildSweepExpiredPermissionsAction(
+  owner: string,
+  limit: number,
+  fromKey?: string
+): CoreAction {
+  const action: CoreAction = {
+    type: 'sweep_expired_permissions',
+    owner,
+    limit,
+  };
+  if (fromKey !== undefined) {
+    action.from_key = fromKey;
+  }
+  return action;
+}
+
 export interface SetKeyPermissionOptions {
   /** NEAR-format public key, e.g. `ed25519:<base58>`. */
   publicKey: string;
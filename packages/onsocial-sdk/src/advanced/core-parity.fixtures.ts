@@ -8,6 +8,10 @@ import {
   buildCancelJoinRequestAction,
   buildCancelProposalAction,
   buildExpireProposalAction,
+  buildFinalizeExpiredProposalAction,
+  buildDelegateVoteAction,
+  buildUndelegateVoteAction,
+  buildDeleteSubtreeAction,
   buildCreateGroupAction,
   buildCreateProposalAction,
   buildGroupPostAction,
@@ -20,10 +24,12 @@ import {
   buildRejectJoinRequestAction,
   buildRemoveGroupMemberAction,
   buildReplyAction,
+  buildSetAtomicAction,
   buildSetGroupPrivacyAction,
   buildSetKeyPermissionAction,
   buildSetPermissionAction,
   buildStandWithAction,
+  buildSweepExpiredPermissionsAction,
   buildTransferGroupOwnershipAction,
   buildUnblacklistGroupMemberAction,
   buildUnstandAction,
@@ -45,6 +51,8 @@ export interface CoreParityCase {
  */
 export const ALL_CORE_ACTION_TYPES = [
   'set',
+  'set_atomic',
+  'delete_subtree',
   'create_group',
   'join_group',
   'leave_group',
@@ -61,7 +69,11 @@ export const ALL_CORE_ACTION_TYPES = [
   'vote_on_proposal',
   'cancel_proposal',
   'expire_proposal',
+  'finalize_expired_proposal',
+  'delegate_vote',
+  'undelegate_vote',
   'set_permission',
+  'sweep_expired_permissions',
   'set_key_permission',
 ] as const;
 
@@ -197,6 +209,28 @@ export function getCoreParityCases(
         },
       },
     },
+    {
+      name: 'set atomic',
+      action: buildSetAtomicAction({
+        'post/p-1': { text: 'hello' },
+        'post/index/latest': 'p-1',
+      }),
+      expectedAction: {
+        type: 'set_atomic',
+        data: {
+          'post/p-1': { text: 'hello' },
+          'post/index/latest': 'p-1',
+        },
+      },
+    },
+    {
+      name: 'delete subtree',
+      action: buildDeleteSubtreeAction('post/drafts/*'),
+      expectedAction: {
+        type: 'delete_subtree',
+        path: 'post/drafts/*',
+      },
+    },
     // ── Group lifecycle ─────────────────────────────────────────────────────
     {
       name: 'create group',
@@ -343,6 +377,32 @@ export function getCoreParityCases(
         proposal_id: 'p-1',
       },
     },
+    {
+      name: 'finalize expired proposal',
+      action: buildFinalizeExpiredProposalAction('builders', 'p-1'),
+      expectedAction: {
+        type: 'finalize_expired_proposal',
+        group_id: 'builders',
+        proposal_id: 'p-1',
+      },
+    },
+    {
+      name: 'delegate vote',
+      action: buildDelegateVoteAction('builders', 'bob.near'),
+      expectedAction: {
+        type: 'delegate_vote',
+        group_id: 'builders',
+        delegate: 'bob.near',
+      },
+    },
+    {
+      name: 'undelegate vote',
+      action: buildUndelegateVoteAction('builders'),
+      expectedAction: {
+        type: 'undelegate_vote',
+        group_id: 'builders',
+      },
+    },
     // ── Permissions ────────────────────────────────────────────────────────
     {
       name: 'set account permission',
@@ -360,6 +420,20 @@ export function getCoreParityCases(
         expires_at: '1700000000000',
       },
     },
+    {
+      name: 'sweep expired permissions',
+      action: buildSweepExpiredPermissionsAction(
+        'alice.near',
+        20,
+        'alice.near/permissions/bob.near/profile'
+      ),
+      expectedAction: {
+        type: 'sweep_expired_permissions',
+        owner: 'alice.near',
+        limit: 20,
+        from_key: 'alice.near/permissions/bob.near/profile',
+      },
+    },
     {
       name: 'set key permission',
       action: buildSetKeyPermissionAction({
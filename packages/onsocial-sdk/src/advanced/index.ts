@@ -15,6 +15,7 @@ export type {
   RewardsAction,
   TokenMetadata,
   AllowlistEntry,
+  AllowlistProof,
   ContractName,
   PreparedActionRequest,
 } from './actions.js';
@@ -23,6 +24,8 @@ export {
   PERMISSION_LEVEL,
   resolveContractId,
   buildCoreSetAction,
+  buildSetAtomicAction,
+  buildDeleteSubtreeAction,
   buildProfileAction,
   buildPostAction,
   buildReplyAction,
@@ -56,8 +59,12 @@ export {
   buildVoteOnProposalAction,
   buildCancelProposalAction,
   buildExpireProposalAction,
+  buildFinalizeExpiredProposalAction,
+  buildDelegateVoteAction,
+  buildUndelegateVoteAction,
   // Permissions / session keys
   buildSetPermissionAction,
+  buildSweepExpiredPermissionsAction,
   buildSetKeyPermissionAction,
   buildSessionKeyGrantAction,
   buildRewardsCreditAction,
@@ -0,0 +1,53 @@
+// ---------------------------------------------------------------------------
+// Parity test — request `Options` vs core-onsocial.
+//
+// Reads `contracts/core-onsocial/src/protocol/types.rs` and asserts that a
+// fully populated `RequestOptions` fixture, passed through `buildOptions`,
+// carries exactly the fields of the contract's `Options` struct. Catches the
+// SDK silently dropping an option the contract accepts.
+// ---------------------------------------------------------------------------
+
+import { describe, expect, it } from 'vitest';
+import { readFileSync, existsSync } from 'node:fs';
+import { dirname, resolve } from 'node:path';
+import { fileURLToPath } from 'node:url';
+import { buildOptions, type RequestOptions } from './actions.js';
+
+const HERE = dirname(fileURLToPath(import.meta.url));
+const CONTRACT_SRC = resolve(
+  HERE,
+  '../../../../contracts/core-onsocial/src/protocol/types.rs'
+);
+
+const FULL_OPTIONS: Required<RequestOptions> = {
+  refund_unused_deposit: true,
+  deadline_ms: '1700000000000',
+  expected_value_hashes: { 'profile/name': null },
+  storage_sponsor: 'app.testnet',
+  index_references: true,
+};
+
+function contractOptionFields(): string[] {
+  const src = readFileSync(CONTRACT_SRC, 'utf8');
+  const body = src.match(/pub struct Options\s*\{([\s\S]*?)\n\}/);
+  if (!body) {
+    throw new Error(`pub struct Options not found in ${CONTRACT_SRC}`);
+  }
+  return [...body[1].matchAll(/^\s*pub\s+(\w+)\s*:/gm)].map((m) => m[1]);
+}
+
+describe('Options SDK ↔ contract parity', () => {
+  it('contract source file exists', () => {
+    expect(existsSync(CONTRACT_SRC)).toBe(true);
+  });
+
+  it('buildOptions keeps every contract option field', () => {
+    expect(Object.keys(buildOptions(FULL_OPTIONS)).sort()).toEqual(
+      contractOptionFields().sort()
+    );
+  });
+
+  it('buildOptions passes values through unchanged', () => {
+    expect(buildOptions(FULL_OPTIONS)).toEqual(FULL_OPTIONS);
+  });
+});
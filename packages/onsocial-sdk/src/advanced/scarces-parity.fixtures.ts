@@ -44,6 +44,7 @@ export const ALL_SCARCES_ACTION_TYPES = [
   'revoke_scarce',
   'revoke_all_scarce',
   'burn_scarce',
+  'batch_burn',
   'renew_token',
   'revoke_token',
   'redeem_token',
@@ -57,13 +58,18 @@ export const ALL_SCARCES_ACTION_TYPES = [
   'delete_collection',
   'pause_collection',
   'resume_collection',
+  'freeze_collection',
+  'reveal_collection',
   'set_allowlist',
   'remove_from_allowlist',
   'set_collection_metadata',
   'set_collection_app_metadata',
+  'batch_update_token_metadata',
   'withdraw_unclaimed_refunds',
+  'release_refund_escrow',
   // sale / auction
   'list_native_scarce',
+  'batch_list_native_scarce',
   'delist_native_scarce',
   'list_native_scarce_auction',
   'settle_auction',
@@ -75,6 +81,7 @@ export const ALL_SCARCES_ACTION_TYPES = [
   'cancel_offer',
   'accept_collection_offer',
   'cancel_collection_offer',
+  'mint_and_accept_collection_offer',
   // lazy listings
   'create_lazy_listing',
   'cancel_lazy_listing',
@@ -94,6 +101,7 @@ export const ALL_SCARCES_ACTION_TYPES = [
   'register_app',
   'set_spending_cap',
   'storage_withdraw',
+  'claim_ft_payout',
   'withdraw_app_pool',
   'withdraw_platform_storage',
   // app admin
@@ -103,6 +111,8 @@ export const ALL_SCARCES_ACTION_TYPES = [
   'remove_moderator',
   'ban_collection',
   'unban_collection',
+  'submit_ban_appeal',
+  'resolve_ban_appeal',
 ] as const;
 
 export function getScarcesParityCases(
@@ -218,6 +228,11 @@ export function getScarcesParityCases(
         collection_id: 'genesis',
       },
     },
+    {
+      name: 'batch burn',
+      action: { type: 'batch_burn', token_ids: ['t1', 't2'] },
+      expectedAction: { type: 'batch_burn', token_ids: ['t1', 't2'] },
+    },
     {
       name: 'renew token',
       action: {
@@ -412,6 +427,16 @@ export function getScarcesParityCases(
       action: { type: 'resume_collection', collection_id: 'genesis' },
       expectedAction: { type: 'resume_collection', collection_id: 'genesis' },
     },
+    {
+      name: 'freeze collection',
+      action: { type: 'freeze_collection', collection_id: 'genesis' },
+      expectedAction: { type: 'freeze_collection', collection_id: 'genesis' },
+    },
+    {
+      name: 'reveal collection',
+      action: { type: 'reveal_collection', collection_id: 'genesis' },
+      expectedAction: { type: 'reveal_collection', collection_id: 'genesis' },
+    },
     {
       name: 'set allowlist',
       action: {
@@ -472,6 +497,19 @@ export function getScarcesParityCases(
         metadata: '{"category":"art"}',
       },
     },
+    {
+      name: 'batch update token metadata',
+      action: {
+        type: 'batch_update_token_metadata',
+        collection_id: 'genesis',
+        updates: [['genesis:1', { title: 'Revealed #1' }]],
+      },
+      expectedAction: {
+        type: 'batch_update_token_metadata',
+        collection_id: 'genesis',
+        updates: [['genesis:1', { title: 'Revealed #1' }]],
+      },
+    },
     {
       name: 'withdraw unclaimed refunds',
       action: {
@@ -483,6 +521,19 @@ export function getScarcesParityCases(
         collection_id: 'genesis',
       },
     },
+    {
+      name: 'release refund escrow',
+      action: {
+        type: 'release_refund_escrow',
+        collection_id: 'genesis',
+        token_ids: ['genesis:1', 'genesis:2'],
+      },
+      expectedAction: {
+        type: 'release_refund_escrow',
+        collection_id: 'genesis',
+        token_ids: ['genesis:1', 'genesis:2'],
+      },
+    },
 
     // ── Sale / auction ──────────────────────────────────────────────────────
     {
@@ -512,6 +563,31 @@ export function getScarcesParityCases(
         expires_at: 1_999_000_000_000_000_000,
       },
     },
+    {
+      name: 'batch list native scarce',
+      action: {
+        type: 'batch_list_native_scarce',
+        listings: [
+          { token_id: 't1', price: '5000000000000000000000000' },
+          {
+            token_id: 't2',
+            price: '2000000000000000000000000',
+            expires_at: 1_999_000_000_000_000_000,
+          },
+        ],
+      },
+      expectedAction: {
+        type: 'batch_list_native_scarce',
+        listings: [
+          { token_id: 't1', price: '5000000000000000000000000' },
+          {
+            token_id: 't2',
+            price: '2000000000000000000000000',
+            expires_at: 1_999_000_000_000_000_000,
+          },
+        ],
+      },
+    },
     {
       name: 'delist native scarce',
       action: { type: 'delist_native_scarce', token_id: 't1' },
@@ -542,6 +618,7 @@ export function getScarcesParityCases(
         expires_at: 1_999_000_000_000_000_000,
         auction_duration_ns: 86_400_000_000_000,
         anti_snipe_extension_ns: 300_000_000_000,
+        anti_snipe_window_ns: 60_000_000_000,
         buy_now_price: '5000000000000000000000000',
       },
       expectedAction: {
@@ -552,6 +629,7 @@ export function getScarcesParityCases(
         expires_at: 1_999_000_000_000_000_000,
         auction_duration_ns: 86_400_000_000_000,
         anti_snipe_extension_ns: 300_000_000_000,
+        anti_snipe_window_ns: 60_000_000_000,
         buy_now_price: '5000000000000000000000000',
       },
     },
@@ -636,6 +714,19 @@ export function getScarcesParityCases(
         collection_id: 'genesis',
       },
     },
+    {
+      name: 'mint and accept collection offer',
+      action: {
+        type: 'mint_and_accept_collection_offer',
+        collection_id: 'genesis',
+        buyer_id: 'bob.near',
+      },
+      expectedAction: {
+        type: 'mint_and_accept_collection_offer',
+        collection_id: 'genesis',
+        buyer_id: 'bob.near',
+      },
+    },
 
     // ── Lazy listings ───────────────────────────────────────────────────────
     {
@@ -729,6 +820,33 @@ export function getScarcesParityCases(
         max_price_per_token: '2000000000000000000000000',
       },
     },
+    {
+      name: 'purchase from collection with allowlist proof',
+      action: {
+        type: 'purchase_from_collection',
+        collection_id: 'genesis',
+        quantity: 1,
+        max_price_per_token: '2000000000000000000000000',
+        allowlist_proof: {
+          allocation: 2,
+          proof: [
+            '9f2c6d1e4b7a8c3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a1b0c9d',
+          ],
+        },
+      },
+      expectedAction: {
+        type: 'purchase_from_collection',
+        collection_id: 'genesis',
+        quantity: 1,
+        max_price_per_token: '2000000000000000000000000',
+        allowlist_proof: {
+          allocation: 2,
+          proof: [
+            '9f2c6d1e4b7a8c3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a1b0c9d',
+          ],
+        },
+      },
+    },
     {
       name: 'purchase lazy listing',
       action: { type: 'purchase_lazy_listing', listing_id: 'l1' },
@@ -856,6 +974,11 @@ export function getScarcesParityCases(
       action: { type: 'storage_withdraw' },
       expectedAction: { type: 'storage_withdraw' },
     },
+    {
+      name: 'claim ft payout',
+      action: { type: 'claim_ft_payout', ft_contract_id: 'usdc.near' },
+      expectedAction: { type: 'claim_ft_payout', ft_contract_id: 'usdc.near' },
+    },
     {
       name: 'withdraw app pool',
       action: {
@@ -975,6 +1098,34 @@ export function getScarcesParityCases(
         collection_id: 'genesis',
       },
     },
+    {
+      name: 'submit ban appeal',
+      action: {
+        type: 'submit_ban_appeal',
+        collection_id: 'genesis',
+        reason: 'original work',
+      },
+      expectedAction: {
+        type: 'submit_ban_appeal',
+        collection_id: 'genesis',
+        reason: 'original work',
+      },
+    },
+    {
+      name: 'resolve ban appeal',
+      action: {
+        type: 'resolve_ban_appeal',
+        collection_id: 'genesis',
+        uphold: false,
+        note: 'ban overturned',
+      },
+      expectedAction: {
+        type: 'resolve_ban_appeal',
+        collection_id: 'genesis',
+        uphold: false,
+        note: 'ban overturned',
+      },
+    },
   ];
 
   return cases.map(({ name, action, expectedAction }) => ({
@@ -5,6 +5,7 @@ import {
   buildAirdropAction,
   buildBatchTransferAction,
   buildBurnScarceAction,
+  buildBatchBurnAction,
   buildCancelAuctionAction,
   buildCancelCollectionOfferAction,
   buildCancelOfferAction,
@@ -14,10 +15,13 @@ import {
   buildDeleteCollectionAction,
   buildListAuctionAction,
   buildListNativeScarceAction,
+  buildBatchListNativeScarceAction,
   buildMakeCollectionOfferAction,
   buildMakeOfferAction,
+  buildMintAndAcceptCollectionOfferAction,
   buildMintFromCollectionAction,
   buildPauseCollectionAction,
+  buildRevealCollectionAction,
   buildPlaceBidAction,
   buildPurchaseFromCollectionAction,
   buildPurchaseLazyListingAction,
@@ -116,6 +120,13 @@ describe('scarces builders — tokens', () => {
       collection_id: 'genesis',
     });
   });
+
+  it('batch_burn wraps the token id list', () => {
+    expect(buildBatchBurnAction(['1', '2'])).toEqual({
+      type: 'batch_burn',
+      token_ids: ['1', '2'],
+    });
+  });
 });
 
 describe('scarces builders — collections', () => {
@@ -190,6 +201,13 @@ describe('scarces builders — collections', () => {
     expect(buildDeleteCollectionAction('g').type).toBe('delete_collection');
     expect(buildPauseCollectionAction('g').collection_id).toBe('g');
   });
+
+  it('reveal_collection carries collection_id', () => {
+    expect(buildRevealCollectionAction('g')).toEqual({
+      type: 'reveal_collection',
+      collection_id: 'g',
+    });
+  });
 });
 
 describe('scarces builders — market', () => {
@@ -208,6 +226,21 @@ describe('scarces builders — market', () => {
     });
   });
 
+  it('batch_list_native_scarce wraps per-token listings', () => {
+    expect(
+      buildBatchListNativeScarceAction([
+        { tokenId: '1', priceNear: '1' },
+        { tokenId: '2', priceNear: '0.5', expiresAt: '9' },
+      ])
+    ).toEqual({
+      type: 'batch_list_native_scarce',
+      listings: [
+        { token_id: '1', price: '1000000000000000000000000' },
+        { token_id: '2', price: '500000000000000000000000', expires_at: 9 },
+      ],
+    });
+  });
+
   it('delist + purchase carry only token_id', () => {
     expect(buildDelistNativeScarceAction('1')).toEqual({
       type: 'delist_native_scarce',
@@ -296,6 +329,10 @@ describe('scarces builders — offers', () => {
       token_id: '1',
       buyer_id: 'b.near',
     });
+    expect(buildMintAndAcceptCollectionOfferAction('g')).toEqual({
+      type: 'mint_and_accept_collection_offer',
+      collection_id: 'g',
+    });
   });
 });
 
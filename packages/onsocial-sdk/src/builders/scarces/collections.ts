@@ -58,13 +58,17 @@ export function buildMintFromCollectionAction(
 export function buildPurchaseFromCollectionAction(
   collectionId: string,
   maxPricePerTokenNear: string,
-  quantity = 1
+  quantity = 1,
+  allowlistProof?: { allocation: number; proof: string[] },
+  refundIfExhausted = false
 ) {
   return {
     type: 'purchase_from_collection' as const,
     collection_id: collectionId,
     quantity,
     max_price_per_token: nearToYocto(maxPricePerTokenNear),
+    ...(allowlistProof ? { allowlist_proof: allowlistProof } : {}),
+    ...(refundIfExhausted ? { refund_if_exhausted: true } : {}),
   };
 }
 
@@ -90,6 +94,13 @@ export function buildResumeCollectionAction(collectionId: string) {
   };
 }
 
+export function buildRevealCollectionAction(collectionId: string) {
+  return {
+    type: 'reveal_collection' as const,
+    collection_id: collectionId,
+  };
+}
+
 export function buildDeleteCollectionAction(collectionId: string) {
   return {
     type: 'delete_collection' as const,
@@ -22,6 +22,7 @@ export {
   buildTransferScarceAction,
   buildBatchTransferAction,
   buildBurnScarceAction,
+  buildBatchBurnAction,
 } from './tokens.js';
 export type { BatchTransferEntry } from './tokens.js';
 
@@ -32,11 +33,13 @@ export {
   buildAirdropAction,
   buildPauseCollectionAction,
   buildResumeCollectionAction,
+  buildRevealCollectionAction,
   buildDeleteCollectionAction,
 } from './collections.js';
 
 export {
   buildListNativeScarceAction,
+  buildBatchListNativeScarceAction,
   buildDelistNativeScarceAction,
   buildPurchaseNativeScarceAction,
 } from './market.js';
@@ -55,6 +58,7 @@ export {
   buildMakeCollectionOfferAction,
   buildCancelCollectionOfferAction,
   buildAcceptCollectionOfferAction,
+  buildMintAndAcceptCollectionOfferAction,
 } from './offers.js';
 
 export {
@@ -16,6 +16,20 @@ export function buildListNativeScarceAction(opts: ListingOptions) {
   };
 }
 
+/** Lists several owned tokens at once; the contract rejects the whole batch if any token isn't the caller's. */
+export function buildBatchListNativeScarceAction(listings: ListingOptions[]) {
+  return {
+    type: 'batch_list_native_scarce' as const,
+    listings: listings.map((opts) => ({
+      token_id: opts.tokenId,
+      price: nearToYocto(opts.priceNear),
+      ...(parseOptionalU64(opts.expiresAt) != null
+        ? { expires_at: parseOptionalU64(opts.expiresAt) }
+        : {}),
+    })),
+  };
+}
+
 export function buildDelistNativeScarceAction(tokenId: string) {
   return {
     type: 'delist_native_scarce' as const,
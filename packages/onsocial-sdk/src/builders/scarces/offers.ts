@@ -61,3 +61,15 @@ export function buildAcceptCollectionOfferAction(
     buyer_id: buyerId,
   };
 }
+
+/** Creator mints the next token and fills `buyerId`'s offer, or the best live one. */
+export function buildMintAndAcceptCollectionOfferAction(
+  collectionId: string,
+  buyerId?: string
+) {
+  return {
+    type: 'mint_and_accept_collection_offer' as const,
+    collection_id: collectionId,
+    ...(buyerId ? { buyer_id: buyerId } : {}),
+  };
+}
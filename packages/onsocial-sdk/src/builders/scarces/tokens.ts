@@ -48,3 +48,10 @@ export function buildBurnScarceAction(tokenId: string, collectionId?: string) {
     ...(collectionId ? { collection_id: collectionId } : {}),
   };
 }
+
+export function buildBatchBurnAction(tokenIds: string[]) {
+  return {
+    type: 'batch_burn' as const,
+    token_ids: tokenIds,
+  };
+}
@@ -66,14 +66,17 @@ export {
   buildTransferScarceAction,
   buildBatchTransferAction,
   buildBurnScarceAction,
+  buildBatchBurnAction,
   buildCreateCollectionAction,
   buildMintFromCollectionAction,
   buildPurchaseFromCollectionAction,
   buildAirdropAction,
   buildPauseCollectionAction,
   buildResumeCollectionAction,
+  buildRevealCollectionAction,
   buildDeleteCollectionAction,
   buildListNativeScarceAction,
+  buildBatchListNativeScarceAction,
   buildDelistNativeScarceAction,
   buildPurchaseNativeScarceAction,
   buildListAuctionAction,
@@ -86,6 +89,7 @@ export {
   buildMakeCollectionOfferAction,
   buildCancelCollectionOfferAction,
   buildAcceptCollectionOfferAction,
+  buildMintAndAcceptCollectionOfferAction,
   buildCreateLazyListingAction,
   buildPurchaseLazyListingAction,
   extractPostMedia,
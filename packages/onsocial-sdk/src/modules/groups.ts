@@ -645,6 +645,25 @@ export class GroupsModule {
     });
   }
 
+  /** Follow `delegate`'s ballot on proposals you have not voted on yourself. */
+  async delegateVote(
+    groupId: string,
+    delegate: string
+  ): Promise<RelayResponse> {
+    return this.execute({
+      type: 'delegate_vote',
+      group_id: groupId,
+      delegate,
+    });
+  }
+
+  async undelegateVote(groupId: string): Promise<RelayResponse> {
+    return this.execute({
+      type: 'undelegate_vote',
+      group_id: groupId,
+    });
+  }
+
   // ── View reads ────────────────────────────────────────────────────────
 
   async getConfig(groupId: string): Promise<Record<string, unknown> | null> {
@@ -89,7 +89,8 @@ function toAmount(input: AmountInput): NearAmount {
  * **Reads** are pure HTTP view calls.
  *
  * **Storage admin writes** (`withdraw`, `tip`, `sponsor`, `unsponsor`,
- *  `setSponsorQuota`, `setSponsorDefault`) are contract-reserved operations.
+ *  `setSponsorQuota`, `setSponsorDefault`, `registerAppPool`,
+ *  `withdrawSharedPool`) are contract-reserved operations.
  *  They route through `execute_admin`, so they require an explicit wallet
  *  broadcast target (`defaultBroadcast: { kind: 'wallet', signer }`).
  *
@@ -290,6 +291,51 @@ export class StorageAccountModule {
     );
   }
 
+  /**
+   * Register the caller as an app whose shared pool sponsors writes that
+   * name it in `options.storage_sponsor`, up to `maxBytesPerUser` each.
+   * Fund the pool with `fundSharedPool`; allow accounts with `setAppPoolSponsor`.
+   */
+  registerAppPool(
+    args: { maxBytesPerUser: number },
+    opts?: WriteOptions
+  ): Promise<RelayResponse> {
+    return this._adminSet(
+      'storage/app_pool_register',
+      { max_bytes_per_user: args.maxBytesPerUser },
+      opts
+    );
+  }
+
+  /**
+   * Allow (or stop allowing) `accountId` to name the caller's app pool in
+   * `options.storage_sponsor`. Writes from other accounts are rejected.
+   */
+  setAppPoolSponsor(
+    accountId: string,
+    enabled: boolean,
+    opts?: WriteOptions
+  ): Promise<RelayResponse> {
+    return this._adminSet(
+      'storage/app_pool_sponsor',
+      { target_id: accountId, enabled },
+      opts
+    );
+  }
+
+  /**
+   * Withdraw unused funds from the caller's own shared pool. Omit `amount`
+   * to withdraw everything not backing used or shared bytes.
+   */
+  withdrawSharedPool(
+    amount?: AmountInput,
+    opts?: WriteOptions
+  ): Promise<RelayResponse> {
+    const data: Record<string, unknown> = {};
+    if (amount !== undefined) data.amount = toAmount(amount);
+    return this._adminSet('storage/shared_pool_withdraw', data, opts);
+  }
+
   // ── Deposit-funded writes (signer required) ───────────────────────────
 
   /** Deposit NEAR into caller's storage record. Requires signer. */
@@ -46,6 +46,7 @@ export const SCARCE_OPERATIONS = [
   'list_native',
   'delist_native',
   'auto_delist',
+  'sale_expired',
   'renew',
   'revoke',
   'redeem',
@@ -55,9 +56,12 @@ export const SCARCE_OPERATIONS = [
   'all_approvals_revoked',
   'auction_created',
   'auction_bid',
+  'auction_extended',
   'auction_settled',
   'auction_cancelled',
+  'auction_unsold',
   'quick_mint',
+  'metadata_update',
 ] as const;
 
 /** COLLECTION_UPDATE family operations. */
@@ -76,10 +80,16 @@ export const COLLECTION_OPERATIONS = [
   'resume',
   'ban',
   'unban',
+  'ban_appeal_submitted',
+  'ban_appeal_resolved',
   'allowlist_update',
   'allowlist_remove',
   'price_update',
   'timing_update',
+  'mint_refund_claimed',
+  'mint_failed_refund',
+  'refund_escrow_released',
+  'freeze',
 ] as const;
 
 /** LAZY_LISTING_UPDATE family operations (mint-on-purchase). */
@@ -87,7 +97,7 @@ export const LAZY_LISTING_OPERATIONS = [
   'created',
   'purchased',
   'cancelled',
-  'expired',
+  'expired_cleaned',
   'expiry_updated',
   'price_updated',
 ] as const;
@@ -124,6 +134,8 @@ export const STORAGE_OPERATIONS = [
   'spending_cap_set',
   'wnear_deposit',
   'wnear_unwrap_failed',
+  'ft_payout_failed',
+  'ft_payout_claimed',
 ] as const;
 
 /** CONTRACT_UPDATE family operations (admin / config). */
@@ -135,6 +147,12 @@ export const CONTRACT_OPERATIONS = [
   'contract_metadata_updated',
   'approved_nft_contract_added',
   'approved_nft_contract_removed',
+  'payment_token_added',
+  'payment_token_removed',
+  'known_marketplace_set',
+  'known_marketplace_removed',
+  'price_bounds_set',
+  'price_bounds_cleared',
   'wnear_account_set',
   'platform_storage_funded',
 ] as const;
@@ -679,6 +679,8 @@ export interface OnChainStorageBalance {
   platform_allowance: number;
   platform_last_refill_ns: number;
   locked_balance: string;
+  app_pool_used_bytes?: number;
+  app_pool_ids?: string[];
 }
 
 export interface PlatformPoolInfo {
@@ -712,6 +714,9 @@ export interface GovernanceConfig {
   platform_onboarding_bytes: number;
   platform_daily_refill_bytes: number;
   platform_allowance_max_bytes: number;
+  max_sponsored_bytes_per_account: number | null;
+  max_self_paid_bytes_per_account: number | null;
+  reserved_prefixes: string[];
 }
 
 export interface ContractInfo {
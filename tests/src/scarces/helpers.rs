@@ -863,9 +863,13 @@ pub struct AuctionView {
     pub bid_count: u32,
     pub expires_at: Option<u64>,
     pub anti_snipe_extension_ns: u64,
+    pub anti_snipe_window_ns: u64,
+    pub anti_snipe_extensions: u32,
     pub buy_now_price: Option<String>,
     pub is_ended: bool,
     pub reserve_met: bool,
+    pub min_next_bid: String,
+    pub time_remaining_ns: Option<u64>,
 }
 
 // =============================================================================
@@ -897,6 +901,8 @@ pub struct LazyListingRecord {
     pub creator_id: String,
     pub metadata: TokenMetadata,
     pub price: String,
+    #[serde(default)]
+    pub payment_token: Option<String>,
     pub royalty: Option<std::collections::HashMap<String, u32>>,
     pub app_id: Option<String>,
     pub transferable: bool,
@@ -2469,6 +2475,22 @@ pub async fn mint_wnear(wnear: &Contract, recipient: &Account, amount: u128) ->
     Ok(())
 }
 
+/// Admin: accept `ft_contract` as payment for FT-priced lazy listings.
+pub async fn add_accepted_payment_token(
+    contract: &Contract,
+    owner: &Account,
+    ft_contract: &Contract,
+) -> Result<()> {
+    owner
+        .call(contract.id(), "add_accepted_payment_token")
+        .args_json(json!({ "token_contract_id": ft_contract.id().to_string() }))
+        .deposit(ONE_YOCTO)
+        .transact()
+        .await?
+        .into_result()?;
+    Ok(())
+}
+
 /// Register `account` for storage on the mock-ft contract.
 pub async fn ft_storage_deposit(ft_contract: &Contract, account: &Account) -> Result<()> {
     account
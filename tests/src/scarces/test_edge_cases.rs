@@ -4,7 +4,7 @@
 // Tests for boundary conditions and error paths in the scarces contract:
 //   - BatchTransfer with invalid/soulbound tokens (atomicity)
 //   - Concurrent native sale purchase (second buyer sees "No sale found")
-//   - Collection max supply exhaustion
+//   - Collection max supply exhaustion (and opt-in sold-out refund)
 //   - Expired sale purchase rejection
 //   - Offer expiry enforcement
 
@@ -375,6 +375,66 @@ async fn test_collection_max_supply_exhaustion() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_collection_sold_out_purchase_refund_opt_in() -> Result<()> {
+    let (worker, _owner, contract) = setup().await?;
+    let creator = user_with_storage(&worker, &contract).await?;
+    let buyer = user_with_storage(&worker, &contract).await?;
+    let late_buyer = user_with_storage(&worker, &contract).await?;
+
+    create_collection(
+        &contract,
+        &creator,
+        "last",
+        1,
+        "100000000000000000000000", // 0.1 NEAR
+        json!({ "title": "Last #{id}" }),
+        DEPOSIT_LARGE,
+    )
+    .await?
+    .into_result()?;
+
+    purchase_from_collection(
+        &contract,
+        &buyer,
+        "last",
+        1,
+        "100000000000000000000000",
+        NearToken::from_near(1),
+    )
+    .await?
+    .into_result()?;
+
+    // A buyer who opted in gets a settled refund instead of a reverted call
+    let result = execute_action(
+        &contract,
+        &late_buyer,
+        json!({
+            "type": "purchase_from_collection",
+            "collection_id": "last",
+            "quantity": 1,
+            "max_price_per_token": "100000000000000000000000",
+            "refund_if_exhausted": true,
+        }),
+        NearToken::from_near(1),
+    )
+    .await?;
+    assert!(
+        result
+            .logs()
+            .iter()
+            .any(|l| l.contains("mint_failed_refund")),
+        "Opted-in purchase from a sold-out collection should be refunded"
+    );
+    result.into_result()?;
+    assert_eq!(
+        nft_supply_for_owner(&contract, late_buyer.id().as_str()).await?,
+        "0"
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_collection_supply_boundary() -> Result<()> {
     let (worker, _owner, contract) = setup().await?;
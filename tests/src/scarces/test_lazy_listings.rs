@@ -463,3 +463,167 @@ async fn test_lazy_listing_lifecycle() -> Result<()> {
 
     Ok(())
 }
+
+// =============================================================================
+// FT-priced listings
+// =============================================================================
+
+const FT_PRICE: u128 = 1_000_000_000_000_000_000_000_000;
+
+/// Scarces + accepted mock FT; creator, fee recipient and contract are
+/// registered on the token and `buyer` holds 10x the listing price.
+async fn setup_ft_listing() -> Result<(
+    near_workspaces::Contract,
+    near_workspaces::Contract,
+    near_workspaces::Account,
+    near_workspaces::Account,
+    near_workspaces::Account,
+    String,
+)> {
+    let (worker, owner, contract) = setup().await?;
+    let ft = deploy_mock_wnear(&worker, &owner, 1_000 * FT_PRICE).await?;
+    add_accepted_payment_token(&contract, &owner, &ft).await?;
+
+    let creator = user_with_storage(&worker, &contract).await?;
+    let buyer = user_with_storage(&worker, &contract).await?;
+    for account in [&owner, &creator, &buyer, contract.as_account()] {
+        ft_storage_deposit(&ft, account).await?;
+    }
+    mint_wnear(&ft, &buyer, 10 * FT_PRICE).await?;
+
+    execute_action(
+        &contract,
+        &creator,
+        json!({
+            "type": "create_lazy_listing",
+            "metadata": default_metadata(),
+            "price": FT_PRICE.to_string(),
+            "payment_token": ft.id().to_string(),
+        }),
+        DEPOSIT_LARGE,
+    )
+    .await?
+    .into_result()?;
+
+    let listings = get_lazy_listings_by_creator(&contract, &creator.id().to_string()).await?;
+    let listing_id = listings[0].0.clone();
+    assert_eq!(
+        listings[0].1.payment_token.as_deref(),
+        Some(ft.id().as_str())
+    );
+    Ok((contract, ft, owner, creator, buyer, listing_id))
+}
+
+fn ft_purchase_msg(listing_id: &str) -> String {
+    json!({ "type": "purchase_lazy_listing", "listing_id": listing_id }).to_string()
+}
+
+#[tokio::test]
+async fn test_purchase_lazy_listing_with_ft() -> Result<()> {
+    let (contract, ft, owner, creator, buyer, listing_id) = setup_ft_listing().await?;
+
+    // Overpay by half; the unused part comes back through the token contract.
+    ft_transfer_call(
+        &ft,
+        &buyer,
+        &contract,
+        FT_PRICE + FT_PRICE / 2,
+        &ft_purchase_msg(&listing_id),
+    )
+    .await?
+    .into_result()?;
+
+    let tokens = nft_tokens_for_owner(&contract, &buyer.id().to_string(), None, Some(10)).await?;
+    assert_eq!(tokens.len(), 1);
+    assert!(get_lazy_listing(&contract, &listing_id).await?.is_none());
+
+    assert_eq!(ft_balance_of(&ft, buyer.id().as_str()).await?, 9 * FT_PRICE);
+    let creator_paid = ft_balance_of(&ft, creator.id().as_str()).await?;
+    let fees_paid = ft_balance_of(&ft, owner.id().as_str()).await?;
+    assert!(creator_paid > 0);
+    assert_eq!(creator_paid + fees_paid, FT_PRICE);
+    assert_eq!(ft_balance_of(&ft, contract.id().as_str()).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purchase_lazy_listing_with_ft_underpayment_rejected() -> Result<()> {
+    let (contract, ft, _owner, _creator, buyer, listing_id) = setup_ft_listing().await?;
+
+    let _ = ft_transfer_call(
+        &ft,
+        &buyer,
+        &contract,
+        FT_PRICE - 1,
+        &ft_purchase_msg(&listing_id),
+    )
+    .await?;
+
+    // ft_on_transfer panicked, so the token contract refunded everything.
+    assert_eq!(
+        ft_balance_of(&ft, buyer.id().as_str()).await?,
+        10 * FT_PRICE
+    );
+    assert!(get_lazy_listing(&contract, &listing_id).await?.is_some());
+    let tokens = nft_tokens_for_owner(&contract, &buyer.id().to_string(), None, Some(10)).await?;
+    assert!(tokens.is_empty());
+
+    // A NEAR purchase of an FT-priced listing is rejected as well.
+    let result =
+        purchase_lazy_listing(&contract, &buyer, &listing_id, NearToken::from_near(2)).await?;
+    assert!(result.into_result().is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ft_payout_to_unregistered_creator_is_claimable() -> Result<()> {
+    let (contract, ft, owner, creator, buyer, listing_id) = setup_ft_listing().await?;
+    creator
+        .call(ft.id(), "storage_unregister")
+        .args_json(json!({}))
+        .deposit(ONE_YOCTO)
+        .transact()
+        .await?
+        .into_result()?;
+
+    ft_transfer_call(
+        &ft,
+        &buyer,
+        &contract,
+        FT_PRICE,
+        &ft_purchase_msg(&listing_id),
+    )
+    .await?
+    .into_result()?;
+
+    // The creator's ft_transfer failed; its share stays owed, not stranded.
+    let fees_paid = ft_balance_of(&ft, owner.id().as_str()).await?;
+    let owed: String = contract
+        .view("get_ft_claimable")
+        .args_json(json!({
+            "ft_contract_id": ft.id().to_string(),
+            "account_id": creator.id().to_string(),
+        }))
+        .await?
+        .json()?;
+    let owed: u128 = owed.parse()?;
+    assert_eq!(owed, FT_PRICE - fees_paid);
+    assert_eq!(ft_balance_of(&ft, contract.id().as_str()).await?, owed);
+
+    ft_storage_deposit(&ft, &creator).await?;
+    execute_action(
+        &contract,
+        &creator,
+        json!({ "type": "claim_ft_payout", "ft_contract_id": ft.id().to_string() }),
+        ONE_YOCTO,
+    )
+    .await?
+    .into_result()?;
+
+    assert_eq!(ft_balance_of(&ft, creator.id().as_str()).await?, owed);
+    assert_eq!(ft_balance_of(&ft, contract.id().as_str()).await?, 0);
+
+    Ok(())
+}
@@ -85,6 +85,14 @@ async fn setup_mock_ft_contract(
         .await?
         .into_result()?;
 
+    // Staking suites transfer into the staking contract without registering it on the FT.
+    contract
+        .call("set_allow_unregistered_receivers")
+        .args_json(json!({ "allow": true }))
+        .transact()
+        .await?
+        .into_result()?;
+
     Ok(contract)
 }
 
@@ -8978,7 +8978,7 @@ async fn test_update_config_via_manager_contract() -> anyhow::Result<()> {
     let proxy_contract = proxy_account.deploy(&proxy_wasm).await?.into_result()?;
     proxy_account
         .call(proxy_contract.id(), "new")
-        .args_json(json!({}))
+        .args_json(json!({ "owner_id": proxy_account.id() }))
         .transact()
         .await?
         .into_result()?;